@@ -0,0 +1,241 @@
+//! AEGIS-128L
+
+use crate::{
+    and, decrypt_in_place_detached, encrypt_in_place_detached, xor, xor_in_place, Block, Key,
+    Nonce, Tag, TagSize, C0, C1,
+};
+use aead::{
+    consts::{U0, U16, U32},
+    generic_array::GenericArray,
+    AeadCore, AeadInPlace, Error, NewAead,
+};
+use aes::{hazmat::cipher_round_par, ParBlocks};
+use core::marker::PhantomData;
+use zeroize::Zeroize;
+
+/// AEGIS-128L: 128-bit key, 128-bit nonce, and 256-bit blocks processed per
+/// state update.
+///
+/// AEGIS-128L is the fastest variant on CPUs with AES instructions and the
+/// recommended choice for high-throughput applications.
+///
+/// Type parameters:
+/// - `T`: size of the authentication tag, valid values: `U16`, `U32`.
+#[derive(Clone)]
+pub struct Aegis128L<T = U16>
+where
+    T: TagSize,
+{
+    /// Secret key
+    key: Key<U16>,
+
+    /// Size of the tag
+    tag_size: PhantomData<T>,
+}
+
+impl<T> NewAead for Aegis128L<T>
+where
+    T: TagSize,
+{
+    type KeySize = U16;
+
+    fn new(key: &Key<U16>) -> Self {
+        Self {
+            key: *key,
+            tag_size: PhantomData,
+        }
+    }
+}
+
+impl<T> AeadCore for Aegis128L<T>
+where
+    T: TagSize,
+{
+    type NonceSize = U16;
+    type TagSize = T;
+    type CiphertextOverhead = U0;
+}
+
+impl<T> AeadInPlace for Aegis128L<T>
+where
+    T: TagSize,
+{
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<U16>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<T>, Error> {
+        encrypt_in_place_detached(State::new(&self.key, nonce), associated_data, buffer)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<U16>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<T>,
+    ) -> Result<(), Error> {
+        decrypt_in_place_detached(State::new(&self.key, nonce), associated_data, buffer, tag)
+    }
+}
+
+impl<T> Drop for Aegis128L<T>
+where
+    T: TagSize,
+{
+    fn drop(&mut self) {
+        self.key.as_mut_slice().zeroize();
+    }
+}
+
+/// AEGIS-128L state: eight AES blocks
+struct State {
+    s: ParBlocks,
+}
+
+impl State {
+    /// Initialize the state with the given key and nonce
+    fn new(key: &Key<U16>, nonce: &Nonce<U16>) -> Self {
+        let mut state = Self {
+            s: load(key, nonce),
+        };
+
+        for _ in 0..10 {
+            update(&mut state.s, nonce, key);
+        }
+
+        state
+    }
+}
+
+impl crate::State for State {
+    type Rate = U32;
+
+    fn absorb(&mut self, ai: &GenericArray<u8, U32>) {
+        let (t0, t1) = ai.split_at(16);
+        update(&mut self.s, Block::from_slice(t0), Block::from_slice(t1));
+    }
+
+    fn enc(&mut self, xi: &mut GenericArray<u8, U32>) {
+        let (z0, z1) = keystream(&self.s);
+        let (t0, t1) = xi.split_at_mut(16);
+        let (t0, t1) = (Block::from_mut_slice(t0), Block::from_mut_slice(t1));
+        let (m0, m1) = (*t0, *t1);
+        xor_in_place(t0, &z0);
+        xor_in_place(t1, &z1);
+        update(&mut self.s, &m0, &m1);
+    }
+
+    fn dec(&mut self, ci: &mut GenericArray<u8, U32>) {
+        let (z0, z1) = keystream(&self.s);
+        let (t0, t1) = ci.split_at_mut(16);
+        let (t0, t1) = (Block::from_mut_slice(t0), Block::from_mut_slice(t1));
+        xor_in_place(t0, &z0);
+        xor_in_place(t1, &z1);
+        update(&mut self.s, t0, t1);
+    }
+
+    fn dec_partial(&mut self, cn: &mut [u8]) {
+        let (z0, z1) = keystream(&self.s);
+        let mut block = GenericArray::<u8, U32>::default();
+        block[..cn.len()].copy_from_slice(cn);
+        xor_in_place(&mut block[..16], &z0);
+        xor_in_place(&mut block[16..], &z1);
+        cn.copy_from_slice(&block[..cn.len()]);
+
+        // Only the plaintext bytes are absorbed, zero-padded to 256 bits
+        block[cn.len()..].iter_mut().for_each(|b| *b = 0);
+        self.absorb(&block);
+        block.as_mut_slice().zeroize();
+    }
+
+    fn finalize(mut self, ad_len_bits: u64, msg_len_bits: u64, tag: &mut [u8]) {
+        let t = length_block(&self.s, ad_len_bits, msg_len_bits);
+
+        for _ in 0..7 {
+            update(&mut self.s, &t, &t);
+        }
+
+        xor_tag(&self.s, tag);
+    }
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        for block in self.s.iter_mut() {
+            block.as_mut_slice().zeroize();
+        }
+    }
+}
+
+// The functions below operate on a single AEGIS-128L state and are shared
+// with the multi-lane AEGIS-128X variants, where each lane is an independent
+// AEGIS-128L state.
+
+/// Load the key and nonce into a state, prior to the initialization rounds
+#[inline(always)]
+pub(crate) fn load(key: &Block, nonce: &Block) -> ParBlocks {
+    let c0 = Block::from(C0);
+    let c1 = Block::from(C1);
+    let key_nonce = xor(key, nonce);
+
+    [
+        key_nonce,
+        c1,
+        c0,
+        c1,
+        key_nonce,
+        xor(key, &c0),
+        xor(key, &c1),
+        xor(key, &c0),
+    ]
+    .into()
+}
+
+/// Update the state, absorbing the two message blocks `m0` and `m1`.
+///
+/// All eight AES rounds are independent, which lets them be computed
+/// with a single parallel invocation of the round function.
+#[inline(always)]
+pub(crate) fn update(s: &mut ParBlocks, m0: &Block, m1: &Block) {
+    let mut blocks: ParBlocks = [s[7], s[0], s[1], s[2], s[3], s[4], s[5], s[6]].into();
+    let mut round_keys = *s;
+    xor_in_place(&mut round_keys[0], m0);
+    xor_in_place(&mut round_keys[4], m1);
+
+    cipher_round_par(&mut blocks, &round_keys);
+    *s = blocks;
+}
+
+/// Keystream for the next 256-bit block
+#[inline(always)]
+pub(crate) fn keystream(s: &ParBlocks) -> (Block, Block) {
+    let z0 = xor(&xor(&s[6], &s[1]), &and(&s[2], &s[3]));
+    let z1 = xor(&xor(&s[2], &s[5]), &and(&s[6], &s[7]));
+    (z0, z1)
+}
+
+/// Block absorbed by the finalization rounds
+#[inline(always)]
+pub(crate) fn length_block(s: &ParBlocks, ad_len_bits: u64, msg_len_bits: u64) -> Block {
+    let mut t = s[2];
+    xor_in_place(&mut t[..8], &ad_len_bits.to_le_bytes());
+    xor_in_place(&mut t[8..], &msg_len_bits.to_le_bytes());
+    t
+}
+
+/// XOR the tag of a finalized state into `tag`, which is either 16 or 32
+/// bytes long.
+#[inline(always)]
+pub(crate) fn xor_tag(s: &ParBlocks, tag: &mut [u8]) {
+    if tag.len() == 16 {
+        let t = xor(&xor(&s[0], &s[1]), &xor(&s[2], &s[3]));
+        xor_in_place(tag, &xor(&t, &xor(&xor(&s[4], &s[5]), &s[6])));
+    } else {
+        let t0 = xor(&xor(&s[0], &s[1]), &xor(&s[2], &s[3]));
+        let t1 = xor(&xor(&s[4], &s[5]), &xor(&s[6], &s[7]));
+        xor_in_place(&mut tag[..16], &t0);
+        xor_in_place(&mut tag[16..], &t1);
+    }
+}
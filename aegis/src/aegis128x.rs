@@ -0,0 +1,213 @@
+//! AEGIS-128X
+
+use crate::{
+    aegis128l::{keystream, length_block, load, update, xor_tag},
+    decrypt_in_place_detached, encrypt_in_place_detached,
+    traits::private::SealedDegree,
+    xor_in_place, Block, Degree, Key, Nonce, Tag, TagSize,
+};
+use aead::{
+    consts::{U0, U16, U2, U4},
+    generic_array::GenericArray,
+    AeadCore, AeadInPlace, Error, NewAead,
+};
+use aes::ParBlocks;
+use core::marker::PhantomData;
+use zeroize::Zeroize;
+
+/// AEGIS-128X with two lanes
+pub type Aegis128X2<T = U16> = Aegis128X<U2, T>;
+
+/// AEGIS-128X with four lanes
+pub type Aegis128X4<T = U16> = Aegis128X<U4, T>;
+
+/// AEGIS-128X: 128-bit key, 128-bit nonce, and `D` interleaved AEGIS-128L
+/// states updated in parallel.
+///
+/// Each state update processes `32 * D` bytes, which lets implementations
+/// with wide vector AES instructions (e.g. VAES/AVX-512) keep all of their
+/// AES units busy. The output differs from [`Aegis128L`][crate::Aegis128L].
+///
+/// Type parameters:
+/// - `D`: number of lanes, valid values: `U2`, `U4`.
+/// - `T`: size of the authentication tag, valid values: `U16`, `U32`.
+#[derive(Clone)]
+pub struct Aegis128X<D, T = U16>
+where
+    D: Degree,
+    T: TagSize,
+{
+    /// Secret key
+    key: Key<U16>,
+
+    /// Number of lanes and size of the tag
+    params: PhantomData<(D, T)>,
+}
+
+impl<D, T> NewAead for Aegis128X<D, T>
+where
+    D: Degree,
+    T: TagSize,
+{
+    type KeySize = U16;
+
+    fn new(key: &Key<U16>) -> Self {
+        Self {
+            key: *key,
+            params: PhantomData,
+        }
+    }
+}
+
+impl<D, T> AeadCore for Aegis128X<D, T>
+where
+    D: Degree,
+    T: TagSize,
+{
+    type NonceSize = U16;
+    type TagSize = T;
+    type CiphertextOverhead = U0;
+}
+
+impl<D, T> AeadInPlace for Aegis128X<D, T>
+where
+    D: Degree,
+    T: TagSize,
+{
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<U16>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<T>, Error> {
+        encrypt_in_place_detached(State::<D>::new(&self.key, nonce), associated_data, buffer)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<U16>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<T>,
+    ) -> Result<(), Error> {
+        decrypt_in_place_detached(
+            State::<D>::new(&self.key, nonce),
+            associated_data,
+            buffer,
+            tag,
+        )
+    }
+}
+
+impl<D, T> Drop for Aegis128X<D, T>
+where
+    D: Degree,
+    T: TagSize,
+{
+    fn drop(&mut self) {
+        self.key.as_mut_slice().zeroize();
+    }
+}
+
+/// AEGIS-128X state: `D` lanes of eight AES blocks each
+struct State<D: Degree> {
+    v: GenericArray<ParBlocks, D>,
+}
+
+impl<D: Degree> State<D> {
+    /// Initialize the state with the given key and nonce
+    fn new(key: &Key<U16>, nonce: &Nonce<U16>) -> Self {
+        let mut v = GenericArray::<ParBlocks, D>::default();
+        for lane in v.iter_mut() {
+            *lane = load(key, nonce);
+        }
+
+        // Lane context: the lane index and the index of the last lane
+        let mut ctx = Block::default();
+        ctx[1] = (D::USIZE - 1) as u8;
+
+        for _ in 0..10 {
+            for (i, lane) in v.iter_mut().enumerate() {
+                ctx[0] = i as u8;
+                xor_in_place(&mut lane[3], &ctx);
+                xor_in_place(&mut lane[7], &ctx);
+                update(lane, nonce, key);
+            }
+        }
+
+        Self { v }
+    }
+
+    /// XOR the keystream for the next `32 * D` bytes into `buf`
+    #[inline(always)]
+    fn apply_keystream(&self, buf: &mut [u8]) {
+        let (t0, t1) = buf.split_at_mut(16 * D::USIZE);
+
+        for (i, lane) in self.v.iter().enumerate() {
+            let (z0, z1) = keystream(lane);
+            xor_in_place(&mut t0[16 * i..16 * (i + 1)], &z0);
+            xor_in_place(&mut t1[16 * i..16 * (i + 1)], &z1);
+        }
+    }
+}
+
+impl<D: Degree> crate::State for State<D> {
+    type Rate = <D as SealedDegree>::Rate;
+
+    fn absorb(&mut self, ai: &GenericArray<u8, Self::Rate>) {
+        let (t0, t1) = ai.split_at(16 * D::USIZE);
+
+        for (i, lane) in self.v.iter_mut().enumerate() {
+            let m0 = Block::from_slice(&t0[16 * i..16 * (i + 1)]);
+            let m1 = Block::from_slice(&t1[16 * i..16 * (i + 1)]);
+            update(lane, m0, m1);
+        }
+    }
+
+    fn enc(&mut self, xi: &mut GenericArray<u8, Self::Rate>) {
+        let mut msg = xi.clone();
+        self.apply_keystream(xi);
+        self.absorb(&msg);
+        msg.as_mut_slice().zeroize();
+    }
+
+    fn dec(&mut self, ci: &mut GenericArray<u8, Self::Rate>) {
+        self.apply_keystream(ci);
+        self.absorb(ci);
+    }
+
+    fn dec_partial(&mut self, cn: &mut [u8]) {
+        let mut block = GenericArray::<u8, Self::Rate>::default();
+        block[..cn.len()].copy_from_slice(cn);
+        self.apply_keystream(&mut block);
+        cn.copy_from_slice(&block[..cn.len()]);
+
+        // Only the plaintext bytes are absorbed, zero-padded to the rate
+        block[cn.len()..].iter_mut().for_each(|b| *b = 0);
+        self.absorb(&block);
+        block.as_mut_slice().zeroize();
+    }
+
+    fn finalize(mut self, ad_len_bits: u64, msg_len_bits: u64, tag: &mut [u8]) {
+        for lane in self.v.iter_mut() {
+            let t = length_block(lane, ad_len_bits, msg_len_bits);
+
+            for _ in 0..7 {
+                update(lane, &t, &t);
+            }
+
+            // The tag is the XOR of the tags of every lane
+            xor_tag(lane, tag);
+        }
+    }
+}
+
+impl<D: Degree> Drop for State<D> {
+    fn drop(&mut self) {
+        for lane in self.v.iter_mut() {
+            for block in lane.iter_mut() {
+                block.as_mut_slice().zeroize();
+            }
+        }
+    }
+}
@@ -0,0 +1,209 @@
+//! AEGIS-256
+
+use crate::{
+    and, decrypt_in_place_detached, encrypt_in_place_detached, xor, xor_in_place, Block, Key,
+    Nonce, Tag, TagSize, C0, C1,
+};
+use aead::{
+    consts::{U0, U16, U32},
+    generic_array::GenericArray,
+    AeadCore, AeadInPlace, Error, NewAead,
+};
+use aes::{hazmat::cipher_round_par, ParBlocks};
+use core::marker::PhantomData;
+use zeroize::Zeroize;
+
+/// AEGIS-256: 256-bit key, 256-bit nonce, and 128-bit blocks processed per
+/// state update.
+///
+/// The 256-bit nonce is large enough to be chosen at random for every
+/// message without running into birthday-bound collisions.
+///
+/// Type parameters:
+/// - `T`: size of the authentication tag, valid values: `U16`, `U32`.
+#[derive(Clone)]
+pub struct Aegis256<T = U16>
+where
+    T: TagSize,
+{
+    /// Secret key
+    key: Key<U32>,
+
+    /// Size of the tag
+    tag_size: PhantomData<T>,
+}
+
+impl<T> NewAead for Aegis256<T>
+where
+    T: TagSize,
+{
+    type KeySize = U32;
+
+    fn new(key: &Key<U32>) -> Self {
+        Self {
+            key: *key,
+            tag_size: PhantomData,
+        }
+    }
+}
+
+impl<T> AeadCore for Aegis256<T>
+where
+    T: TagSize,
+{
+    type NonceSize = U32;
+    type TagSize = T;
+    type CiphertextOverhead = U0;
+}
+
+impl<T> AeadInPlace for Aegis256<T>
+where
+    T: TagSize,
+{
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<U32>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<T>, Error> {
+        encrypt_in_place_detached(State::new(&self.key, nonce), associated_data, buffer)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<U32>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<T>,
+    ) -> Result<(), Error> {
+        decrypt_in_place_detached(State::new(&self.key, nonce), associated_data, buffer, tag)
+    }
+}
+
+impl<T> Drop for Aegis256<T>
+where
+    T: TagSize,
+{
+    fn drop(&mut self) {
+        self.key.as_mut_slice().zeroize();
+    }
+}
+
+/// AEGIS-256 state: six AES blocks
+struct State {
+    s: [Block; 6],
+}
+
+impl State {
+    /// Initialize the state with the given key and nonce
+    fn new(key: &Key<U32>, nonce: &Nonce<U32>) -> Self {
+        let c0 = Block::from(C0);
+        let c1 = Block::from(C1);
+        let k0 = Block::from_slice(&key[..16]);
+        let k1 = Block::from_slice(&key[16..]);
+        let k0n0 = xor(k0, Block::from_slice(&nonce[..16]));
+        let k1n1 = xor(k1, Block::from_slice(&nonce[16..]));
+
+        let mut state = Self {
+            s: [k0n0, k1n1, c1, c0, xor(k0, &c0), xor(k1, &c1)],
+        };
+
+        for _ in 0..4 {
+            state.update(k0);
+            state.update(k1);
+            state.update(&k0n0);
+            state.update(&k1n1);
+        }
+
+        state
+    }
+
+    /// Update the state, absorbing the message block `m`.
+    ///
+    /// The six AES rounds are independent and are computed with a single
+    /// parallel invocation of the round function, leaving two lanes unused.
+    #[inline(always)]
+    fn update(&mut self, m: &Block) {
+        let s = &self.s;
+        let zero = Block::default();
+        let mut blocks: ParBlocks = [s[5], s[0], s[1], s[2], s[3], s[4], zero, zero].into();
+        let round_keys: ParBlocks =
+            [xor(&s[0], m), s[1], s[2], s[3], s[4], s[5], zero, zero].into();
+
+        cipher_round_par(&mut blocks, &round_keys);
+        self.s.copy_from_slice(&blocks[..6]);
+    }
+
+    /// Keystream for the next 128-bit block
+    #[inline(always)]
+    fn keystream(&self) -> Block {
+        let s = &self.s;
+        xor(&xor(&s[1], &s[4]), &xor(&s[5], &and(&s[2], &s[3])))
+    }
+}
+
+impl crate::State for State {
+    type Rate = U16;
+
+    fn absorb(&mut self, ai: &GenericArray<u8, U16>) {
+        self.update(ai);
+    }
+
+    fn enc(&mut self, xi: &mut GenericArray<u8, U16>) {
+        let z = self.keystream();
+        let m = *xi;
+        xor_in_place(xi, &z);
+        self.update(&m);
+    }
+
+    fn dec(&mut self, ci: &mut GenericArray<u8, U16>) {
+        let z = self.keystream();
+        xor_in_place(ci, &z);
+        self.update(ci);
+    }
+
+    fn dec_partial(&mut self, cn: &mut [u8]) {
+        let z = self.keystream();
+        let mut block = Block::default();
+        block[..cn.len()].copy_from_slice(cn);
+        xor_in_place(&mut block, &z);
+        cn.copy_from_slice(&block[..cn.len()]);
+
+        // Only the plaintext bytes are absorbed, zero-padded to 128 bits
+        block[cn.len()..].iter_mut().for_each(|b| *b = 0);
+        self.update(&block);
+        block.as_mut_slice().zeroize();
+    }
+
+    fn finalize(mut self, ad_len_bits: u64, msg_len_bits: u64, tag: &mut [u8]) {
+        let mut t = self.s[3];
+        xor_in_place(&mut t[..8], &ad_len_bits.to_le_bytes());
+        xor_in_place(&mut t[8..], &msg_len_bits.to_le_bytes());
+
+        for _ in 0..7 {
+            self.update(&t);
+        }
+
+        let s = &self.s;
+        if tag.len() == 16 {
+            let t = xor(
+                &xor(&xor(&s[0], &s[1]), &xor(&s[2], &s[3])),
+                &xor(&s[4], &s[5]),
+            );
+            tag.copy_from_slice(&t);
+        } else {
+            let t0 = xor(&xor(&s[0], &s[1]), &s[2]);
+            let t1 = xor(&xor(&s[3], &s[4]), &s[5]);
+            tag[..16].copy_from_slice(&t0);
+            tag[16..].copy_from_slice(&t1);
+        }
+    }
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        for block in self.s.iter_mut() {
+            block.as_mut_slice().zeroize();
+        }
+    }
+}
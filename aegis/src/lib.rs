@@ -0,0 +1,311 @@
+//! [AEGIS][1]: [Authenticated Encryption with Associated Data (AEAD)][2]
+//! ciphers built on the AES round function, as specified in
+//! [draft-irtf-cfrg-aegis-aead][3].
+//!
+//! This crate implements the following variants:
+//!
+//! - [`Aegis128L`]: 128-bit key, 128-bit nonce, 128-bit or 256-bit tag.
+//! - [`Aegis256`]: 256-bit key, 256-bit nonce, 128-bit or 256-bit tag.
+//! - [`Aegis128X2`] and [`Aegis128X4`]: multi-lane variants of AEGIS-128L
+//!   for CPUs with wide vector AES instructions.
+//!
+//! ## Performance Notes
+//!
+//! The AES round function is provided by the [`aes`] crate's `hazmat` API,
+//! which uses AES-NI on x86/x86_64 when it is detected at runtime and falls
+//! back to a portable bitsliced implementation otherwise.
+//!
+//! On aarch64 the ARMv8 Cryptography Extensions can be used by enabling the
+//! nightly-only `armv8` feature of this crate.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! AEGIS is not nonce misuse-resistant: reusing a nonce under the same key
+//! allows an attacker to recover the internal state, which breaks both the
+//! confidentiality and the authenticity of any message encrypted with it.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! Simple usage (allocating, no associated data):
+//!
+//! ```
+//! use aegis::{Aegis128L, Key, Nonce};
+//! use aegis::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher: Aegis128L = Aegis128L::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce 128"); // 128-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! Note that if you enable the `heapless` feature of this crate,
+//! you will receive an impl of [`aead::Buffer`] for `heapless::Vec`
+//! (re-exported from the [`aead`] crate as [`aead::heapless::Vec`]),
+//! which can then be passed as the `buffer` parameter to the in-place encrypt
+//! and decrypt methods:
+//!
+//! ```
+//! # #[cfg(feature = "heapless")]
+//! # {
+//! use aegis::{Aegis128L, Key, Nonce};
+//! use aegis::aead::{AeadInPlace, NewAead};
+//! use aegis::aead::heapless::Vec;
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher: Aegis128L = Aegis128L::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce 128"); // 128-bits; unique per message
+//!
+//! let mut buffer: Vec<u8, 128> = Vec::new(); // Buffer needs 16-bytes overhead for the tag
+//! buffer.extend_from_slice(b"plaintext message");
+//!
+//! // Encrypt `buffer` in-place, replacing the plaintext contents with ciphertext
+//! cipher.encrypt_in_place(nonce, b"", &mut buffer).expect("encryption failure!");
+//!
+//! // `buffer` now contains the message ciphertext
+//! assert_ne!(&buffer, b"plaintext message");
+//!
+//! // Decrypt `buffer` in-place, replacing its ciphertext context with the original plaintext
+//! cipher.decrypt_in_place(nonce, b"", &mut buffer).expect("decryption failure!");
+//! assert_eq!(&buffer, b"plaintext message");
+//! # }
+//! ```
+//!
+//! ## 256-bit Tags
+//!
+//! Every AEGIS variant supports either a 128-bit or a 256-bit authentication
+//! tag, selected with the `T` generic parameter:
+//!
+//! ```
+//! use aegis::{Aegis128L, Key, Nonce};
+//! use aegis::aead::{Aead, NewAead, consts::U32};
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = Aegis128L::<U32>::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce 128");
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref()).unwrap();
+//! assert_eq!(ciphertext.len(), b"plaintext message".len() + 32);
+//! ```
+//!
+//! [1]: https://competitions.cr.yp.to/round3/aegisv11.pdf
+//! [2]: https://en.wikipedia.org/wiki/Authenticated_encryption
+//! [3]: https://datatracker.ietf.org/doc/draft-irtf-cfrg-aegis-aead/
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![deny(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use aead::{self, consts, AeadCore, AeadInPlace, Error, NewAead};
+pub use traits::{Degree, TagSize};
+
+mod aegis128l;
+mod aegis128x;
+mod aegis256;
+mod traits;
+
+use aead::generic_array::{typenum::Unsigned, ArrayLength, GenericArray};
+use subtle::ConstantTimeEq;
+
+pub use aegis128l::Aegis128L;
+pub use aegis128x::{Aegis128X, Aegis128X2, Aegis128X4};
+pub use aegis256::Aegis256;
+
+/// Maximum length of associated data
+pub const A_MAX: u64 = (1 << 61) - 1;
+
+/// Maximum length of plaintext (and of ciphertext, excluding the tag)
+pub const P_MAX: u64 = (1 << 61) - 1;
+
+/// Fibonacci sequence constant `C0`
+const C0: [u8; 16] = [
+    0x00, 0x01, 0x01, 0x02, 0x03, 0x05, 0x08, 0x0d, 0x15, 0x22, 0x37, 0x59, 0x90, 0xe9, 0x79, 0x62,
+];
+
+/// Fibonacci sequence constant `C1`
+const C1: [u8; 16] = [
+    0xdb, 0x3d, 0x18, 0x55, 0x6d, 0xc2, 0x2f, 0xf1, 0x20, 0x11, 0x31, 0x42, 0x73, 0xb5, 0x28, 0xdd,
+];
+
+/// AEGIS keys
+pub type Key<KeySize = consts::U16> = GenericArray<u8, KeySize>;
+
+/// AEGIS nonces
+pub type Nonce<NonceSize = consts::U16> = GenericArray<u8, NonceSize>;
+
+/// AEGIS tags
+pub type Tag<TagSize = consts::U16> = GenericArray<u8, TagSize>;
+
+/// AES block, the unit AEGIS states are built from
+type Block = aes::Block;
+
+/// Internal state of an AEGIS variant, instantiated with a particular key
+/// and nonce.
+trait State {
+    /// Number of message bytes absorbed by a single state update
+    type Rate: ArrayLength<u8>;
+
+    /// Absorb a block of associated data
+    fn absorb(&mut self, ai: &GenericArray<u8, Self::Rate>);
+
+    /// Encrypt a block of plaintext in-place
+    fn enc(&mut self, xi: &mut GenericArray<u8, Self::Rate>);
+
+    /// Decrypt a block of ciphertext in-place
+    fn dec(&mut self, ci: &mut GenericArray<u8, Self::Rate>);
+
+    /// Decrypt the trailing partial block of ciphertext in-place
+    fn dec_partial(&mut self, cn: &mut [u8]);
+
+    /// Compute the authentication tag, filling the whole `tag` slice, which
+    /// must be either 16 or 32 bytes long.
+    fn finalize(self, ad_len_bits: u64, msg_len_bits: u64, tag: &mut [u8]);
+}
+
+/// Encrypt `buffer` in-place using the given freshly initialized state
+fn encrypt_in_place_detached<S, T>(
+    mut state: S,
+    associated_data: &[u8],
+    buffer: &mut [u8],
+) -> Result<Tag<T>, Error>
+where
+    S: State,
+    T: TagSize,
+{
+    if buffer.len() as u64 > P_MAX || associated_data.len() as u64 > A_MAX {
+        return Err(Error);
+    }
+
+    absorb_associated_data(&mut state, associated_data);
+
+    let mut chunks = buffer.chunks_exact_mut(S::Rate::to_usize());
+    for chunk in &mut chunks {
+        state.enc(GenericArray::from_mut_slice(chunk));
+    }
+
+    let rem = chunks.into_remainder();
+    if !rem.is_empty() {
+        let mut block = GenericArray::<u8, S::Rate>::default();
+        block[..rem.len()].copy_from_slice(rem);
+        state.enc(&mut block);
+        rem.copy_from_slice(&block[..rem.len()]);
+    }
+
+    let mut tag = Tag::<T>::default();
+    state.finalize(
+        (associated_data.len() as u64) * 8,
+        (buffer.len() as u64) * 8,
+        &mut tag,
+    );
+    Ok(tag)
+}
+
+/// Decrypt `buffer` in-place using the given freshly initialized state,
+/// zeroing it if the tag does not verify.
+fn decrypt_in_place_detached<S, T>(
+    mut state: S,
+    associated_data: &[u8],
+    buffer: &mut [u8],
+    tag: &Tag<T>,
+) -> Result<(), Error>
+where
+    S: State,
+    T: TagSize,
+{
+    if buffer.len() as u64 > P_MAX || associated_data.len() as u64 > A_MAX {
+        return Err(Error);
+    }
+
+    absorb_associated_data(&mut state, associated_data);
+
+    let mut chunks = buffer.chunks_exact_mut(S::Rate::to_usize());
+    for chunk in &mut chunks {
+        state.dec(GenericArray::from_mut_slice(chunk));
+    }
+
+    let rem = chunks.into_remainder();
+    if !rem.is_empty() {
+        state.dec_partial(rem);
+    }
+
+    let mut expected_tag = Tag::<T>::default();
+    state.finalize(
+        (associated_data.len() as u64) * 8,
+        (buffer.len() as u64) * 8,
+        &mut expected_tag,
+    );
+
+    if expected_tag.ct_eq(tag).unwrap_u8() == 1 {
+        Ok(())
+    } else {
+        // Don't release unauthenticated plaintext
+        buffer.iter_mut().for_each(|b| *b = 0);
+        Err(Error)
+    }
+}
+
+/// Absorb the associated data, zero-padding the last block
+fn absorb_associated_data<S: State>(state: &mut S, associated_data: &[u8]) {
+    let mut chunks = associated_data.chunks_exact(S::Rate::to_usize());
+    for chunk in &mut chunks {
+        state.absorb(GenericArray::from_slice(chunk));
+    }
+
+    let rem = chunks.remainder();
+    if !rem.is_empty() {
+        let mut block = GenericArray::<u8, S::Rate>::default();
+        block[..rem.len()].copy_from_slice(rem);
+        state.absorb(&block);
+    }
+}
+
+#[inline(always)]
+fn xor(a: &Block, b: &Block) -> Block {
+    let mut out = *a;
+    xor_in_place(&mut out, b);
+    out
+}
+
+#[inline(always)]
+fn xor_in_place(a: &mut [u8], b: &[u8]) {
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        *x ^= y;
+    }
+}
+
+#[inline(always)]
+fn and(a: &Block, b: &Block) -> Block {
+    let mut out = *a;
+    for (x, y) in out.iter_mut().zip(b.iter()) {
+        *x &= y;
+    }
+    out
+}
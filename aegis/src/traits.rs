@@ -0,0 +1,38 @@
+use aead::consts::{U128, U16, U2, U32, U4, U64};
+use aead::generic_array::{typenum::Unsigned, ArrayLength};
+use aes::ParBlocks;
+
+pub(crate) mod private {
+    use aead::generic_array::ArrayLength;
+
+    // Sealed traits stop other crates from implementing any traits that use it.
+    pub trait SealedTag {}
+
+    impl SealedTag for super::U16 {}
+    impl SealedTag for super::U32 {}
+
+    pub trait SealedDegree {
+        /// Number of message bytes absorbed by a single update of all lanes
+        type Rate: ArrayLength<u8>;
+    }
+
+    impl SealedDegree for super::U2 {
+        type Rate = super::U64;
+    }
+
+    impl SealedDegree for super::U4 {
+        type Rate = super::U128;
+    }
+}
+
+/// Sizes of the AEGIS authentication tag: `U16` (128-bit) or `U32` (256-bit).
+pub trait TagSize: ArrayLength<u8> + Unsigned + private::SealedTag {}
+
+impl TagSize for U16 {}
+impl TagSize for U32 {}
+
+/// Number of parallel lanes of the AEGIS-X variants: `U2` or `U4`.
+pub trait Degree: ArrayLength<ParBlocks> + Unsigned + private::SealedDegree {}
+
+impl Degree for U2 {}
+impl Degree for U4 {}
@@ -0,0 +1,110 @@
+//! AEGIS-128L tests
+
+#[macro_use]
+extern crate hex_literal;
+
+#[macro_use]
+mod common;
+
+use self::common::{FailVector, TestVector};
+use aegis::aead::{
+    consts::{U16, U32},
+    generic_array::GenericArray,
+    Aead, AeadInPlace, NewAead, Payload,
+};
+use aegis::Aegis128L;
+
+/// AEGIS-128L test vectors
+///
+/// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-aegis-aead/>
+const TEST_VECTORS: &[TestVector<[u8; 16], [u8; 16]>] = &[
+    TestVector {
+        key: &hex!("10010000000000000000000000000000"),
+        nonce: &hex!("10000200000000000000000000000000"),
+        aad: &hex!(""),
+        plaintext: &hex!("00000000000000000000000000000000"),
+        ciphertext: &hex!("c1c0e58bd913006feba00f4b3cc3594e"),
+        tag128: &hex!("abe0ece80c24868a226a35d16bdae37a"),
+        tag256: &hex!("25835bfbb21632176cf03840687cb968cace4617af1bd0f7d064c639a5c79ee4"),
+    },
+    TestVector {
+        key: &hex!("10010000000000000000000000000000"),
+        nonce: &hex!("10000200000000000000000000000000"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag128: &hex!("c2b879a67def9d74e6c14f708bbcc9b4"),
+        tag256: &hex!("1360dc9db8ae42455f6e5b6a9d488ea4f2184c4e12120249335c4ee84bafe25d"),
+    },
+    TestVector {
+        key: &hex!("10010000000000000000000000000000"),
+        nonce: &hex!("10000200000000000000000000000000"),
+        aad: &hex!("0001020304050607"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        ciphertext: &hex!("79d94593d8c2119d7e8fd9b8fc77845c5c077a05b2528b6ac54b563aed8efe84"),
+        tag128: &hex!("cc6f3372f6aa1bb82388d695c3962d9a"),
+        tag256: &hex!("022cb796fe7e0ae1197525ff67e309484cfbab6528ddef89f17d74ef8ecd82b3"),
+    },
+    TestVector {
+        key: &hex!("10010000000000000000000000000000"),
+        nonce: &hex!("10000200000000000000000000000000"),
+        aad: &hex!("0001020304050607"),
+        plaintext: &hex!("000102030405060708090a0b0c0d"),
+        ciphertext: &hex!("79d94593d8c2119d7e8fd9b8fc77"),
+        tag128: &hex!("5c04b3dba849b2701effbe32c7f0fab7"),
+        tag256: &hex!("86f1b80bfb463aba711d15405d094baf4a55a15dbfec81a76f35ed0b9c8b04ac"),
+    },
+    TestVector {
+        key: &hex!("10010000000000000000000000000000"),
+        nonce: &hex!("10000200000000000000000000000000"),
+        aad: &hex!(
+            "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20212223242526272829"
+        ),
+        plaintext: &hex!(
+            "101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f3031323334353637"
+        ),
+        ciphertext: &hex!(
+            "b31052ad1cca4e291abcf2df3502e6bdb1bfd6db36798be3607b1f94d34478aa7ede7f7a990fec10"
+        ),
+        tag128: &hex!("7542a745733014f9474417b337399507"),
+        tag256: &hex!("b91e2947a33da8bee89b6794e647baf0fc835ff574aca3fc27c33be0db2aff98"),
+    },
+];
+
+/// Vectors which must fail verification
+const FAIL_VECTORS: &[FailVector<[u8; 16], [u8; 16]>] = &[
+    FailVector {
+        key: &hex!("10000200000000000000000000000000"),
+        nonce: &hex!("10010000000000000000000000000000"),
+        aad: &hex!("0001020304050607"),
+        ciphertext: &hex!("79d94593d8c2119d7e8fd9b8fc77"),
+        tag128: &hex!("5c04b3dba849b2701effbe32c7f0fab7"),
+        tag256: &hex!("86f1b80bfb463aba711d15405d094baf4a55a15dbfec81a76f35ed0b9c8b04ac"),
+    },
+    FailVector {
+        key: &hex!("10010000000000000000000000000000"),
+        nonce: &hex!("10000200000000000000000000000000"),
+        aad: &hex!("0001020304050607"),
+        ciphertext: &hex!("79d94593d8c2119d7e8fd9b8fc78"),
+        tag128: &hex!("5c04b3dba849b2701effbe32c7f0fab7"),
+        tag256: &hex!("86f1b80bfb463aba711d15405d094baf4a55a15dbfec81a76f35ed0b9c8b04ac"),
+    },
+    FailVector {
+        key: &hex!("10010000000000000000000000000000"),
+        nonce: &hex!("10000200000000000000000000000000"),
+        aad: &hex!("0001020304050608"),
+        ciphertext: &hex!("79d94593d8c2119d7e8fd9b8fc77"),
+        tag128: &hex!("5c04b3dba849b2701effbe32c7f0fab7"),
+        tag256: &hex!("86f1b80bfb463aba711d15405d094baf4a55a15dbfec81a76f35ed0b9c8b04ac"),
+    },
+    FailVector {
+        key: &hex!("10010000000000000000000000000000"),
+        nonce: &hex!("10000200000000000000000000000000"),
+        aad: &hex!("0001020304050607"),
+        ciphertext: &hex!("79d94593d8c2119d7e8fd9b8fc77"),
+        tag128: &hex!("6c04b3dba849b2701effbe32c7f0fab8"),
+        tag256: &hex!("86f1b80bfb463aba711d15405d094baf4a55a15dbfec81a76f35ed0b9c8b04ad"),
+    },
+];
+
+tests!(Aegis128L, TEST_VECTORS, FAIL_VECTORS);
@@ -0,0 +1,41 @@
+//! AEGIS-128X2 tests
+
+#[macro_use]
+extern crate hex_literal;
+
+#[macro_use]
+mod common;
+
+use self::common::TestVector;
+use aegis::aead::{
+    consts::{U16, U32},
+    generic_array::GenericArray,
+    Aead, NewAead, Payload,
+};
+use aegis::Aegis128X2;
+
+/// AEGIS-128X2 test vectors
+///
+/// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-aegis-aead/>
+const TEST_VECTORS: &[TestVector<[u8; 16], [u8; 16]>] = &[
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("101112131415161718191a1b1c1d1e1f"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag128: &hex!("63117dc57756e402819a82e13eca8379"),
+        tag256: &hex!("b92c71fdbd358b8a4de70b27631ace90cffd9b9cfba82028412bac41b4f53759"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("101112131415161718191a1b1c1d1e1f"),
+        aad: &hex!("0102030401020304"),
+        plaintext: &hex!("040506070405060704050607040506070405060704050607040506070405060704050607040506070405060704050607040506070405060704050607040506070405060704050607040506070405060704050607040506070405060704050607040506070405060704050607040506070405060704050607"),
+        ciphertext: &hex!("5795544301997f93621b278809d6331b3bfa6f18e90db12c4aa35965b5e98c5fc6fb4e54bcb6111842c20637252eff747cb3a8f85b37de80919a589fe0f24872bc926360696739e05520647e390989e1eb5fd42f99678a0276a498f8c454761c9d6aacb647ad56be62b29c22cd4b5761b38f43d5a5ee062f"),
+        tag128: &hex!("1aebc200804f405cab637f2adebb6d77"),
+        tag256: &hex!("c471876f9b4978c44f2ae1ce770cdb11a094ee3feca64e7afcd48bfe52c60eca"),
+    },
+];
+
+tests!(Aegis128X2, TEST_VECTORS);
@@ -0,0 +1,41 @@
+//! AEGIS-128X4 tests
+
+#[macro_use]
+extern crate hex_literal;
+
+#[macro_use]
+mod common;
+
+use self::common::TestVector;
+use aegis::aead::{
+    consts::{U16, U32},
+    generic_array::GenericArray,
+    Aead, NewAead, Payload,
+};
+use aegis::Aegis128X4;
+
+/// AEGIS-128X4 test vectors
+///
+/// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-aegis-aead/>
+const TEST_VECTORS: &[TestVector<[u8; 16], [u8; 16]>] = &[
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("101112131415161718191a1b1c1d1e1f"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag128: &hex!("5bef762d0947c00455b97bb3af30dfa3"),
+        tag256: &hex!("a4b25437f4be93cfa856a2f27e4416b42cac79fd4698f2cdbe6af25673e10a68"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("101112131415161718191a1b1c1d1e1f"),
+        aad: &hex!("0102030401020304"),
+        plaintext: &hex!("040506070405060704050607040506070405060704050607040506070405060704050607040506070405060704050607040506070405060704050607040506070405060704050607040506070405060704050607040506070405060704050607040506070405060704050607040506070405060704050607"),
+        ciphertext: &hex!("e836118562f4479c9d35c17356a833114c21f9aa39e4dda5e5c87f4152a00fce9a7c38f832eafe8b1c12f8a7cf12a81a1ad8a9c24ba9dedfbdaa586ffea67ddc801ea97d9ab4a872f42d0e352e2713dacd609f9442c17517c5a29daf3e2a3fac4ff6b1380c4e46df7b086af6ce6bc1ed594b8dd64aed2a7e"),
+        tag128: &hex!("0e56ab94e2e85db80f9d54010caabfb4"),
+        tag256: &hex!("69abf0f64a137dd6e122478d777e98bc422823006cf57f5ee822dd78397230b2"),
+    },
+];
+
+tests!(Aegis128X4, TEST_VECTORS);
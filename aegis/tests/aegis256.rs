@@ -0,0 +1,110 @@
+//! AEGIS-256 tests
+
+#[macro_use]
+extern crate hex_literal;
+
+#[macro_use]
+mod common;
+
+use self::common::{FailVector, TestVector};
+use aegis::aead::{
+    consts::{U16, U32},
+    generic_array::GenericArray,
+    Aead, AeadInPlace, NewAead, Payload,
+};
+use aegis::Aegis256;
+
+/// AEGIS-256 test vectors
+///
+/// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-aegis-aead/>
+const TEST_VECTORS: &[TestVector<[u8; 32], [u8; 32]>] = &[
+    TestVector {
+        key: &hex!("1001000000000000000000000000000000000000000000000000000000000000"),
+        nonce: &hex!("1000020000000000000000000000000000000000000000000000000000000000"),
+        aad: &hex!(""),
+        plaintext: &hex!("00000000000000000000000000000000"),
+        ciphertext: &hex!("754fc3d8c973246dcc6d741412a4b236"),
+        tag128: &hex!("3fe91994768b332ed7f570a19ec5896e"),
+        tag256: &hex!("1181a1d18091082bf0266f66297d167d2e68b845f61a3b0527d31fc7b7b89f13"),
+    },
+    TestVector {
+        key: &hex!("1001000000000000000000000000000000000000000000000000000000000000"),
+        nonce: &hex!("1000020000000000000000000000000000000000000000000000000000000000"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag128: &hex!("e3def978a0f054afd1e761d7553afba3"),
+        tag256: &hex!("6a348c930adbd654896e1666aad67de989ea75ebaa2b82fb588977b1ffec864a"),
+    },
+    TestVector {
+        key: &hex!("1001000000000000000000000000000000000000000000000000000000000000"),
+        nonce: &hex!("1000020000000000000000000000000000000000000000000000000000000000"),
+        aad: &hex!("0001020304050607"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        ciphertext: &hex!("f373079ed84b2709faee373584585d60accd191db310ef5d8b11833df9dec711"),
+        tag128: &hex!("8d86f91ee606e9ff26a01b64ccbdd91d"),
+        tag256: &hex!("b7d28d0c3c0ebd409fd22b44160503073a547412da0854bfb9723020dab8da1a"),
+    },
+    TestVector {
+        key: &hex!("1001000000000000000000000000000000000000000000000000000000000000"),
+        nonce: &hex!("1000020000000000000000000000000000000000000000000000000000000000"),
+        aad: &hex!("0001020304050607"),
+        plaintext: &hex!("000102030405060708090a0b0c0d"),
+        ciphertext: &hex!("f373079ed84b2709faee37358458"),
+        tag128: &hex!("c60b9c2d33ceb058f96e6dd03c215652"),
+        tag256: &hex!("8c1cc703c81281bee3f6d9966e14948b4a175b2efbdc31e61a98b4465235c2d9"),
+    },
+    TestVector {
+        key: &hex!("1001000000000000000000000000000000000000000000000000000000000000"),
+        nonce: &hex!("1000020000000000000000000000000000000000000000000000000000000000"),
+        aad: &hex!(
+            "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20212223242526272829"
+        ),
+        plaintext: &hex!(
+            "101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f3031323334353637"
+        ),
+        ciphertext: &hex!(
+            "57754a7d09963e7c787583a2e7b859bb24fa1e04d49fd550b2511a358e3bca252a9b1b8b30cc4a67"
+        ),
+        tag128: &hex!("ab8a7d53fd0e98d727accca94925e128"),
+        tag256: &hex!("a3aca270c006094d71c20e6910b5161c0826df233d08919a566ec2c05990f734"),
+    },
+];
+
+/// Vectors which must fail verification
+const FAIL_VECTORS: &[FailVector<[u8; 32], [u8; 32]>] = &[
+    FailVector {
+        key: &hex!("1000020000000000000000000000000000000000000000000000000000000000"),
+        nonce: &hex!("1001000000000000000000000000000000000000000000000000000000000000"),
+        aad: &hex!("0001020304050607"),
+        ciphertext: &hex!("f373079ed84b2709faee37358458"),
+        tag128: &hex!("c60b9c2d33ceb058f96e6dd03c215652"),
+        tag256: &hex!("8c1cc703c81281bee3f6d9966e14948b4a175b2efbdc31e61a98b4465235c2d9"),
+    },
+    FailVector {
+        key: &hex!("1001000000000000000000000000000000000000000000000000000000000000"),
+        nonce: &hex!("1000020000000000000000000000000000000000000000000000000000000000"),
+        aad: &hex!("0001020304050607"),
+        ciphertext: &hex!("f373079ed84b2709faee37358459"),
+        tag128: &hex!("c60b9c2d33ceb058f96e6dd03c215652"),
+        tag256: &hex!("8c1cc703c81281bee3f6d9966e14948b4a175b2efbdc31e61a98b4465235c2d9"),
+    },
+    FailVector {
+        key: &hex!("1001000000000000000000000000000000000000000000000000000000000000"),
+        nonce: &hex!("1000020000000000000000000000000000000000000000000000000000000000"),
+        aad: &hex!("0001020304050608"),
+        ciphertext: &hex!("f373079ed84b2709faee37358458"),
+        tag128: &hex!("c60b9c2d33ceb058f96e6dd03c215652"),
+        tag256: &hex!("8c1cc703c81281bee3f6d9966e14948b4a175b2efbdc31e61a98b4465235c2d9"),
+    },
+    FailVector {
+        key: &hex!("1001000000000000000000000000000000000000000000000000000000000000"),
+        nonce: &hex!("1000020000000000000000000000000000000000000000000000000000000000"),
+        aad: &hex!("0001020304050607"),
+        ciphertext: &hex!("f373079ed84b2709faee37358458"),
+        tag128: &hex!("c60b9c2d33ceb058f96e6dd03c215653"),
+        tag256: &hex!("8c1cc703c81281bee3f6d9966e14948b4a175b2efbdc31e61a98b4465235c2da"),
+    },
+];
+
+tests!(Aegis256, TEST_VECTORS, FAIL_VECTORS);
@@ -0,0 +1,117 @@
+//! Common functionality shared by tests
+
+/// Test vectors
+#[derive(Debug)]
+pub struct TestVector<K: 'static, N: 'static> {
+    pub key: &'static K,
+    pub nonce: &'static N,
+    pub aad: &'static [u8],
+    pub plaintext: &'static [u8],
+    pub ciphertext: &'static [u8],
+    pub tag128: &'static [u8; 16],
+    pub tag256: &'static [u8; 32],
+}
+
+/// Test vectors which must fail to verify
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct FailVector<K: 'static, N: 'static> {
+    pub key: &'static K,
+    pub nonce: &'static N,
+    pub aad: &'static [u8],
+    pub ciphertext: &'static [u8],
+    pub tag128: &'static [u8; 16],
+    pub tag256: &'static [u8; 32],
+}
+
+#[macro_export]
+macro_rules! tests {
+    ($aead:ident, $vectors:expr $(, $fail_vectors:expr)?) => {
+        tests!($aead<U16>, tag128, $vectors $(, $fail_vectors)?);
+        tests!($aead<U32>, tag256, $vectors $(, $fail_vectors)?);
+    };
+    ($aead:ident<$tag_size:ty>, $tag:ident, $vectors:expr $(, $fail_vectors:expr)?) => {
+        mod $tag {
+            use super::*;
+
+            #[test]
+            fn encrypt() {
+                for vector in $vectors {
+                    let key = GenericArray::from_slice(vector.key);
+                    let nonce = GenericArray::from_slice(vector.nonce);
+                    let payload = Payload {
+                        msg: vector.plaintext,
+                        aad: vector.aad,
+                    };
+
+                    let cipher = <$aead<$tag_size>>::new(key);
+                    let ciphertext = cipher.encrypt(nonce, payload).unwrap();
+                    let (ct, tag) = ciphertext.split_at(vector.plaintext.len());
+                    assert_eq!(vector.ciphertext, ct);
+                    assert_eq!(&vector.$tag[..], tag);
+                }
+            }
+
+            #[test]
+            fn decrypt() {
+                for vector in $vectors {
+                    let key = GenericArray::from_slice(vector.key);
+                    let nonce = GenericArray::from_slice(vector.nonce);
+                    let mut ciphertext = Vec::from(vector.ciphertext);
+                    ciphertext.extend_from_slice(vector.$tag);
+
+                    let payload = Payload {
+                        msg: &ciphertext,
+                        aad: vector.aad,
+                    };
+
+                    let cipher = <$aead<$tag_size>>::new(key);
+                    let plaintext = cipher.decrypt(nonce, payload).unwrap();
+
+                    assert_eq!(vector.plaintext, plaintext.as_slice());
+                }
+            }
+
+            #[test]
+            fn decrypt_modified() {
+                let vector = &$vectors[0];
+                let key = GenericArray::from_slice(vector.key);
+                let nonce = GenericArray::from_slice(vector.nonce);
+
+                let mut ciphertext = Vec::from(vector.ciphertext);
+                ciphertext.extend_from_slice(vector.$tag);
+
+                // Tweak the first byte
+                ciphertext[0] ^= 0xaa;
+
+                let payload = Payload {
+                    msg: &ciphertext,
+                    aad: vector.aad,
+                };
+
+                let cipher = <$aead<$tag_size>>::new(key);
+                assert!(cipher.decrypt(nonce, payload).is_err());
+            }
+
+            $(
+            #[test]
+            fn decrypt_invalid() {
+                for vector in $fail_vectors {
+                    let key = GenericArray::from_slice(vector.key);
+                    let nonce = GenericArray::from_slice(vector.nonce);
+                    let mut buffer = Vec::from(vector.ciphertext);
+                    let tag = GenericArray::from_slice(vector.$tag);
+
+                    let cipher = <$aead<$tag_size>>::new(key);
+                    let result =
+                        cipher.decrypt_in_place_detached(nonce, vector.aad, &mut buffer, tag);
+                    assert!(result.is_err());
+
+                    // Unauthenticated plaintext must not be released
+                    assert!(buffer.iter().all(|&b| b == 0));
+                }
+            }
+            )?
+        }
+    };
+}
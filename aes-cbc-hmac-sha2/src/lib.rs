@@ -0,0 +1,352 @@
+//! [AES_CBC_HMAC_SHA2][1]: [Authenticated Encryption with Associated Data (AEAD)][2]
+//! ciphers composed of AES in CBC mode and HMAC-SHA-2, as specified in
+//! [RFC 7518 Section 5.2][3] for use as JSON Web Encryption (JWE) content
+//! encryption algorithms.
+//!
+//! This crate implements the following variants:
+//!
+//! - [`Aes128CbcHmacSha256`] (`A128CBC-HS256`): 256-bit key, 128-bit tag.
+//! - [`Aes192CbcHmacSha384`] (`A192CBC-HS384`): 384-bit key, 192-bit tag.
+//! - [`Aes256CbcHmacSha512`] (`A256CBC-HS512`): 512-bit key, 256-bit tag.
+//!
+//! The first half of the key is the HMAC key and the second half is the AES
+//! key. The nonce is the 128-bit CBC initialization vector, and the
+//! plaintext is padded with PKCS#7 before it's encrypted, so the ciphertext
+//! is 1 to 16 bytes longer than the plaintext, followed by the tag:
+//!
+//! ```text
+//! E = AES-CBC(ENC_KEY, IV, P || PKCS#7 padding)
+//! T = HMAC-SHA-2(MAC_KEY, A || IV || E || AL)[..T_LEN]
+//! ```
+//!
+//! where `AL` is the length of the associated data in bits, encoded as a
+//! 64-bit big endian integer.
+//!
+//! Since the length of the ciphertext differs from the length of the
+//! plaintext, the detached `AeadInPlace` methods aren't supported and always
+//! return an error. Use [`AeadInPlace::encrypt_in_place`] or
+//! [`AeadInPlace::decrypt_in_place`] instead: the ciphertext they produce
+//! (and expect) is the JWE ciphertext followed by the JWE authentication tag.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! The IV MUST be unpredictable, so it should be generated with a
+//! cryptographically secure RNG for each message rather than with a counter.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! Simple usage (allocating, no associated data):
+//!
+//! ```
+//! use aes_cbc_hmac_sha2::{Aes128CbcHmacSha256, Key, Nonce};
+//! use aes_cbc_hmac_sha2::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = Aes128CbcHmacSha256::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique random iv"); // unpredictable per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! Note that if you enable the `heapless` feature of this crate,
+//! you will receive an impl of [`aead::Buffer`] for `heapless::Vec`
+//! (re-exported from the [`aead`] crate as [`aead::heapless::Vec`]),
+//! which can then be passed as the `buffer` parameter to the in-place encrypt
+//! and decrypt methods:
+//!
+//! ```
+//! # #[cfg(feature = "heapless")]
+//! # {
+//! use aes_cbc_hmac_sha2::{Aes128CbcHmacSha256, Key, Nonce};
+//! use aes_cbc_hmac_sha2::aead::{AeadInPlace, NewAead};
+//! use aes_cbc_hmac_sha2::aead::heapless::Vec;
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = Aes128CbcHmacSha256::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique random iv"); // unpredictable per message
+//!
+//! let mut buffer: Vec<u8, 128> = Vec::new();
+//! buffer.extend_from_slice(b"plaintext message");
+//!
+//! // Encrypt `buffer` in-place, replacing the plaintext contents with ciphertext
+//! cipher.encrypt_in_place(nonce, b"", &mut buffer).expect("encryption failure!");
+//!
+//! // `buffer` now contains the message ciphertext
+//! assert_ne!(&buffer, b"plaintext message");
+//!
+//! // Decrypt `buffer` in-place, replacing its ciphertext context with the original plaintext
+//! cipher.decrypt_in_place(nonce, b"", &mut buffer).expect("decryption failure!");
+//! assert_eq!(&buffer, b"plaintext message");
+//! # }
+//! ```
+//!
+//! [1]: https://tools.ietf.org/html/draft-mcgrew-aead-aes-cbc-hmac-sha2-05
+//! [2]: https://en.wikipedia.org/wiki/Authenticated_encryption
+//! [3]: https://tools.ietf.org/html/rfc7518#section-5.2
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+pub use aead::{self, AeadCore, AeadInPlace, Error, NewAead};
+
+use aead::{
+    consts::{U16, U24, U32, U48, U64},
+    generic_array::GenericArray,
+    Buffer,
+};
+use aes::{Aes128, Aes192, Aes256};
+use cipher::{Block, BlockDecrypt, BlockEncrypt, NewBlockCipher};
+use hmac::{Hmac, Mac, NewMac};
+use sha2::{Sha256, Sha384, Sha512};
+use subtle::ConstantTimeEq;
+
+/// AES_CBC_HMAC_SHA2 keys: `MAC_KEY || ENC_KEY`
+pub type Key<KeySize> = GenericArray<u8, KeySize>;
+
+/// AES_CBC_HMAC_SHA2 nonces, i.e. CBC initialization vectors (128-bit)
+pub type Nonce = GenericArray<u8, U16>;
+
+/// AES_CBC_HMAC_SHA2 tags
+pub type Tag<TagSize> = GenericArray<u8, TagSize>;
+
+/// Size of an AES block
+const BLOCK_SIZE: usize = 16;
+
+macro_rules! impl_aes_cbc_hmac_sha2 {
+    (
+        $name:ident, $aes:ident, $sha:ident, $key_size:ty, $tag_size:ty, $t_len:expr,
+        $doc:expr
+    ) => {
+        #[doc = $doc]
+        #[derive(Clone)]
+        pub struct $name {
+            /// Encryption cipher, keyed with `ENC_KEY`
+            cipher: $aes,
+
+            /// HMAC instance, keyed with `MAC_KEY`
+            mac: Hmac<$sha>,
+        }
+
+        impl NewAead for $name {
+            type KeySize = $key_size;
+
+            fn new(key: &Key<$key_size>) -> Self {
+                // `MAC_KEY` and `ENC_KEY` are both as long as the tag
+                let (mac_key, enc_key) = key.split_at($t_len);
+
+                Self {
+                    cipher: $aes::new(GenericArray::from_slice(enc_key)),
+                    mac: Hmac::new_from_slice(mac_key).expect("HMAC accepts keys of any length"),
+                }
+            }
+        }
+
+        impl AeadCore for $name {
+            type NonceSize = U16;
+            type TagSize = $tag_size;
+            type CiphertextOverhead = U16;
+        }
+
+        impl AeadInPlace for $name {
+            fn encrypt_in_place(
+                &self,
+                nonce: &Nonce,
+                associated_data: &[u8],
+                buffer: &mut dyn Buffer,
+            ) -> Result<(), Error> {
+                let pad_len = BLOCK_SIZE - buffer.len() % BLOCK_SIZE;
+                buffer.extend_from_slice(&[pad_len as u8; BLOCK_SIZE][..pad_len])?;
+
+                cbc_encrypt(&self.cipher, nonce, buffer.as_mut());
+
+                let tag = self.compute_tag(nonce, associated_data, buffer.as_ref());
+                buffer.extend_from_slice(&tag)
+            }
+
+            /// Unsupported: always returns an error, as padding the message
+            /// changes its length.
+            fn encrypt_in_place_detached(
+                &self,
+                _nonce: &Nonce,
+                _associated_data: &[u8],
+                _buffer: &mut [u8],
+            ) -> Result<Tag<$tag_size>, Error> {
+                Err(Error)
+            }
+
+            fn decrypt_in_place(
+                &self,
+                nonce: &Nonce,
+                associated_data: &[u8],
+                buffer: &mut dyn Buffer,
+            ) -> Result<(), Error> {
+                let tag_len = $t_len;
+
+                if buffer.len() < BLOCK_SIZE + tag_len || (buffer.len() - tag_len) % BLOCK_SIZE != 0
+                {
+                    return Err(Error);
+                }
+
+                let ciphertext_len = buffer.len() - tag_len;
+                let (ciphertext, tag) = buffer.as_mut().split_at_mut(ciphertext_len);
+                let expected_tag = self.compute_tag(nonce, associated_data, ciphertext);
+
+                if expected_tag.ct_eq(tag).unwrap_u8() == 0 {
+                    return Err(Error);
+                }
+
+                cbc_decrypt(&self.cipher, nonce, ciphertext);
+                let plaintext_len = unpad(ciphertext)?;
+                buffer.truncate(plaintext_len);
+                Ok(())
+            }
+
+            /// Unsupported: always returns an error, as removing the padding
+            /// of the message changes its length.
+            fn decrypt_in_place_detached(
+                &self,
+                _nonce: &Nonce,
+                _associated_data: &[u8],
+                _buffer: &mut [u8],
+                _tag: &Tag<$tag_size>,
+            ) -> Result<(), Error> {
+                Err(Error)
+            }
+        }
+
+        impl $name {
+            /// Compute `HMAC(MAC_KEY, A || IV || E || AL)`, truncated to the
+            /// length of the tag.
+            fn compute_tag(
+                &self,
+                nonce: &Nonce,
+                associated_data: &[u8],
+                ciphertext: &[u8],
+            ) -> Tag<$tag_size> {
+                let associated_data_bits = (associated_data.len() as u64) * 8;
+
+                let mut mac = self.mac.clone();
+                mac.update(associated_data);
+                mac.update(nonce);
+                mac.update(ciphertext);
+                mac.update(&associated_data_bits.to_be_bytes());
+
+                let full_tag = mac.finalize().into_bytes();
+                Tag::clone_from_slice(&full_tag[..$t_len])
+            }
+        }
+    };
+}
+
+impl_aes_cbc_hmac_sha2!(
+    Aes128CbcHmacSha256,
+    Aes128,
+    Sha256,
+    U32,
+    U16,
+    16,
+    "AES_128_CBC_HMAC_SHA_256 (`A128CBC-HS256`): AES-128 in CBC mode with a \
+     128-bit HMAC-SHA-256 tag"
+);
+
+impl_aes_cbc_hmac_sha2!(
+    Aes192CbcHmacSha384,
+    Aes192,
+    Sha384,
+    U48,
+    U24,
+    24,
+    "AES_192_CBC_HMAC_SHA_384 (`A192CBC-HS384`): AES-192 in CBC mode with a \
+     192-bit HMAC-SHA-384 tag"
+);
+
+impl_aes_cbc_hmac_sha2!(
+    Aes256CbcHmacSha512,
+    Aes256,
+    Sha512,
+    U64,
+    U32,
+    32,
+    "AES_256_CBC_HMAC_SHA_512 (`A256CBC-HS512`): AES-256 in CBC mode with a \
+     256-bit HMAC-SHA-512 tag"
+);
+
+/// Encrypt `buffer`, whose length is a multiple of the block size, in CBC mode
+fn cbc_encrypt<C: BlockEncrypt<BlockSize = U16>>(cipher: &C, iv: &Nonce, buffer: &mut [u8]) {
+    let mut prev = *iv;
+
+    for chunk in buffer.chunks_exact_mut(BLOCK_SIZE) {
+        let block = Block::<C>::from_mut_slice(chunk);
+
+        for (a, b) in block.iter_mut().zip(prev.iter()) {
+            *a ^= b;
+        }
+
+        cipher.encrypt_block(block);
+        prev = *block;
+    }
+}
+
+/// Decrypt `buffer`, whose length is a multiple of the block size, in CBC mode
+fn cbc_decrypt<C: BlockDecrypt<BlockSize = U16>>(cipher: &C, iv: &Nonce, buffer: &mut [u8]) {
+    let mut prev = *iv;
+
+    for chunk in buffer.chunks_exact_mut(BLOCK_SIZE) {
+        let block = Block::<C>::from_mut_slice(chunk);
+        let ciphertext = *block;
+
+        cipher.decrypt_block(block);
+
+        for (a, b) in block.iter_mut().zip(prev.iter()) {
+            *a ^= b;
+        }
+
+        prev = ciphertext;
+    }
+}
+
+/// Check the PKCS#7 padding of `buffer`, returning the length of the
+/// plaintext it pads.
+fn unpad(buffer: &[u8]) -> Result<usize, Error> {
+    let pad_len = *buffer.last().ok_or(Error)? as usize;
+
+    if pad_len == 0 || pad_len > BLOCK_SIZE {
+        return Err(Error);
+    }
+
+    let (plaintext, padding) = buffer.split_at(buffer.len() - pad_len);
+
+    if padding.iter().any(|&b| b as usize != pad_len) {
+        return Err(Error);
+    }
+
+    Ok(plaintext.len())
+}
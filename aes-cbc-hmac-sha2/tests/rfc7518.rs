@@ -0,0 +1,191 @@
+//! AES_CBC_HMAC_SHA2 tests
+
+#[macro_use]
+extern crate hex_literal;
+
+use aes_cbc_hmac_sha2::aead::{generic_array::GenericArray, Aead, AeadInPlace, NewAead, Payload};
+use aes_cbc_hmac_sha2::{Aes128CbcHmacSha256, Aes192CbcHmacSha384, Aes256CbcHmacSha512};
+
+/// Plaintext of the RFC 7518 test cases
+const PLAINTEXT: &[u8] = &hex!(
+    "
+    41206369706865722073797374656d206d757374206e6f742062652072657175
+    6972656420746f206265207365637265742c20616e64206974206d7573742062
+    652061626c6520746f2066616c6c20696e746f207468652068616e6473206f66
+    2074686520656e656d7920776974686f757420696e636f6e76656e69656e6365
+"
+);
+
+/// Initialization vector of the RFC 7518 test cases
+const IV: [u8; 16] = hex!("1af38c2dc2b96ffdd86694092341bc04");
+
+/// Associated data of the RFC 7518 test cases: "The second principle of
+/// Auguste Kerckhoffs"
+const AAD: &[u8] =
+    &hex!("546865207365636f6e64207072696e6369706c65206f662041756775737465204b6572636b686f666673");
+
+macro_rules! tests {
+    ($name:ident, $aead:ty, $key:expr, $ciphertext:expr, $tag:expr) => {
+        mod $name {
+            use super::*;
+
+            const KEY: &[u8] = &$key;
+            const CIPHERTEXT: &[u8] = &$ciphertext;
+            const TAG: &[u8] = &$tag;
+
+            fn cipher() -> $aead {
+                <$aead>::new(GenericArray::from_slice(KEY))
+            }
+
+            fn ciphertext_and_tag() -> Vec<u8> {
+                [CIPHERTEXT, TAG].concat()
+            }
+
+            #[test]
+            fn encrypt() {
+                let payload = Payload {
+                    msg: PLAINTEXT,
+                    aad: AAD,
+                };
+
+                let ciphertext = cipher().encrypt(&IV.into(), payload).unwrap();
+                assert_eq!(ciphertext_and_tag(), ciphertext);
+            }
+
+            #[test]
+            fn decrypt() {
+                let ciphertext = ciphertext_and_tag();
+                let payload = Payload {
+                    msg: &ciphertext,
+                    aad: AAD,
+                };
+
+                let plaintext = cipher().decrypt(&IV.into(), payload).unwrap();
+                assert_eq!(PLAINTEXT, plaintext.as_slice());
+            }
+
+            #[test]
+            fn decrypt_modified() {
+                let mut ciphertext = ciphertext_and_tag();
+
+                // Tweak the first byte of the ciphertext and the last byte of the tag
+                for &i in &[0, ciphertext.len() - 1] {
+                    ciphertext[i] ^= 1;
+                    let payload = Payload {
+                        msg: &ciphertext,
+                        aad: AAD,
+                    };
+                    assert!(cipher().decrypt(&IV.into(), payload).is_err());
+                    ciphertext[i] ^= 1;
+                }
+
+                // Truncate the ciphertext by a block, and the tag by a byte
+                let truncated = [&CIPHERTEXT[16..], TAG].concat();
+                assert!(cipher().decrypt(&IV.into(), truncated.as_ref()).is_err());
+                let truncated = &ciphertext[..ciphertext.len() - 1];
+                assert!(cipher().decrypt(&IV.into(), truncated).is_err());
+
+                // Modify the associated data
+                let payload = Payload {
+                    msg: &ciphertext,
+                    aad: b"The second principle of Auguste Kerckhoffs!",
+                };
+                assert!(cipher().decrypt(&IV.into(), payload).is_err());
+            }
+
+            #[test]
+            fn round_trip() {
+                // Every padding length, including a full block
+                for len in 0..=32 {
+                    let plaintext = &PLAINTEXT[..len];
+                    let ciphertext = cipher().encrypt(&IV.into(), plaintext).unwrap();
+                    assert_eq!(ciphertext.len(), (len / 16 + 1) * 16 + TAG.len());
+
+                    let decrypted = cipher().decrypt(&IV.into(), ciphertext.as_ref()).unwrap();
+                    assert_eq!(plaintext, decrypted.as_slice());
+                }
+            }
+
+            #[test]
+            fn detached_unsupported() {
+                let mut buffer = PLAINTEXT.to_vec();
+                assert!(cipher()
+                    .encrypt_in_place_detached(&IV.into(), AAD, &mut buffer)
+                    .is_err());
+
+                let mut buffer = CIPHERTEXT.to_vec();
+                let tag = GenericArray::clone_from_slice(TAG);
+                assert!(cipher()
+                    .decrypt_in_place_detached(&IV.into(), AAD, &mut buffer, &tag)
+                    .is_err());
+            }
+        }
+    };
+}
+
+// AES_128_CBC_HMAC_SHA_256 test case
+//
+// <https://tools.ietf.org/html/rfc7518#appendix-B.1>
+tests!(
+    aes128cbc_hmac_sha256,
+    Aes128CbcHmacSha256,
+    hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+    hex!(
+        "
+        c80edfa32ddf39d5ef00c0b468834279a2e46a1b8049f792f76bfe54b903a9c9
+        a94ac9b47ad2655c5f10f9aef71427e2fc6f9b3f399a221489f16362c7032336
+        09d45ac69864e3321cf82935ac4096c86e133314c54019e8ca7980dfa4b9cf1b
+        384c486f3a54c51078158ee5d79de59fbd34d848b3d69550a67646344427ade5
+        4b8851ffb598f7f80074b9473c82e2db
+    "
+    ),
+    hex!("652c3fa36b0a7c5b3219fab3a30bc1c4")
+);
+
+// AES_192_CBC_HMAC_SHA_384 test case
+//
+// <https://tools.ietf.org/html/rfc7518#appendix-B.2>
+tests!(
+    aes192cbc_hmac_sha384,
+    Aes192CbcHmacSha384,
+    hex!(
+        "
+        000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f
+        202122232425262728292a2b2c2d2e2f
+    "
+    ),
+    hex!(
+        "
+        ea65da6b59e61edb419be62d19712ae5d303eeb50052d0dfd6697f77224c8edb
+        000d279bdc14c1072654bd30944230c657bed4ca0c9f4a8466f22b226d174621
+        4bf8cfc2400add9f5126e479663fc90b3bed787a2f0ffcbf3904be2a641d5c21
+        05bfe591bae23b1d7449e532eef60a9ac8bb6c6b01d35d49787bcd57ef484927
+        f280adc91ac0c4e79c7b11efc60054e3
+    "
+    ),
+    hex!("8490ac0e58949bfe51875d733f93ac2075168039ccc733d7")
+);
+
+// AES_256_CBC_HMAC_SHA_512 test case
+//
+// <https://tools.ietf.org/html/rfc7518#appendix-B.3>
+tests!(
+    aes256cbc_hmac_sha512,
+    Aes256CbcHmacSha512,
+    hex!(
+        "
+        000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f
+        202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f
+    "
+    ),
+    hex!(
+        "
+        4affaaadb78c31c5da4b1b590d10ffbd3dd8d5d302423526912da037ecbcc7bd
+        822c301dd67c373bccb584ad3e9279c2e6d12a1374b77f077553df829410446b
+        36ebd97066296ae6427ea75c2e0846a11a09ccf5370dc80bfecbad28c73f09b3
+        a3b75e662a2594410ae496b2e2e6609e31e6e02cc837f053d21f37ff4f51950b
+        be2638d09dd7a4930930806d0703b1f6
+    "
+    ),
+    hex!("4dd3b4c088a7f45c216839645b2012bf2e6269a8c56a816dbc1b267761955bc5")
+);
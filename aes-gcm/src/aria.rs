@@ -0,0 +1,47 @@
+//! ARIA block ciphers ([RFC 5794]) for use with [`Gcm`][crate::Gcm].
+//!
+//! These wrap the implementations of the [`aria`](https://docs.rs/aria)
+//! crate, which is built on a newer version of the `cipher` traits, and
+//! expose them through the traits this crate is generic over.
+//!
+//! The `aria` crate uses table lookups for the S-boxes, so unlike the AES
+//! implementations it is not constant time.
+//!
+//! [RFC 5794]: https://tools.ietf.org/html/rfc5794
+
+use ::aria::cipher::{BlockEncrypt as _, KeyInit as _};
+use cipher::{
+    consts::{U1, U16, U32},
+    Block, BlockCipher, BlockCipherKey, BlockEncrypt, NewBlockCipher,
+};
+
+macro_rules! impl_aria {
+    ($name:ident, $key_size:ty, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone)]
+        pub struct $name(::aria::$name);
+
+        impl NewBlockCipher for $name {
+            type KeySize = $key_size;
+
+            fn new(key: &BlockCipherKey<Self>) -> Self {
+                Self(::aria::$name::new(key))
+            }
+        }
+
+        impl BlockCipher for $name {
+            type BlockSize = U16;
+            type ParBlocks = U1;
+        }
+
+        impl BlockEncrypt for $name {
+            #[inline]
+            fn encrypt_block(&self, block: &mut Block<Self>) {
+                self.0.encrypt_block(block);
+            }
+        }
+    };
+}
+
+impl_aria!(Aria128, U16, "ARIA block cipher with a 128-bit key");
+impl_aria!(Aria256, U32, "ARIA block cipher with a 256-bit key");
@@ -49,6 +49,114 @@
 //! assert_eq!(&plaintext, b"plaintext message");
 //! ```
 //!
+//! ## Extended Nonces
+//!
+//! [`XAes256Gcm`] implements [XAES-256-GCM][5], which extends AES-256-GCM to
+//! 192-bit nonces by deriving a key for each message. Nonces can then be
+//! generated randomly without practical limits on the number of messages:
+//!
+//! ```
+//! use aes_gcm::{XAes256Gcm, Key, XNonce};
+//! use aes_gcm::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = XAes256Gcm::new(key);
+//!
+//! let nonce = XNonce::from_slice(b"extra long unique nonce!"); // 192-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## Other Nonce Sizes
+//!
+//! The nonce size of [`AesGcm`] is a type parameter. Nonces other than 96
+//! bits long are hashed into the initial counter block with GHASH, as
+//! specified by NIST SP 800-38D, e.g. for the 64-bit IVs of some legacy
+//! hardware:
+//!
+//! ```
+//! use aes_gcm::{aes::Aes128, AesGcm, Key, Nonce};
+//! use aes_gcm::aead::{consts::U8, Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = AesGcm::<Aes128, U8>::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"64 bits!"); // unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## MACsec Extended Packet Numbering
+//!
+//! [`Aes128GcmXpn`] and [`Aes256GcmXpn`] implement the GCM-AES-XPN cipher
+//! suites of [IEEE 802.1AE][7] (MACsec), whose IV combines the salt and the
+//! short secure channel identifier (SSCI) of the secure association with the
+//! 64-bit packet number (PN) of each frame:
+//!
+//! ```
+//! use aes_gcm::{Aes128GcmXpn, Key, PacketNumber, Salt, Ssci};
+//! use aes_gcm::aead::Aead;
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let ssci = Ssci::from_slice(&[0, 0, 0, 1]);
+//! let salt = Salt::from_slice(b"salt of SAK!");
+//! let cipher = Aes128GcmXpn::new(key, ssci, salt);
+//!
+//! let pn = PacketNumber::from(0x1_0000_0000u64.to_be_bytes()); // unique per frame
+//!
+//! let ciphertext = cipher.encrypt(&pn, b"plaintext frame".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(&pn, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext frame");
+//! ```
+//!
+//! ## Short Tags
+//!
+//! Truncating AES-GCM tags makes forgeries much easier than their length
+//! suggests. [`AesGcmSst`] implements [AES-GCM-SST][6], which derives fresh
+//! authentication keys for every nonce so that short tags (e.g. 32 or 64-bit
+//! tags, as used for media) keep close to ideal forgery probabilities. Its
+//! tag size is a type parameter:
+//!
+//! ```
+//! use aes_gcm::{Aes128GcmSst, Key, Nonce};
+//! use aes_gcm::aead::{consts::U8, Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = Aes128GcmSst::<U8>::new(key); // 64-bit tags
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce"); // 96-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//! assert_eq!(ciphertext.len(), 17 + 8);
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! Plain AES-GCM with truncated tags is only provided for interoperability
+//! with existing protocols: [`Aes128Gcm8`] / [`Aes256Gcm8`] and
+//! [`Aes128Gcm12`] / [`Aes256Gcm12`] match the IPsec ESP transforms with
+//! 8 and 12-octet ICVs of [RFC 4106][8].
+//!
 //! ## In-place Usage (eliminates `alloc` requirement)
 //!
 //! This crate has an optional `alloc` feature which can be disabled in e.g.
@@ -95,6 +203,10 @@
 //! [2]: https://en.wikipedia.org/wiki/Galois/Counter_Mode
 //! [3]: https://research.nccgroup.com/2020/02/26/public-report-rustcrypto-aes-gcm-and-chacha20poly1305-implementation-review/
 //! [4]: https://www.mobilecoin.com/
+//! [5]: https://c2sp.org/XAES-256-GCM
+//! [6]: https://datatracker.ietf.org/doc/draft-mattsson-cfrg-aes-gcm-sst/
+//! [7]: https://1.ieee802.org/security/802-1ae/
+//! [8]: https://tools.ietf.org/html/rfc4106
 
 #![no_std]
 #![cfg_attr(docsrs, feature(doc_cfg))]
@@ -110,8 +222,32 @@ pub use aead::{self, AeadCore, AeadInPlace, Error, NewAead};
 #[cfg(feature = "aes")]
 pub use aes;
 
+#[cfg(feature = "sm4")]
+pub use sm4;
+
+#[cfg(feature = "aria")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aria")))]
+pub mod aria;
+
+#[cfg(feature = "oid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
+pub mod oid;
+
+mod sst;
+#[cfg(feature = "aes")]
+mod xaes;
+mod xpn;
+
+pub use sst::{AesGcmSst, SstTag, SstTagSize, SST_A_MAX, SST_P_MAX};
+
+#[cfg(feature = "aes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aes")))]
+pub use xaes::{XAes256Gcm, XNonce};
+
+pub use xpn::{AesGcmXpn, PacketNumber, Salt, Ssci};
+
 use cipher::{
-    consts::{U0, U16},
+    consts::{U0, U12, U13, U14, U15, U16, U8},
     generic_array::{typenum::Unsigned, ArrayLength, GenericArray},
     Block, BlockCipher, BlockCipherKey, BlockEncrypt, FromBlockCipher, NewBlockCipher,
     StreamCipher, StreamCipherSeek,
@@ -127,7 +263,7 @@ use ghash::{
 use zeroize::Zeroize;
 
 #[cfg(feature = "aes")]
-use aes::{cipher::consts::U12, Aes128, Aes256};
+use aes::{Aes128, Aes256};
 
 /// Maximum length of associated data
 pub const A_MAX: u64 = 1 << 36;
@@ -145,7 +281,39 @@ pub type Key<KeySize> = GenericArray<u8, KeySize>;
 pub type Nonce<NonceSize> = GenericArray<u8, NonceSize>;
 
 /// AES-GCM tags
-pub type Tag = GenericArray<u8, U16>;
+pub type Tag<TagSize = U16> = GenericArray<u8, TagSize>;
+
+mod private {
+    // Sealed traits stop other crates from implementing any traits that use it.
+    pub trait SealedTag {}
+
+    impl SealedTag for super::U8 {}
+    impl SealedTag for super::U12 {}
+    impl SealedTag for super::U13 {}
+    impl SealedTag for super::U14 {}
+    impl SealedTag for super::U15 {}
+    impl SealedTag for super::U16 {}
+}
+
+/// Tag sizes supported by GCM: `U12` to `U16` bytes, and `U8` for
+/// interoperability with protocols that negotiate 64-bit tags, such as the
+/// 8-octet ICV transform of IPsec ESP ([RFC 4106]).
+///
+/// NIST SP 800-38D only permits 64-bit tags for applications that bound the
+/// length of messages and the number of failed decryptions per key (see its
+/// Appendix C), as their forgery probability is much weaker than their length
+/// suggests. 32-bit tags aren't provided at all. See [`AesGcmSst`] for a mode
+/// with secure short tags.
+///
+/// [RFC 4106]: https://tools.ietf.org/html/rfc4106
+pub trait GcmTagSize: ArrayLength<u8> + private::SealedTag {}
+
+impl GcmTagSize for U8 {}
+impl GcmTagSize for U12 {}
+impl GcmTagSize for U13 {}
+impl GcmTagSize for U14 {}
+impl GcmTagSize for U15 {}
+impl GcmTagSize for U16 {}
 
 /// AES-GCM with a 128-bit key and 96-bit nonce
 #[cfg(feature = "aes")]
@@ -157,56 +325,162 @@ pub type Aes128Gcm = AesGcm<Aes128, U12>;
 #[cfg_attr(docsrs, doc(cfg(feature = "aes")))]
 pub type Aes256Gcm = AesGcm<Aes256, U12>;
 
-/// AES-GCM: generic over an underlying AES implementation and nonce size.
+/// AES-GCM with a 128-bit key, 96-bit nonce and 64-bit tag, as used by the
+/// `ENCR_AES_GCM_8` IPsec transform ([RFC 4106](https://tools.ietf.org/html/rfc4106))
+#[cfg(feature = "aes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aes")))]
+pub type Aes128Gcm8 = AesGcm<Aes128, U12, U8>;
+
+/// AES-GCM with a 256-bit key, 96-bit nonce and 64-bit tag, as used by the
+/// `ENCR_AES_GCM_8` IPsec transform ([RFC 4106](https://tools.ietf.org/html/rfc4106))
+#[cfg(feature = "aes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aes")))]
+pub type Aes256Gcm8 = AesGcm<Aes256, U12, U8>;
+
+/// AES-GCM with a 128-bit key, 96-bit nonce and 96-bit tag, as used by the
+/// `ENCR_AES_GCM_12` IPsec transform ([RFC 4106](https://tools.ietf.org/html/rfc4106))
+#[cfg(feature = "aes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aes")))]
+pub type Aes128Gcm12 = AesGcm<Aes128, U12, U12>;
+
+/// AES-GCM with a 256-bit key, 96-bit nonce and 96-bit tag, as used by the
+/// `ENCR_AES_GCM_12` IPsec transform ([RFC 4106](https://tools.ietf.org/html/rfc4106))
+#[cfg(feature = "aes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aes")))]
+pub type Aes256Gcm12 = AesGcm<Aes256, U12, U12>;
+
+/// SM4-GCM with a 96-bit nonce, as used by the TLS 1.3 ShangMi cipher suites
+/// ([RFC 8998](https://tools.ietf.org/html/rfc8998))
+#[cfg(feature = "sm4")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sm4")))]
+pub type Sm4Gcm = Gcm<sm4::Sm4, U12>;
+
+/// ARIA-GCM with a 128-bit key and 96-bit nonce, as used by the TLS cipher
+/// suites of [RFC 6209](https://tools.ietf.org/html/rfc6209)
+#[cfg(feature = "aria")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aria")))]
+pub type Aria128Gcm = Gcm<aria::Aria128, U12>;
+
+/// ARIA-GCM with a 256-bit key and 96-bit nonce, as used by the TLS cipher
+/// suites of [RFC 6209](https://tools.ietf.org/html/rfc6209)
+#[cfg(feature = "aria")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aria")))]
+pub type Aria256Gcm = Gcm<aria::Aria256, U12>;
+
+/// AES-GCM-SST with a 128-bit key, 96-bit nonce and `TagSize`-byte tag
+#[cfg(feature = "aes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aes")))]
+pub type Aes128GcmSst<TagSize> = AesGcmSst<Aes128, TagSize>;
+
+/// AES-GCM-SST with a 256-bit key, 96-bit nonce and `TagSize`-byte tag
+#[cfg(feature = "aes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aes")))]
+pub type Aes256GcmSst<TagSize> = AesGcmSst<Aes256, TagSize>;
+
+/// GCM-AES-XPN-128: MACsec extended packet numbering with a 128-bit key
+#[cfg(feature = "aes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aes")))]
+pub type Aes128GcmXpn = AesGcmXpn<Aes128>;
+
+/// GCM-AES-XPN-256: MACsec extended packet numbering with a 256-bit key
+#[cfg(feature = "aes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aes")))]
+pub type Aes256GcmXpn = AesGcmXpn<Aes256>;
+
+/// AES-GCM: generic over an underlying AES implementation, nonce size and tag
+/// size.
 ///
 /// This type is generic to support substituting alternative AES implementations
-/// (e.g. embedded hardware implementations)
+/// (e.g. embedded hardware implementations).
+///
+/// If in doubt, use the built-in [`Aes128Gcm`] and [`Aes256Gcm`] type aliases.
+pub type AesGcm<Aes, NonceSize, TagSize = U16> = Gcm<Aes, NonceSize, TagSize>;
+
+/// Galois/Counter Mode: generic over a block cipher with a 128-bit block
+/// size, nonce size and tag size.
 ///
-/// It is NOT intended to be instantiated with any block cipher besides AES!
-/// Doing so runs the risk of unintended cryptographic properties!
+/// Any block cipher implementing the traits of the [`cipher`] crate can be
+/// used, including implementations from other crates or hardware vendors.
+/// GCM is standardized with AES (see [`AesGcm`]), and other 128-bit block
+/// ciphers such as SM4, ARIA or Camellia have GCM cipher suites as well.
 ///
-/// The `N` generic parameter can be used to instantiate AES-GCM with other
-/// nonce sizes, however it's recommended to use it with `typenum::U12`,
-/// the default of 96-bits.
+/// Type parameters:
+/// - `Cipher`: block cipher, with a 128-bit block size.
+/// - `NonceSize`: size of the nonce. It's recommended to use `U12`, the default
+///   of 96-bits, as other sizes are hashed into the initial counter block.
+///   Any non-zero size is supported, e.g. `U8` or `U16` for the 64 and 128-bit
+///   IVs of some legacy hardware; a zero-length nonce is rejected with an error,
+///   as NIST SP 800-38D requires IVs of at least 1 bit.
+/// - `TagSize`: size of the tag, `U12` to `U16` or `U8` bytes (see [`GcmTagSize`]).
+///   Defaults to the full 128-bit tag.
 ///
-/// If in doubt, use the built-in [`Aes128Gcm`] and [`Aes256Gcm`] type aliases.
+/// Instantiating it with a cipher that isn't a secure 128-bit block cipher
+/// runs the risk of unintended cryptographic properties!
+///
+/// ```
+/// use aes_gcm::{aes::Aes128, Gcm, Key, Nonce};
+/// use aes_gcm::aead::{consts::{U12, U14}, Aead, NewAead};
+///
+/// // Any type implementing the `cipher` block cipher traits works here
+/// type MyGcm = Gcm<Aes128, U12, U14>;
+///
+/// let key = Key::from_slice(b"very secret key.");
+/// let cipher = MyGcm::new(key);
+///
+/// let nonce = Nonce::from_slice(b"unique nonce"); // 96-bits; unique per message
+///
+/// let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+///     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+/// assert_eq!(ciphertext.len(), 17 + 14);
+///
+/// let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+///     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+///
+/// assert_eq!(&plaintext, b"plaintext message");
+/// ```
 #[derive(Clone)]
-pub struct AesGcm<Aes, NonceSize>
+pub struct Gcm<Cipher, NonceSize, TagSize = U16>
 where
-    Aes: BlockCipher<BlockSize = U16> + BlockEncrypt,
-    Aes::ParBlocks: ArrayLength<Block<Aes>>,
+    Cipher: BlockCipher<BlockSize = U16> + BlockEncrypt,
+    Cipher::ParBlocks: ArrayLength<Block<Cipher>>,
     NonceSize: ArrayLength<u8>,
+    TagSize: GcmTagSize,
 {
     /// Encryption cipher
-    cipher: Aes,
+    cipher: Cipher,
 
     /// GHASH authenticator
     ghash: GHash,
 
     /// Length of the nonce
     nonce_size: PhantomData<NonceSize>,
+
+    /// Length of the tag
+    tag_size: PhantomData<TagSize>,
 }
 
-impl<Aes, NonceSize> NewAead for AesGcm<Aes, NonceSize>
+impl<Cipher, NonceSize, TagSize> NewAead for Gcm<Cipher, NonceSize, TagSize>
 where
-    Aes: NewBlockCipher + BlockCipher<BlockSize = U16> + BlockEncrypt,
-    Aes::ParBlocks: ArrayLength<Block<Aes>>,
+    Cipher: NewBlockCipher + BlockCipher<BlockSize = U16> + BlockEncrypt,
+    Cipher::ParBlocks: ArrayLength<Block<Cipher>>,
     NonceSize: ArrayLength<u8>,
+    TagSize: GcmTagSize,
 {
-    type KeySize = Aes::KeySize;
+    type KeySize = Cipher::KeySize;
 
-    fn new(key: &BlockCipherKey<Aes>) -> Self {
-        Aes::new(key).into()
+    fn new(key: &BlockCipherKey<Cipher>) -> Self {
+        Cipher::new(key).into()
     }
 }
 
-impl<Aes, NonceSize> From<Aes> for AesGcm<Aes, NonceSize>
+impl<Cipher, NonceSize, TagSize> From<Cipher> for Gcm<Cipher, NonceSize, TagSize>
 where
-    Aes: NewBlockCipher + BlockCipher<BlockSize = U16> + BlockEncrypt,
-    Aes::ParBlocks: ArrayLength<Block<Aes>>,
+    Cipher: BlockCipher<BlockSize = U16> + BlockEncrypt,
+    Cipher::ParBlocks: ArrayLength<Block<Cipher>>,
     NonceSize: ArrayLength<u8>,
+    TagSize: GcmTagSize,
 {
-    fn from(cipher: Aes) -> Self {
+    fn from(cipher: Cipher) -> Self {
         let mut ghash_key = ghash::Key::default();
         cipher.encrypt_block(&mut ghash_key);
 
@@ -219,48 +493,54 @@ where
             cipher,
             ghash,
             nonce_size: PhantomData,
+            tag_size: PhantomData,
         }
     }
 }
 
-impl<Aes, NonceSize> AeadCore for AesGcm<Aes, NonceSize>
+impl<Cipher, NonceSize, TagSize> AeadCore for Gcm<Cipher, NonceSize, TagSize>
 where
-    Aes: NewBlockCipher + BlockCipher<BlockSize = U16> + BlockEncrypt,
-    Aes::ParBlocks: ArrayLength<Block<Aes>>,
+    Cipher: BlockCipher<BlockSize = U16> + BlockEncrypt,
+    Cipher::ParBlocks: ArrayLength<Block<Cipher>>,
     NonceSize: ArrayLength<u8>,
+    TagSize: GcmTagSize,
 {
     type NonceSize = NonceSize;
-    type TagSize = U16;
+    type TagSize = TagSize;
     type CiphertextOverhead = U0;
 }
 
-impl<Aes, NonceSize> AeadInPlace for AesGcm<Aes, NonceSize>
+impl<Cipher, NonceSize, TagSize> AeadInPlace for Gcm<Cipher, NonceSize, TagSize>
 where
-    Aes: NewBlockCipher + BlockCipher<BlockSize = U16> + BlockEncrypt,
-    Aes::ParBlocks: ArrayLength<Block<Aes>>,
+    Cipher: BlockCipher<BlockSize = U16> + BlockEncrypt,
+    Cipher::ParBlocks: ArrayLength<Block<Cipher>>,
     NonceSize: ArrayLength<u8>,
+    TagSize: GcmTagSize,
 {
     fn encrypt_in_place_detached(
         &self,
         nonce: &Nonce<Self::NonceSize>,
         associated_data: &[u8],
         buffer: &mut [u8],
-    ) -> Result<Tag, Error> {
-        if buffer.len() as u64 > P_MAX || associated_data.len() as u64 > A_MAX {
+    ) -> Result<Tag<TagSize>, Error> {
+        if NonceSize::to_usize() == 0
+            || buffer.len() as u64 > P_MAX
+            || associated_data.len() as u64 > A_MAX
+        {
             return Err(Error);
         }
 
         // TODO(tarcieri): interleave encryption with GHASH
         // See: <https://github.com/RustCrypto/AEADs/issues/74>
         let mut ctr = self.init_ctr(nonce);
-        ctr.seek(Aes::BlockSize::to_usize());
+        ctr.seek(Cipher::BlockSize::to_usize());
         ctr.apply_keystream(buffer);
 
-        let mut tag = self.compute_tag(associated_data, buffer);
+        let mut full_tag = self.compute_tag(associated_data, buffer);
         ctr.seek(0);
-        ctr.apply_keystream(tag.as_mut_slice());
+        ctr.apply_keystream(full_tag.as_mut_slice());
 
-        Ok(tag)
+        Ok(Tag::clone_from_slice(&full_tag[..TagSize::to_usize()]))
     }
 
     fn decrypt_in_place_detached(
@@ -268,33 +548,65 @@ where
         nonce: &Nonce<Self::NonceSize>,
         associated_data: &[u8],
         buffer: &mut [u8],
-        tag: &Tag,
+        tag: &Tag<TagSize>,
     ) -> Result<(), Error> {
-        if buffer.len() as u64 > C_MAX || associated_data.len() as u64 > A_MAX {
-            return Err(Error);
-        }
-
         // TODO(tarcieri): interleave encryption with GHASH
         // See: <https://github.com/RustCrypto/AEADs/issues/74>
-        let mut expected_tag = self.compute_tag(associated_data, buffer);
-        let mut ctr = self.init_ctr(nonce);
-        ctr.apply_keystream(expected_tag.as_mut_slice());
+        let mut ctr = self.verify_tag(nonce, associated_data, buffer, tag)?;
+        ctr.seek(Cipher::BlockSize::to_usize());
+        ctr.apply_keystream(buffer);
+        Ok(())
+    }
+}
 
-        use subtle::ConstantTimeEq;
-        if expected_tag.ct_eq(&tag).unwrap_u8() == 1 {
-            ctr.apply_keystream(buffer);
-            Ok(())
-        } else {
-            Err(Error)
-        }
+impl<Cipher, NonceSize, TagSize> Gcm<Cipher, NonceSize, TagSize>
+where
+    Cipher: BlockCipher<BlockSize = U16> + BlockEncrypt,
+    Cipher::ParBlocks: ArrayLength<Block<Cipher>>,
+    NonceSize: ArrayLength<u8>,
+    TagSize: GcmTagSize,
+{
+    /// Check the authenticity of a ciphertext followed by its tag, as
+    /// produced by [`AeadInPlace::encrypt_in_place`], without decrypting it.
+    ///
+    /// GHASH is computed over the ciphertext, so messages can be verified
+    /// without producing their plaintext, e.g. by relays which must never
+    /// see it.
+    pub fn verify(
+        &self,
+        nonce: &Nonce<NonceSize>,
+        associated_data: &[u8],
+        ciphertext: &[u8],
+    ) -> Result<(), Error> {
+        let tag_pos = ciphertext
+            .len()
+            .checked_sub(TagSize::to_usize())
+            .ok_or(Error)?;
+
+        let (ciphertext, tag) = ciphertext.split_at(tag_pos);
+        self.verify_detached(nonce, associated_data, ciphertext, Tag::from_slice(tag))
+    }
+
+    /// Check the authenticity of a ciphertext and its detached tag without
+    /// decrypting it.
+    pub fn verify_detached(
+        &self,
+        nonce: &Nonce<NonceSize>,
+        associated_data: &[u8],
+        ciphertext: &[u8],
+        tag: &Tag<TagSize>,
+    ) -> Result<(), Error> {
+        self.verify_tag(nonce, associated_data, ciphertext, tag)
+            .map(|_| ())
     }
 }
 
-impl<Aes, NonceSize> AesGcm<Aes, NonceSize>
+impl<Cipher, NonceSize, TagSize> Gcm<Cipher, NonceSize, TagSize>
 where
-    Aes: NewBlockCipher + BlockCipher<BlockSize = U16> + BlockEncrypt,
-    Aes::ParBlocks: ArrayLength<Block<Aes>>,
+    Cipher: BlockCipher<BlockSize = U16> + BlockEncrypt,
+    Cipher::ParBlocks: ArrayLength<Block<Cipher>>,
     NonceSize: ArrayLength<u8>,
+    TagSize: GcmTagSize,
 {
     /// Initialize counter mode.
     ///
@@ -305,7 +617,7 @@ where
     /// > If len(IV)=96, then J0 = IV || 0{31} || 1.
     /// > If len(IV) ≠ 96, then let s = 128 ⎡len(IV)/128⎤-len(IV), and
     /// >     J0=GHASH(IV||0s+64||[len(IV)]64).
-    fn init_ctr(&self, nonce: &Nonce<NonceSize>) -> Ctr32BE<&Aes> {
+    fn init_ctr(&self, nonce: &Nonce<NonceSize>) -> Ctr32BE<&Cipher> {
         let j0 = if NonceSize::to_usize() == 12 {
             let mut block = ghash::Block::default();
             block[..12].copy_from_slice(nonce);
@@ -325,8 +637,40 @@ where
         Ctr32BE::from_block_cipher(&self.cipher, &j0)
     }
 
+    /// Check the lengths of the nonce, associated data and ciphertext, and
+    /// the authenticity of the ciphertext and its tag.
+    ///
+    /// Returns the counter mode state initialized with the nonce, so
+    /// decryption doesn't compute it again (with GHASH, when the nonce isn't
+    /// 96 bits).
+    fn verify_tag(
+        &self,
+        nonce: &Nonce<NonceSize>,
+        associated_data: &[u8],
+        ciphertext: &[u8],
+        tag: &Tag<TagSize>,
+    ) -> Result<Ctr32BE<&Cipher>, Error> {
+        if NonceSize::to_usize() == 0
+            || ciphertext.len() as u64 > C_MAX
+            || associated_data.len() as u64 > A_MAX
+        {
+            return Err(Error);
+        }
+
+        let mut expected_tag = self.compute_tag(associated_data, ciphertext);
+        let mut ctr = self.init_ctr(nonce);
+        ctr.apply_keystream(expected_tag.as_mut_slice());
+
+        use subtle::ConstantTimeEq;
+        if expected_tag[..TagSize::to_usize()].ct_eq(tag).unwrap_u8() == 1 {
+            Ok(ctr)
+        } else {
+            Err(Error)
+        }
+    }
+
     /// Authenticate the given plaintext and associated data using GHASH
-    fn compute_tag(&self, associated_data: &[u8], buffer: &[u8]) -> Tag {
+    fn compute_tag(&self, associated_data: &[u8], buffer: &[u8]) -> Tag<U16> {
         let mut ghash = self.ghash.clone();
         ghash.update_padded(associated_data);
         ghash.update_padded(buffer);
@@ -0,0 +1,145 @@
+//! Object identifiers (OIDs) of AES-GCM, as registered by NIST and used by
+//! the Cryptographic Message Syntax ([RFC 5084]).
+//!
+//! With the `der` feature, [`GcmParameters`] encodes and decodes the
+//! `AlgorithmIdentifier`s of these algorithms.
+//!
+//! [RFC 5084]: https://tools.ietf.org/html/rfc5084
+
+pub use const_oid::ObjectIdentifier;
+
+#[cfg(feature = "der")]
+#[cfg_attr(docsrs, doc(cfg(feature = "der")))]
+pub use spki::{der, AlgorithmIdentifier};
+
+#[cfg(feature = "der")]
+use core::convert::{TryFrom, TryInto};
+#[cfg(feature = "der")]
+use der::{asn1::Any, asn1::OctetString, Decodable, Encodable, ErrorKind, Message, Tag};
+
+/// `aes128-GCM`: AES-GCM with a 128-bit key
+pub const AES_128_GCM: ObjectIdentifier = ObjectIdentifier::new("2.16.840.1.101.3.4.1.6");
+
+/// `aes192-GCM`: AES-GCM with a 192-bit key
+pub const AES_192_GCM: ObjectIdentifier = ObjectIdentifier::new("2.16.840.1.101.3.4.1.26");
+
+/// `aes256-GCM`: AES-GCM with a 256-bit key
+pub const AES_256_GCM: ObjectIdentifier = ObjectIdentifier::new("2.16.840.1.101.3.4.1.46");
+
+/// Default length of the tag (`aes-ICVlen`) of [`GcmParameters`]
+#[cfg(feature = "der")]
+const DEFAULT_ICV_LEN: u8 = 12;
+
+/// AES-GCM algorithm parameters, as defined in [RFC 5084 Section 3.2]:
+///
+/// ```text
+/// GCMParameters ::= SEQUENCE {
+///   aes-nonce        OCTET STRING, -- recommended size is 12 octets
+///   aes-ICVlen       AES-GCM-ICVlen DEFAULT 12 }
+///
+/// AES-GCM-ICVlen ::= INTEGER (12 | 13 | 14 | 15 | 16)
+/// ```
+///
+/// An explicitly encoded default `aes-ICVlen` is accepted when decoding, as
+/// some encoders always include it, but is omitted when encoding.
+///
+/// [RFC 5084 Section 3.2]: https://tools.ietf.org/html/rfc5084#section-3.2
+#[cfg(feature = "der")]
+#[cfg_attr(docsrs, doc(cfg(feature = "der")))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct GcmParameters<'a> {
+    /// Nonce (`aes-nonce`)
+    nonce: &'a [u8],
+
+    /// Length of the tag in bytes (`aes-ICVlen`)
+    icv_len: u8,
+}
+
+#[cfg(feature = "der")]
+impl<'a> GcmParameters<'a> {
+    /// Create new parameters from a nonce and a tag length in bytes.
+    ///
+    /// Returns an error if the nonce is empty, or if the tag length is not
+    /// in the 12..=16 range allowed by RFC 5084.
+    pub fn new(nonce: &'a [u8], icv_len: u8) -> der::Result<Self> {
+        if nonce.is_empty() {
+            return Err(ErrorKind::Length {
+                tag: Tag::OctetString,
+            }
+            .into());
+        }
+
+        if !(12..=16).contains(&icv_len) {
+            return Err(ErrorKind::Value { tag: Tag::Integer }.into());
+        }
+
+        Ok(Self { nonce, icv_len })
+    }
+
+    /// Decode the parameters of an AES-GCM `AlgorithmIdentifier`.
+    ///
+    /// Returns an error if its OID is not one of [`AES_128_GCM`],
+    /// [`AES_192_GCM`] or [`AES_256_GCM`].
+    pub fn from_algorithm_identifier(algorithm: &AlgorithmIdentifier<'a>) -> der::Result<Self> {
+        match algorithm.oid {
+            AES_128_GCM | AES_192_GCM | AES_256_GCM => algorithm.parameters_any()?.try_into(),
+            oid => Err(ErrorKind::UnknownOid { oid }.into()),
+        }
+    }
+
+    /// Encode an `AlgorithmIdentifier` with the given OID and these
+    /// parameters into `buf`, returning the encoded bytes.
+    pub fn encode_algorithm_identifier<'b>(
+        &self,
+        oid: ObjectIdentifier,
+        buf: &'b mut [u8],
+    ) -> der::Result<&'b [u8]> {
+        let mut encoder = der::Encoder::new(buf);
+        encoder.message(&[&oid, self])?;
+        encoder.finish()
+    }
+
+    /// Nonce (`aes-nonce`)
+    pub fn nonce(&self) -> &'a [u8] {
+        self.nonce
+    }
+
+    /// Length of the tag in bytes (`aes-ICVlen`)
+    pub fn icv_len(&self) -> u8 {
+        self.icv_len
+    }
+}
+
+#[cfg(feature = "der")]
+impl<'a> TryFrom<&'a [u8]> for GcmParameters<'a> {
+    type Error = der::Error;
+
+    fn try_from(bytes: &'a [u8]) -> der::Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+#[cfg(feature = "der")]
+impl<'a> TryFrom<Any<'a>> for GcmParameters<'a> {
+    type Error = der::Error;
+
+    fn try_from(any: Any<'a>) -> der::Result<Self> {
+        any.sequence(|decoder| {
+            let nonce = decoder.octet_string()?.as_bytes();
+            let icv_len: Option<u8> = decoder.decode()?;
+            Self::new(nonce, icv_len.unwrap_or(DEFAULT_ICV_LEN))
+        })
+    }
+}
+
+#[cfg(feature = "der")]
+impl<'a> Message<'a> for GcmParameters<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> der::Result<T>,
+    {
+        let nonce = OctetString::new(self.nonce)?;
+        let icv_len = Some(self.icv_len).filter(|&len| len != DEFAULT_ICV_LEN);
+        f(&[&nonce, &icv_len])
+    }
+}
@@ -0,0 +1,246 @@
+//! AES-GCM-SST: Galois Counter Mode with Strong Secure Tags, as described in
+//! [draft-mattsson-cfrg-aes-gcm-sst][1].
+//!
+//! [1]: https://datatracker.ietf.org/doc/draft-mattsson-cfrg-aes-gcm-sst/
+
+use crate::Nonce;
+use aead::{AeadCore, AeadInPlace, Error, NewAead};
+use cipher::{
+    consts::{U0, U10, U11, U12, U13, U14, U15, U16, U4, U5, U6, U7, U8, U9},
+    generic_array::{ArrayLength, GenericArray},
+    Block, BlockCipher, BlockCipherKey, BlockEncrypt, FromBlockCipher, NewBlockCipher,
+    StreamCipher,
+};
+use core::marker::PhantomData;
+use ctr::Ctr32BE;
+use polyval::{
+    universal_hash::{NewUniversalHash, UniversalHash},
+    Polyval,
+};
+use subtle::ConstantTimeEq;
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Maximum length of associated data
+pub const SST_A_MAX: u64 = 1 << 36;
+
+/// Maximum length of plaintext
+pub const SST_P_MAX: u64 = (1 << 36) - 48;
+
+/// AES-GCM-SST tags
+pub type SstTag<TagSize> = GenericArray<u8, TagSize>;
+
+mod private {
+    // Sealed traits stop other crates from implementing any traits that use it.
+    pub trait SealedTag {}
+}
+
+/// Tag sizes supported by AES-GCM-SST: `U4` to `U16` bytes.
+pub trait SstTagSize: ArrayLength<u8> + private::SealedTag {}
+
+macro_rules! impl_tag_sizes {
+    ($($size:ident),+) => {
+        $(
+            impl private::SealedTag for $size {}
+            impl SstTagSize for $size {}
+        )+
+    };
+}
+
+impl_tag_sizes!(U4, U5, U6, U7, U8, U9, U10, U11, U12, U13, U14, U15, U16);
+
+/// AES-GCM-SST: generic over an underlying AES implementation and tag size.
+///
+/// Like AES-GCM, AES-GCM-SST encrypts with AES in counter mode, but each
+/// nonce additionally derives the two hash keys `H` and `Q` and the tag
+/// mask `M` from the first three keystream blocks. Hashing the message with
+/// fresh keys gives short tags forgery probabilities close to ideal, where
+/// truncating a GCM tag weakens it much more than its length suggests, which
+/// makes AES-GCM-SST suited to media protocols that use 32 to 64-bit tags.
+///
+/// Nonces are 96-bit and MUST NOT be reused. Unlike AES-GCM, the tag length
+/// must also stay the same for all the messages encrypted with a key.
+///
+/// It is NOT intended to be instantiated with any block cipher besides AES!
+#[derive(Clone)]
+pub struct AesGcmSst<Aes, TagSize>
+where
+    Aes: BlockCipher<BlockSize = U16> + BlockEncrypt,
+    Aes::ParBlocks: ArrayLength<Block<Aes>>,
+    TagSize: SstTagSize,
+{
+    /// Encryption cipher
+    cipher: Aes,
+
+    /// Length of the tag
+    tag_size: PhantomData<TagSize>,
+}
+
+impl<Aes, TagSize> NewAead for AesGcmSst<Aes, TagSize>
+where
+    Aes: NewBlockCipher + BlockCipher<BlockSize = U16> + BlockEncrypt,
+    Aes::ParBlocks: ArrayLength<Block<Aes>>,
+    TagSize: SstTagSize,
+{
+    type KeySize = Aes::KeySize;
+
+    fn new(key: &BlockCipherKey<Aes>) -> Self {
+        Aes::new(key).into()
+    }
+}
+
+impl<Aes, TagSize> From<Aes> for AesGcmSst<Aes, TagSize>
+where
+    Aes: NewBlockCipher + BlockCipher<BlockSize = U16> + BlockEncrypt,
+    Aes::ParBlocks: ArrayLength<Block<Aes>>,
+    TagSize: SstTagSize,
+{
+    fn from(cipher: Aes) -> Self {
+        Self {
+            cipher,
+            tag_size: PhantomData,
+        }
+    }
+}
+
+impl<Aes, TagSize> AeadCore for AesGcmSst<Aes, TagSize>
+where
+    Aes: NewBlockCipher + BlockCipher<BlockSize = U16> + BlockEncrypt,
+    Aes::ParBlocks: ArrayLength<Block<Aes>>,
+    TagSize: SstTagSize,
+{
+    type NonceSize = U12;
+    type TagSize = TagSize;
+    type CiphertextOverhead = U0;
+}
+
+impl<Aes, TagSize> AeadInPlace for AesGcmSst<Aes, TagSize>
+where
+    Aes: NewBlockCipher + BlockCipher<BlockSize = U16> + BlockEncrypt,
+    Aes::ParBlocks: ArrayLength<Block<Aes>>,
+    TagSize: SstTagSize,
+{
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<U12>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<SstTag<TagSize>, Error> {
+        if buffer.len() as u64 > SST_P_MAX || associated_data.len() as u64 > SST_A_MAX {
+            return Err(Error);
+        }
+
+        let (mut ctr, keys) = self.init_ctr(nonce);
+        ctr.apply_keystream(buffer);
+        Ok(keys.compute_tag(associated_data, buffer))
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<U12>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &SstTag<TagSize>,
+    ) -> Result<(), Error> {
+        if buffer.len() as u64 > SST_P_MAX || associated_data.len() as u64 > SST_A_MAX {
+            return Err(Error);
+        }
+
+        let (mut ctr, keys) = self.init_ctr(nonce);
+        let expected_tag: SstTag<TagSize> = keys.compute_tag(associated_data, buffer);
+
+        if expected_tag.ct_eq(tag).unwrap_u8() == 1 {
+            ctr.apply_keystream(buffer);
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+}
+
+impl<Aes, TagSize> AesGcmSst<Aes, TagSize>
+where
+    Aes: NewBlockCipher + BlockCipher<BlockSize = U16> + BlockEncrypt,
+    Aes::ParBlocks: ArrayLength<Block<Aes>>,
+    TagSize: SstTagSize,
+{
+    /// Initialize counter mode with `N || 0^32` and derive the keys of the
+    /// message from the three first keystream blocks.
+    fn init_ctr(&self, nonce: &Nonce<U12>) -> (Ctr32BE<&Aes>, MessageKeys) {
+        let mut block = Block::<Aes>::default();
+        block[..12].copy_from_slice(nonce);
+
+        let mut ctr = Ctr32BE::from_block_cipher(&self.cipher, &block);
+        let mut keystream = [0u8; 48];
+        ctr.apply_keystream(&mut keystream);
+
+        let keys = MessageKeys {
+            h: Polyval::new(polyval::Key::from_slice(&keystream[..16])),
+            q: Polyval::new(polyval::Key::from_slice(&keystream[16..32])),
+            m: *polyval::Block::from_slice(&keystream[32..]),
+        };
+
+        #[cfg(feature = "zeroize")]
+        keystream.zeroize();
+
+        (ctr, keys)
+    }
+}
+
+/// Keys of a single message, derived from its nonce
+struct MessageKeys {
+    /// POLYVAL instance keyed with `H`
+    h: Polyval,
+
+    /// POLYVAL instance keyed with `Q`
+    q: Polyval,
+
+    /// Tag mask `M`
+    m: polyval::Block,
+}
+
+impl MessageKeys {
+    /// Authenticate the given ciphertext and associated data:
+    /// `POLYVAL(Q, POLYVAL(H, A || C) ⊕ L) ⊕ M`, where `L` is the block of the
+    /// lengths.
+    fn compute_tag<TagSize: SstTagSize>(
+        mut self,
+        associated_data: &[u8],
+        buffer: &[u8],
+    ) -> SstTag<TagSize> {
+        self.h.update_padded(associated_data);
+        self.h.update_padded(buffer);
+        let mut block = self.h.finalize_reset().into_bytes();
+
+        let buffer_bits = (buffer.len() as u64) * 8;
+        let associated_data_bits = (associated_data.len() as u64) * 8;
+
+        for (a, b) in block[..8].iter_mut().zip(&buffer_bits.to_le_bytes()) {
+            *a ^= b;
+        }
+
+        for (a, b) in block[8..]
+            .iter_mut()
+            .zip(&associated_data_bits.to_le_bytes())
+        {
+            *a ^= b;
+        }
+
+        self.q.update(&block);
+        let mut full_tag = self.q.finalize_reset().into_bytes();
+
+        for (a, b) in full_tag.iter_mut().zip(self.m.iter()) {
+            *a ^= b;
+        }
+
+        SstTag::clone_from_slice(&full_tag[..TagSize::to_usize()])
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for MessageKeys {
+    fn drop(&mut self) {
+        self.m.zeroize();
+    }
+}
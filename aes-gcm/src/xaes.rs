@@ -0,0 +1,120 @@
+//! XAES-256-GCM: AES-256-GCM with 192-bit nonces, as specified at
+//! <https://c2sp.org/XAES-256-GCM>.
+
+use crate::{Aes256Gcm, Key, Nonce, Tag};
+use aead::{AeadCore, AeadInPlace, Error, NewAead};
+use aes::Aes256;
+use cipher::{
+    consts::{U0, U12, U16, U24, U32},
+    Block, BlockEncrypt, NewBlockCipher,
+};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// XAES-256-GCM nonces (192-bit)
+pub type XNonce = Nonce<U24>;
+
+/// XAES-256-GCM: AES-256-GCM with a 256-bit key and a 192-bit nonce.
+///
+/// A fresh AES-256-GCM key is derived for every message from the key and the
+/// first 96 bits of the nonce, using AES-256 in a CMAC-based KDF in counter
+/// mode (NIST SP 800-108r1). The remaining 96 bits of the nonce are used as
+/// the AES-256-GCM nonce.
+///
+/// The extended nonce makes it safe to use random nonces for an effectively
+/// unlimited number of messages, while only relying on AES-256.
+#[derive(Clone)]
+pub struct XAes256Gcm {
+    /// Key derivation cipher
+    cipher: Aes256,
+
+    /// CMAC subkey `K1`
+    k1: Block<Aes256>,
+}
+
+impl NewAead for XAes256Gcm {
+    type KeySize = U32;
+
+    fn new(key: &Key<U32>) -> Self {
+        Aes256::new(key).into()
+    }
+}
+
+impl From<Aes256> for XAes256Gcm {
+    fn from(cipher: Aes256) -> Self {
+        let mut l = Block::<Aes256>::default();
+        cipher.encrypt_block(&mut l);
+
+        let l = u128::from_be_bytes(l.into());
+        let k1 = (l << 1) ^ ((l >> 127) * 0x87);
+
+        Self {
+            cipher,
+            k1: k1.to_be_bytes().into(),
+        }
+    }
+}
+
+impl AeadCore for XAes256Gcm {
+    type NonceSize = U24;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl AeadInPlace for XAes256Gcm {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &XNonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        let (cipher, nonce) = self.derive(nonce);
+        cipher.encrypt_in_place_detached(nonce, associated_data, buffer)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &XNonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        let (cipher, nonce) = self.derive(nonce);
+        cipher.decrypt_in_place_detached(nonce, associated_data, buffer, tag)
+    }
+}
+
+impl XAes256Gcm {
+    /// Derive the AES-256-GCM instance and 96-bit nonce of a message.
+    ///
+    /// The key is `AES-256(K, M1 ⊕ K1) || AES-256(K, M2 ⊕ K1)`, where
+    /// `Mi = [0, i, 'X', 0] || N[..12]`.
+    fn derive<'n>(&self, nonce: &'n XNonce) -> (Aes256Gcm, &'n Nonce<U12>) {
+        let mut key = Key::<U32>::default();
+
+        for (i, half) in key.chunks_exact_mut(16).enumerate() {
+            let mut block = Block::<Aes256>::default();
+            block[1] = i as u8 + 1;
+            block[2] = b'X';
+            block[4..].copy_from_slice(&nonce[..12]);
+
+            for (a, b) in block.iter_mut().zip(self.k1.iter()) {
+                *a ^= b;
+            }
+
+            self.cipher.encrypt_block(&mut block);
+            half.copy_from_slice(&block);
+
+            #[cfg(feature = "zeroize")]
+            block.zeroize();
+        }
+
+        let cipher = Aes256Gcm::new(&key);
+
+        #[cfg(feature = "zeroize")]
+        key.zeroize();
+
+        (cipher, Nonce::from_slice(&nonce[12..]))
+    }
+}
@@ -0,0 +1,118 @@
+//! GCM-AES-XPN: the extended packet numbering cipher suites of MACsec, as
+//! specified in IEEE 802.1AEbw-2013 (now part of IEEE 802.1AE).
+
+use crate::{AesGcm, Nonce, Tag};
+use aead::{AeadCore, AeadInPlace, Error, NewAead};
+use cipher::{
+    consts::{U0, U12, U16, U4, U8},
+    generic_array::{ArrayLength, GenericArray},
+    Block, BlockCipher, BlockCipherKey, BlockEncrypt, NewBlockCipher,
+};
+
+/// MACsec short secure channel identifiers (32-bit)
+pub type Ssci = GenericArray<u8, U4>;
+
+/// MACsec salts (96-bit)
+pub type Salt = GenericArray<u8, U12>;
+
+/// MACsec extended packet numbers (64-bit, big endian), used as the nonces of
+/// GCM-AES-XPN
+pub type PacketNumber = Nonce<U8>;
+
+/// GCM-AES-XPN: AES-GCM with a 96-bit IV made of the short secure channel
+/// identifier (SSCI) and the 64-bit packet number (PN) of each frame, XORed
+/// with the salt of the secure association key:
+///
+/// ```text
+/// IV = Salt ⊕ (SSCI || PN)
+/// ```
+///
+/// The SSCI and the salt are fixed for the lifetime of a secure association,
+/// so they are given when the cipher is instantiated, and the nonce passed
+/// to the [`AeadInPlace`] methods is the packet number of the frame.
+///
+/// Packet numbers MUST NOT be reused with the same key and SSCI.
+#[derive(Clone)]
+pub struct AesGcmXpn<Aes>
+where
+    Aes: BlockCipher<BlockSize = U16> + BlockEncrypt,
+    Aes::ParBlocks: ArrayLength<Block<Aes>>,
+{
+    /// AES-GCM with 96-bit nonces
+    gcm: AesGcm<Aes, U12>,
+
+    /// `Salt ⊕ (SSCI || 0^64)`
+    iv_mask: Nonce<U12>,
+}
+
+impl<Aes> AesGcmXpn<Aes>
+where
+    Aes: NewBlockCipher + BlockCipher<BlockSize = U16> + BlockEncrypt,
+    Aes::ParBlocks: ArrayLength<Block<Aes>>,
+{
+    /// Create a new GCM-AES-XPN instance for a secure association, from its
+    /// key (SAK), short secure channel identifier (SSCI) and salt.
+    pub fn new(key: &BlockCipherKey<Aes>, ssci: &Ssci, salt: &Salt) -> Self {
+        let mut iv_mask = *salt;
+        for (a, b) in iv_mask.iter_mut().zip(ssci) {
+            *a ^= b;
+        }
+
+        Self {
+            gcm: AesGcm::new(key),
+            iv_mask,
+        }
+    }
+}
+
+impl<Aes> AeadCore for AesGcmXpn<Aes>
+where
+    Aes: BlockCipher<BlockSize = U16> + BlockEncrypt,
+    Aes::ParBlocks: ArrayLength<Block<Aes>>,
+{
+    type NonceSize = U8;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl<Aes> AeadInPlace for AesGcmXpn<Aes>
+where
+    Aes: BlockCipher<BlockSize = U16> + BlockEncrypt,
+    Aes::ParBlocks: ArrayLength<Block<Aes>>,
+{
+    fn encrypt_in_place_detached(
+        &self,
+        pn: &PacketNumber,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        self.gcm
+            .encrypt_in_place_detached(&self.iv(pn), associated_data, buffer)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        pn: &PacketNumber,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        self.gcm
+            .decrypt_in_place_detached(&self.iv(pn), associated_data, buffer, tag)
+    }
+}
+
+impl<Aes> AesGcmXpn<Aes>
+where
+    Aes: BlockCipher<BlockSize = U16> + BlockEncrypt,
+    Aes::ParBlocks: ArrayLength<Block<Aes>>,
+{
+    /// `Salt ⊕ (SSCI || PN)`
+    fn iv(&self, pn: &PacketNumber) -> Nonce<U12> {
+        let mut iv = self.iv_mask;
+        for (a, b) in iv[4..].iter_mut().zip(pn) {
+            *a ^= b;
+        }
+        iv
+    }
+}
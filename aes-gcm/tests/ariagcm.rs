@@ -0,0 +1,104 @@
+//! ARIA-GCM tests
+
+#![cfg(feature = "aria")]
+
+#[macro_use]
+extern crate hex_literal;
+
+#[macro_use]
+mod common;
+
+use self::common::TestVector;
+use aes_gcm::aead::{generic_array::GenericArray, Aead, NewAead, Payload};
+
+/// RFC 6209 specifies the ARIA-GCM cipher suites but doesn't include test
+/// vectors for them, so these were generated with OpenSSL's ARIA-GCM, which
+/// is checked against the ARIA test vectors of RFC 5794.
+mod aria128gcm {
+    use super::*;
+    use aes_gcm::Aria128Gcm;
+
+    const TEST_VECTORS: &[TestVector<[u8; 16]>] = &[
+        TestVector {
+            key: &hex!("a94963d1046f43c9b6749c90fbf32ed9"),
+            nonce: &hex!("78377b525757b494427f8901"),
+            plaintext: &hex!("134f9a788d8fcb4d7135f07c4bce4ae4"),
+            aad: b"",
+            ciphertext: &hex!("f6b9c0cb0fe2f5984fedc0f0d784963c"),
+            tag: &hex!("d2ecb2a53489c8ebc165fa86e0bbb226"),
+        },
+        TestVector {
+            key: &hex!("a94963d1046f43c9b6749c90fbf32ed9"),
+            nonce: &hex!("78377b525757b494427f8901"),
+            plaintext: &hex!(
+                "
+                134f9a788d8fcb4d7135f07c4bce4ae4988147241a24e33b50bd424b22fa83df
+                c82e86d7d09aef8a18c332ad1999ab4aa7053e25bba6ecbdbf086261
+            "
+            ),
+            aad: &hex!("d0128e296de3da59e3848674f0699d139fa9eafa"),
+            ciphertext: &hex!(
+                "
+                f6b9c0cb0fe2f5984fedc0f0d784963c62352729edf19e4b413e46f6caf5929b
+                09183c6c4e2750da65cc6594f53c406ed879ff8afdedb153c98db1e5
+            "
+            ),
+            tag: &hex!("beb7e46ddf0d3b62f4009f2ed457f1ea"),
+        },
+        TestVector {
+            key: &hex!("a94963d1046f43c9b6749c90fbf32ed9"),
+            nonce: &hex!("78377b525757b494427f8901"),
+            plaintext: b"",
+            aad: b"",
+            ciphertext: b"",
+            tag: &hex!("31af01bb569b6dbdb45a4d06fc9c6110"),
+        },
+    ];
+
+    tests!(Aria128Gcm, TEST_VECTORS);
+}
+
+/// See [`aria128gcm`] for the origin of the vectors.
+mod aria256gcm {
+    use super::*;
+    use aes_gcm::Aria256Gcm;
+
+    const TEST_VECTORS: &[TestVector<[u8; 32]>] = &[
+        TestVector {
+            key: &hex!("3671f84859cef1f2c2f3b7cf2d834c4d2c79ff3890135dfff752367e7186bf72"),
+            nonce: &hex!("78377b525757b494427f8901"),
+            plaintext: &hex!("134f9a788d8fcb4d7135f07c4bce4ae4"),
+            aad: b"",
+            ciphertext: &hex!("b9170d32f79628e7996464256f6d094a"),
+            tag: &hex!("40de92e6e7e43aae9414ff89832aa7ef"),
+        },
+        TestVector {
+            key: &hex!("3671f84859cef1f2c2f3b7cf2d834c4d2c79ff3890135dfff752367e7186bf72"),
+            nonce: &hex!("78377b525757b494427f8901"),
+            plaintext: &hex!(
+                "
+                134f9a788d8fcb4d7135f07c4bce4ae4988147241a24e33b50bd424b22fa83df
+                c82e86d7d09aef8a18c332ad1999ab4aa7053e25bba6ecbdbf086261
+            "
+            ),
+            aad: &hex!("d0128e296de3da59e3848674f0699d139fa9eafa"),
+            ciphertext: &hex!(
+                "
+                b9170d32f79628e7996464256f6d094a2e8541535d4824d8a9e9a27bd0ad3cc4
+                f2c675b665458a25776923820a47207a2bf59367e0322b71d87988c5
+            "
+            ),
+            tag: &hex!("848d15dbfa2f484b89a1850451423dae"),
+        },
+        TestVector {
+            key: &hex!("3671f84859cef1f2c2f3b7cf2d834c4d2c79ff3890135dfff752367e7186bf72"),
+            nonce: &hex!("78377b525757b494427f8901"),
+            plaintext: b"",
+            aad: b"",
+            ciphertext: b"",
+            tag: &hex!("4f65e14ce20e9157ed1e2c9e9fbd98ae"),
+        },
+    ];
+
+    tests!(Aria256Gcm, TEST_VECTORS);
+}
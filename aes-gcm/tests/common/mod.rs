@@ -74,5 +74,40 @@ macro_rules! tests {
 
             // TODO(tarcieri): test ciphertext is unmodified in in-place API
         }
+
+        #[test]
+        fn verify() {
+            for vector in $vectors {
+                let key = GenericArray::from_slice(vector.key);
+                let nonce = GenericArray::from_slice(vector.nonce);
+                let mut ciphertext = Vec::from(vector.ciphertext);
+                ciphertext.extend_from_slice(vector.tag);
+
+                let cipher = <$aead>::new(key);
+                assert!(cipher.verify(nonce, vector.aad, &ciphertext).is_ok());
+
+                let tag = GenericArray::from_slice(vector.tag);
+                assert!(cipher
+                    .verify_detached(nonce, vector.aad, vector.ciphertext, tag)
+                    .is_ok());
+            }
+        }
+
+        #[test]
+        fn verify_modified() {
+            let vector = &$vectors[0];
+            let key = GenericArray::from_slice(vector.key);
+            let nonce = GenericArray::from_slice(vector.nonce);
+
+            let mut ciphertext = Vec::from(vector.ciphertext);
+            ciphertext.extend_from_slice(vector.tag);
+
+            // Tweak the last byte of the tag
+            *ciphertext.last_mut().unwrap() ^= 0xaa;
+
+            let cipher = <$aead>::new(key);
+            assert!(cipher.verify(nonce, vector.aad, &ciphertext).is_err());
+            assert!(cipher.verify(nonce, vector.aad, &ciphertext[..15]).is_err());
+        }
     };
 }
@@ -0,0 +1,160 @@
+//! AES-GCM-SST tests
+
+#[macro_use]
+extern crate hex_literal;
+
+use aes_gcm::aead::{
+    consts::{U12, U16, U4, U8},
+    generic_array::GenericArray,
+    Aead, NewAead, Payload,
+};
+use aes_gcm::{Aes128GcmSst, Aes256GcmSst};
+
+/// AES-GCM-SST test vector
+struct TestVector<K: 'static> {
+    key: &'static K,
+    nonce: &'static [u8; 12],
+    aad: &'static [u8],
+    plaintext: &'static [u8],
+    ciphertext: &'static [u8],
+    tag: &'static [u8],
+}
+
+/// Known-answer tests of an AES-GCM-SST instance
+macro_rules! sst_tests {
+    ($name:ident, $aead:ty, $vectors:expr) => {
+        mod $name {
+            use super::*;
+
+            #[test]
+            fn encrypt() {
+                for vector in $vectors {
+                    let payload = Payload {
+                        msg: vector.plaintext,
+                        aad: vector.aad,
+                    };
+
+                    let cipher = <$aead>::new(GenericArray::from_slice(vector.key));
+                    let ciphertext = cipher
+                        .encrypt(GenericArray::from_slice(vector.nonce), payload)
+                        .unwrap();
+                    let (ct, tag) = ciphertext.split_at(vector.plaintext.len());
+                    assert_eq!(vector.ciphertext, ct);
+                    assert_eq!(vector.tag, tag);
+                }
+            }
+
+            #[test]
+            fn decrypt() {
+                for vector in $vectors {
+                    let mut ciphertext = Vec::from(vector.ciphertext);
+                    ciphertext.extend_from_slice(vector.tag);
+
+                    let payload = Payload {
+                        msg: &ciphertext,
+                        aad: vector.aad,
+                    };
+
+                    let cipher = <$aead>::new(GenericArray::from_slice(vector.key));
+                    let plaintext = cipher
+                        .decrypt(GenericArray::from_slice(vector.nonce), payload)
+                        .unwrap();
+                    assert_eq!(vector.plaintext, plaintext.as_slice());
+                }
+            }
+
+            #[test]
+            fn decrypt_modified() {
+                for vector in $vectors {
+                    let mut ciphertext = Vec::from(vector.ciphertext);
+                    ciphertext.extend_from_slice(vector.tag);
+
+                    // Tweak the first byte
+                    ciphertext[0] ^= 0xaa;
+
+                    let payload = Payload {
+                        msg: &ciphertext,
+                        aad: vector.aad,
+                    };
+
+                    let cipher = <$aead>::new(GenericArray::from_slice(vector.key));
+                    let nonce = GenericArray::from_slice(vector.nonce);
+                    assert!(cipher.decrypt(nonce, payload).is_err());
+                }
+            }
+        }
+    };
+}
+
+/// Test vectors generated with an independent implementation of
+/// draft-mattsson-cfrg-aes-gcm-sst
+const AES128_TAG32_VECTORS: &[TestVector<[u8; 16]>] = &[TestVector {
+    key: &hex!("000102030405060708090a0b0c0d0e0f"),
+    nonce: &hex!("303132333435363738393a3b"),
+    aad: b"",
+    plaintext: b"",
+    ciphertext: b"",
+    tag: &hex!("9b1d49ea"),
+}];
+
+const AES128_TAG64_VECTORS: &[TestVector<[u8; 16]>] = &[
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("303132333435363738393a3b"),
+        aad: b"",
+        plaintext: &hex!("808386898c8f9295989b9ea1a4a7aaad"),
+        ciphertext: &hex!("8412bf44f638b4c881d7aa179b839823"),
+        tag: &hex!("a2c141e2a5cd6319"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("303132333435363738393a3b"),
+        aad: &hex!("01080f161d242b323940474e55"),
+        plaintext: &hex!("808386898c8f92"),
+        ciphertext: &hex!("8412bf44f638b4"),
+        tag: &hex!("93f197a6fc6c6b5a"),
+    },
+];
+
+const AES128_TAG96_VECTORS: &[TestVector<[u8; 16]>] = &[TestVector {
+    key: &hex!("000102030405060708090a0b0c0d0e0f"),
+    nonce: &hex!("303132333435363738393a3b"),
+    aad: &hex!("01080f161d242b323940474e555c636a71787f868d949ba2a9b0b7bec5ccd3dae1e8eff6fd040b12"),
+    plaintext: &hex!("808386898c8f9295989b9ea1a4a7aaadb0b3b6b9bcbfc2c5c8cbced1d4d7dadde0"),
+    ciphertext: &hex!("8412bf44f638b4c881d7aa179b839823bd020f0d4d6d1d926bf09c55cb98b4888a"),
+    tag: &hex!("ad61cbcdb03e58ffae06d630"),
+}];
+
+const AES256_TAG64_VECTORS: &[TestVector<[u8; 32]>] = &[
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        nonce: &hex!("303132333435363738393a3b"),
+        aad: b"",
+        plaintext: b"",
+        ciphertext: b"",
+        tag: &hex!("b33531c0e96f4a03"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        nonce: &hex!("303132333435363738393a3b"),
+        aad: &hex!("01080f161d242b323940474e555c636a71787f86"),
+        plaintext: &hex!("808386898c8f9295989b9ea1a4a7aaadb0b3b6b9bcbfc2c5c8cbced1d4d7dadde0e3e6e9eceff2f5f8fbfe0104070a0d101316191c1f2225282b2e3134373a3d"),
+        ciphertext: &hex!("1ca4c9de4fb1d690bf25cfed4c14d493f3d3badd907f596266efdc98ae1109f04eafbba0c75e37d255bb9f55444f5bf94075a89688b6151ad830b767525846fd"),
+        tag: &hex!("a3426913916eb763"),
+    },
+];
+
+const AES256_TAG128_VECTORS: &[TestVector<[u8; 32]>] = &[TestVector {
+    key: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+    nonce: &hex!("303132333435363738393a3b"),
+    aad: &hex!("01080f161d242b323940474e555c636a71"),
+    plaintext: &hex!("808386898c8f9295989b9ea1a4a7aaadb0b3b6b9bcbfc2c5c8cbced1d4d7da"),
+    ciphertext: &hex!("1ca4c9de4fb1d690bf25cfed4c14d493f3d3badd907f596266efdc98ae1109"),
+    tag: &hex!("179029a067c1e58a5a62f10d7b9f150c"),
+}];
+
+sst_tests!(aes128_tag32, Aes128GcmSst<U4>, AES128_TAG32_VECTORS);
+sst_tests!(aes128_tag64, Aes128GcmSst<U8>, AES128_TAG64_VECTORS);
+sst_tests!(aes128_tag96, Aes128GcmSst<U12>, AES128_TAG96_VECTORS);
+sst_tests!(aes256_tag64, Aes256GcmSst<U8>, AES256_TAG64_VECTORS);
+sst_tests!(aes256_tag128, Aes256GcmSst<U16>, AES256_TAG128_VECTORS);
@@ -0,0 +1,121 @@
+//! Tests for GCM instantiated with block ciphers from other crates
+
+#[macro_use]
+extern crate hex_literal;
+
+use aes_gcm::{
+    aead::{
+        consts::{U12, U16},
+        generic_array::GenericArray,
+        Aead, AeadInPlace, NewAead, Payload,
+    },
+    aes::{
+        cipher::{Block, BlockCipher, BlockCipherKey, BlockEncrypt, NewBlockCipher},
+        Aes128,
+    },
+    Gcm,
+};
+use twofish::Twofish;
+
+/// A block cipher defined outside of this crate, e.g. a hardware vendor's
+/// implementation, wrapping AES-128 so known-answer tests can be used
+struct VendorAes128(Aes128);
+
+impl BlockCipher for VendorAes128 {
+    type BlockSize = U16;
+    type ParBlocks = <Aes128 as BlockCipher>::ParBlocks;
+}
+
+impl BlockEncrypt for VendorAes128 {
+    fn encrypt_block(&self, block: &mut Block<Self>) {
+        self.0.encrypt_block(block)
+    }
+}
+
+impl NewBlockCipher for VendorAes128 {
+    type KeySize = U16;
+
+    fn new(key: &BlockCipherKey<Self>) -> Self {
+        Self(Aes128::new(key))
+    }
+}
+
+/// Based on the following `gcmEncryptExtIV128.rsp` test vector:
+///
+/// [Keylen = 128]
+/// [IVlen = 96]
+/// [PTlen = 128]
+/// [AADlen = 0]
+/// [Taglen = 128]
+///
+/// Count = 0
+#[test]
+fn vendor_cipher_encrypt() {
+    let key = hex!("7fddb57453c241d03efbed3ac44e371c");
+    let nonce = hex!("ee283a3fc75575e33efd4887");
+    let plaintext = hex!("d5de42b461646c255c87bd2962d3b9a2");
+
+    let cipher = Gcm::<VendorAes128, U12>::new(&key.into());
+    let ciphertext = cipher
+        .encrypt(GenericArray::from_slice(&nonce), &plaintext[..])
+        .unwrap();
+
+    let (ct, tag) = ciphertext.split_at(ciphertext.len() - 16);
+    assert_eq!(hex!("2ccda4a5415cb91e135c2a0f78c9b2fd"), ct);
+    assert_eq!(hex!("b36d1df9b9d5e596f83e8b7f52971cb3"), tag);
+}
+
+/// Truncated tags are the prefix of the full tag
+#[test]
+fn vendor_cipher_truncated_tag() {
+    let key = hex!("7fddb57453c241d03efbed3ac44e371c");
+    let nonce = hex!("ee283a3fc75575e33efd4887");
+    let mut buffer = hex!("d5de42b461646c255c87bd2962d3b9a2");
+
+    let cipher: Gcm<VendorAes128, U12, U12> = VendorAes128::new(&key.into()).into();
+    let tag = cipher
+        .encrypt_in_place_detached(GenericArray::from_slice(&nonce), b"", &mut buffer)
+        .unwrap();
+
+    assert_eq!(hex!("2ccda4a5415cb91e135c2a0f78c9b2fd"), buffer);
+    assert_eq!(hex!("b36d1df9b9d5e596f83e8b7f"), tag.as_slice());
+}
+
+#[test]
+fn twofish_round_trip() {
+    let key = GenericArray::from_slice(b"an example very very secret key.");
+    let nonce = GenericArray::from_slice(b"unique nonce");
+    let plaintext = b"plaintext message";
+    let aad = b"associated data";
+
+    let cipher = Gcm::<Twofish, U12>::new(key);
+    let payload = Payload {
+        msg: plaintext,
+        aad,
+    };
+    let ciphertext = cipher.encrypt(nonce, payload).unwrap();
+    assert_eq!(ciphertext.len(), plaintext.len() + 16);
+
+    let payload = Payload {
+        msg: &ciphertext,
+        aad,
+    };
+    assert_eq!(
+        plaintext,
+        cipher.decrypt(nonce, payload).unwrap().as_slice()
+    );
+}
+
+#[test]
+fn twofish_decrypt_modified() {
+    let key = GenericArray::from_slice(b"an example very very secret key.");
+    let nonce = GenericArray::from_slice(b"unique nonce");
+
+    let cipher = Gcm::<Twofish, U12>::new(key);
+    let mut ciphertext = cipher.encrypt(nonce, &b"plaintext message"[..]).unwrap();
+
+    // Tweak the first byte
+    ciphertext[0] ^= 0xaa;
+
+    assert!(cipher.decrypt(nonce, ciphertext.as_slice()).is_err());
+}
@@ -0,0 +1,93 @@
+//! `AlgorithmIdentifier` tests
+
+#![cfg(feature = "der")]
+
+#[macro_use]
+extern crate hex_literal;
+
+use aes_gcm::oid::{
+    der::Decodable, AlgorithmIdentifier, GcmParameters, ObjectIdentifier, AES_128_GCM, AES_256_GCM,
+};
+
+const NONCE: [u8; 12] = hex!("000102030405060708090a0b");
+
+/// aes128-GCM with the default 12-byte tag
+const AES_128_GCM_ALGID: &[u8] =
+    &hex!("301b0609608648016503040106300e040c000102030405060708090a0b");
+
+/// aes256-GCM with a 16-byte tag
+const AES_256_GCM_ALGID: &[u8] =
+    &hex!("301e060960864801650304012e3011040c000102030405060708090a0b020110");
+
+#[test]
+fn decode_default_icv_len() {
+    let algorithm = AlgorithmIdentifier::from_der(AES_128_GCM_ALGID).unwrap();
+    assert_eq!(algorithm.oid, AES_128_GCM);
+
+    let params = GcmParameters::from_algorithm_identifier(&algorithm).unwrap();
+    assert_eq!(params.nonce(), &NONCE);
+    assert_eq!(params.icv_len(), 12);
+}
+
+#[test]
+fn decode_explicit_icv_len() {
+    let algorithm = AlgorithmIdentifier::from_der(AES_256_GCM_ALGID).unwrap();
+    assert_eq!(algorithm.oid, AES_256_GCM);
+
+    let params = GcmParameters::from_algorithm_identifier(&algorithm).unwrap();
+    assert_eq!(params.nonce(), &NONCE);
+    assert_eq!(params.icv_len(), 16);
+}
+
+#[test]
+fn decode_explicit_default_icv_len() {
+    let params = GcmParameters::from_der(&hex!("3011040c000102030405060708090a0b02010c")).unwrap();
+    assert_eq!(params, GcmParameters::new(&NONCE, 12).unwrap());
+}
+
+#[test]
+fn decode_invalid_icv_len() {
+    assert!(GcmParameters::from_der(&hex!("3011040c000102030405060708090a0b020108")).is_err());
+    assert!(GcmParameters::from_der(&hex!("3011040c000102030405060708090a0b020111")).is_err());
+}
+
+#[test]
+fn decode_wrong_oid() {
+    // aes128-CCM
+    let der = hex!("301b0609608648016503040107300e040c000102030405060708090a0b");
+    let algorithm = AlgorithmIdentifier::from_der(&der).unwrap();
+    assert!(GcmParameters::from_algorithm_identifier(&algorithm).is_err());
+}
+
+#[test]
+fn encode() {
+    let mut buf = [0u8; 64];
+
+    let params = GcmParameters::new(&NONCE, 12).unwrap();
+    let der = params
+        .encode_algorithm_identifier(AES_128_GCM, &mut buf)
+        .unwrap();
+    assert_eq!(der, AES_128_GCM_ALGID);
+
+    let params = GcmParameters::new(&NONCE, 16).unwrap();
+    let der = params
+        .encode_algorithm_identifier(AES_256_GCM, &mut buf)
+        .unwrap();
+    assert_eq!(der, AES_256_GCM_ALGID);
+}
+
+#[test]
+fn new_invalid() {
+    assert!(GcmParameters::new(&[], 16).is_err());
+    assert!(GcmParameters::new(&NONCE, 11).is_err());
+    assert!(GcmParameters::new(&NONCE, 17).is_err());
+}
+
+#[test]
+fn oids() {
+    assert_eq!(AES_128_GCM, ObjectIdentifier::new("2.16.840.1.101.3.4.1.6"));
+    assert_eq!(
+        AES_256_GCM,
+        ObjectIdentifier::new("2.16.840.1.101.3.4.1.46")
+    );
+}
@@ -1,7 +1,9 @@
 //! Tests for AES-GCM when used with non-96-bit IVs.
 //!
 //! Vectors taken from NIST CAVS vectors' `gcmEncryptExtIV128.rsp` file
-/// <https://csrc.nist.gov/Projects/cryptographic-algorithm-validation-program/CAVP-TESTING-BLOCK-CIPHER-MODES>
+//! <https://csrc.nist.gov/Projects/cryptographic-algorithm-validation-program/CAVP-TESTING-BLOCK-CIPHER-MODES>
+//! and from the test cases of McGrew and Viega's GCM specification
+//! <https://csrc.nist.rip/groups/ST/toolkit/BCM/documents/proposedmodes/gcm/gcm-revised-spec.pdf>
 
 #[macro_use]
 extern crate hex_literal;
@@ -9,12 +11,153 @@ extern crate hex_literal;
 use aes_gcm::{
     aead::{
         generic_array::{typenum, GenericArray},
-        Aead, NewAead,
+        Aead, AeadInPlace, NewAead, Payload,
     },
-    aes::Aes128,
+    aes::{Aes128, Aes256},
     AesGcm,
 };
 
+macro_rules! ivlen_tests {
+    ($aead:ty, $key:expr, $nonce:expr, $aad:expr, $plaintext:expr, $ciphertext:expr, $tag:expr) => {
+        #[test]
+        fn encrypt() {
+            let payload = Payload {
+                msg: &$plaintext,
+                aad: &$aad,
+            };
+
+            let ciphertext = <$aead>::new(&$key.into())
+                .encrypt(GenericArray::from_slice(&$nonce), payload)
+                .unwrap();
+
+            let (ct, tag) = ciphertext.split_at(ciphertext.len() - 16);
+            assert_eq!(&$ciphertext[..], ct);
+            assert_eq!(&$tag[..], tag);
+        }
+
+        #[test]
+        fn decrypt() {
+            let mut ciphertext = $ciphertext.to_vec();
+            ciphertext.extend_from_slice(&$tag);
+            let payload = Payload {
+                msg: &ciphertext,
+                aad: &$aad,
+            };
+
+            let plaintext = <$aead>::new(&$key.into())
+                .decrypt(GenericArray::from_slice(&$nonce), payload)
+                .unwrap();
+
+            assert_eq!(&$plaintext[..], plaintext.as_slice());
+        }
+    };
+}
+
+/// Test Case 5 (AES-128) and 17 (AES-256) of the GCM specification
+mod ivlen64 {
+    use super::*;
+
+    mod aes128 {
+        use super::*;
+
+        ivlen_tests!(
+            AesGcm<Aes128, typenum::U8>,
+            hex!("feffe9928665731c6d6a8f9467308308"),
+            hex!("cafebabefacedbad"),
+            hex!("feedfacedeadbeeffeedfacedeadbeefabaddad2"),
+            hex!(
+                "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a72
+                 1c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39"
+            ),
+            hex!(
+                "61353b4c2806934a777ff51fa22a4755699b2a714fcdc6f83766e5f97b6c7423
+                 73806900e49f24b22b097544d4896b424989b5e1ebac0f07c23f4598"
+            ),
+            hex!("3612d2e79e3b0785561be14aaca2fccb")
+        );
+    }
+
+    mod aes256 {
+        use super::*;
+
+        ivlen_tests!(
+            AesGcm<Aes256, typenum::U8>,
+            hex!("feffe9928665731c6d6a8f9467308308feffe9928665731c6d6a8f9467308308"),
+            hex!("cafebabefacedbad"),
+            hex!("feedfacedeadbeeffeedfacedeadbeefabaddad2"),
+            hex!(
+                "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a72
+                 1c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39"
+            ),
+            hex!(
+                "c3762df1ca787d32ae47c13bf19844cbaf1ae14d0b976afac52ff7d79bba9de0
+                 feb582d33934a4f0954cc2363bc73f7862ac430e64abe499f47c9b1f"
+            ),
+            hex!("3a337dbf46a792c45e454913fe2ea8f2")
+        );
+    }
+}
+
+/// The inputs of Test Case 5 with a 128-bit IV, encrypted with OpenSSL
+mod ivlen128 {
+    use super::*;
+
+    ivlen_tests!(
+        AesGcm<Aes128, typenum::U16>,
+        hex!("feffe9928665731c6d6a8f9467308308"),
+        hex!("cafebabefacedbaddecaf888feedface"),
+        hex!("feedfacedeadbeeffeedfacedeadbeefabaddad2"),
+        hex!(
+            "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a72
+             1c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39"
+        ),
+        hex!(
+            "710b5746678b6ba050b7b313b4eb3eb5b4b787bc37adf97a283cf269efb1745f
+             33f559ac93afcc71c1527aca31492c6b1a33a3ce34872f7e05f793aa"
+        ),
+        hex!("8632a7ac1974d9378bc3d3ac53a2a1ab")
+    );
+}
+
+/// Test Case 6 of the GCM specification
+mod ivlen480 {
+    use super::*;
+
+    ivlen_tests!(
+        AesGcm<Aes128, typenum::U60>,
+        hex!("feffe9928665731c6d6a8f9467308308"),
+        hex!(
+            "9313225df88406e555909c5aff5269aa6a7a9538534f7da1e4c303d2a318a728
+             c3c0c95156809539fcf0e2429a6b525416aedbf5a0de6a57a637b39b"
+        ),
+        hex!("feedfacedeadbeeffeedfacedeadbeefabaddad2"),
+        hex!(
+            "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a72
+             1c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39"
+        ),
+        hex!(
+            "8ce24998625615b603a033aca13fb894be9112a5c3a211a8ba262a3cca7e2ca7
+             01e4a9a4fba43c90ccdcb281d48c7c6fd62875d2aca417034c34aee5"
+        ),
+        hex!("619cc5aefffe0bfa462af43c1699d050")
+    );
+}
+
+/// NIST SP 800-38D requires IVs to be at least 1 bit long
+#[test]
+fn empty_nonce_is_rejected() {
+    let cipher = AesGcm::<Aes128, typenum::U0>::new(&[0; 16].into());
+    let nonce = GenericArray::default();
+
+    let mut buffer = *b"plaintext";
+    assert!(cipher
+        .encrypt_in_place_detached(&nonce, b"", &mut buffer)
+        .is_err());
+    assert!(cipher
+        .decrypt_in_place_detached(&nonce, b"", &mut buffer, &Default::default())
+        .is_err());
+}
+
 /// Based on the following `gcmEncryptExtIV128.rsp` test vector:
 ///
 /// [Keylen = 128]
@@ -0,0 +1,110 @@
+//! AES-GCM with the truncated tags of the RFC 4106 IPsec ESP transforms
+//!
+//! GCM tags are truncated by keeping their leading bytes, so these use NIST
+//! CAVS vectors from `gcmEncryptExtIV128.rsp` and `gcmEncryptExtIV256.rsp`
+//! with the tag truncated to its first 64 or 96 bits.
+
+#[macro_use]
+extern crate hex_literal;
+
+use aes_gcm::aead::{generic_array::GenericArray, Aead, AeadInPlace, NewAead, Payload};
+use aes_gcm::{Aes128Gcm12, Aes128Gcm8, Aes256Gcm12, Aes256Gcm8};
+
+macro_rules! truncated_tag_tests {
+    ($name:ident, $aead:ty, $key:expr, $nonce:expr, $aad:expr, $pt:expr, $ct:expr, $tag:expr) => {
+        mod $name {
+            use super::*;
+
+            #[test]
+            fn encrypt() {
+                let cipher = <$aead>::new(GenericArray::from_slice(&$key));
+                let payload = Payload {
+                    msg: &$pt,
+                    aad: &$aad,
+                };
+                let ciphertext = cipher
+                    .encrypt(GenericArray::from_slice(&$nonce), payload)
+                    .unwrap();
+
+                let (ct, tag) = ciphertext.split_at($ct.len());
+                assert_eq!(&$ct, ct);
+                assert_eq!(&$tag, tag);
+            }
+
+            #[test]
+            fn decrypt() {
+                let cipher = <$aead>::new(GenericArray::from_slice(&$key));
+                let mut ciphertext = $ct.to_vec();
+                ciphertext.extend_from_slice(&$tag);
+                let payload = Payload {
+                    msg: &ciphertext,
+                    aad: &$aad,
+                };
+                let plaintext = cipher
+                    .decrypt(GenericArray::from_slice(&$nonce), payload)
+                    .unwrap();
+
+                assert_eq!(&$pt, plaintext.as_slice());
+            }
+
+            #[test]
+            fn decrypt_modified() {
+                let cipher = <$aead>::new(GenericArray::from_slice(&$key));
+                let nonce = GenericArray::from_slice(&$nonce);
+
+                let mut tag = $tag;
+                tag[0] ^= 0xaa;
+
+                let mut buffer = $ct;
+                assert!(cipher
+                    .decrypt_in_place_detached(nonce, &$aad, &mut buffer, &tag.into())
+                    .is_err());
+                assert_eq!($ct, buffer);
+            }
+        }
+    };
+}
+
+truncated_tag_tests!(
+    aes128gcm8,
+    Aes128Gcm8,
+    hex!("c939cc13397c1d37de6ae0e1cb7c423c"),
+    hex!("b3d8cc017cbb89b39e0f67e2"),
+    hex!("24825602bd12a984e0092d3e448eda5f"),
+    hex!("c3b3c41f113a31b73d9a5cd432103069"),
+    hex!("93fe7d9e9bfd10348a5606e5cafa7354"),
+    hex!("0032a1dc85f1c978")
+);
+
+truncated_tag_tests!(
+    aes128gcm12,
+    Aes128Gcm12,
+    hex!("c939cc13397c1d37de6ae0e1cb7c423c"),
+    hex!("b3d8cc017cbb89b39e0f67e2"),
+    hex!("24825602bd12a984e0092d3e448eda5f"),
+    hex!("c3b3c41f113a31b73d9a5cd432103069"),
+    hex!("93fe7d9e9bfd10348a5606e5cafa7354"),
+    hex!("0032a1dc85f1c9786925a2e7")
+);
+
+truncated_tag_tests!(
+    aes256gcm8,
+    Aes256Gcm8,
+    hex!("92e11dcdaa866f5ce790fd24501f92509aacf4cb8b1339d50c9c1240935dd08b"),
+    hex!("ac93a1a6145299bde902f21a"),
+    hex!("1e0889016f67601c8ebea4943bc23ad6"),
+    hex!("2d71bcfa914e4ac045b2aa60955fad24"),
+    hex!("8995ae2e6df3dbf96fac7b7137bae67f"),
+    hex!("eca5aa77d51d4a0a")
+);
+
+truncated_tag_tests!(
+    aes256gcm12,
+    Aes256Gcm12,
+    hex!("92e11dcdaa866f5ce790fd24501f92509aacf4cb8b1339d50c9c1240935dd08b"),
+    hex!("ac93a1a6145299bde902f21a"),
+    hex!("1e0889016f67601c8ebea4943bc23ad6"),
+    hex!("2d71bcfa914e4ac045b2aa60955fad24"),
+    hex!("8995ae2e6df3dbf96fac7b7137bae67f"),
+    hex!("eca5aa77d51d4a0a14d9c51e")
+);
@@ -0,0 +1,37 @@
+//! SM4-GCM tests
+
+#![cfg(feature = "sm4")]
+
+#[macro_use]
+extern crate hex_literal;
+
+#[macro_use]
+mod common;
+
+use self::common::TestVector;
+use aes_gcm::aead::{generic_array::GenericArray, Aead, NewAead, Payload};
+use aes_gcm::Sm4Gcm;
+
+/// Example of SM4-GCM from RFC 8998, Appendix A.1
+///
+/// <https://tools.ietf.org/html/rfc8998#appendix-A.1>
+const TEST_VECTORS: &[TestVector<[u8; 16]>] = &[TestVector {
+    key: &hex!("0123456789ABCDEFFEDCBA9876543210"),
+    nonce: &hex!("00001234567800000000ABCD"),
+    plaintext: &hex!(
+        "
+        AAAAAAAAAAAAAAAABBBBBBBBBBBBBBBBCCCCCCCCCCCCCCCCDDDDDDDDDDDDDDDD
+        EEEEEEEEEEEEEEEEFFFFFFFFFFFFFFFFEEEEEEEEEEEEEEEEAAAAAAAAAAAAAAAA
+    "
+    ),
+    aad: &hex!("FEEDFACEDEADBEEFFEEDFACEDEADBEEFABADDAD2"),
+    ciphertext: &hex!(
+        "
+        17F399F08C67D5EE19D0DC9969C4BB7D5FD46FD3756489069157B282BB200735
+        D82710CA5C22F0CCFA7CBF93D496AC15A56834CBCF98C397B4024A2691233B8D
+    "
+    ),
+    tag: &hex!("83DE3541E4C2B58177E065A9BF7B62EC"),
+}];
+
+tests!(Sm4Gcm, TEST_VECTORS);
@@ -0,0 +1,88 @@
+//! XAES-256-GCM tests
+
+#[macro_use]
+extern crate hex_literal;
+
+use aes_gcm::aead::{Aead, NewAead, Payload};
+use aes_gcm::{Key, XAes256Gcm, XNonce};
+
+/// XAES-256-GCM test vector
+struct TestVector {
+    key: &'static [u8; 32],
+    nonce: &'static [u8; 24],
+    plaintext: &'static [u8],
+    aad: &'static [u8],
+    ciphertext: &'static [u8],
+}
+
+/// Test vectors from the C2SP specification
+///
+/// <https://c2sp.org/XAES-256-GCM>
+const TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        key: &[0x01; 32],
+        nonce: b"ABCDEFGHIJKLMNOPQRSTUVWX",
+        plaintext: b"XAES-256-GCM",
+        aad: b"",
+        ciphertext: &hex!("ce546ef63c9cc60765923609b33a9a1974e96e52daf2fcf7075e2271"),
+    },
+    TestVector {
+        key: &[0x03; 32],
+        nonce: b"ABCDEFGHIJKLMNOPQRSTUVWX",
+        plaintext: b"XAES-256-GCM",
+        aad: b"c2sp.org/XAES-256-GCM",
+        ciphertext: &hex!("986ec1832593df5443a179437fd083bf3fdb41abd740a21f71eb769d"),
+    },
+];
+
+#[test]
+fn encrypt() {
+    for vector in TEST_VECTORS {
+        let cipher = XAes256Gcm::new(Key::from_slice(vector.key));
+        let payload = Payload {
+            msg: vector.plaintext,
+            aad: vector.aad,
+        };
+
+        let ciphertext = cipher
+            .encrypt(XNonce::from_slice(vector.nonce), payload)
+            .unwrap();
+        assert_eq!(vector.ciphertext, ciphertext.as_slice());
+    }
+}
+
+#[test]
+fn decrypt() {
+    for vector in TEST_VECTORS {
+        let cipher = XAes256Gcm::new(Key::from_slice(vector.key));
+        let payload = Payload {
+            msg: vector.ciphertext,
+            aad: vector.aad,
+        };
+
+        let plaintext = cipher
+            .decrypt(XNonce::from_slice(vector.nonce), payload)
+            .unwrap();
+        assert_eq!(vector.plaintext, plaintext.as_slice());
+    }
+}
+
+#[test]
+fn decrypt_modified() {
+    let vector = &TEST_VECTORS[1];
+    let cipher = XAes256Gcm::new(Key::from_slice(vector.key));
+
+    let mut ciphertext = Vec::from(vector.ciphertext);
+
+    // Tweak the first byte
+    ciphertext[0] ^= 0xaa;
+
+    let payload = Payload {
+        msg: &ciphertext,
+        aad: vector.aad,
+    };
+
+    assert!(cipher
+        .decrypt(XNonce::from_slice(vector.nonce), payload)
+        .is_err());
+}
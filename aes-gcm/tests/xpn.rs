@@ -0,0 +1,139 @@
+//! GCM-AES-XPN tests
+//!
+//! GCM-AES-XPN is AES-GCM with the IV `Salt ⊕ (SSCI || PN)`, so these tests
+//! check it against AES-GCM with that IV computed independently.
+
+#[macro_use]
+extern crate hex_literal;
+
+use aes_gcm::aead::{Aead, AeadInPlace, NewAead, Payload};
+use aes_gcm::{
+    Aes128Gcm, Aes128GcmXpn, Aes256Gcm, Aes256GcmXpn, Key, Nonce, PacketNumber, Salt, Ssci,
+};
+
+const SSCI: [u8; 4] = hex!("7a30c118");
+
+const SALT: [u8; 12] = hex!("e630e81a48de86a21c66fa6d");
+
+const PACKET_NUMBERS: &[u64] = &[0, 1, 0x7fff_ffff, 0xb0df_459c, 0x1_0000_0000, !0];
+
+const AAD: &[u8] = &hex!("e20106d7cd0df0761e8dcd3d88e54c2a76d457ed");
+
+const PLAINTEXT: &[u8] = b"a MACsec frame with an extended packet number";
+
+/// `Salt ⊕ (SSCI || PN)`
+fn iv(pn: u64) -> [u8; 12] {
+    let mut iv = [0u8; 12];
+    iv[..4].copy_from_slice(&SSCI);
+    iv[4..].copy_from_slice(&pn.to_be_bytes());
+    for (a, b) in iv.iter_mut().zip(SALT.iter()) {
+        *a ^= b;
+    }
+    iv
+}
+
+macro_rules! tests {
+    ($name:ident, $xpn:ty, $gcm:ty, $key:expr) => {
+        mod $name {
+            use super::*;
+
+            fn xpn() -> $xpn {
+                <$xpn>::new(
+                    Key::from_slice($key),
+                    Ssci::from_slice(&SSCI),
+                    Salt::from_slice(&SALT),
+                )
+            }
+
+            #[test]
+            fn encrypt() {
+                let gcm = <$gcm>::new(Key::from_slice($key));
+
+                for &pn in PACKET_NUMBERS {
+                    let payload = Payload {
+                        msg: PLAINTEXT,
+                        aad: AAD,
+                    };
+                    let ciphertext = xpn()
+                        .encrypt(&PacketNumber::from(pn.to_be_bytes()), payload)
+                        .unwrap();
+
+                    let payload = Payload {
+                        msg: PLAINTEXT,
+                        aad: AAD,
+                    };
+                    let expected = gcm.encrypt(Nonce::from_slice(&iv(pn)), payload).unwrap();
+                    assert_eq!(expected, ciphertext);
+                }
+            }
+
+            #[test]
+            fn decrypt() {
+                for &pn in PACKET_NUMBERS {
+                    let pn = PacketNumber::from(pn.to_be_bytes());
+                    let mut buffer = PLAINTEXT.to_vec();
+                    let tag = xpn()
+                        .encrypt_in_place_detached(&pn, AAD, &mut buffer)
+                        .unwrap();
+
+                    xpn()
+                        .decrypt_in_place_detached(&pn, AAD, &mut buffer, &tag)
+                        .unwrap();
+                    assert_eq!(PLAINTEXT, buffer.as_slice());
+                }
+            }
+
+            #[test]
+            fn decrypt_modified() {
+                let pn = PacketNumber::from(0x1_0000_0000u64.to_be_bytes());
+                let payload = Payload {
+                    msg: PLAINTEXT,
+                    aad: AAD,
+                };
+                let mut ciphertext = xpn().encrypt(&pn, payload).unwrap();
+
+                // Tweak the first byte
+                ciphertext[0] ^= 0xaa;
+
+                let payload = Payload {
+                    msg: &ciphertext,
+                    aad: AAD,
+                };
+                assert!(xpn().decrypt(&pn, payload).is_err());
+                ciphertext[0] ^= 0xaa;
+
+                // Another packet number, or another SSCI
+                let payload = Payload {
+                    msg: &ciphertext,
+                    aad: AAD,
+                };
+                let other_pn = PacketNumber::from(1u64.to_be_bytes());
+                assert!(xpn().decrypt(&other_pn, payload).is_err());
+
+                let other = <$xpn>::new(
+                    Key::from_slice($key),
+                    Ssci::from_slice(&[0, 0, 0, 1]),
+                    Salt::from_slice(&SALT),
+                );
+                let payload = Payload {
+                    msg: &ciphertext,
+                    aad: AAD,
+                };
+                assert!(other.decrypt(&pn, payload).is_err());
+            }
+        }
+    };
+}
+
+tests!(
+    gcm_aes_xpn_128,
+    Aes128GcmXpn,
+    Aes128Gcm,
+    &hex!("ad7a2bd03eac835a6f620fdcb506b345")
+);
+tests!(
+    gcm_aes_xpn_256,
+    Aes256GcmXpn,
+    Aes256Gcm,
+    &hex!("e3c08a8f06c6e3ad95a70557b23f75483ce33021a9c72b7025666204c69c0b72")
+);
@@ -0,0 +1,339 @@
+//! Key encryption keys, and the wrapping function `W` of RFC 3394 that both
+//! algorithms are built on.
+
+use aead::Error;
+use cipher::{
+    consts::U16, generic_array::GenericArray, BlockCipher, BlockCipherKey, BlockDecrypt,
+    BlockEncrypt, NewBlockCipher,
+};
+use subtle::ConstantTimeEq;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Size of a semiblock: keys are wrapped in 64-bit units.
+pub const SEMIBLOCK_SIZE: usize = 8;
+
+/// Default initial value of AES-KW
+///
+/// <https://tools.ietf.org/html/rfc3394#section-2.2.3.1>
+pub const IV: [u8; SEMIBLOCK_SIZE] = [0xa6; SEMIBLOCK_SIZE];
+
+/// Constant prefix of the alternative initial value of AES-KWP, which is
+/// followed by the 32-bit big endian length of the key data
+///
+/// <https://tools.ietf.org/html/rfc5649#section-3>
+pub const KWP_IV_PREFIX: [u8; 4] = [0xa6, 0x59, 0x59, 0xa6];
+
+/// Maximum length of the key data wrapped with padding: its length is encoded
+/// on 32 bits.
+pub const KWP_MAX_LEN: usize = 0xffff_ffff;
+
+/// A key encryption key (KEK), which wraps and unwraps key data with AES-KW
+/// (RFC 3394) or AES-KWP (RFC 5649).
+///
+/// The `wrap` methods write the wrapped key into an output buffer, which must
+/// be [`SEMIBLOCK_SIZE`] bytes longer than the key data (rounded up to a
+/// multiple of [`SEMIBLOCK_SIZE`] with padding). The `unwrap` methods check the
+/// integrity of the wrapped key before writing the key data out.
+#[derive(Clone)]
+pub struct Kek<Aes>
+where
+    Aes: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+{
+    /// Block cipher
+    cipher: Aes,
+}
+
+impl<Aes> Kek<Aes>
+where
+    Aes: NewBlockCipher + BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+{
+    /// Create a new key encryption key.
+    pub fn new(key: &BlockCipherKey<Aes>) -> Self {
+        Self {
+            cipher: Aes::new(key),
+        }
+    }
+}
+
+impl<Aes> From<Aes> for Kek<Aes>
+where
+    Aes: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+{
+    fn from(cipher: Aes) -> Self {
+        Self { cipher }
+    }
+}
+
+impl<Aes> Kek<Aes>
+where
+    Aes: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+{
+    /// Wrap `data` with AES-KW into `out`, which must be [`SEMIBLOCK_SIZE`]
+    /// bytes longer than `data`.
+    ///
+    /// Returns [`Error`] unless `data` is made of at least two semiblocks.
+    pub fn wrap(&self, data: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        if !is_kw_data_len(data.len()) || out.len() != data.len() + SEMIBLOCK_SIZE {
+            return Err(Error);
+        }
+
+        let (a, r) = out.split_at_mut(SEMIBLOCK_SIZE);
+        r.copy_from_slice(data);
+        a.copy_from_slice(&self.wrap_in_place(IV, r));
+        Ok(())
+    }
+
+    /// Unwrap the AES-KW wrapped key `data` into `out`, which must be
+    /// [`SEMIBLOCK_SIZE`] bytes shorter than `data`.
+    ///
+    /// Returns [`Error`] if the integrity check fails, in which case `out` is
+    /// zeroed.
+    pub fn unwrap(&self, data: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        if data.len() < SEMIBLOCK_SIZE
+            || !is_kw_data_len(data.len() - SEMIBLOCK_SIZE)
+            || out.len() != data.len() - SEMIBLOCK_SIZE
+        {
+            return Err(Error);
+        }
+
+        let mut a = [0u8; SEMIBLOCK_SIZE];
+        a.copy_from_slice(&data[..SEMIBLOCK_SIZE]);
+        out.copy_from_slice(&data[SEMIBLOCK_SIZE..]);
+
+        if self.unwrap_in_place(a, out).ct_eq(&IV).into() {
+            Ok(())
+        } else {
+            // Don't release the unauthenticated key data
+            out.iter_mut().for_each(|b| *b = 0);
+            Err(Error)
+        }
+    }
+
+    /// Wrap `data` with AES-KWP into `out`, and return the wrapped key.
+    ///
+    /// `out` must be at least [`SEMIBLOCK_SIZE`] bytes longer than `data`
+    /// padded to a multiple of [`SEMIBLOCK_SIZE`]. Returns [`Error`] if `data`
+    /// is empty or longer than [`KWP_MAX_LEN`].
+    pub fn wrap_with_padding<'a>(&self, data: &[u8], out: &'a mut [u8]) -> Result<&'a [u8], Error> {
+        if data.is_empty() || data.len() > KWP_MAX_LEN {
+            return Err(Error);
+        }
+
+        let wrapped_len = padded_len(data.len()) + SEMIBLOCK_SIZE;
+        if out.len() < wrapped_len {
+            return Err(Error);
+        }
+
+        let out = &mut out[..wrapped_len];
+        out[SEMIBLOCK_SIZE..SEMIBLOCK_SIZE + data.len()].copy_from_slice(data);
+        out[SEMIBLOCK_SIZE + data.len()..]
+            .iter_mut()
+            .for_each(|b| *b = 0);
+        self.wrap_with_padding_in_place(data.len(), out);
+        Ok(out)
+    }
+
+    /// Unwrap the AES-KWP wrapped key `data` into `out`, which must be at
+    /// least [`SEMIBLOCK_SIZE`] bytes shorter than `data`, and return the key
+    /// data.
+    ///
+    /// Returns [`Error`] if the integrity check fails, in which case `out` is
+    /// zeroed.
+    pub fn unwrap_with_padding<'a>(
+        &self,
+        data: &[u8],
+        out: &'a mut [u8],
+    ) -> Result<&'a [u8], Error> {
+        if data.len() < 2 * SEMIBLOCK_SIZE
+            || data.len() % SEMIBLOCK_SIZE != 0
+            || out.len() < data.len() - SEMIBLOCK_SIZE
+        {
+            return Err(Error);
+        }
+
+        let mut a = [0u8; SEMIBLOCK_SIZE];
+        a.copy_from_slice(&data[..SEMIBLOCK_SIZE]);
+        let out = &mut out[..data.len() - SEMIBLOCK_SIZE];
+        out.copy_from_slice(&data[SEMIBLOCK_SIZE..]);
+
+        match self.unwrap_with_padding_in_place(a, out) {
+            Some(len) => Ok(&out[..len]),
+            None => {
+                // Don't release the unauthenticated key data
+                out.iter_mut().for_each(|b| *b = 0);
+                Err(Error)
+            }
+        }
+    }
+
+    /// Wrap `data` with AES-KW, returning a `Vec` containing the wrapped key.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn wrap_vec(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut out = vec![0; data.len() + SEMIBLOCK_SIZE];
+        self.wrap(data, &mut out)?;
+        Ok(out)
+    }
+
+    /// Unwrap the AES-KW wrapped key `data`, returning a `Vec` containing the
+    /// key data.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn unwrap_vec(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut out = vec![0; data.len().saturating_sub(SEMIBLOCK_SIZE)];
+        self.unwrap(data, &mut out)?;
+        Ok(out)
+    }
+
+    /// Wrap `data` with AES-KWP, returning a `Vec` containing the wrapped key.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn wrap_with_padding_vec(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut out = vec![0; padded_len(data.len()) + SEMIBLOCK_SIZE];
+        self.wrap_with_padding(data, &mut out)?;
+        Ok(out)
+    }
+
+    /// Unwrap the AES-KWP wrapped key `data`, returning a `Vec` containing the
+    /// key data.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn unwrap_with_padding_vec(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut out = vec![0; data.len().saturating_sub(SEMIBLOCK_SIZE)];
+        let len = self.unwrap_with_padding(data, &mut out)?.len();
+        out.truncate(len);
+        Ok(out)
+    }
+
+    /// AES-KWP wrapping of `buffer`, whose first semiblock is overwritten by
+    /// the initial value, and which contains `data_len` bytes of key data
+    /// followed by zero padding.
+    pub(crate) fn wrap_with_padding_in_place(&self, data_len: usize, buffer: &mut [u8]) {
+        let mut aiv = [0u8; SEMIBLOCK_SIZE];
+        aiv[..4].copy_from_slice(&KWP_IV_PREFIX);
+        aiv[4..].copy_from_slice(&(data_len as u32).to_be_bytes());
+
+        if buffer.len() == 2 * SEMIBLOCK_SIZE {
+            // A single semiblock of key data is encrypted as one AES block
+            buffer[..SEMIBLOCK_SIZE].copy_from_slice(&aiv);
+            self.cipher
+                .encrypt_block(GenericArray::from_mut_slice(buffer));
+        } else {
+            let (a, r) = buffer.split_at_mut(SEMIBLOCK_SIZE);
+            a.copy_from_slice(&self.wrap_in_place(aiv, r));
+        }
+    }
+
+    /// AES-KWP unwrapping of the semiblocks `r`, which are preceded by `a` in
+    /// the wrapped key. Returns the length of the key data at the start of `r`
+    /// if the integrity check passes.
+    pub(crate) fn unwrap_with_padding_in_place(
+        &self,
+        a: [u8; SEMIBLOCK_SIZE],
+        r: &mut [u8],
+    ) -> Option<usize> {
+        let mut aiv = [0u8; SEMIBLOCK_SIZE];
+
+        if r.len() == SEMIBLOCK_SIZE {
+            // A single semiblock of key data is encrypted as one AES block
+            let mut block = GenericArray::<u8, U16>::default();
+            block[..SEMIBLOCK_SIZE].copy_from_slice(&a);
+            block[SEMIBLOCK_SIZE..].copy_from_slice(r);
+            self.cipher.decrypt_block(&mut block);
+
+            aiv.copy_from_slice(&block[..SEMIBLOCK_SIZE]);
+            r.copy_from_slice(&block[SEMIBLOCK_SIZE..]);
+        } else {
+            aiv = self.unwrap_in_place(a, r);
+        }
+
+        let mut len = [0u8; 4];
+        len.copy_from_slice(&aiv[4..]);
+        let len = u32::from_be_bytes(len) as usize;
+
+        // The padding must be shorter than a semiblock, and made of zeros
+        let len_ok = len <= r.len() && len + SEMIBLOCK_SIZE > r.len();
+        let padding = &r[len.min(r.len())..];
+        let zeros = padding.iter().fold(0, |acc, b| acc | b);
+
+        if (aiv[..4].ct_eq(&KWP_IV_PREFIX) & zeros.ct_eq(&0) & (len_ok as u8).ct_eq(&1)).into() {
+            Some(len)
+        } else {
+            None
+        }
+    }
+
+    /// Wrapping function `W` over the semiblocks `r`, starting from the
+    /// initial value `a`. Returns the semiblock preceding `r` in the wrapped
+    /// key.
+    pub(crate) fn wrap_in_place(
+        &self,
+        mut a: [u8; SEMIBLOCK_SIZE],
+        r: &mut [u8],
+    ) -> [u8; SEMIBLOCK_SIZE] {
+        let n = r.len() / SEMIBLOCK_SIZE;
+        let mut block = GenericArray::<u8, U16>::default();
+
+        for j in 0..6 {
+            for (i, r_i) in r.chunks_exact_mut(SEMIBLOCK_SIZE).enumerate() {
+                block[..SEMIBLOCK_SIZE].copy_from_slice(&a);
+                block[SEMIBLOCK_SIZE..].copy_from_slice(r_i);
+                self.cipher.encrypt_block(&mut block);
+
+                let t = (n * j + i + 1) as u64;
+                a.copy_from_slice(&block[..SEMIBLOCK_SIZE]);
+                xor(&mut a, &t.to_be_bytes());
+                r_i.copy_from_slice(&block[SEMIBLOCK_SIZE..]);
+            }
+        }
+
+        a
+    }
+
+    /// Unwrapping function `W⁻¹` over the semiblocks `r`, which are preceded
+    /// by `a` in the wrapped key. Returns the initial value, to be checked by
+    /// the caller.
+    pub(crate) fn unwrap_in_place(
+        &self,
+        mut a: [u8; SEMIBLOCK_SIZE],
+        r: &mut [u8],
+    ) -> [u8; SEMIBLOCK_SIZE] {
+        let n = r.len() / SEMIBLOCK_SIZE;
+        let mut block = GenericArray::<u8, U16>::default();
+
+        for j in (0..6).rev() {
+            for (i, r_i) in r.chunks_exact_mut(SEMIBLOCK_SIZE).enumerate().rev() {
+                let t = (n * j + i + 1) as u64;
+                xor(&mut a, &t.to_be_bytes());
+                block[..SEMIBLOCK_SIZE].copy_from_slice(&a);
+                block[SEMIBLOCK_SIZE..].copy_from_slice(r_i);
+                self.cipher.decrypt_block(&mut block);
+
+                a.copy_from_slice(&block[..SEMIBLOCK_SIZE]);
+                r_i.copy_from_slice(&block[SEMIBLOCK_SIZE..]);
+            }
+        }
+
+        a
+    }
+}
+
+/// Is `len` a valid length of AES-KW key data?
+pub(crate) fn is_kw_data_len(len: usize) -> bool {
+    len >= 2 * SEMIBLOCK_SIZE && len % SEMIBLOCK_SIZE == 0
+}
+
+/// Length of `len` bytes of key data padded to a multiple of
+/// [`SEMIBLOCK_SIZE`]
+pub(crate) fn padded_len(len: usize) -> usize {
+    (len + SEMIBLOCK_SIZE - 1) / SEMIBLOCK_SIZE * SEMIBLOCK_SIZE
+}
+
+#[inline]
+fn xor(out: &mut [u8], data: &[u8]) {
+    for (a, b) in out.iter_mut().zip(data) {
+        *a ^= b;
+    }
+}
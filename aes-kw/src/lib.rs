@@ -0,0 +1,366 @@
+//! Pure Rust implementation of the [AES Key Wrap][1] (AES-KW, RFC 3394) and
+//! [AES Key Wrap with Padding][2] (AES-KWP, RFC 5649) algorithms, also
+//! specified as KW and KWP in [NIST SP 800-38F][3].
+//!
+//! Key wrapping protects the confidentiality and integrity of cryptographic
+//! keys with a key encryption key (KEK), e.g. to store them, or to transport
+//! them in JOSE, CMS or from HSMs. Both algorithms are deterministic and take
+//! no nonce: as the wrapped data is meant to be a uniformly random key,
+//! wrapping the same key twice under the same KEK only reveals that it is the
+//! same.
+//!
+//! - AES-KW wraps key data made of at least two 64-bit semiblocks,
+//! - AES-KWP wraps key data of any length from 1 to 2<sup>32</sup>-1 bytes,
+//!   padding it to a multiple of 64 bits.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! [`Kek`] wraps and unwraps key data into caller-provided buffers, or into
+//! `Vec`s when the `alloc` feature is enabled:
+//!
+//! ```
+//! use aes_kw::KekAes128;
+//! use hex_literal::hex;
+//!
+//! let kek = KekAes128::new(&hex!("000102030405060708090A0B0C0D0E0F").into());
+//! let key = hex!("00112233445566778899AABBCCDDEEFF");
+//!
+//! let wrapped = kek.wrap_vec(&key).expect("wrap failure!");
+//! assert_eq!(wrapped, hex!("1FA68B0A8112B447AEF34BD8FB5A7B829D3E862371D2CFE5"));
+//!
+//! let unwrapped = kek.unwrap_vec(&wrapped).expect("unwrap failure!");
+//! assert_eq!(unwrapped, key);
+//!
+//! // AES-KWP takes key data of any length
+//! let mut buf = [0u8; 24];
+//! let wrapped = kek.wrap_with_padding(b"secret", &mut buf).expect("wrap failure!");
+//! assert_eq!(wrapped.len(), 16);
+//! ```
+//!
+//! ## AEAD API
+//!
+//! [`AesKw`] and [`AesKwp`] expose both algorithms through the
+//! [`AeadInPlace`] trait, as deterministic AEADs with an empty nonce and no
+//! support for associated data: encryption returns an [`Error`] if any is
+//! provided. The ciphertexts are the wrapped keys of RFC 3394 and RFC 5649,
+//! whose integrity check value comes first:
+//!
+//! ```
+//! use aes_kw::{Aes256Kwp, Key, Nonce};
+//! use aes_kw::aead::{Aead, NewAead};
+//!
+//! let kek = Key::<Aes256Kwp>::from_slice(b"an example very very secret key.");
+//! let cipher = Aes256Kwp::new(kek);
+//! let nonce = Nonce::default();
+//!
+//! let wrapped = cipher.encrypt(&nonce, b"content encryption key".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//! assert_eq!(wrapped.len(), 32);
+//!
+//! let unwrapped = cipher.decrypt(&nonce, wrapped.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&unwrapped, b"content encryption key");
+//! ```
+//!
+//! AES-KW ciphertexts can also be split into the semiblock `A`, used as the
+//! tag, and the wrapped key data. AES-KWP pads the key data, so the detached
+//! methods of [`AeadInPlace`] are unsupported by [`AesKwp`] and always return
+//! an error.
+//!
+//! [1]: https://tools.ietf.org/html/rfc3394
+//! [2]: https://tools.ietf.org/html/rfc5649
+//! [3]: https://csrc.nist.gov/publications/detail/sp/800-38f/final
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![deny(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+#[cfg(feature = "alloc")]
+#[macro_use]
+extern crate alloc;
+
+pub use aead::{self, consts, AeadCore, AeadInPlace, Buffer, Error, NewAead};
+use kek::is_kw_data_len;
+
+pub use kek::{Kek, IV, KWP_IV_PREFIX, KWP_MAX_LEN, SEMIBLOCK_SIZE};
+
+mod kek;
+
+use aes::{Aes128, Aes192, Aes256};
+use cipher::{
+    consts::{U0, U16, U8},
+    generic_array::GenericArray,
+    BlockCipher, BlockCipherKey, BlockDecrypt, BlockEncrypt, NewBlockCipher,
+};
+use subtle::ConstantTimeEq;
+
+/// Key encryption keys
+pub type Key<Cipher> = GenericArray<u8, <Cipher as NewAead>::KeySize>;
+
+/// Nonces, which are empty: key wrapping is deterministic
+pub type Nonce = GenericArray<u8, U0>;
+
+/// AES-KW tags: the first semiblock `A` of the wrapped key
+pub type Tag = GenericArray<u8, U8>;
+
+/// AES-128 key encryption key
+pub type KekAes128 = Kek<Aes128>;
+
+/// AES-192 key encryption key
+pub type KekAes192 = Kek<Aes192>;
+
+/// AES-256 key encryption key
+pub type KekAes256 = Kek<Aes256>;
+
+/// AES-KW with a 128-bit key encryption key
+pub type Aes128Kw = AesKw<Aes128>;
+
+/// AES-KW with a 192-bit key encryption key
+pub type Aes192Kw = AesKw<Aes192>;
+
+/// AES-KW with a 256-bit key encryption key
+pub type Aes256Kw = AesKw<Aes256>;
+
+/// AES-KWP with a 128-bit key encryption key
+pub type Aes128Kwp = AesKwp<Aes128>;
+
+/// AES-KWP with a 192-bit key encryption key
+pub type Aes192Kwp = AesKwp<Aes192>;
+
+/// AES-KWP with a 256-bit key encryption key
+pub type Aes256Kwp = AesKwp<Aes256>;
+
+/// AES Key Wrap (RFC 3394) as a deterministic AEAD, generic over an AES
+/// implementation.
+///
+/// Key data must be made of at least two semiblocks, and no associated data
+/// is supported.
+#[derive(Clone)]
+pub struct AesKw<Aes>
+where
+    Aes: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+{
+    kek: Kek<Aes>,
+}
+
+/// AES Key Wrap with Padding (RFC 5649) as a deterministic AEAD, generic
+/// over an AES implementation.
+///
+/// Key data must be 1 to [`KWP_MAX_LEN`] bytes long, and no associated data
+/// is supported.
+#[derive(Clone)]
+pub struct AesKwp<Aes>
+where
+    Aes: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+{
+    kek: Kek<Aes>,
+}
+
+macro_rules! impl_aead_common {
+    ($name:ident) => {
+        impl<Aes> NewAead for $name<Aes>
+        where
+            Aes: NewBlockCipher + BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+        {
+            type KeySize = Aes::KeySize;
+
+            fn new(key: &BlockCipherKey<Aes>) -> Self {
+                Self { kek: Kek::new(key) }
+            }
+        }
+
+        impl<Aes> From<Kek<Aes>> for $name<Aes>
+        where
+            Aes: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+        {
+            fn from(kek: Kek<Aes>) -> Self {
+                Self { kek }
+            }
+        }
+
+        impl<Aes> AeadCore for $name<Aes>
+        where
+            Aes: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+        {
+            type NonceSize = U0;
+            type TagSize = U8;
+            type CiphertextOverhead = U0;
+        }
+    };
+}
+
+impl_aead_common!(AesKw);
+impl_aead_common!(AesKwp);
+
+impl<Aes> AeadInPlace for AesKw<Aes>
+where
+    Aes: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+{
+    fn encrypt_in_place(
+        &self,
+        _nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        let data_len = buffer.len();
+        if !associated_data.is_empty() || !is_kw_data_len(data_len) {
+            return Err(Error);
+        }
+
+        buffer.extend_from_slice(&[0; SEMIBLOCK_SIZE])?;
+        buffer.as_mut().copy_within(..data_len, SEMIBLOCK_SIZE);
+
+        let (a, r) = buffer.as_mut().split_at_mut(SEMIBLOCK_SIZE);
+        a.copy_from_slice(&self.kek.wrap_in_place(IV, r));
+        Ok(())
+    }
+
+    fn encrypt_in_place_detached(
+        &self,
+        _nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        if !associated_data.is_empty() || !is_kw_data_len(buffer.len()) {
+            return Err(Error);
+        }
+
+        Ok(self.kek.wrap_in_place(IV, buffer).into())
+    }
+
+    fn decrypt_in_place(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        if buffer.len() < SEMIBLOCK_SIZE {
+            return Err(Error);
+        }
+
+        let (a, r) = buffer.as_mut().split_at_mut(SEMIBLOCK_SIZE);
+        let tag = Tag::clone_from_slice(a);
+        self.decrypt_in_place_detached(nonce, associated_data, r, &tag)?;
+
+        let data_len = buffer.len() - SEMIBLOCK_SIZE;
+        buffer.as_mut().copy_within(SEMIBLOCK_SIZE.., 0);
+        buffer.truncate(data_len);
+        Ok(())
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        _nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        if !associated_data.is_empty() || !is_kw_data_len(buffer.len()) {
+            return Err(Error);
+        }
+
+        let mut a = [0u8; SEMIBLOCK_SIZE];
+        a.copy_from_slice(tag);
+
+        if self.kek.unwrap_in_place(a, buffer).ct_eq(&IV).into() {
+            Ok(())
+        } else {
+            // Don't release the unauthenticated key data
+            buffer.iter_mut().for_each(|b| *b = 0);
+            Err(Error)
+        }
+    }
+}
+
+impl<Aes> AeadInPlace for AesKwp<Aes>
+where
+    Aes: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+{
+    fn encrypt_in_place(
+        &self,
+        _nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        let data_len = buffer.len();
+        if !associated_data.is_empty() || data_len == 0 || data_len > KWP_MAX_LEN {
+            return Err(Error);
+        }
+
+        let padding_len = kek::padded_len(data_len) - data_len;
+        buffer.extend_from_slice(&[0; 2 * SEMIBLOCK_SIZE][..SEMIBLOCK_SIZE + padding_len])?;
+        buffer.as_mut().copy_within(..data_len, SEMIBLOCK_SIZE);
+
+        self.kek
+            .wrap_with_padding_in_place(data_len, buffer.as_mut());
+        Ok(())
+    }
+
+    /// Unsupported: always returns an error, as the key data is padded.
+    fn encrypt_in_place_detached(
+        &self,
+        _nonce: &Nonce,
+        _associated_data: &[u8],
+        _buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        Err(Error)
+    }
+
+    fn decrypt_in_place(
+        &self,
+        _nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        if !associated_data.is_empty()
+            || buffer.len() < 2 * SEMIBLOCK_SIZE
+            || buffer.len() % SEMIBLOCK_SIZE != 0
+        {
+            return Err(Error);
+        }
+
+        let (a, r) = buffer.as_mut().split_at_mut(SEMIBLOCK_SIZE);
+        let mut iv = [0u8; SEMIBLOCK_SIZE];
+        iv.copy_from_slice(a);
+
+        match self.kek.unwrap_with_padding_in_place(iv, r) {
+            Some(data_len) => {
+                buffer
+                    .as_mut()
+                    .copy_within(SEMIBLOCK_SIZE..SEMIBLOCK_SIZE + data_len, 0);
+                buffer.truncate(data_len);
+                Ok(())
+            }
+            None => {
+                // Don't release the unauthenticated key data
+                buffer.as_mut().iter_mut().for_each(|b| *b = 0);
+                Err(Error)
+            }
+        }
+    }
+
+    /// Unsupported: always returns an error, as the key data is padded.
+    fn decrypt_in_place_detached(
+        &self,
+        _nonce: &Nonce,
+        _associated_data: &[u8],
+        _buffer: &mut [u8],
+        _tag: &Tag,
+    ) -> Result<(), Error> {
+        Err(Error)
+    }
+}
@@ -0,0 +1,232 @@
+//! AES-KW and AES-KWP tests
+
+use aead::{Aead, AeadInPlace, NewAead, Payload};
+use aes_kw::{Aes128Kw, Aes192Kw, Aes192Kwp, Aes256Kw, Kek, Nonce};
+use hex_literal::hex;
+
+/// Key wrap test vector
+struct TestVector {
+    kek: &'static [u8],
+    key: &'static [u8],
+    wrapped: &'static [u8],
+}
+
+/// AES-KW test vectors from RFC 3394
+///
+/// <https://tools.ietf.org/html/rfc3394#section-4>
+const KW_128_VECTORS: &[TestVector] = &[TestVector {
+    kek: &hex!("000102030405060708090A0B0C0D0E0F"),
+    key: &hex!("00112233445566778899AABBCCDDEEFF"),
+    wrapped: &hex!("1FA68B0A8112B447AEF34BD8FB5A7B829D3E862371D2CFE5"),
+}];
+
+const KW_192_VECTORS: &[TestVector] = &[
+    TestVector {
+        kek: &hex!("000102030405060708090A0B0C0D0E0F1011121314151617"),
+        key: &hex!("00112233445566778899AABBCCDDEEFF"),
+        wrapped: &hex!("96778B25AE6CA435F92B5B97C050AED2468AB8A17AD84E5D"),
+    },
+    TestVector {
+        kek: &hex!("000102030405060708090A0B0C0D0E0F1011121314151617"),
+        key: &hex!("00112233445566778899AABBCCDDEEFF0001020304050607"),
+        wrapped: &hex!("031D33264E15D33268F24EC260743EDCE1C6C7DDEE725A936BA814915C6762D2"),
+    },
+];
+
+const KW_256_VECTORS: &[TestVector] = &[
+    TestVector {
+        kek: &hex!("000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F"),
+        key: &hex!("00112233445566778899AABBCCDDEEFF"),
+        wrapped: &hex!("64E8C3F9CE0F5BA263E9777905818A2A93C8191E7D6E8AE7"),
+    },
+    TestVector {
+        kek: &hex!("000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F"),
+        key: &hex!("00112233445566778899AABBCCDDEEFF0001020304050607"),
+        wrapped: &hex!("A8F9BC1612C68B3FF6E6F4FBE30E71E4769C8B80A32CB8958CD5D17D6B254DA1"),
+    },
+    TestVector {
+        kek: &hex!("000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F"),
+        key: &hex!("00112233445566778899AABBCCDDEEFF000102030405060708090A0B0C0D0E0F"),
+        wrapped: &hex!(
+            "28C9F404C4B810F4CBCCB35CFB87F8263F5786E2D80ED326CBC7F0E71A99F43BFB988B9B7A02DD21"
+        ),
+    },
+];
+
+/// AES-KWP test vectors from RFC 5649
+///
+/// <https://tools.ietf.org/html/rfc5649#section-6>
+const KWP_192_VECTORS: &[TestVector] = &[
+    TestVector {
+        kek: &hex!("5840df6e29b02af1ab493b705bf16ea1ae8338f4dcc176a8"),
+        key: &hex!("c37b7e6492584340bed12207808941155068f738"),
+        wrapped: &hex!("138bdeaa9b8fa7fc61f97742e72248ee5ae6ae5360d1ae6a5f54f373fa543b6a"),
+    },
+    TestVector {
+        kek: &hex!("5840df6e29b02af1ab493b705bf16ea1ae8338f4dcc176a8"),
+        key: &hex!("466f7250617369"),
+        wrapped: &hex!("afbeb0f07dfbf5419200f2ccb50bb24f"),
+    },
+];
+
+macro_rules! kw_tests {
+    ($name:ident, $cipher:ty, $vectors:expr) => {
+        mod $name {
+            use super::*;
+
+            fn cipher(kek: &[u8]) -> $cipher {
+                <$cipher>::new_from_slice(kek).unwrap()
+            }
+
+            #[test]
+            fn wrap() {
+                for vector in $vectors {
+                    let kek = cipher(vector.kek);
+                    let wrapped = kek.encrypt(&Nonce::default(), vector.key).unwrap();
+                    assert_eq!(vector.wrapped, wrapped.as_slice());
+
+                    let mut buffer = vector.key.to_vec();
+                    let tag = kek
+                        .encrypt_in_place_detached(&Nonce::default(), b"", &mut buffer)
+                        .unwrap();
+                    assert_eq!(&vector.wrapped[..8], tag.as_slice());
+                    assert_eq!(&vector.wrapped[8..], buffer.as_slice());
+                }
+            }
+
+            #[test]
+            fn unwrap() {
+                for vector in $vectors {
+                    let kek = cipher(vector.kek);
+                    let key = kek.decrypt(&Nonce::default(), vector.wrapped).unwrap();
+                    assert_eq!(vector.key, key.as_slice());
+                }
+            }
+
+            #[test]
+            fn unwrap_modified() {
+                for vector in $vectors {
+                    let kek = cipher(vector.kek);
+                    let mut wrapped = vector.wrapped.to_vec();
+
+                    // Tweak the first and the last byte
+                    for &i in &[0, wrapped.len() - 1] {
+                        wrapped[i] ^= 1;
+                        assert!(kek.decrypt(&Nonce::default(), wrapped.as_ref()).is_err());
+                        wrapped[i] ^= 1;
+                    }
+                }
+            }
+        }
+    };
+}
+
+kw_tests!(aes128kw, Aes128Kw, KW_128_VECTORS);
+kw_tests!(aes192kw, Aes192Kw, KW_192_VECTORS);
+kw_tests!(aes256kw, Aes256Kw, KW_256_VECTORS);
+
+mod aes192kwp {
+    use super::*;
+
+    #[test]
+    fn wrap() {
+        for vector in KWP_192_VECTORS {
+            let cipher = Aes192Kwp::new_from_slice(vector.kek).unwrap();
+            let wrapped = cipher.encrypt(&Nonce::default(), vector.key).unwrap();
+            assert_eq!(vector.wrapped, wrapped.as_slice());
+        }
+    }
+
+    #[test]
+    fn unwrap() {
+        for vector in KWP_192_VECTORS {
+            let cipher = Aes192Kwp::new_from_slice(vector.kek).unwrap();
+            let key = cipher.decrypt(&Nonce::default(), vector.wrapped).unwrap();
+            assert_eq!(vector.key, key.as_slice());
+        }
+    }
+
+    #[test]
+    fn unwrap_modified() {
+        for vector in KWP_192_VECTORS {
+            let cipher = Aes192Kwp::new_from_slice(vector.kek).unwrap();
+            let mut wrapped = vector.wrapped.to_vec();
+
+            for &i in &[0, wrapped.len() - 1] {
+                wrapped[i] ^= 1;
+                assert!(cipher.decrypt(&Nonce::default(), wrapped.as_ref()).is_err());
+                wrapped[i] ^= 1;
+            }
+        }
+    }
+
+    #[test]
+    fn detached_unsupported() {
+        let cipher = Aes192Kwp::new_from_slice(KWP_192_VECTORS[0].kek).unwrap();
+        let mut buffer = [0u8; 16];
+        assert!(cipher
+            .encrypt_in_place_detached(&Nonce::default(), b"", &mut buffer)
+            .is_err());
+    }
+}
+
+/// The standalone API matches the AEAD one
+#[test]
+fn kek() {
+    for vector in KWP_192_VECTORS {
+        let kek = Kek::<aes::Aes192>::new(vector.kek.into());
+        let mut out = [0u8; 64];
+        let wrapped = kek.wrap_with_padding(vector.key, &mut out).unwrap();
+        assert_eq!(vector.wrapped, wrapped);
+
+        let mut out = [0u8; 64];
+        let key = kek.unwrap_with_padding(vector.wrapped, &mut out).unwrap();
+        assert_eq!(vector.key, key);
+    }
+
+    for vector in KW_256_VECTORS {
+        let kek = Kek::<aes::Aes256>::new(vector.kek.into());
+        assert_eq!(vector.wrapped, kek.wrap_vec(vector.key).unwrap().as_slice());
+        assert_eq!(
+            vector.key,
+            kek.unwrap_vec(vector.wrapped).unwrap().as_slice()
+        );
+
+        let mut wrapped = vector.wrapped.to_vec();
+        wrapped[8] ^= 1;
+        assert!(kek.unwrap_vec(&wrapped).is_err());
+    }
+}
+
+#[test]
+fn kwp_round_trip() {
+    let cipher = Aes192Kwp::new_from_slice(KWP_192_VECTORS[0].kek).unwrap();
+    let data = [0x42; 40];
+
+    for len in 1..=data.len() {
+        let wrapped = cipher.encrypt(&Nonce::default(), &data[..len]).unwrap();
+        assert_eq!(wrapped.len(), (len + 7) / 8 * 8 + 8);
+
+        let key = cipher.decrypt(&Nonce::default(), wrapped.as_ref()).unwrap();
+        assert_eq!(&data[..len], key.as_slice());
+    }
+}
+
+#[test]
+fn invalid_lengths() {
+    let kw = Aes128Kw::new_from_slice(KW_128_VECTORS[0].kek).unwrap();
+    let kwp = Aes192Kwp::new_from_slice(KWP_192_VECTORS[0].kek).unwrap();
+    let nonce = Nonce::default();
+
+    // AES-KW needs at least two semiblocks, AES-KWP at least one byte
+    assert!(kw.encrypt(&nonce, [0u8; 8].as_ref()).is_err());
+    assert!(kw.encrypt(&nonce, [0u8; 17].as_ref()).is_err());
+    assert!(kwp.encrypt(&nonce, [0u8; 0].as_ref()).is_err());
+
+    // Associated data is unsupported
+    let payload = Payload {
+        msg: &[0u8; 16],
+        aad: b"header",
+    };
+    assert!(kw.encrypt(&nonce, payload).is_err());
+}
@@ -66,10 +66,79 @@
 //! # }
 //! ```
 //!
+//! ## Deterministic Encryption (no nonce)
+//!
+//! SIV doesn't need a nonce: without one, it is a deterministic AEAD, where
+//! encrypting the same plaintext with the same associated data always yields
+//! the same ciphertext, and which reveals nothing else. This is what key
+//! wrapping and deduplicating storage need.
+//!
+//! The [`DeterministicAead`] trait provides this nonce-less API. It takes a
+//! list of associated data components ("headers"), which are authenticated
+//! separately rather than concatenated, and is implemented by both the
+//! [`siv::Siv`] interface and the [`SivAead`] types:
+//!
+//! ```
+//! use aes_siv::{Aes128SivAead, DeterministicAead, Key};
+//! use aes_siv::aead::NewAead;
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let mut cipher = Aes128SivAead::new(key);
+//!
+//! let headers: [&[u8]; 2] = [b"user id", b"file name"];
+//!
+//! let ciphertext = cipher.encrypt(&headers, b"plaintext message")
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! // The same inputs give the same ciphertext
+//! assert_eq!(ciphertext, cipher.encrypt(&headers, b"plaintext message").unwrap());
+//!
+//! let plaintext = cipher.decrypt(&headers, &ciphertext)
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! With the [`aead`] traits, the [`SivAead`] types use the nonce as the last
+//! header, as recommended by RFC 5297. Since both traits have `encrypt` and
+//! `decrypt` methods, only import the one in use, or call them as e.g.
+//! `DeterministicAead::encrypt(&mut cipher, ...)`.
+//!
+//! ## AES-PMAC-SIV
+//!
+//! When the `pmac` feature is enabled, [`Aes128PmacSivAead`] and
+//! [`Aes256PmacSivAead`] (and [`siv::Aes128PmacSiv`] and [`siv::Aes256PmacSiv`]
+//! for the `Siv` interface) provide AES-PMAC-SIV, the variant of AES-SIV
+//! defined by the [Miscreant][5] project, which uses PMAC rather than CMAC to
+//! compute the synthetic IV. Unlike CMAC, PMAC processes each block
+//! independently, which allows it to be parallelized.
+//!
+//! ```
+//! # #[cfg(feature = "pmac")]
+//! # {
+//! use aes_siv::{Aes128PmacSivAead, Key, Nonce}; // Or `Aes256PmacSivAead`
+//! use aes_siv::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = Aes128PmacSivAead::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"any unique nonce"); // 128-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! # }
+//! ```
+//!
 //! [1]: https://github.com/miscreant/meta/wiki/AES-SIV
 //! [2]: https://tools.ietf.org/html/rfc5297
 //! [3]: https://en.wikipedia.org/wiki/Authenticated_encryption
 //! [4]: https://github.com/miscreant/meta/wiki/Nonce-Reuse-Misuse-Resistance
+//! [5]: https://github.com/miscreant/meta/wiki/AES-PMAC-SIV
 
 #![no_std]
 #![doc(
@@ -85,7 +154,7 @@ pub use aead;
 
 pub mod siv;
 
-use crate::siv::Siv;
+use crate::siv::{Siv, IV_SIZE};
 use aead::{
     consts::{U0, U16, U32, U64},
     generic_array::{ArrayLength, GenericArray},
@@ -98,6 +167,9 @@ use core::{marker::PhantomData, ops::Add};
 use crypto_mac::{Mac, NewMac};
 use ctr::Ctr128BE;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 #[cfg(feature = "pmac")]
 use pmac::Pmac;
 
@@ -113,6 +185,133 @@ pub type Nonce = GenericArray<u8, U16>;
 /// AES-SIV tags (i.e. the Synthetic Initialization Vector value)
 pub type Tag = GenericArray<u8, U16>;
 
+/// Deterministic Authenticated Encryption with Associated Data: encryption
+/// without a nonce, where the same plaintext and associated data always yield
+/// the same ciphertext.
+///
+/// The associated data is a list of components ("headers"), which are
+/// authenticated separately rather than concatenated, and the tag (i.e. the
+/// synthetic IV) is prepended to the ciphertext, as in RFC 5297.
+///
+/// Implemented by [`Siv`] and [`SivAead`]. For [`SivAead`], encrypting with
+/// the headers `[associated_data, nonce]` is the same as encrypting with the
+/// [`aead`] traits.
+pub trait DeterministicAead {
+    /// Encrypt the given plaintext, allocating and returning a `Vec<u8>` for
+    /// the tag followed by the ciphertext.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] if `headers.len()` is greater than
+    /// [`siv::MAX_HEADERS`].
+    #[cfg(feature = "alloc")]
+    fn encrypt<I, T>(&mut self, headers: I, plaintext: &[u8]) -> Result<Vec<u8>, Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<[u8]>,
+    {
+        let mut buffer = Vec::with_capacity(IV_SIZE + plaintext.len());
+        buffer.extend_from_slice(plaintext);
+        self.encrypt_in_place(headers, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Encrypt the given buffer containing a plaintext message in-place,
+    /// prepending the tag.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] if `headers.len()` is greater than
+    /// [`siv::MAX_HEADERS`], or the buffer can't be extended.
+    fn encrypt_in_place<I, T>(&mut self, headers: I, buffer: &mut dyn Buffer) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<[u8]>,
+    {
+        let pt_len = buffer.len();
+
+        // Make room in the buffer for the tag, which needs to be prepended
+        buffer.extend_from_slice(Tag::default().as_slice())?;
+        buffer.as_mut().copy_within(..pt_len, IV_SIZE);
+
+        let tag = self.encrypt_in_place_detached(headers, &mut buffer.as_mut()[IV_SIZE..])?;
+        buffer.as_mut()[..IV_SIZE].copy_from_slice(tag.as_slice());
+        Ok(())
+    }
+
+    /// Encrypt the given plaintext in-place, returning the tag on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] if `headers.len()` is greater than
+    /// [`siv::MAX_HEADERS`].
+    fn encrypt_in_place_detached<I, T>(
+        &mut self,
+        headers: I,
+        plaintext: &mut [u8],
+    ) -> Result<Tag, Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<[u8]>;
+
+    /// Decrypt the given tag followed by the ciphertext, allocating and
+    /// returning a `Vec<u8>` for the plaintext.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] if the ciphertext is not authentic.
+    #[cfg(feature = "alloc")]
+    fn decrypt<I, T>(&mut self, headers: I, ciphertext: &[u8]) -> Result<Vec<u8>, Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<[u8]>,
+    {
+        let mut buffer = ciphertext.to_vec();
+        self.decrypt_in_place(headers, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Decrypt the given buffer containing the tag followed by the
+    /// ciphertext in-place, and truncate it to the plaintext.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] if the ciphertext is not authentic.
+    fn decrypt_in_place<I, T>(&mut self, headers: I, buffer: &mut dyn Buffer) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<[u8]>,
+    {
+        if buffer.len() < IV_SIZE {
+            return Err(Error);
+        }
+
+        let tag = Tag::clone_from_slice(&buffer.as_ref()[..IV_SIZE]);
+        self.decrypt_in_place_detached(headers, &mut buffer.as_mut()[IV_SIZE..], &tag)?;
+
+        let pt_len = buffer.len() - IV_SIZE;
+        buffer.as_mut().copy_within(IV_SIZE.., 0);
+        buffer.truncate(pt_len);
+        Ok(())
+    }
+
+    /// Decrypt the given ciphertext in-place, authenticating it against the
+    /// provided tag.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] if the ciphertext is not authentic.
+    fn decrypt_in_place_detached<I, T>(
+        &mut self,
+        headers: I,
+        ciphertext: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<[u8]>;
+}
+
 /// The `SivAead` type wraps the more powerful `Siv` interface in a more
 /// commonly used Authenticated Encryption with Associated Data (AEAD) API,
 /// which accepts a key, nonce, and associated data when encrypting/decrypting.
@@ -127,6 +326,21 @@ where
     mac: PhantomData<M>, // TODO(tarcieri): include `M` in `KeySize` calculation
 }
 
+impl<C, M> Clone for SivAead<C, M>
+where
+    C: NewCipher<NonceSize = U16> + StreamCipher,
+    M: Mac<OutputSize = U16>,
+    <C as NewCipher>::KeySize: Add,
+    KeySize<C>: ArrayLength<u8>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key.clone(),
+            mac: PhantomData,
+        }
+    }
+}
+
 /// SIV AEAD modes based on CMAC
 pub type CmacSivAead<BlockCipher> = SivAead<Ctr128BE<BlockCipher>, Cmac<BlockCipher>>;
 
@@ -247,3 +461,67 @@ where
         )
     }
 }
+
+impl<C, M> DeterministicAead for Siv<C, M>
+where
+    C: NewCipher<NonceSize = U16> + StreamCipher,
+    M: Mac<OutputSize = U16> + NewMac,
+{
+    fn encrypt_in_place_detached<I, T>(
+        &mut self,
+        headers: I,
+        plaintext: &mut [u8],
+    ) -> Result<Tag, Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<[u8]>,
+    {
+        Siv::encrypt_in_place_detached(self, headers, plaintext)
+    }
+
+    fn decrypt_in_place_detached<I, T>(
+        &mut self,
+        headers: I,
+        ciphertext: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<[u8]>,
+    {
+        Siv::decrypt_in_place_detached(self, headers, ciphertext, tag)
+    }
+}
+
+impl<C, M> DeterministicAead for SivAead<C, M>
+where
+    C: NewCipher<NonceSize = U16> + StreamCipher,
+    M: Mac<OutputSize = U16> + NewMac,
+    <C as NewCipher>::KeySize: Add,
+    KeySize<C>: ArrayLength<u8>,
+{
+    fn encrypt_in_place_detached<I, T>(
+        &mut self,
+        headers: I,
+        plaintext: &mut [u8],
+    ) -> Result<Tag, Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<[u8]>,
+    {
+        Siv::<C, M>::new(self.key.clone()).encrypt_in_place_detached(headers, plaintext)
+    }
+
+    fn decrypt_in_place_detached<I, T>(
+        &mut self,
+        headers: I,
+        ciphertext: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<[u8]>,
+    {
+        Siv::<C, M>::new(self.key.clone()).decrypt_in_place_detached(headers, ciphertext, tag)
+    }
+}
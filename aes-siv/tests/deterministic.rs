@@ -0,0 +1,158 @@
+//! AES-SIV tests for the `DeterministicAead` trait
+
+#[macro_use]
+extern crate hex_literal;
+
+use aes_siv::aead::{generic_array::GenericArray, AeadInPlace, NewAead};
+use aes_siv::{siv::Aes128Siv, Aes128SivAead, DeterministicAead};
+
+/// Test vectors
+#[derive(Debug)]
+pub struct TestVector {
+    pub key: &'static [u8; 32],
+    pub headers: &'static [&'static [u8]],
+    pub plaintext: &'static [u8],
+    pub ciphertext: &'static [u8],
+}
+
+/// RFC 5297 test vectors: A.1 (deterministic) and A.2 (nonce-based, with the
+/// nonce as the last header)
+const TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        key: &hex!("fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff"),
+        headers: &[&hex!("101112131415161718191a1b1c1d1e1f2021222324252627")],
+        plaintext: &hex!("112233445566778899aabbccddee"),
+        ciphertext: &hex!("85632d07c6e8f37f950acd320a2ecc9340c02b9690c4dc04daef7f6afe5c"),
+    },
+    TestVector {
+        key: &hex!("7f7e7d7c7b7a79787776757473727170404142434445464748494a4b4c4d4e4f"),
+        headers: &[
+            &hex!("00112233445566778899aabbccddeeffdeaddadadeaddadaffeeddccbbaa99887766554433221100"),
+            &hex!("102030405060708090a0"),
+            &hex!("09f911029d74e35bd84156c5635688c0"),
+        ],
+        plaintext: &hex!("7468697320697320736f6d6520706c61696e7465787420746f20656e6372797074207573696e67205349562d414553"),
+        ciphertext: &hex!("7bdb6e3b432667eb06f4d14bff2fbd0fcb900f2fddbe404326601965c889bf17dba77ceb094fa663b7a3f748ba8af829ea64ad544a272e9c485b62a3fd5c0d"),
+    },
+];
+
+macro_rules! tests {
+    ($name:ident, $new:expr) => {
+        mod $name {
+            use super::*;
+
+            #[test]
+            fn encrypt() {
+                for vector in TEST_VECTORS {
+                    let mut cipher = $new(vector.key);
+                    let ciphertext =
+                        DeterministicAead::encrypt(&mut cipher, vector.headers, vector.plaintext)
+                            .unwrap();
+                    assert_eq!(vector.ciphertext, ciphertext.as_slice());
+                }
+            }
+
+            #[test]
+            fn encrypt_in_place_detached() {
+                for vector in TEST_VECTORS {
+                    let mut cipher = $new(vector.key);
+                    let mut buffer = vector.plaintext.to_vec();
+                    let tag = DeterministicAead::encrypt_in_place_detached(
+                        &mut cipher,
+                        vector.headers,
+                        &mut buffer,
+                    )
+                    .unwrap();
+                    let (expected_tag, expected_ciphertext) = vector.ciphertext.split_at(16);
+                    assert_eq!(expected_tag, &tag[..]);
+                    assert_eq!(expected_ciphertext, &buffer[..]);
+                }
+            }
+
+            #[test]
+            fn decrypt() {
+                for vector in TEST_VECTORS {
+                    let mut cipher = $new(vector.key);
+                    let plaintext =
+                        DeterministicAead::decrypt(&mut cipher, vector.headers, vector.ciphertext)
+                            .unwrap();
+                    assert_eq!(vector.plaintext, plaintext.as_slice());
+                }
+            }
+
+            #[test]
+            fn decrypt_in_place() {
+                for vector in TEST_VECTORS {
+                    let mut cipher = $new(vector.key);
+                    let mut buffer = vector.ciphertext.to_vec();
+                    DeterministicAead::decrypt_in_place(&mut cipher, vector.headers, &mut buffer)
+                        .unwrap();
+                    assert_eq!(vector.plaintext, buffer.as_slice());
+                }
+            }
+
+            #[test]
+            fn decrypt_modified() {
+                let vector = &TEST_VECTORS[0];
+                let mut cipher = $new(vector.key);
+                let mut ciphertext = Vec::from(vector.ciphertext);
+
+                // Tweak the first byte of the tag, and the last byte of the
+                // ciphertext
+                for &i in &[0, ciphertext.len() - 1] {
+                    ciphertext[i] ^= 0xaa;
+                    assert!(
+                        DeterministicAead::decrypt(&mut cipher, vector.headers, &ciphertext)
+                            .is_err()
+                    );
+                    ciphertext[i] ^= 0xaa;
+                }
+
+                // Or drop a header
+                assert!(
+                    DeterministicAead::decrypt(&mut cipher, &vector.headers[1..], &ciphertext)
+                        .is_err()
+                );
+            }
+
+            #[test]
+            fn decrypt_truncated() {
+                let mut cipher = $new(TEST_VECTORS[0].key);
+                let headers = TEST_VECTORS[0].headers;
+                assert!(DeterministicAead::decrypt(&mut cipher, headers, &[0; 15]).is_err());
+            }
+        }
+    };
+}
+
+tests!(siv, |key| Aes128Siv::new(GenericArray::clone_from_slice(
+    key
+)));
+tests!(siv_aead, |key| Aes128SivAead::new(
+    GenericArray::from_slice(key)
+));
+
+/// With the headers `[associated_data, nonce]`, the deterministic API is the
+/// same as the nonce-based one
+#[test]
+fn siv_aead_nonce() {
+    let vector = &TEST_VECTORS[1];
+    let mut cipher = Aes128SivAead::new(GenericArray::from_slice(vector.key));
+
+    // The concatenation of the associated data components isn't the same as
+    // a single component, so only compare with one of them plus the nonce
+    let aad = vector.headers[0];
+    let nonce = vector.headers[2];
+
+    let mut buffer = vector.plaintext.to_vec();
+    let tag = cipher
+        .encrypt_in_place_detached(GenericArray::from_slice(nonce), aad, &mut buffer)
+        .unwrap();
+
+    let mut expected = vector.plaintext.to_vec();
+    let expected_tag =
+        DeterministicAead::encrypt_in_place_detached(&mut cipher, &[aad, nonce], &mut expected)
+            .unwrap();
+    assert_eq!(tag, expected_tag);
+    assert_eq!(buffer, expected);
+}
@@ -0,0 +1,174 @@
+//! The AEZ enciphering scheme: AEZ-tiny for strings shorter than 32 bytes,
+//! and AEZ-core for the longer ones.
+
+use crate::tweakable::{from_bytes, pad, Keys, ONE};
+
+/// Whether to encipher or decipher
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Direction {
+    Encipher,
+    Decipher,
+}
+
+/// Encipher or decipher `buffer`, which must not be empty, in-place
+pub(crate) fn cipher(keys: &Keys, delta: u128, direction: Direction, buffer: &mut [u8]) {
+    debug_assert!(!buffer.is_empty());
+
+    if buffer.len() < 32 {
+        aez_tiny(keys, delta, direction, buffer);
+    } else {
+        aez_core(keys, delta, direction, buffer);
+    }
+}
+
+/// AEZ-tiny: a Feistel network of `E^{0,i}` over the two halves of `buffer`,
+/// which can be split in the middle of a byte.
+fn aez_tiny(keys: &Keys, delta: u128, direction: Direction, buffer: &mut [u8]) {
+    let bits = buffer.len() * 8;
+    let n = bits / 2;
+    let mask = !(!0u128 >> n);
+
+    let rounds: u8 = match buffer.len() {
+        1 => 24,
+        2 => 16,
+        3..=15 => 10,
+        _ => 8,
+    };
+    let i = if buffer.len() < 16 { 7 } else { 6 };
+
+    if direction == Direction::Decipher && buffer.len() < 16 {
+        flip_first_bit(keys, delta, buffer);
+    }
+
+    let half = n / 8;
+    let (mut left, mut right) = if n % 8 == 0 {
+        (from_bytes(&buffer[..half]), from_bytes(&buffer[half..]))
+    } else {
+        (
+            from_bytes(&buffer[..=half]) & mask,
+            from_bytes(&buffer[half..]) << 4,
+        )
+    };
+
+    for round in 0..rounds {
+        let j = match direction {
+            Direction::Encipher => round,
+            Direction::Decipher => rounds - 1 - round,
+        };
+
+        let padded = right | ONE >> n;
+        let new_right = (left ^ keys.e(0, i, delta ^ padded ^ u128::from(j))) & mask;
+        left = right;
+        right = new_right;
+    }
+
+    // Output `right || left`, each `n` bits long
+    let right = right.to_be_bytes();
+    if n % 8 == 0 {
+        let left = left.to_be_bytes();
+        buffer[..half].copy_from_slice(&right[..half]);
+        buffer[half..].copy_from_slice(&left[..half]);
+    } else {
+        let left = (left >> 4).to_be_bytes();
+        buffer[..=half].copy_from_slice(&right[..=half]);
+        buffer[half] |= left[0];
+        buffer[half + 1..].copy_from_slice(&left[1..=half]);
+    }
+
+    if direction == Direction::Encipher && buffer.len() < 16 {
+        flip_first_bit(keys, delta, buffer);
+    }
+}
+
+/// XOR the first bit of `buffer` with the first bit of
+/// `E^{0,3}(delta ^ (buffer | 10*))`, which is shorter than a block.
+fn flip_first_bit(keys: &Keys, delta: u128, buffer: &mut [u8]) {
+    let block = from_bytes(buffer) | ONE;
+    let bit = keys.e(0, 3, delta ^ block) & ONE;
+    buffer[0] ^= (bit >> 120) as u8;
+}
+
+/// AEZ-core: a two-pass scheme over block pairs, followed by the trailing
+/// partial blocks `u` and `v`, and the final blocks `x` and `y`.
+fn aez_core(keys: &Keys, delta: u128, direction: Direction, buffer: &mut [u8]) {
+    let (first, second) = match direction {
+        Direction::Encipher => (1, 2),
+        Direction::Decipher => (2, 1),
+    };
+
+    let uv_len = buffer.len() % 32;
+    let pairs_len = buffer.len() - 32 - uv_len;
+    let (pairs, rest) = buffer.split_at_mut(pairs_len);
+    let (uv, xy) = rest.split_at_mut(uv_len);
+    let (u, v) = uv.split_at_mut(core::cmp::min(uv_len, 16));
+    let m_x = from_bytes(&xy[..16]);
+    let m_y = from_bytes(&xy[16..]);
+
+    // First pass: compute `W_i || X_i` for each pair, and their checksum `X`
+    let mut x = 0;
+    for (pair, offset) in pairs.chunks_exact_mut(32).zip(keys.offsets(1)) {
+        let m = from_bytes(&pair[..16]);
+        let m_ = from_bytes(&pair[16..]);
+
+        let w = m ^ keys.aes4(m_ ^ offset);
+        let x_i = m_ ^ keys.e(0, 0, w);
+
+        pair[..16].copy_from_slice(&w.to_be_bytes());
+        pair[16..].copy_from_slice(&x_i.to_be_bytes());
+        x ^= x_i;
+    }
+
+    match uv_len {
+        0 => (),
+        1..=15 => x ^= keys.e(0, 4, pad(u)),
+        _ => x ^= keys.e(0, 4, from_bytes(u)) ^ keys.e(0, 5, pad(v)),
+    }
+
+    let s_x = m_x ^ delta ^ x ^ keys.e(0, first, m_y);
+    let s_y = m_y ^ keys.e_neg(first, s_x);
+    let s = s_x ^ s_y;
+
+    // Second pass: mask each pair with `E^{2,i}(S)`, and compute the
+    // checksum `Y`
+    let mut y = 0;
+    for ((pair, offset), s_offset) in pairs
+        .chunks_exact_mut(32)
+        .zip(keys.offsets(1))
+        .zip(keys.offsets(2))
+    {
+        let s_ = keys.aes4(s ^ s_offset);
+        let y_i = from_bytes(&pair[..16]) ^ s_;
+        let z = from_bytes(&pair[16..]) ^ s_;
+
+        let c_ = y_i ^ keys.e(0, 0, z);
+        let c = z ^ keys.aes4(c_ ^ offset);
+
+        pair[..16].copy_from_slice(&c.to_be_bytes());
+        pair[16..].copy_from_slice(&c_.to_be_bytes());
+        y ^= y_i;
+    }
+
+    if uv_len != 0 {
+        xor_in_place(u, &keys.e_neg(4, s).to_be_bytes());
+
+        if uv_len < 16 {
+            y ^= keys.e(0, 4, pad(u));
+        } else {
+            xor_in_place(v, &keys.e_neg(5, s).to_be_bytes());
+            y ^= keys.e(0, 4, from_bytes(u)) ^ keys.e(0, 5, pad(v));
+        }
+    }
+
+    let c_y = s_x ^ keys.e_neg(second, s_y);
+    let c_x = s_y ^ delta ^ y ^ keys.e(0, second, c_y);
+
+    xy[..16].copy_from_slice(&c_x.to_be_bytes());
+    xy[16..].copy_from_slice(&c_y.to_be_bytes());
+}
+
+#[inline(always)]
+fn xor_in_place(a: &mut [u8], b: &[u8]) {
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        *x ^= y;
+    }
+}
@@ -0,0 +1,355 @@
+//! [AEZ][1]: a robust [Authenticated Encryption with Associated Data (AEAD)][2]
+//! cipher built on the AES round function, with arbitrary ciphertext
+//! expansion.
+//!
+//! AEZ is a tweakable wide-block cipher: it encrypts a message by appending
+//! `τ` zero bytes to it and enciphering the whole string, with a tweak
+//! derived from the key, the nonce, the associated data and `τ`. Any change
+//! to the ciphertext changes the whole deciphered string, and is detected by
+//! checking the `τ` trailing zero bytes. This crate implements version 5 of
+//! the [CAESAR submission][1].
+//!
+//! The expansion `τ` is the `TagSize` generic parameter of [`Aez`], and can
+//! be any number of bytes, including zero to encipher messages without
+//! expanding them. The default of 16 bytes, along with a 96-bit nonce, is the
+//! parameter set AEZ was submitted to CAESAR with.
+//!
+//! AEZ takes keys of any length: [`NewAead::new`] takes a 48-byte key, which
+//! is used as is, and [`NewAead::new_from_slice`] accepts any other length,
+//! from which a 48-byte key is extracted with BLAKE2b.
+//!
+//! ## Performance Notes
+//!
+//! The AES round function is provided by the [`aes`] crate's `hazmat` API,
+//! which uses AES-NI on x86/x86_64 when it is detected at runtime and falls
+//! back to a portable bitsliced implementation otherwise.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! AEZ is misuse resistant: reusing a nonce only reveals whether the same
+//! message was encrypted with the same associated data. Its authenticity only
+//! relies on the redundancy of the plaintext when `τ` is small, and messages
+//! encrypted with an expansion of 0 aren't authenticated at all.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! Simple usage (allocating, no associated data):
+//!
+//! ```
+//! use aez::{Aez, Key, Nonce};
+//! use aez::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"an example very very secret key, 48 bytes long!!");
+//! let cipher: Aez = Aez::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce"); // 96-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! Note that if you enable the `heapless` feature of this crate,
+//! you will receive an impl of [`aead::Buffer`] for `heapless::Vec`
+//! (re-exported from the [`aead`] crate as [`aead::heapless::Vec`]),
+//! which can then be passed as the `buffer` parameter to the in-place encrypt
+//! and decrypt methods:
+//!
+//! ```
+//! # #[cfg(feature = "heapless")]
+//! # {
+//! use aez::{Aez, Key, Nonce};
+//! use aez::aead::{AeadInPlace, NewAead};
+//! use aez::aead::heapless::Vec;
+//!
+//! let key = Key::from_slice(b"an example very very secret key, 48 bytes long!!");
+//! let cipher: Aez = Aez::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce"); // 96-bits; unique per message
+//!
+//! let mut buffer: Vec<u8, 128> = Vec::new(); // Buffer needs 16-bytes overhead for the expansion
+//! buffer.extend_from_slice(b"plaintext message");
+//!
+//! // Encrypt `buffer` in-place, replacing the plaintext contents with ciphertext
+//! cipher.encrypt_in_place(nonce, b"", &mut buffer).expect("encryption failure!");
+//!
+//! // `buffer` now contains the message ciphertext
+//! assert_ne!(&buffer, b"plaintext message");
+//!
+//! // Decrypt `buffer` in-place, replacing its ciphertext context with the original plaintext
+//! cipher.decrypt_in_place(nonce, b"", &mut buffer).expect("decryption failure!");
+//! assert_eq!(&buffer, b"plaintext message");
+//! # }
+//! ```
+//!
+//! ## Ciphertext Expansion
+//!
+//! The expansion is selected with the `TagSize` generic parameter. With an
+//! expansion of 0, [`Aez`] is a tweakable cipher enciphering messages of any
+//! length into ciphertexts of the same length, for formats which can't
+//! accommodate a tag:
+//!
+//! ```
+//! use aez::{Aez, Key, Nonce};
+//! use aez::aead::{Aead, NewAead, consts::U0};
+//!
+//! let key = Key::from_slice(b"an example very very secret key, 48 bytes long!!");
+//! let cipher = Aez::<U0>::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce");
+//! let ciphertext = cipher.encrypt(nonce, b"record".as_ref()).unwrap();
+//! assert_eq!(ciphertext.len(), b"record".len());
+//! ```
+//!
+//! AEZ's ciphertexts aren't made of an encrypted message and a separate tag,
+//! so the detached methods of [`AeadInPlace`] are unsupported and always
+//! return an error.
+//!
+//! [1]: https://competitions.cr.yp.to/round2/aezv5.pdf
+//! [2]: https://en.wikipedia.org/wiki/Authenticated_encryption
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![deny(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+pub use aead::{self, consts, AeadCore, AeadInPlace, Error, NewAead};
+
+mod encipher;
+mod tweakable;
+
+use crate::encipher::Direction;
+use crate::tweakable::{from_bytes, pad, Keys, ONE};
+use aead::{
+    consts::{U0, U12, U16, U48},
+    generic_array::{typenum::Unsigned, ArrayLength, GenericArray},
+    Buffer,
+};
+use blake2::{
+    digest::{Update, VariableOutput},
+    VarBlake2b,
+};
+use core::marker::PhantomData;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// AEZ keys (384-bit): `I || J || L`
+pub type Key = GenericArray<u8, U48>;
+
+/// AEZ nonces
+pub type Nonce<NonceSize = U12> = GenericArray<u8, NonceSize>;
+
+/// AEZ tags, i.e. the trailing `τ` bytes of ciphertexts
+pub type Tag<TagSize = U16> = GenericArray<u8, TagSize>;
+
+/// AEZ: generic over the ciphertext expansion `τ` and the nonce size, which
+/// default to 16 and 12 bytes.
+#[derive(Clone)]
+pub struct Aez<TagSize = U16, NonceSize = U12>
+where
+    TagSize: ArrayLength<u8>,
+    NonceSize: ArrayLength<u8>,
+{
+    keys: Keys,
+    sizes: PhantomData<(TagSize, NonceSize)>,
+}
+
+impl<TagSize, NonceSize> NewAead for Aez<TagSize, NonceSize>
+where
+    TagSize: ArrayLength<u8>,
+    NonceSize: ArrayLength<u8>,
+{
+    type KeySize = U48;
+
+    fn new(key: &Key) -> Self {
+        Self {
+            keys: Keys::new(key),
+            sizes: PhantomData,
+        }
+    }
+
+    /// Create a new AEZ instance from a key of any length, which is used as
+    /// is if it is 48 bytes long, or hashed into one with BLAKE2b otherwise.
+    fn new_from_slice(key: &[u8]) -> Result<Self, Error> {
+        if key.len() == U48::USIZE {
+            return Ok(Self::new(Key::from_slice(key)));
+        }
+
+        let mut extracted = Key::default();
+        let mut hasher = VarBlake2b::new(U48::USIZE).expect("valid BLAKE2b output size");
+        hasher.update(key);
+        hasher.finalize_variable(|hash| extracted.copy_from_slice(hash));
+
+        let cipher = Self::new(&extracted);
+        extracted.as_mut_slice().zeroize();
+        Ok(cipher)
+    }
+}
+
+impl<TagSize, NonceSize> AeadCore for Aez<TagSize, NonceSize>
+where
+    TagSize: ArrayLength<u8>,
+    NonceSize: ArrayLength<u8>,
+{
+    type NonceSize = NonceSize;
+    type TagSize = TagSize;
+    type CiphertextOverhead = U0;
+}
+
+impl<TagSize, NonceSize> AeadInPlace for Aez<TagSize, NonceSize>
+where
+    TagSize: ArrayLength<u8>,
+    NonceSize: ArrayLength<u8>,
+{
+    fn encrypt_in_place(
+        &self,
+        nonce: &Nonce<NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        let delta = self.hash(nonce, associated_data);
+
+        if buffer.is_empty() {
+            let mut tag = Tag::<TagSize>::default();
+            self.prf(delta, &mut tag);
+            return buffer.extend_from_slice(&tag);
+        }
+
+        buffer.extend_from_slice(&Tag::<TagSize>::default())?;
+        encipher::cipher(&self.keys, delta, Direction::Encipher, buffer.as_mut());
+        Ok(())
+    }
+
+    /// Unsupported: always returns an error, as the expansion is enciphered
+    /// along with the message.
+    fn encrypt_in_place_detached(
+        &self,
+        _nonce: &Nonce<NonceSize>,
+        _associated_data: &[u8],
+        _buffer: &mut [u8],
+    ) -> Result<Tag<TagSize>, Error> {
+        Err(Error)
+    }
+
+    fn decrypt_in_place(
+        &self,
+        nonce: &Nonce<NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        let tag_len = TagSize::USIZE;
+
+        if buffer.len() < tag_len {
+            return Err(Error);
+        }
+
+        let delta = self.hash(nonce, associated_data);
+
+        if buffer.len() == tag_len {
+            let mut expected_tag = Tag::<TagSize>::default();
+            self.prf(delta, &mut expected_tag);
+
+            return if expected_tag.ct_eq(buffer.as_ref()).into() {
+                buffer.truncate(0);
+                Ok(())
+            } else {
+                Err(Error)
+            };
+        }
+
+        encipher::cipher(&self.keys, delta, Direction::Decipher, buffer.as_mut());
+
+        let plaintext_len = buffer.len() - tag_len;
+        let zeros = buffer.as_ref()[plaintext_len..]
+            .iter()
+            .fold(0, |acc, b| acc | b);
+
+        if zeros.ct_eq(&0).into() {
+            buffer.truncate(plaintext_len);
+            Ok(())
+        } else {
+            // Don't release the unauthenticated plaintext
+            buffer.as_mut().iter_mut().for_each(|b| *b = 0);
+            Err(Error)
+        }
+    }
+
+    /// Unsupported: always returns an error, as the expansion is enciphered
+    /// along with the message.
+    fn decrypt_in_place_detached(
+        &self,
+        _nonce: &Nonce<NonceSize>,
+        _associated_data: &[u8],
+        _buffer: &mut [u8],
+        _tag: &Tag<TagSize>,
+    ) -> Result<(), Error> {
+        Err(Error)
+    }
+}
+
+impl<TagSize, NonceSize> Aez<TagSize, NonceSize>
+where
+    TagSize: ArrayLength<u8>,
+    NonceSize: ArrayLength<u8>,
+{
+    /// AEZ-hash of the tweak `([τ]_128, N, A)`
+    fn hash(&self, nonce: &[u8], associated_data: &[u8]) -> u128 {
+        let tau_bits = (TagSize::U64 as u128) * 8;
+
+        self.keys.e(3, 1, tau_bits)
+            ^ self.hash_tweak(4, nonce)
+            ^ self.hash_tweak(5, associated_data)
+    }
+
+    /// Hash one component of the tweak with `E^{j,1}`, `E^{j,2}`, ..., and
+    /// its trailing partial block, if any, with `E^{j,0}`
+    fn hash_tweak(&self, j: u32, tweak: &[u8]) -> u128 {
+        if tweak.is_empty() {
+            return self.keys.e(j, 0, ONE);
+        }
+
+        let mut sum = 0;
+        let mut chunks = tweak.chunks_exact(16);
+        for (chunk, offset) in (&mut chunks).zip(self.keys.offsets(j)) {
+            sum ^= self.keys.aes4(from_bytes(chunk) ^ offset);
+        }
+
+        let rem = chunks.remainder();
+        if !rem.is_empty() {
+            sum ^= self.keys.e(j, 0, pad(rem));
+        }
+
+        sum
+    }
+
+    /// AEZ-prf: fill `out` with `E^{-1,3}(delta ^ [0]) || E^{-1,3}(delta ^ [1]) || ...`
+    fn prf(&self, delta: u128, out: &mut [u8]) {
+        for (i, chunk) in out.chunks_mut(16).enumerate() {
+            let block = self.keys.e_neg(3, delta ^ i as u128).to_be_bytes();
+            chunk.copy_from_slice(&block[..chunk.len()]);
+        }
+    }
+}
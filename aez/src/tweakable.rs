@@ -0,0 +1,181 @@
+//! The tweakable block cipher `E` AEZ is built upon, made of AES4 and AES10.
+//!
+//! Blocks are represented as big endian `u128`s, so that the first bit of a
+//! string is the most significant bit of the block.
+
+use crate::Key;
+use aes::{hazmat::cipher_round, Block};
+use zeroize::Zeroize;
+
+/// The block `10*`
+pub(crate) const ONE: u128 = 1 << 127;
+
+/// AEZ subkeys `I`, `J` and `L`, along with the precomputed multiples of `L`
+/// used as offsets.
+#[derive(Clone)]
+pub(crate) struct Keys {
+    /// `I`
+    i: u128,
+
+    /// `J`
+    j: u128,
+
+    /// `0 * L` to `7 * L`
+    l: [u128; 8],
+
+    /// `I`, `J` and `L` as AES round keys
+    round_keys: [Block; 3],
+}
+
+impl Keys {
+    /// Split `I || J || L` into the three subkeys
+    pub(crate) fn new(key: &Key) -> Self {
+        let i = from_bytes(&key[..16]);
+        let j = from_bytes(&key[16..32]);
+        let l = from_bytes(&key[32..]);
+
+        let mut multiples = [0; 8];
+        for n in 1..8 {
+            multiples[n] = if n % 2 == 0 {
+                double(multiples[n / 2])
+            } else {
+                multiples[n - 1] ^ l
+            };
+        }
+
+        Self {
+            i,
+            j,
+            l: multiples,
+            round_keys: [
+                Block::clone_from_slice(&key[..16]),
+                Block::clone_from_slice(&key[16..32]),
+                Block::clone_from_slice(&key[32..]),
+            ],
+        }
+    }
+
+    /// `E^{j,i}(x)`, for `j >= 0`
+    pub(crate) fn e(&self, j: u32, i: usize, x: u128) -> u128 {
+        let mut i_offset = self.i;
+        for _ in 0..(i + 7) / 8 {
+            i_offset = double(i_offset);
+        }
+
+        self.aes4(x ^ self.j_offset(j) ^ i_offset ^ self.l[i % 8])
+    }
+
+    /// `E^{-1,i}(x)`, for `i < 8`
+    pub(crate) fn e_neg(&self, i: usize, x: u128) -> u128 {
+        self.aes10(x ^ self.l[i])
+    }
+
+    /// Offsets of `E^{j,1}`, `E^{j,2}`, ... for consecutive blocks
+    pub(crate) fn offsets(&self, j: u32) -> Offsets<'_> {
+        Offsets {
+            keys: self,
+            j_offset: self.j_offset(j),
+            i_offset: self.i,
+            i: 0,
+        }
+    }
+
+    /// AES4 keyed with `(0, J, I, L, 0)`
+    pub(crate) fn aes4(&self, x: u128) -> u128 {
+        let [i, j, l] = &self.round_keys;
+        let mut block = Block::from(x.to_be_bytes());
+
+        cipher_round(&mut block, j);
+        cipher_round(&mut block, i);
+        cipher_round(&mut block, l);
+        cipher_round(&mut block, &Block::default());
+
+        from_bytes(&block)
+    }
+
+    /// AES10 keyed with `(0, I, J, L, I, J, L, I, J, L, I)`
+    fn aes10(&self, x: u128) -> u128 {
+        let [i, j, l] = &self.round_keys;
+        let mut block = Block::from(x.to_be_bytes());
+
+        for _ in 0..3 {
+            cipher_round(&mut block, i);
+            cipher_round(&mut block, j);
+            cipher_round(&mut block, l);
+        }
+        cipher_round(&mut block, i);
+
+        from_bytes(&block)
+    }
+
+    /// `j * J`
+    fn j_offset(&self, j: u32) -> u128 {
+        mul(self.j, j as usize)
+    }
+}
+
+impl Drop for Keys {
+    fn drop(&mut self) {
+        self.i.zeroize();
+        self.j.zeroize();
+        self.l.zeroize();
+        for key in self.round_keys.iter_mut() {
+            key.as_mut_slice().zeroize();
+        }
+    }
+}
+
+/// Iterator over the offsets `j * J ^ 2^ceil(i / 8) * I ^ (i mod 8) * L` of
+/// `E^{j,i}`, for `i = 1, 2, ...`
+pub(crate) struct Offsets<'k> {
+    keys: &'k Keys,
+    j_offset: u128,
+    i_offset: u128,
+    i: usize,
+}
+
+impl Iterator for Offsets<'_> {
+    type Item = u128;
+
+    fn next(&mut self) -> Option<u128> {
+        if self.i % 8 == 0 {
+            self.i_offset = double(self.i_offset);
+        }
+        self.i += 1;
+
+        Some(self.j_offset ^ self.i_offset ^ self.keys.l[self.i % 8])
+    }
+}
+
+/// Load up to 16 bytes into a block, zero-padding them
+pub(crate) fn from_bytes(bytes: &[u8]) -> u128 {
+    let mut block = [0u8; 16];
+    block[..bytes.len()].copy_from_slice(bytes);
+    u128::from_be_bytes(block)
+}
+
+/// Load less than 16 bytes into a block, padding them with `10*`
+pub(crate) fn pad(bytes: &[u8]) -> u128 {
+    from_bytes(bytes) | ONE >> (8 * bytes.len())
+}
+
+/// Multiply by `x` in GF(2<sup>128</sup>)
+pub(crate) fn double(x: u128) -> u128 {
+    (x << 1) ^ ((x >> 127) * 0x87)
+}
+
+/// Multiply by the integer `n` in GF(2<sup>128</sup>)
+fn mul(x: u128, mut n: usize) -> u128 {
+    let mut result = 0;
+    let mut power = x;
+
+    while n != 0 {
+        if n & 1 == 1 {
+            result ^= power;
+        }
+        power = double(power);
+        n >>= 1;
+    }
+
+    result
+}
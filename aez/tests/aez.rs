@@ -0,0 +1,477 @@
+//! AEZ tests
+
+#[macro_use]
+extern crate hex_literal;
+
+use aez::aead::{
+    consts::{U0, U16, U32},
+    generic_array::GenericArray,
+    Aead, AeadInPlace, NewAead, Payload,
+};
+use aez::Aez;
+
+/// Test vectors
+#[derive(Debug)]
+struct TestVector {
+    key: &'static [u8],
+    nonce: &'static [u8],
+    aad: &'static [u8],
+    plaintext: &'static [u8],
+    ciphertext: &'static [u8],
+}
+
+/// AEZ test vectors with the parameters of the CAESAR submission: 96-bit
+/// nonces and 128-bit expansion.
+///
+/// Generated with the reference implementation of AEZ v5, including the
+/// BLAKE2b key extraction of the last one.
+const CAESAR_VECTORS: &[TestVector] = &[
+    TestVector {
+        key: &hex!(
+            "
+            c84d1ba0b6f662acabcdacccff922e33e5e730eb0e9d0014f6b321460c74a20b
+            93e469ca7eb0066bacf4aa5ff56cf193
+            "
+        ),
+        nonce: &hex!("7809fc050bd37cc74456a7fd"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!("83d1485fa648ebf1ae86e4b0c550aab9"),
+    },
+    TestVector {
+        key: &hex!(
+            "
+            a97b2282385f68197f7f9f894b7e6c50f01347107c40f279b838b4fa3225ab17
+            4a4a17e52741a0772ec30f8858bada0d
+            "
+        ),
+        nonce: &hex!("c98a6dfaab7dadca0e9566b8"),
+        aad: &hex!("a6b4a2dbcffbe619f72ed3547f52e82bc1d8e52b"),
+        plaintext: &hex!(""),
+        ciphertext: &hex!("fe21c6b8067b7ed473103f640efa3365"),
+    },
+    TestVector {
+        key: &hex!(
+            "
+            9a39a9ba2eca3a3e60c9d40301bd3657526c09135dcfc974da20a8a6fa1edb1a
+            05e819345efeb40b5bc8cd4a7c7e9290
+            "
+        ),
+        nonce: &hex!("d08fcd450aae5596e10cf732"),
+        aad: &hex!(""),
+        plaintext: &hex!("e7"),
+        ciphertext: &hex!("d7ec26085d9b47bb6fe0ce2b28ccf04b97"),
+    },
+    TestVector {
+        key: &hex!(
+            "
+            b011d44f2c88afcc1f7a6aba2fb82101cb649928bb3b01987e7dc8f189c7470f
+            16b13888ad619cdb697b367b7b33a566
+            "
+        ),
+        nonce: &hex!("4b66adcc1e90f74265866779"),
+        aad: &hex!("f5d6c2c82fb13d"),
+        plaintext: &hex!("c8e540"),
+        ciphertext: &hex!("6c15ce8ee842b701a9cce533bcb0361e5dce31"),
+    },
+    TestVector {
+        key: &hex!(
+            "
+            3b44e4ab6a3ba2634d577bcdfe454eedfddb7118ddffe1e8e6a35222d84afe17
+            944362116cbdd9fb307674954e016017
+            "
+        ),
+        nonce: &hex!("fe461af0dd1bb78105c400d6"),
+        aad: &hex!("ce84e36a468cbf5803316ae900837153"),
+        plaintext: &hex!("4a8dee1b1b4c60733f918c7f6d93d7"),
+        ciphertext: &hex!("a62c58c7e5998ad513f244e01256fab1c28060ee5b16d5b824ce73275f47ba"),
+    },
+    TestVector {
+        key: &hex!(
+            "
+            a1faa377e460c917ecc0495a4f8daab3b5164786dbe2590642ea506e8fd28da1
+            b9cec4b05ccacb138bc4a8eb4af4d0e8
+            "
+        ),
+        nonce: &hex!("ed5000ce574446f7b9ae7ec9"),
+        aad: &hex!(""),
+        plaintext: &hex!("cc6aab6f10821c005cde8430dfdc2956cd"),
+        ciphertext: &hex!(
+            "
+            9d8a7cb82288c363318a980a4bdd27e077e329f0b2ca3ff0a1664b1e97aaecc2
+            c0
+            "
+        ),
+    },
+    TestVector {
+        key: &hex!(
+            "
+            5ae80476377eb20b4fcd9f54a9be6074a8a176042c5c7cb5e5f1dd74014b3c40
+            f28c8df650e8886438b1c9d8b1b4d778
+            "
+        ),
+        nonce: &hex!("feaa2436ca43ac0c8bd6ea94"),
+        aad: &hex!("8feee9a3c6d828555b5432a7"),
+        plaintext: &hex!(
+            "
+            c596848dff44a27ec6bc72a8f4dae48a04161b248dbb989d7a8c5e4b5f676d5b
+            6c
+            "
+        ),
+        ciphertext: &hex!(
+            "
+            a8c5023946d415f25e0c71cabd81b27bc0a60b696170a7160b7c0de70f45e472
+            ad96f2fa14d0d793e951578106aa2e2df5
+            "
+        ),
+    },
+    TestVector {
+        key: &hex!(
+            "
+            b6338a2c7c37cb607d7fa30f54f2809623fc71be16e87191ab1f7f6cc7c89f6c
+            f966e7a2ddcd0fd26129a2b4ae80b301
+            "
+        ),
+        nonce: &hex!("d32936c82514af38d56eaab0"),
+        aad: &hex!("e76e822b8086785bb7f0dbb64d5255735b2ff5f37ab959208da85a1e5b9f45c0"),
+        plaintext: &hex!(
+            "
+            fc384a87b8f9c18482fab30b9a73876bc5fcaed865aab2518813b1f296930b47
+            39aba4778f0e6ce0d9373f5b3a7cad897004f9047639b3ed01f7ae16f15b742f
+            "
+        ),
+        ciphertext: &hex!(
+            "
+            b9201843298de93c0dafc995f0637b7a17fe6632bebe780404dc2f1fdce698ec
+            b7923e63f775d133b047d0024fc86f22975bde2029aee6fcc6a6ede1978c2036
+            6b86eea50e2967316f2d6150053a19f8
+            "
+        ),
+    },
+    TestVector {
+        key: &hex!(
+            "
+            933e00cb4c3e6960b2d302649d12817cf91cf32d2a8bfdeae37fb3653bac9664
+            2afd688067be8055bf491ee10c80c85f
+            "
+        ),
+        nonce: &hex!("fc2af760b1bc5cb03dfa7a45"),
+        aad: &hex!(
+            "
+            c012d938aa187dd570ede274e087e256d82ee2f6b3b121faa109c23d5b0257e8
+            4fea3263dfc4362995424114bd
+            "
+        ),
+        plaintext: &hex!(
+            "
+            2d59ceb94502825c1f32cadb5d4f2145866f2b3d11b230d3d1169caace47ad67
+            0793241b717f1133229b7de1d1d5213fc100aa32d7d72cf119db0165bd9ae1a6
+            49c20f5c9a8da7c32ed014303a6070cc5f
+            "
+        ),
+        ciphertext: &hex!(
+            "
+            c0546d2ef5f0826544c4e2d17d8df696afafb02d1b875f2d2535280648918b58
+            8c329775ceb709287c7245b892d1f8ae3aaf1c75fdd60119980a28dcf1f94d87
+            472fae98aad5b22b56304a44b778bce5d008505fe7235ede609e0c428623575b
+            73
+            "
+        ),
+    },
+    TestVector {
+        key: &hex!("6ec41543eeb698f3976734097872ef19"),
+        nonce: &hex!("5018dd9bac80f219ebd64207"),
+        aad: &hex!("da2a17e3a3af247ff340"),
+        plaintext: &hex!("ffda196df84fab3f83c97a05ca249015cd960485"),
+        ciphertext: &hex!(
+            "
+            de378f02d35f6a7af64d9e4bf4c660670dfa36063707fdd78742342c928dff35
+            26826c25
+            "
+        ),
+    },
+];
+
+/// AEZ test vectors with 128-bit nonces, from the `aez` crate
+///
+/// <https://github.com/sphinx-cryptography/aez>
+const NONCE128_VECTORS: &[TestVector] = &[TestVector {
+    key: &hex!(
+        "
+            85ee018bb3692c0831893ea7f4dd5336baa2842963f8c15c740de1e207001885
+            e1ae75c05550a6f265f305908297b078
+            "
+    ),
+    nonce: &hex!("7ed2c2d22198108f04ce72a9be5021f9"),
+    aad: &hex!(
+        "
+            64c8788672276b4daa72f5b6bc738027959d44ed363db940d5ad561a06c1a2cf
+            b2
+            "
+    ),
+    plaintext: &hex!("b9c497e08e50b810f1b04ae848201e558ca72ede656752a04b1a2497e9e19e53"),
+    ciphertext: &hex!(
+        "
+            19a8873d9b06f98f4c38603ea0e8173f5b55deff868ff2a52a0b899b1bafc249
+            e36eea521bd755a17772000aa7c695a0
+            "
+    ),
+}];
+
+/// AEZ test vectors without ciphertext expansion
+///
+/// Generated with the reference implementation of AEZ v5.
+const TAU0_VECTORS: &[TestVector] = &[
+    TestVector {
+        key: &hex!(
+            "
+            319cde42e1be7243a489bc8e1d71290f4fef2447d7b5309454fd34d02022470f
+            e70348a9bb073795a517f16e6012c534
+            "
+        ),
+        nonce: &hex!("10ae82de0821393d9c8bd33a"),
+        aad: &hex!("6c9884"),
+        plaintext: &hex!("870d7b1345"),
+        ciphertext: &hex!("24c5b6c858"),
+    },
+    TestVector {
+        key: &hex!(
+            "
+            b1ca5f76ea3aba054e3fd27d5c30e100cd2e4dbf10f7c83698365075c27e0853
+            2d1bba5b11e0aa33ec90e78a0f9d5cf5
+            "
+        ),
+        nonce: &hex!("195be179a739d44e73ed68fc"),
+        aad: &hex!(""),
+        plaintext: &hex!("6e2d250b42fc6372530da257e20bbb49"),
+        ciphertext: &hex!("6d423385dd1139a16bc4f9cc9a3e19b4"),
+    },
+    TestVector {
+        key: &hex!(
+            "
+            fde580a71a302ae88c10a148979634fbaf113b0b38d400379046320ccb9a7746
+            611f5db8000eaa62b79aa689d73cdee4
+            "
+        ),
+        nonce: &hex!("521927192c72a8e738e36917"),
+        aad: &hex!("08ffd9533552f48b8e8dae8b7f3e801e1c"),
+        plaintext: &hex!(
+            "
+            89e10c957eb2b9ffc65c5519cbe3b58bfa4b9f2806dc7329d77a88fac3a116a3
+            6690fcb937489e7e
+            "
+        ),
+        ciphertext: &hex!(
+            "
+            1d2a1a419c9d73616166ed41288918a8a705f77b742f23b4e5b1ff0a82760074
+            6aac8de697f58467
+            "
+        ),
+    },
+];
+
+/// AEZ test vectors with 256-bit expansion, which the reference
+/// implementation doesn't support.
+///
+/// Generated with the `zears` crate.
+const TAU256_VECTORS: &[TestVector] = &[
+    TestVector {
+        key: &hex!(
+            "
+            86d1917227ca090b13e15414a3365ae9b76bd0ddac470a343cb2261887bfd072
+            72c82738bfed0280cb73d54618e4e4fb
+            "
+        ),
+        nonce: &hex!("f8f25d48d4bd419b91fb4ae4"),
+        aad: &hex!("5a312a484d"),
+        plaintext: &hex!(""),
+        ciphertext: &hex!("8e8e47b61d669678a6f8a4865ea5929a59dd8b8fc6c4497684943c99c2d77d1a"),
+    },
+    TestVector {
+        key: &hex!(
+            "
+            e09fd5698a89e87f1e3f9ce9e9b8f89bfca44d7905a95a96746bbb3166bfafb5
+            229aea3d8626d36a6afbd7218430a2b9
+            "
+        ),
+        nonce: &hex!("3eae838fd459e4c93fdb98b4"),
+        aad: &hex!("975675602313277fe2"),
+        plaintext: &hex!("4662543622d66986aa7f5760d8de3286226accc89f118a59"),
+        ciphertext: &hex!(
+            "
+            89800ee129ee81a4dd2046498735708b133bdabb61de418de5e3c8162acc7fdf
+            90a9924f0a3af3eb08613b9ef2aff97ce0cfd17511b38ae2
+            "
+        ),
+    },
+    TestVector {
+        key: &hex!(
+            "
+            08c527993a4b145fcf571e9f762ea000b0d1c798762e9ebdebe85344b942cd63
+            eb5a0e12e604156a21712aa49ff4f387
+            "
+        ),
+        nonce: &hex!("aca112abb44316ca20986501"),
+        aad: &hex!(""),
+        plaintext: &hex!(
+            "
+            b540b6998310040d3283b0dddb88a97d6ba756e3618bc088c1463570bb11b35e
+            e9ec12b0e230d7b129e4df6a132b452c8a5a502167e6764f2653c7924b69bd44
+            1318d418ca01
+            "
+        ),
+        ciphertext: &hex!(
+            "
+            abb0d648f9d843ba76203d2a215ddf300615adf0888df8ba82f73e4a66f35ddb
+            abc4f7010814f8ba453457730febe1788d1f323de11dae21bcab7254218245e3
+            e01ec7502a9be049ce22748bdc49e3958a9389ba2ca713162451b692c8cc5b91
+            902408ead22e
+            "
+        ),
+    },
+];
+
+macro_rules! tests {
+    ($name:ident, $aead:ty, $vectors:expr) => {
+        mod $name {
+            use super::*;
+
+            fn cipher(vector: &TestVector) -> $aead {
+                <$aead>::new_from_slice(vector.key).unwrap()
+            }
+
+            #[test]
+            fn encrypt() {
+                for vector in $vectors {
+                    let payload = Payload {
+                        msg: vector.plaintext,
+                        aad: vector.aad,
+                    };
+
+                    let nonce = GenericArray::from_slice(vector.nonce);
+                    let ciphertext = cipher(vector).encrypt(nonce, payload).unwrap();
+                    assert_eq!(vector.ciphertext, ciphertext.as_slice());
+                }
+            }
+
+            #[test]
+            fn decrypt() {
+                for vector in $vectors {
+                    let payload = Payload {
+                        msg: vector.ciphertext,
+                        aad: vector.aad,
+                    };
+
+                    let nonce = GenericArray::from_slice(vector.nonce);
+                    let plaintext = cipher(vector).decrypt(nonce, payload).unwrap();
+                    assert_eq!(vector.plaintext, plaintext.as_slice());
+                }
+            }
+
+            #[test]
+            fn decrypt_modified() {
+                for vector in $vectors {
+                    let nonce = GenericArray::from_slice(vector.nonce);
+                    let mut ciphertext = Vec::from(vector.ciphertext);
+
+                    // Tweak the first and the last byte
+                    for &i in &[0, ciphertext.len() - 1] {
+                        ciphertext[i] ^= 1;
+                        let payload = Payload {
+                            msg: &ciphertext,
+                            aad: vector.aad,
+                        };
+                        assert!(cipher(vector).decrypt(nonce, payload).is_err());
+                        ciphertext[i] ^= 1;
+                    }
+                }
+            }
+
+            #[test]
+            fn detached_unsupported() {
+                let vector = &$vectors[0];
+                let nonce = GenericArray::from_slice(vector.nonce);
+
+                let mut buffer = Vec::from(vector.plaintext);
+                assert!(cipher(vector)
+                    .encrypt_in_place_detached(nonce, vector.aad, &mut buffer)
+                    .is_err());
+            }
+        }
+    };
+}
+
+tests!(caesar, Aez, CAESAR_VECTORS);
+tests!(nonce128, Aez<U16, U16>, NONCE128_VECTORS);
+tests!(tau256, Aez<U32>, TAU256_VECTORS);
+
+mod tau0 {
+    use super::*;
+
+    #[test]
+    fn encipher() {
+        for vector in TAU0_VECTORS {
+            let payload = Payload {
+                msg: vector.plaintext,
+                aad: vector.aad,
+            };
+
+            let cipher = Aez::<U0>::new_from_slice(vector.key).unwrap();
+            let nonce = GenericArray::from_slice(vector.nonce);
+            let ciphertext = cipher.encrypt(nonce, payload).unwrap();
+            assert_eq!(vector.ciphertext, ciphertext.as_slice());
+
+            let payload = Payload {
+                msg: &ciphertext,
+                aad: vector.aad,
+            };
+            let plaintext = cipher.decrypt(nonce, payload).unwrap();
+            assert_eq!(vector.plaintext, plaintext.as_slice());
+        }
+    }
+}
+
+/// AEZ enciphers the whole message: changing any ciphertext byte changes the
+/// whole plaintext.
+#[test]
+fn wide_block() {
+    let vector = &TAU0_VECTORS[2];
+    let cipher = Aez::<U0>::new_from_slice(vector.key).unwrap();
+    let nonce = GenericArray::from_slice(vector.nonce);
+
+    let mut ciphertext = Vec::from(vector.ciphertext);
+    ciphertext[0] ^= 1;
+
+    let payload = Payload {
+        msg: &ciphertext,
+        aad: vector.aad,
+    };
+    let plaintext = cipher.decrypt(nonce, payload).unwrap();
+    for (a, b) in plaintext.chunks(16).zip(vector.plaintext.chunks(16)) {
+        assert_ne!(a, b);
+    }
+}
+
+#[test]
+fn round_trip() {
+    let cipher = Aez::<U16>::new_from_slice(b"short key").unwrap();
+    let nonce = GenericArray::from_slice(b"unique nonce");
+    let data = [0x42; 200];
+
+    // AEZ-tiny, and AEZ-core with every length of partial blocks
+    for len in 0..=200 {
+        let payload = Payload {
+            msg: &data[..len],
+            aad: &data[..len % 40],
+        };
+        let ciphertext = cipher.encrypt(nonce, payload).unwrap();
+        assert_eq!(ciphertext.len(), len + 16);
+
+        let payload = Payload {
+            msg: &ciphertext,
+            aad: &data[..len % 40],
+        };
+        let plaintext = cipher.decrypt(nonce, payload).unwrap();
+        assert_eq!(&data[..len], plaintext.as_slice());
+    }
+}
@@ -0,0 +1,209 @@
+//! Self-describing ciphertext envelopes, which carry the algorithm, key ID
+//! and nonce needed to decrypt them.
+//!
+//! An envelope is a header followed by the ciphertext and tag of the
+//! algorithm:
+//!
+//! ```text
+//! version || algorithm || key ID length || key ID || nonce || ciphertext || tag
+//! ```
+//!
+//! - `version` is the byte [`VERSION`]
+//! - `algorithm` is the [IANA identifier](crate::iana) of the algorithm, as a
+//!   big-endian `u16`
+//! - `key ID` is an application-defined identifier of the key, of up to 255
+//!   bytes, preceded by its length as a byte
+//! - `nonce` is [`Algorithm::nonce_size`] bytes long
+//!
+//! The whole header is authenticated: the associated data of the algorithm is
+//! the header followed by the associated data of the envelope. Only
+//! algorithms with an IANA identifier can be used, so e.g.
+//! [`Algorithm::XChaCha20Poly1305`] can't.
+//!
+//! [`open`] looks up the key of an envelope by its ID, and checks that its
+//! algorithm is the one of the header, so an envelope can't be decrypted with
+//! another algorithm than the one the key is meant for.
+//!
+//! # Usage
+//!
+//! ```
+//! # #[cfg(all(feature = "alloc", feature = "chacha20poly1305"))]
+//! # {
+//! use any_aead::{envelope, Algorithm, AnyAead};
+//!
+//! let key = AnyAead::new(Algorithm::ChaCha20Poly1305, b"an example very very secret key.")
+//!     .expect("invalid key length");
+//!
+//! let nonce = b"unique nonce"; // unique per message
+//!
+//! let sealed = envelope::seal(&key, b"key-1", nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let header = envelope::Header::parse(&sealed).expect("invalid header");
+//! assert_eq!(header.algorithm, Algorithm::ChaCha20Poly1305);
+//! assert_eq!(header.key_id, b"key-1");
+//!
+//! let plaintext = envelope::open(sealed.as_ref(), |key_id| match key_id {
+//!     b"key-1" => Some(&key),
+//!     _ => None,
+//! })
+//! .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! # }
+//! ```
+//!
+//! [`Algorithm::nonce_size`]: crate::Algorithm::nonce_size
+//! [`Algorithm::XChaCha20Poly1305`]: crate::Algorithm::XChaCha20Poly1305
+
+use crate::{Algorithm, Error};
+
+#[cfg(feature = "alloc")]
+use {crate::AnyAead, aead::Payload, alloc::vec::Vec};
+
+/// Version of the envelope format
+pub const VERSION: u8 = 0x01;
+
+/// Size of the header without the key ID and nonce: the version, algorithm
+/// and key ID length
+const FIXED_HEADER_SIZE: usize = 4;
+
+/// Largest tag size of the algorithms
+#[cfg(feature = "alloc")]
+const MAX_TAG_SIZE: usize = 16;
+
+/// Header of an envelope.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Header<'a> {
+    /// Algorithm of the ciphertext
+    pub algorithm: Algorithm,
+
+    /// ID of the key of the ciphertext
+    pub key_id: &'a [u8],
+
+    /// Nonce of the ciphertext
+    pub nonce: &'a [u8],
+}
+
+impl<'a> Header<'a> {
+    /// Parse the header at the start of an envelope.
+    ///
+    /// Returns an error if the header is truncated, its version isn't
+    /// [`VERSION`], or its algorithm is unknown or not enabled by the Cargo
+    /// features of this crate. The header isn't authenticated until the
+    /// envelope is [opened](open).
+    pub fn parse(envelope: &'a [u8]) -> Result<Self, Error> {
+        if envelope.len() < FIXED_HEADER_SIZE || envelope[0] != VERSION {
+            return Err(Error);
+        }
+
+        let iana_id = u16::from_be_bytes([envelope[1], envelope[2]]);
+        let algorithm = Algorithm::from_iana_id(iana_id).ok_or(Error)?;
+        let key_id_end = FIXED_HEADER_SIZE + envelope[3] as usize;
+        let nonce_end = key_id_end + algorithm.nonce_size();
+
+        if envelope.len() < nonce_end {
+            return Err(Error);
+        }
+
+        Ok(Self {
+            algorithm,
+            key_id: &envelope[FIXED_HEADER_SIZE..key_id_end],
+            nonce: &envelope[key_id_end..nonce_end],
+        })
+    }
+
+    /// Length of the encoded header in bytes
+    pub fn encoded_len(&self) -> usize {
+        FIXED_HEADER_SIZE + self.key_id.len() + self.nonce.len()
+    }
+
+    /// Append the encoded header to `out`
+    #[cfg(feature = "alloc")]
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), Error> {
+        let iana_id = self.algorithm.iana_id().ok_or(Error)?;
+        if self.key_id.len() > 0xff || self.nonce.len() != self.algorithm.nonce_size() {
+            return Err(Error);
+        }
+
+        out.push(VERSION);
+        out.extend_from_slice(&iana_id.to_be_bytes());
+        out.push(self.key_id.len() as u8);
+        out.extend_from_slice(self.key_id);
+        out.extend_from_slice(self.nonce);
+        Ok(())
+    }
+}
+
+/// Encrypt the given plaintext payload with `key`, whose ID is `key_id`, and
+/// return the resulting envelope.
+///
+/// Returns an error if the algorithm of the key has no IANA identifier, the
+/// key ID is longer than 255 bytes, or the nonce has the wrong length.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn seal<'msg, 'aad>(
+    key: &AnyAead,
+    key_id: &[u8],
+    nonce: &[u8],
+    plaintext: impl Into<Payload<'msg, 'aad>>,
+) -> Result<Vec<u8>, Error> {
+    let payload = plaintext.into();
+    let header = Header {
+        algorithm: key.algorithm(),
+        key_id,
+        nonce,
+    };
+
+    // The associated data of the algorithm is the header, followed by that
+    // of the envelope
+    let header_len = header.encoded_len();
+    let mut associated_data = Vec::with_capacity(header_len + payload.aad.len());
+    header.encode(&mut associated_data)?;
+    associated_data.extend_from_slice(payload.aad);
+
+    let tag_size = key.algorithm().tag_size();
+    let mut buffer = Vec::with_capacity(header_len + payload.msg.len() + tag_size);
+    buffer.extend_from_slice(&associated_data[..header_len]);
+    buffer.extend_from_slice(payload.msg);
+
+    let mut tag = [0u8; MAX_TAG_SIZE];
+    let tag = &mut tag[..tag_size];
+    key.encrypt_in_place_detached(nonce, &associated_data, &mut buffer[header_len..], tag)?;
+    buffer.extend_from_slice(tag);
+    Ok(buffer)
+}
+
+/// Decrypt the given envelope payload, and return the resulting plaintext.
+///
+/// `keys` is called with the key ID of the envelope, and returns the key with
+/// that ID, if any. Returns an error if there is none, the algorithm of the
+/// key isn't that of the envelope, or the envelope isn't authentic.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn open<'msg, 'aad, 'k, F>(
+    envelope: impl Into<Payload<'msg, 'aad>>,
+    keys: F,
+) -> Result<Vec<u8>, Error>
+where
+    F: FnOnce(&[u8]) -> Option<&'k AnyAead>,
+{
+    let payload = envelope.into();
+    let header = Header::parse(payload.msg)?;
+    let key = keys(header.key_id).ok_or(Error)?;
+
+    if key.algorithm() != header.algorithm {
+        return Err(Error);
+    }
+
+    let (encoded_header, ciphertext) = payload.msg.split_at(header.encoded_len());
+    let mut associated_data = Vec::with_capacity(encoded_header.len() + payload.aad.len());
+    associated_data.extend_from_slice(encoded_header);
+    associated_data.extend_from_slice(payload.aad);
+
+    let payload = Payload {
+        msg: ciphertext,
+        aad: &associated_data,
+    };
+    key.decrypt(header.nonce, payload)
+}
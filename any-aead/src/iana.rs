@@ -0,0 +1,172 @@
+//! Identifiers of the IANA ["AEAD Algorithms"][1] registry.
+//!
+//! Protocols such as IKEv2, OSCORE and JOSE refer to AEADs by these numeric
+//! identifiers. [`Algorithm::from_iana_id`] maps an identifier to the
+//! [`Algorithm`] with the same key, nonce and tag sizes, and
+//! [`Algorithm::iana_id`] maps it back.
+//!
+//! The registry allows OCB nonces from 1 to 15 bytes and SIV nonces of any
+//! length, but the [`Algorithm`]s of this crate have fixed nonce sizes:
+//! identifiers 15 and 17 map to AES-SIV with 128-bit nonces, and identifiers
+//! 20 to 28 map to AES-OCB3 with 96-bit nonces. [`AEAD_AES_SIV_CMAC_384`] has
+//! no [`Algorithm`], as the `aes-siv` crate has no AES-192 instance.
+//!
+//! [1]: https://www.iana.org/assignments/aead-parameters/aead-parameters.xhtml
+//! [`Algorithm`]: crate::Algorithm
+//! [`Algorithm::from_iana_id`]: crate::Algorithm::from_iana_id
+//! [`Algorithm::iana_id`]: crate::Algorithm::iana_id
+
+/// AES-GCM with a 128-bit key ([RFC 5116])
+///
+/// [RFC 5116]: https://tools.ietf.org/html/rfc5116
+pub const AEAD_AES_128_GCM: u16 = 1;
+
+/// AES-GCM with a 256-bit key ([RFC 5116])
+///
+/// [RFC 5116]: https://tools.ietf.org/html/rfc5116
+pub const AEAD_AES_256_GCM: u16 = 2;
+
+/// AES-CCM with a 128-bit key ([RFC 5116])
+///
+/// [RFC 5116]: https://tools.ietf.org/html/rfc5116
+pub const AEAD_AES_128_CCM: u16 = 3;
+
+/// AES-CCM with a 256-bit key ([RFC 5116])
+///
+/// [RFC 5116]: https://tools.ietf.org/html/rfc5116
+pub const AEAD_AES_256_CCM: u16 = 4;
+
+/// AES-GCM with a 128-bit key and 64-bit tag ([RFC 5282])
+///
+/// [RFC 5282]: https://tools.ietf.org/html/rfc5282
+pub const AEAD_AES_128_GCM_8: u16 = 5;
+
+/// AES-GCM with a 256-bit key and 64-bit tag ([RFC 5282])
+///
+/// [RFC 5282]: https://tools.ietf.org/html/rfc5282
+pub const AEAD_AES_256_GCM_8: u16 = 6;
+
+/// AES-GCM with a 128-bit key and 96-bit tag ([RFC 5282])
+///
+/// [RFC 5282]: https://tools.ietf.org/html/rfc5282
+pub const AEAD_AES_128_GCM_12: u16 = 7;
+
+/// AES-GCM with a 256-bit key and 96-bit tag ([RFC 5282])
+///
+/// [RFC 5282]: https://tools.ietf.org/html/rfc5282
+pub const AEAD_AES_256_GCM_12: u16 = 8;
+
+/// AES-CCM with a 128-bit key and 88-bit nonce ([RFC 5282])
+///
+/// [RFC 5282]: https://tools.ietf.org/html/rfc5282
+pub const AEAD_AES_128_CCM_SHORT: u16 = 9;
+
+/// AES-CCM with a 256-bit key and 88-bit nonce ([RFC 5282])
+///
+/// [RFC 5282]: https://tools.ietf.org/html/rfc5282
+pub const AEAD_AES_256_CCM_SHORT: u16 = 10;
+
+/// AES-CCM with a 128-bit key, 88-bit nonce and 64-bit tag ([RFC 5282])
+///
+/// [RFC 5282]: https://tools.ietf.org/html/rfc5282
+pub const AEAD_AES_128_CCM_SHORT_8: u16 = 11;
+
+/// AES-CCM with a 256-bit key, 88-bit nonce and 64-bit tag ([RFC 5282])
+///
+/// [RFC 5282]: https://tools.ietf.org/html/rfc5282
+pub const AEAD_AES_256_CCM_SHORT_8: u16 = 12;
+
+/// AES-CCM with a 128-bit key, 88-bit nonce and 96-bit tag ([RFC 5282])
+///
+/// [RFC 5282]: https://tools.ietf.org/html/rfc5282
+pub const AEAD_AES_128_CCM_SHORT_12: u16 = 13;
+
+/// AES-CCM with a 256-bit key, 88-bit nonce and 96-bit tag ([RFC 5282])
+///
+/// [RFC 5282]: https://tools.ietf.org/html/rfc5282
+pub const AEAD_AES_256_CCM_SHORT_12: u16 = 14;
+
+/// AES-SIV with AES-128 ([RFC 5297])
+///
+/// [RFC 5297]: https://tools.ietf.org/html/rfc5297
+pub const AEAD_AES_SIV_CMAC_256: u16 = 15;
+
+/// AES-SIV with AES-192 ([RFC 5297])
+///
+/// [RFC 5297]: https://tools.ietf.org/html/rfc5297
+pub const AEAD_AES_SIV_CMAC_384: u16 = 16;
+
+/// AES-SIV with AES-256 ([RFC 5297])
+///
+/// [RFC 5297]: https://tools.ietf.org/html/rfc5297
+pub const AEAD_AES_SIV_CMAC_512: u16 = 17;
+
+/// AES-CCM with a 128-bit key and 64-bit tag ([RFC 6655])
+///
+/// [RFC 6655]: https://tools.ietf.org/html/rfc6655
+pub const AEAD_AES_128_CCM_8: u16 = 18;
+
+/// AES-CCM with a 256-bit key and 64-bit tag ([RFC 6655])
+///
+/// [RFC 6655]: https://tools.ietf.org/html/rfc6655
+pub const AEAD_AES_256_CCM_8: u16 = 19;
+
+/// AES-OCB with a 128-bit key and 128-bit tag ([RFC 7253])
+///
+/// [RFC 7253]: https://tools.ietf.org/html/rfc7253
+pub const AEAD_AES_128_OCB_TAGLEN128: u16 = 20;
+
+/// AES-OCB with a 128-bit key and 96-bit tag ([RFC 7253])
+///
+/// [RFC 7253]: https://tools.ietf.org/html/rfc7253
+pub const AEAD_AES_128_OCB_TAGLEN96: u16 = 21;
+
+/// AES-OCB with a 128-bit key and 64-bit tag ([RFC 7253])
+///
+/// [RFC 7253]: https://tools.ietf.org/html/rfc7253
+pub const AEAD_AES_128_OCB_TAGLEN64: u16 = 22;
+
+/// AES-OCB with a 192-bit key and 128-bit tag ([RFC 7253])
+///
+/// [RFC 7253]: https://tools.ietf.org/html/rfc7253
+pub const AEAD_AES_192_OCB_TAGLEN128: u16 = 23;
+
+/// AES-OCB with a 192-bit key and 96-bit tag ([RFC 7253])
+///
+/// [RFC 7253]: https://tools.ietf.org/html/rfc7253
+pub const AEAD_AES_192_OCB_TAGLEN96: u16 = 24;
+
+/// AES-OCB with a 192-bit key and 64-bit tag ([RFC 7253])
+///
+/// [RFC 7253]: https://tools.ietf.org/html/rfc7253
+pub const AEAD_AES_192_OCB_TAGLEN64: u16 = 25;
+
+/// AES-OCB with a 256-bit key and 128-bit tag ([RFC 7253])
+///
+/// [RFC 7253]: https://tools.ietf.org/html/rfc7253
+pub const AEAD_AES_256_OCB_TAGLEN128: u16 = 26;
+
+/// AES-OCB with a 256-bit key and 96-bit tag ([RFC 7253])
+///
+/// [RFC 7253]: https://tools.ietf.org/html/rfc7253
+pub const AEAD_AES_256_OCB_TAGLEN96: u16 = 27;
+
+/// AES-OCB with a 256-bit key and 64-bit tag ([RFC 7253])
+///
+/// [RFC 7253]: https://tools.ietf.org/html/rfc7253
+pub const AEAD_AES_256_OCB_TAGLEN64: u16 = 28;
+
+/// ChaCha20Poly1305 ([RFC 8439])
+///
+/// [RFC 8439]: https://tools.ietf.org/html/rfc8439
+pub const AEAD_CHACHA20_POLY1305: u16 = 29;
+
+/// AES-GCM-SIV with a 128-bit key ([RFC 8452])
+///
+/// [RFC 8452]: https://tools.ietf.org/html/rfc8452
+pub const AEAD_AES_128_GCM_SIV: u16 = 30;
+
+/// AES-GCM-SIV with a 256-bit key ([RFC 8452])
+///
+/// [RFC 8452]: https://tools.ietf.org/html/rfc8452
+pub const AEAD_AES_256_GCM_SIV: u16 = 31;
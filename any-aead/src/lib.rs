@@ -0,0 +1,561 @@
+//! Runtime selection between the [Authenticated Encryption with Associated
+//! Data (AEAD)][1] ciphers of this workspace, e.g. when the algorithm is read
+//! from configuration or negotiated by a protocol.
+//!
+//! [`Algorithm`] identifies an AEAD by name, and gives its key, nonce and tag
+//! sizes. [`AnyAead`] holds an instance of any of them, constructed from an
+//! [`Algorithm`] and the bytes of a key, and forwards the usual encryption and
+//! decryption methods to it.
+//!
+//! Each algorithm family is gated under a Cargo feature named after its
+//! crate, all of which are enabled by default:
+//!
+//! - `aes-gcm`: AES-GCM with 128 and 256-bit keys, and 128, 96 or 64-bit tags
+//! - `aes-gcm-siv`: AES-GCM-SIV with 128 and 256-bit keys
+//! - `aes-siv`: AES-SIV with AES-128 and AES-256
+//! - `ccm`: AES-CCM with 128 and 256-bit keys, 96 or 88-bit nonces and 128, 96
+//!   or 64-bit tags
+//! - `chacha20poly1305`: [`Algorithm::ChaCha20Poly1305`] and
+//!   [`Algorithm::XChaCha20Poly1305`]
+//! - `ocb3`: AES-OCB3 with 128, 192 and 256-bit keys, and 128, 96 or 64-bit
+//!   tags
+//!
+//! The [`iana`] module maps the identifiers of the IANA "AEAD Algorithms"
+//! registry to [`Algorithm`]s, and the [`envelope`] module seals ciphertexts
+//! in envelopes which carry their algorithm, key ID and nonce.
+//!
+//! Since the key, nonce and tag sizes of [`AnyAead`] depend on the algorithm,
+//! it can't implement the [`AeadCore`] trait, which fixes
+//! them for each type. Its methods take them as slices instead, and return an
+//! [`Error`] if their lengths are wrong for the algorithm.
+//!
+//! # Usage
+//!
+//! ```
+//! # #[cfg(feature = "aes-gcm")]
+//! # {
+//! use any_aead::{Algorithm, AnyAead};
+//!
+//! let algorithm: Algorithm = "AES-256-GCM".parse().expect("unknown algorithm");
+//! assert_eq!(algorithm.key_size(), 32);
+//! assert_eq!(algorithm.nonce_size(), 12);
+//!
+//! let cipher = AnyAead::new(algorithm, b"an example very very secret key.")
+//!     .expect("invalid key length");
+//!
+//! let nonce = b"unique nonce"; // unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! # }
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AnyAead::encrypt_in_place`] and [`AnyAead::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! [1]: https://en.wikipedia.org/wiki/Authenticated_encryption
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+// With no algorithm enabled, `Algorithm` and `AnyAead` have no variants
+#![cfg_attr(
+    not(any(
+        feature = "aes-gcm",
+        feature = "aes-gcm-siv",
+        feature = "aes-siv",
+        feature = "ccm",
+        feature = "chacha20poly1305",
+        feature = "ocb3"
+    )),
+    allow(unreachable_code, unused_imports, unused_variables)
+)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod envelope;
+pub mod iana;
+
+pub use aead::{self, Error};
+
+use aead::{
+    consts::{U11, U12, U16, U8},
+    generic_array::{typenum::Unsigned, GenericArray},
+    AeadCore, AeadInPlace, Buffer, NewAead,
+};
+use aes::{Aes128, Aes192, Aes256};
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "alloc")]
+use aead::Payload;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+macro_rules! algorithms {
+    ($(
+        $(#[doc = $doc:expr])*
+        #[cfg($cfg:meta)]
+        $variant:ident($aead:ty) = $name:expr, $iana:expr,
+    )*) => {
+        /// AEAD algorithms supported by [`AnyAead`].
+        ///
+        /// More algorithms may be added, and which ones are available depends
+        /// on the Cargo features of this crate.
+        #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+        #[non_exhaustive]
+        pub enum Algorithm {
+            $(
+                $(#[doc = $doc])*
+                #[cfg($cfg)]
+                #[cfg_attr(docsrs, doc(cfg($cfg)))]
+                $variant,
+            )*
+        }
+
+        impl Algorithm {
+            /// Name of the algorithm, as accepted by [`str::parse`]
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(
+                        #[cfg($cfg)]
+                        Algorithm::$variant => $name,
+                    )*
+                }
+            }
+
+            /// Size of the keys of the algorithm in bytes
+            pub fn key_size(self) -> usize {
+                match self {
+                    $(
+                        #[cfg($cfg)]
+                        Algorithm::$variant => <$aead as NewAead>::KeySize::to_usize(),
+                    )*
+                }
+            }
+
+            /// Size of the nonces of the algorithm in bytes
+            pub fn nonce_size(self) -> usize {
+                match self {
+                    $(
+                        #[cfg($cfg)]
+                        Algorithm::$variant => <$aead as AeadCore>::NonceSize::to_usize(),
+                    )*
+                }
+            }
+
+            /// Size of the tags of the algorithm in bytes
+            pub fn tag_size(self) -> usize {
+                match self {
+                    $(
+                        #[cfg($cfg)]
+                        Algorithm::$variant => <$aead as AeadCore>::TagSize::to_usize(),
+                    )*
+                }
+            }
+
+            /// Identifier of the algorithm in the IANA "AEAD Algorithms"
+            /// registry, if it has one (see the [`iana`] module)
+            pub fn iana_id(self) -> Option<u16> {
+                match self {
+                    $(
+                        #[cfg($cfg)]
+                        Algorithm::$variant => $iana,
+                    )*
+                }
+            }
+
+            /// Get the algorithm with the given identifier in the IANA "AEAD
+            /// Algorithms" registry, if it is supported (see the [`iana`]
+            /// module)
+            pub fn from_iana_id(id: u16) -> Option<Self> {
+                $(
+                    #[cfg($cfg)]
+                    {
+                        if $iana == Some(id) {
+                            return Some(Algorithm::$variant);
+                        }
+                    }
+                )*
+
+                None
+            }
+
+            /// Get the algorithm with the given name, ignoring ASCII case
+            fn from_name(name: &str) -> Option<Self> {
+                $(
+                    #[cfg($cfg)]
+                    {
+                        if name.eq_ignore_ascii_case($name) {
+                            return Some(Algorithm::$variant);
+                        }
+                    }
+                )*
+
+                None
+            }
+        }
+
+        /// AEAD whose algorithm is selected at runtime.
+        ///
+        /// See the [toplevel documentation](index.html) for a usage example.
+        #[derive(Clone)]
+        #[non_exhaustive]
+        pub enum AnyAead {
+            $(
+                $(#[doc = $doc])*
+                #[cfg($cfg)]
+                #[cfg_attr(docsrs, doc(cfg($cfg)))]
+                $variant($aead),
+            )*
+        }
+
+        impl AnyAead {
+            /// Create a new instance of the given algorithm.
+            ///
+            /// Returns an error if the length of the key is not
+            /// [`Algorithm::key_size`].
+            pub fn new(algorithm: Algorithm, key: &[u8]) -> Result<Self, Error> {
+                if key.len() != algorithm.key_size() {
+                    return Err(Error);
+                }
+
+                Ok(match algorithm {
+                    $(
+                        #[cfg($cfg)]
+                        Algorithm::$variant => {
+                            AnyAead::$variant(<$aead>::new(GenericArray::from_slice(key)))
+                        }
+                    )*
+                })
+            }
+
+            /// Algorithm of this instance
+            pub fn algorithm(&self) -> Algorithm {
+                match *self {
+                    $(
+                        #[cfg($cfg)]
+                        AnyAead::$variant(_) => Algorithm::$variant,
+                    )*
+                }
+            }
+
+            /// Encrypt the given buffer containing a plaintext message
+            /// in-place, appending the tag (see
+            /// [`AeadInPlace::encrypt_in_place`]).
+            pub fn encrypt_in_place(
+                &self,
+                nonce: &[u8],
+                associated_data: &[u8],
+                buffer: &mut dyn Buffer,
+            ) -> Result<(), Error> {
+                self.check_nonce(nonce)?;
+
+                match *self {
+                    $(
+                        #[cfg($cfg)]
+                        AnyAead::$variant(ref aead) => aead.encrypt_in_place(
+                            GenericArray::from_slice(nonce),
+                            associated_data,
+                            buffer,
+                        ),
+                    )*
+                }
+            }
+
+            /// Encrypt the data in-place, writing the tag to `tag`, which
+            /// must be [`Algorithm::tag_size`] bytes long (see
+            /// [`AeadInPlace::encrypt_in_place_detached`]).
+            pub fn encrypt_in_place_detached(
+                &self,
+                nonce: &[u8],
+                associated_data: &[u8],
+                buffer: &mut [u8],
+                tag: &mut [u8],
+            ) -> Result<(), Error> {
+                self.check_nonce(nonce)?;
+                self.check_tag(tag)?;
+
+                match *self {
+                    $(
+                        #[cfg($cfg)]
+                        AnyAead::$variant(ref aead) => {
+                            let tag_out = aead.encrypt_in_place_detached(
+                                GenericArray::from_slice(nonce),
+                                associated_data,
+                                buffer,
+                            )?;
+                            tag.copy_from_slice(&tag_out);
+                        }
+                    )*
+                }
+
+                Ok(())
+            }
+
+            /// Decrypt the message in-place, returning an error in the event
+            /// the provided authentication tag does not match the given
+            /// ciphertext (see [`AeadInPlace::decrypt_in_place`]).
+            pub fn decrypt_in_place(
+                &self,
+                nonce: &[u8],
+                associated_data: &[u8],
+                buffer: &mut dyn Buffer,
+            ) -> Result<(), Error> {
+                self.check_nonce(nonce)?;
+
+                match *self {
+                    $(
+                        #[cfg($cfg)]
+                        AnyAead::$variant(ref aead) => aead.decrypt_in_place(
+                            GenericArray::from_slice(nonce),
+                            associated_data,
+                            buffer,
+                        ),
+                    )*
+                }
+            }
+
+            /// Decrypt the message in-place with a detached tag, which must
+            /// be [`Algorithm::tag_size`] bytes long (see
+            /// [`AeadInPlace::decrypt_in_place_detached`]).
+            pub fn decrypt_in_place_detached(
+                &self,
+                nonce: &[u8],
+                associated_data: &[u8],
+                buffer: &mut [u8],
+                tag: &[u8],
+            ) -> Result<(), Error> {
+                self.check_nonce(nonce)?;
+                self.check_tag(tag)?;
+
+                match *self {
+                    $(
+                        #[cfg($cfg)]
+                        AnyAead::$variant(ref aead) => aead.decrypt_in_place_detached(
+                            GenericArray::from_slice(nonce),
+                            associated_data,
+                            buffer,
+                            GenericArray::from_slice(tag),
+                        ),
+                    )*
+                }
+            }
+        }
+    };
+}
+
+algorithms! {
+    /// AES-GCM with a 128-bit key
+    #[cfg(feature = "aes-gcm")]
+    Aes128Gcm(aes_gcm::Aes128Gcm) = "AES-128-GCM", Some(iana::AEAD_AES_128_GCM),
+
+    /// AES-GCM with a 256-bit key
+    #[cfg(feature = "aes-gcm")]
+    Aes256Gcm(aes_gcm::Aes256Gcm) = "AES-256-GCM", Some(iana::AEAD_AES_256_GCM),
+
+    /// AES-GCM with a 128-bit key and 64-bit tag
+    #[cfg(feature = "aes-gcm")]
+    Aes128Gcm8(aes_gcm::Aes128Gcm8) = "AES-128-GCM-8", Some(iana::AEAD_AES_128_GCM_8),
+
+    /// AES-GCM with a 128-bit key and 96-bit tag
+    #[cfg(feature = "aes-gcm")]
+    Aes128Gcm12(aes_gcm::Aes128Gcm12) = "AES-128-GCM-12", Some(iana::AEAD_AES_128_GCM_12),
+
+    /// AES-GCM with a 256-bit key and 64-bit tag
+    #[cfg(feature = "aes-gcm")]
+    Aes256Gcm8(aes_gcm::Aes256Gcm8) = "AES-256-GCM-8", Some(iana::AEAD_AES_256_GCM_8),
+
+    /// AES-GCM with a 256-bit key and 96-bit tag
+    #[cfg(feature = "aes-gcm")]
+    Aes256Gcm12(aes_gcm::Aes256Gcm12) = "AES-256-GCM-12", Some(iana::AEAD_AES_256_GCM_12),
+
+    /// AES-CCM with a 128-bit key, 128-bit tag and 96-bit nonce
+    #[cfg(feature = "ccm")]
+    Aes128Ccm(ccm::Ccm<Aes128, U16, U12>) = "AES-128-CCM", Some(iana::AEAD_AES_128_CCM),
+
+    /// AES-CCM with a 128-bit key, 64-bit tag and 96-bit nonce
+    #[cfg(feature = "ccm")]
+    Aes128Ccm8(ccm::Ccm<Aes128, U8, U12>) = "AES-128-CCM-8", Some(iana::AEAD_AES_128_CCM_8),
+
+    /// AES-CCM with a 256-bit key, 128-bit tag and 96-bit nonce
+    #[cfg(feature = "ccm")]
+    Aes256Ccm(ccm::Ccm<Aes256, U16, U12>) = "AES-256-CCM", Some(iana::AEAD_AES_256_CCM),
+
+    /// AES-CCM with a 256-bit key, 64-bit tag and 96-bit nonce
+    #[cfg(feature = "ccm")]
+    Aes256Ccm8(ccm::Ccm<Aes256, U8, U12>) = "AES-256-CCM-8", Some(iana::AEAD_AES_256_CCM_8),
+
+    /// AES-CCM with a 128-bit key, 128-bit tag and 88-bit nonce
+    #[cfg(feature = "ccm")]
+    Aes128CcmShort(ccm::Ccm<Aes128, U16, U11>) = "AES-128-CCM-SHORT", Some(iana::AEAD_AES_128_CCM_SHORT),
+
+    /// AES-CCM with a 128-bit key, 64-bit tag and 88-bit nonce
+    #[cfg(feature = "ccm")]
+    Aes128CcmShort8(ccm::Ccm<Aes128, U8, U11>) = "AES-128-CCM-SHORT-8", Some(iana::AEAD_AES_128_CCM_SHORT_8),
+
+    /// AES-CCM with a 128-bit key, 96-bit tag and 88-bit nonce
+    #[cfg(feature = "ccm")]
+    Aes128CcmShort12(ccm::Ccm<Aes128, U12, U11>) = "AES-128-CCM-SHORT-12", Some(iana::AEAD_AES_128_CCM_SHORT_12),
+
+    /// AES-CCM with a 256-bit key, 128-bit tag and 88-bit nonce
+    #[cfg(feature = "ccm")]
+    Aes256CcmShort(ccm::Ccm<Aes256, U16, U11>) = "AES-256-CCM-SHORT", Some(iana::AEAD_AES_256_CCM_SHORT),
+
+    /// AES-CCM with a 256-bit key, 64-bit tag and 88-bit nonce
+    #[cfg(feature = "ccm")]
+    Aes256CcmShort8(ccm::Ccm<Aes256, U8, U11>) = "AES-256-CCM-SHORT-8", Some(iana::AEAD_AES_256_CCM_SHORT_8),
+
+    /// AES-CCM with a 256-bit key, 96-bit tag and 88-bit nonce
+    #[cfg(feature = "ccm")]
+    Aes256CcmShort12(ccm::Ccm<Aes256, U12, U11>) = "AES-256-CCM-SHORT-12", Some(iana::AEAD_AES_256_CCM_SHORT_12),
+
+    /// AES-SIV with AES-128 in CMAC and CTR mode, and 128-bit nonces
+    #[cfg(feature = "aes-siv")]
+    AesSivCmac256(aes_siv::Aes128SivAead) = "AES-SIV-CMAC-256", Some(iana::AEAD_AES_SIV_CMAC_256),
+
+    /// AES-SIV with AES-256 in CMAC and CTR mode, and 128-bit nonces
+    #[cfg(feature = "aes-siv")]
+    AesSivCmac512(aes_siv::Aes256SivAead) = "AES-SIV-CMAC-512", Some(iana::AEAD_AES_SIV_CMAC_512),
+
+    /// AES-OCB3 with a 128-bit key, 128-bit tag and 96-bit nonce
+    #[cfg(feature = "ocb3")]
+    Aes128Ocb3(ocb3::Ocb3<Aes128, U12, U16>) = "AES-128-OCB-TAGLEN128", Some(iana::AEAD_AES_128_OCB_TAGLEN128),
+
+    /// AES-OCB3 with a 128-bit key, 96-bit tag and 96-bit nonce
+    #[cfg(feature = "ocb3")]
+    Aes128Ocb3Tag96(ocb3::Ocb3<Aes128, U12, U12>) = "AES-128-OCB-TAGLEN96", Some(iana::AEAD_AES_128_OCB_TAGLEN96),
+
+    /// AES-OCB3 with a 128-bit key, 64-bit tag and 96-bit nonce
+    #[cfg(feature = "ocb3")]
+    Aes128Ocb3Tag64(ocb3::Ocb3<Aes128, U12, U8>) = "AES-128-OCB-TAGLEN64", Some(iana::AEAD_AES_128_OCB_TAGLEN64),
+
+    /// AES-OCB3 with a 192-bit key, 128-bit tag and 96-bit nonce
+    #[cfg(feature = "ocb3")]
+    Aes192Ocb3(ocb3::Ocb3<Aes192, U12, U16>) = "AES-192-OCB-TAGLEN128", Some(iana::AEAD_AES_192_OCB_TAGLEN128),
+
+    /// AES-OCB3 with a 192-bit key, 96-bit tag and 96-bit nonce
+    #[cfg(feature = "ocb3")]
+    Aes192Ocb3Tag96(ocb3::Ocb3<Aes192, U12, U12>) = "AES-192-OCB-TAGLEN96", Some(iana::AEAD_AES_192_OCB_TAGLEN96),
+
+    /// AES-OCB3 with a 192-bit key, 64-bit tag and 96-bit nonce
+    #[cfg(feature = "ocb3")]
+    Aes192Ocb3Tag64(ocb3::Ocb3<Aes192, U12, U8>) = "AES-192-OCB-TAGLEN64", Some(iana::AEAD_AES_192_OCB_TAGLEN64),
+
+    /// AES-OCB3 with a 256-bit key, 128-bit tag and 96-bit nonce
+    #[cfg(feature = "ocb3")]
+    Aes256Ocb3(ocb3::Ocb3<Aes256, U12, U16>) = "AES-256-OCB-TAGLEN128", Some(iana::AEAD_AES_256_OCB_TAGLEN128),
+
+    /// AES-OCB3 with a 256-bit key, 96-bit tag and 96-bit nonce
+    #[cfg(feature = "ocb3")]
+    Aes256Ocb3Tag96(ocb3::Ocb3<Aes256, U12, U12>) = "AES-256-OCB-TAGLEN96", Some(iana::AEAD_AES_256_OCB_TAGLEN96),
+
+    /// AES-OCB3 with a 256-bit key, 64-bit tag and 96-bit nonce
+    #[cfg(feature = "ocb3")]
+    Aes256Ocb3Tag64(ocb3::Ocb3<Aes256, U12, U8>) = "AES-256-OCB-TAGLEN64", Some(iana::AEAD_AES_256_OCB_TAGLEN64),
+
+    /// ChaCha20Poly1305 (RFC 8439)
+    #[cfg(feature = "chacha20poly1305")]
+    ChaCha20Poly1305(chacha20poly1305::ChaCha20Poly1305) = "ChaCha20-Poly1305", Some(iana::AEAD_CHACHA20_POLY1305),
+
+    /// XChaCha20Poly1305, with 192-bit nonces
+    #[cfg(feature = "chacha20poly1305")]
+    XChaCha20Poly1305(chacha20poly1305::XChaCha20Poly1305) = "XChaCha20-Poly1305", None,
+
+    /// AES-GCM-SIV with a 128-bit key
+    #[cfg(feature = "aes-gcm-siv")]
+    Aes128GcmSiv(aes_gcm_siv::Aes128GcmSiv) = "AES-128-GCM-SIV", Some(iana::AEAD_AES_128_GCM_SIV),
+
+    /// AES-GCM-SIV with a 256-bit key
+    #[cfg(feature = "aes-gcm-siv")]
+    Aes256GcmSiv(aes_gcm_siv::Aes256GcmSiv) = "AES-256-GCM-SIV", Some(iana::AEAD_AES_256_GCM_SIV),
+}
+
+impl fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Algorithm {
+    type Err = Error;
+
+    /// Parse the [name](Algorithm::name) of an algorithm, ignoring ASCII
+    /// case.
+    fn from_str(name: &str) -> Result<Self, Error> {
+        Self::from_name(name).ok_or(Error)
+    }
+}
+
+impl AnyAead {
+    /// Encrypt the given plaintext payload, and return the resulting
+    /// ciphertext followed by the tag (see [`aead::Aead::encrypt`]).
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn encrypt<'msg, 'aad>(
+        &self,
+        nonce: &[u8],
+        plaintext: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<Vec<u8>, Error> {
+        let payload = plaintext.into();
+        let mut buffer = Vec::with_capacity(payload.msg.len() + self.algorithm().tag_size());
+        buffer.extend_from_slice(payload.msg);
+        self.encrypt_in_place(nonce, payload.aad, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Decrypt the given ciphertext followed by its tag, and return the
+    /// resulting plaintext, or an error if it isn't authentic (see
+    /// [`aead::Aead::decrypt`]).
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn decrypt<'msg, 'aad>(
+        &self,
+        nonce: &[u8],
+        ciphertext: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<Vec<u8>, Error> {
+        let payload = ciphertext.into();
+        let mut buffer = Vec::from(payload.msg);
+        self.decrypt_in_place(nonce, payload.aad, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Check the length of a nonce for the algorithm
+    fn check_nonce(&self, nonce: &[u8]) -> Result<(), Error> {
+        if nonce.len() == self.algorithm().nonce_size() {
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+
+    /// Check the length of a detached tag for the algorithm
+    fn check_tag(&self, tag: &[u8]) -> Result<(), Error> {
+        if tag.len() == self.algorithm().tag_size() {
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+}
+
+impl fmt::Debug for AnyAead {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AnyAead").field(&self.algorithm()).finish()
+    }
+}
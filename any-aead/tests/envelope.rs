@@ -0,0 +1,198 @@
+//! Tests of the `envelope` module
+#![cfg(all(
+    feature = "alloc",
+    feature = "aes-gcm",
+    feature = "aes-gcm-siv",
+    feature = "chacha20poly1305"
+))]
+
+#[macro_use]
+extern crate hex_literal;
+
+use any_aead::aead::Payload;
+use any_aead::envelope::{self, Header};
+use any_aead::{Algorithm, AnyAead};
+
+const AAD: &[u8] = b"associated data";
+
+const PLAINTEXT: &[u8] = b"In the beginning the Universe was created.";
+
+/// Key bytes `0x00..` of the test vectors
+const KEY: [u8; 32] = hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+
+/// Test vectors
+#[derive(Debug)]
+pub struct TestVector {
+    pub algorithm: Algorithm,
+    pub key_id: &'static [u8],
+    pub nonce: &'static [u8],
+    pub envelope: &'static [u8],
+}
+
+/// Envelopes computed with Python's cryptography package
+const TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        algorithm: Algorithm::Aes256Gcm,
+        key_id: b"key-1",
+        nonce: b"unique nonce",
+        envelope: &hex!("010002056b65792d31756e69717565206e6f6e6365a02dd4fae31140aee091c8ab5f8276dda84f5cfb789d1fd135a3b36c0fd4a6f759fb76a6b0ce6b68bdddcbc0f72a14e0b9cb8fb3b6c4b94710f1"),
+    },
+    TestVector {
+        algorithm: Algorithm::ChaCha20Poly1305,
+        key_id: b"2021-06",
+        nonce: b"unique nonce",
+        envelope: &hex!("01001d07323032312d3036756e69717565206e6f6e6365fa87fbd0e961f627af5bc8b564cfb106f2f0575b3f4f08cc52a92d86ba3b9e06dfe66165ea0759ea1a1a374cd01e36f8fd4743130e564267951c"),
+    },
+    TestVector {
+        algorithm: Algorithm::Aes128GcmSiv,
+        key_id: b"",
+        nonce: &hex!("000102030405060708090a0b"),
+        envelope: &hex!("01001e00000102030405060708090a0b07e1d98bf575631380d00350f47320e84b087a5ed9033d0d8b1f059684b0ee715dd6fd96b15c5b52efc5cb47990b070a79e6dc0dd77c29766545"),
+    },
+];
+
+fn key(algorithm: Algorithm) -> AnyAead {
+    AnyAead::new(algorithm, &KEY[..algorithm.key_size()]).unwrap()
+}
+
+fn open(
+    envelope: &[u8],
+    aad: &[u8],
+    key_id: &[u8],
+    key: &AnyAead,
+) -> Result<Vec<u8>, any_aead::Error> {
+    let payload = Payload { msg: envelope, aad };
+    envelope::open(payload, |id| if id == key_id { Some(key) } else { None })
+}
+
+#[test]
+fn seal() {
+    for vector in TEST_VECTORS {
+        let payload = Payload {
+            msg: PLAINTEXT,
+            aad: AAD,
+        };
+        let sealed =
+            envelope::seal(&key(vector.algorithm), vector.key_id, vector.nonce, payload).unwrap();
+        assert_eq!(vector.envelope, sealed.as_slice());
+    }
+}
+
+#[test]
+fn open_vectors() {
+    for vector in TEST_VECTORS {
+        let key = key(vector.algorithm);
+        let plaintext = open(vector.envelope, AAD, vector.key_id, &key).unwrap();
+        assert_eq!(PLAINTEXT, plaintext.as_slice());
+    }
+}
+
+#[test]
+fn parse() {
+    for vector in TEST_VECTORS {
+        let header = Header::parse(vector.envelope).unwrap();
+        assert_eq!(header.algorithm, vector.algorithm);
+        assert_eq!(header.key_id, vector.key_id);
+        assert_eq!(header.nonce, vector.nonce);
+        assert_eq!(
+            header.encoded_len(),
+            4 + vector.key_id.len() + vector.nonce.len()
+        );
+    }
+
+    // Truncated headers
+    let vector = &TEST_VECTORS[0];
+    for len in 0..Header::parse(vector.envelope).unwrap().encoded_len() {
+        assert!(Header::parse(&vector.envelope[..len]).is_err());
+    }
+
+    // Unknown version
+    let mut envelope = vector.envelope.to_vec();
+    envelope[0] = 0x02;
+    assert!(Header::parse(&envelope).is_err());
+
+    // Algorithm without an `Algorithm`: AES-SIV-CMAC-384, and an unassigned
+    // identifier
+    for &id in &[16u16, 0x7fff] {
+        envelope[0] = envelope::VERSION;
+        envelope[1..3].copy_from_slice(&id.to_be_bytes());
+        assert!(Header::parse(&envelope).is_err());
+    }
+}
+
+/// Every byte of the header is authenticated, as well as the ciphertext, tag
+/// and associated data
+#[test]
+fn open_modified() {
+    let vector = &TEST_VECTORS[0];
+    let key = key(vector.algorithm);
+    let mut envelope = vector.envelope.to_vec();
+
+    for i in 0..envelope.len() {
+        envelope[i] ^= 0x01;
+        assert!(open(&envelope, AAD, vector.key_id, &key).is_err());
+        envelope[i] ^= 0x01;
+    }
+
+    assert!(open(&envelope, b"", vector.key_id, &key).is_err());
+}
+
+#[test]
+fn open_truncated() {
+    let vector = &TEST_VECTORS[1];
+    let key = key(vector.algorithm);
+
+    for len in 0..vector.envelope.len() {
+        assert!(open(&vector.envelope[..len], AAD, vector.key_id, &key).is_err());
+    }
+}
+
+#[test]
+fn unknown_key() {
+    let vector = &TEST_VECTORS[0];
+    let key = key(vector.algorithm);
+    assert!(open(vector.envelope, AAD, b"key-2", &key).is_err());
+}
+
+/// Envelopes are only decrypted with the algorithm of their header, even if
+/// the key with their ID is of another algorithm with the same key size
+#[test]
+fn algorithm_mismatch() {
+    let vector = &TEST_VECTORS[0];
+    let key = key(Algorithm::ChaCha20Poly1305);
+    assert!(open(vector.envelope, AAD, vector.key_id, &key).is_err());
+}
+
+#[test]
+fn roundtrip() {
+    let key_id = [0x42; 255];
+    for &algorithm in &[
+        Algorithm::Aes128Gcm,
+        Algorithm::Aes256Gcm12,
+        Algorithm::Aes256GcmSiv,
+        Algorithm::ChaCha20Poly1305,
+    ] {
+        let key = key(algorithm);
+        let nonce = vec![0x24; algorithm.nonce_size()];
+        let sealed = envelope::seal(&key, &key_id, &nonce, PLAINTEXT).unwrap();
+        assert_eq!(
+            sealed.len(),
+            4 + key_id.len() + nonce.len() + PLAINTEXT.len() + algorithm.tag_size()
+        );
+        assert_eq!(open(&sealed, b"", &key_id, &key).unwrap(), PLAINTEXT);
+    }
+}
+
+#[test]
+fn seal_errors() {
+    // No IANA identifier
+    let xchacha = key(Algorithm::XChaCha20Poly1305);
+    assert!(envelope::seal(&xchacha, b"key-1", &[0; 24], PLAINTEXT).is_err());
+
+    // Key ID too long
+    let key = key(Algorithm::ChaCha20Poly1305);
+    assert!(envelope::seal(&key, &[0; 256], &[0; 12], PLAINTEXT).is_err());
+
+    // Wrong nonce length
+    assert!(envelope::seal(&key, b"key-1", &[0; 11], PLAINTEXT).is_err());
+}
@@ -0,0 +1,247 @@
+//! Tests of `AnyAead` against the AEAD crates it dispatches to
+
+use any_aead::aead::{
+    generic_array::{typenum::Unsigned, GenericArray},
+    Aead, AeadCore, AeadInPlace, NewAead, Payload,
+};
+use any_aead::{Algorithm, AnyAead};
+
+const AAD: &[u8] = b"associated data";
+
+const PLAINTEXT: &[u8] = b"In the beginning the Universe was created.";
+
+/// Check that `AnyAead` with the given algorithm behaves like `A`
+fn check<A: NewAead + Aead + AeadInPlace>(algorithm: Algorithm, name: &str) {
+    assert_eq!(algorithm.name(), name);
+    assert_eq!(name.parse::<Algorithm>().unwrap(), algorithm);
+    assert_eq!(
+        name.to_ascii_lowercase().parse::<Algorithm>().unwrap(),
+        algorithm
+    );
+    assert_eq!(algorithm.key_size(), A::KeySize::to_usize());
+    assert_eq!(algorithm.nonce_size(), A::NonceSize::to_usize());
+    assert_eq!(algorithm.tag_size(), <A as AeadCore>::TagSize::to_usize());
+
+    let key = (0..algorithm.key_size() as u8).collect::<Vec<_>>();
+    let nonce = (0..algorithm.nonce_size() as u8).collect::<Vec<_>>();
+    let cipher = AnyAead::new(algorithm, &key).unwrap();
+    assert_eq!(cipher.algorithm(), algorithm);
+
+    let payload = Payload {
+        msg: PLAINTEXT,
+        aad: AAD,
+    };
+    let expected = A::new(GenericArray::from_slice(&key))
+        .encrypt(GenericArray::from_slice(&nonce), payload)
+        .unwrap();
+
+    let payload = Payload {
+        msg: PLAINTEXT,
+        aad: AAD,
+    };
+    let ciphertext = cipher.encrypt(&nonce, payload).unwrap();
+    assert_eq!(ciphertext, expected);
+
+    let payload = Payload {
+        msg: &ciphertext,
+        aad: AAD,
+    };
+    assert_eq!(cipher.decrypt(&nonce, payload).unwrap(), PLAINTEXT);
+
+    let mut buffer = PLAINTEXT.to_vec();
+    let mut tag = vec![0; algorithm.tag_size()];
+    cipher
+        .encrypt_in_place_detached(&nonce, AAD, &mut buffer, &mut tag)
+        .unwrap();
+
+    // AES-SIV puts the tag before the ciphertext
+    let mut expected_buffer = PLAINTEXT.to_vec();
+    let expected_tag = A::new(GenericArray::from_slice(&key))
+        .encrypt_in_place_detached(GenericArray::from_slice(&nonce), AAD, &mut expected_buffer)
+        .unwrap();
+    assert_eq!(buffer, expected_buffer);
+    assert_eq!(tag, expected_tag.as_slice());
+
+    cipher
+        .decrypt_in_place_detached(&nonce, AAD, &mut buffer, &tag)
+        .unwrap();
+    assert_eq!(&buffer, PLAINTEXT);
+
+    // Modified ciphertext
+    let mut modified = ciphertext.clone();
+    modified[0] ^= 1;
+    let payload = Payload {
+        msg: &modified,
+        aad: AAD,
+    };
+    assert!(cipher.decrypt(&nonce, payload).is_err());
+
+    // Wrong lengths of key, nonce and tag
+    assert!(AnyAead::new(algorithm, &key[1..]).is_err());
+    assert!(AnyAead::new(algorithm, &[0; 65]).is_err());
+    assert!(cipher.encrypt(&nonce[1..], PLAINTEXT).is_err());
+    assert!(cipher.decrypt(&[0; 25], ciphertext.as_ref()).is_err());
+
+    let mut buffer = PLAINTEXT.to_vec();
+    let mut short_tag = vec![0; algorithm.tag_size() - 1];
+    assert!(cipher
+        .encrypt_in_place_detached(&nonce, AAD, &mut buffer, &mut short_tag)
+        .is_err());
+    assert!(cipher
+        .decrypt_in_place_detached(&nonce, AAD, &mut buffer, &[])
+        .is_err());
+}
+
+#[cfg(feature = "aes-gcm")]
+#[test]
+fn aes_gcm() {
+    check::<aes_gcm::Aes128Gcm>(Algorithm::Aes128Gcm, "AES-128-GCM");
+    check::<aes_gcm::Aes256Gcm>(Algorithm::Aes256Gcm, "AES-256-GCM");
+    check::<aes_gcm::Aes128Gcm8>(Algorithm::Aes128Gcm8, "AES-128-GCM-8");
+    check::<aes_gcm::Aes256Gcm8>(Algorithm::Aes256Gcm8, "AES-256-GCM-8");
+    check::<aes_gcm::Aes128Gcm12>(Algorithm::Aes128Gcm12, "AES-128-GCM-12");
+    check::<aes_gcm::Aes256Gcm12>(Algorithm::Aes256Gcm12, "AES-256-GCM-12");
+}
+
+#[cfg(feature = "aes-gcm-siv")]
+#[test]
+fn aes_gcm_siv() {
+    check::<aes_gcm_siv::Aes128GcmSiv>(Algorithm::Aes128GcmSiv, "AES-128-GCM-SIV");
+    check::<aes_gcm_siv::Aes256GcmSiv>(Algorithm::Aes256GcmSiv, "AES-256-GCM-SIV");
+}
+
+#[cfg(feature = "aes-siv")]
+#[test]
+fn aes_siv() {
+    check::<aes_siv::Aes128SivAead>(Algorithm::AesSivCmac256, "AES-SIV-CMAC-256");
+    check::<aes_siv::Aes256SivAead>(Algorithm::AesSivCmac512, "AES-SIV-CMAC-512");
+}
+
+#[cfg(feature = "ccm")]
+#[test]
+fn ccm() {
+    use aes::{Aes128, Aes256};
+    use ccm::{
+        consts::{U11, U12, U16, U8},
+        Ccm,
+    };
+
+    check::<Ccm<Aes128, U16, U12>>(Algorithm::Aes128Ccm, "AES-128-CCM");
+    check::<Ccm<Aes256, U16, U12>>(Algorithm::Aes256Ccm, "AES-256-CCM");
+    check::<Ccm<Aes128, U8, U12>>(Algorithm::Aes128Ccm8, "AES-128-CCM-8");
+    check::<Ccm<Aes256, U8, U12>>(Algorithm::Aes256Ccm8, "AES-256-CCM-8");
+    check::<Ccm<Aes128, U16, U11>>(Algorithm::Aes128CcmShort, "AES-128-CCM-SHORT");
+    check::<Ccm<Aes256, U16, U11>>(Algorithm::Aes256CcmShort, "AES-256-CCM-SHORT");
+    check::<Ccm<Aes128, U8, U11>>(Algorithm::Aes128CcmShort8, "AES-128-CCM-SHORT-8");
+    check::<Ccm<Aes256, U8, U11>>(Algorithm::Aes256CcmShort8, "AES-256-CCM-SHORT-8");
+    check::<Ccm<Aes128, U12, U11>>(Algorithm::Aes128CcmShort12, "AES-128-CCM-SHORT-12");
+    check::<Ccm<Aes256, U12, U11>>(Algorithm::Aes256CcmShort12, "AES-256-CCM-SHORT-12");
+}
+
+#[cfg(feature = "ocb3")]
+#[test]
+fn ocb3() {
+    use ocb3::{
+        aead::consts::{U12, U16, U8},
+        aes::{Aes128, Aes192, Aes256},
+        Ocb3,
+    };
+
+    check::<Ocb3<Aes128, U12, U16>>(Algorithm::Aes128Ocb3, "AES-128-OCB-TAGLEN128");
+    check::<Ocb3<Aes128, U12, U12>>(Algorithm::Aes128Ocb3Tag96, "AES-128-OCB-TAGLEN96");
+    check::<Ocb3<Aes128, U12, U8>>(Algorithm::Aes128Ocb3Tag64, "AES-128-OCB-TAGLEN64");
+    check::<Ocb3<Aes192, U12, U16>>(Algorithm::Aes192Ocb3, "AES-192-OCB-TAGLEN128");
+    check::<Ocb3<Aes192, U12, U12>>(Algorithm::Aes192Ocb3Tag96, "AES-192-OCB-TAGLEN96");
+    check::<Ocb3<Aes192, U12, U8>>(Algorithm::Aes192Ocb3Tag64, "AES-192-OCB-TAGLEN64");
+    check::<Ocb3<Aes256, U12, U16>>(Algorithm::Aes256Ocb3, "AES-256-OCB-TAGLEN128");
+    check::<Ocb3<Aes256, U12, U12>>(Algorithm::Aes256Ocb3Tag96, "AES-256-OCB-TAGLEN96");
+    check::<Ocb3<Aes256, U12, U8>>(Algorithm::Aes256Ocb3Tag64, "AES-256-OCB-TAGLEN64");
+}
+
+#[cfg(feature = "chacha20poly1305")]
+#[test]
+fn chacha20poly1305() {
+    check::<chacha20poly1305::ChaCha20Poly1305>(Algorithm::ChaCha20Poly1305, "ChaCha20-Poly1305");
+    check::<chacha20poly1305::XChaCha20Poly1305>(
+        Algorithm::XChaCha20Poly1305,
+        "XChaCha20-Poly1305",
+    );
+}
+
+/// Key, nonce and tag sizes of the IANA "AEAD Algorithms" registry, from the
+/// RFCs defining each algorithm. Nonce sizes are those of this crate where
+/// the registry allows a range.
+#[cfg(all(
+    feature = "aes-gcm",
+    feature = "aes-gcm-siv",
+    feature = "aes-siv",
+    feature = "ccm",
+    feature = "chacha20poly1305",
+    feature = "ocb3"
+))]
+#[test]
+fn iana() {
+    type Sizes = (usize, usize, usize);
+
+    let registry: &[(u16, Option<Sizes>)] = &[
+        (1, Some((16, 12, 16))),
+        (2, Some((32, 12, 16))),
+        (3, Some((16, 12, 16))),
+        (4, Some((32, 12, 16))),
+        (5, Some((16, 12, 8))),
+        (6, Some((32, 12, 8))),
+        (7, Some((16, 12, 12))),
+        (8, Some((32, 12, 12))),
+        (9, Some((16, 11, 16))),
+        (10, Some((32, 11, 16))),
+        (11, Some((16, 11, 8))),
+        (12, Some((32, 11, 8))),
+        (13, Some((16, 11, 12))),
+        (14, Some((32, 11, 12))),
+        (15, Some((32, 16, 16))),
+        (16, None),
+        (17, Some((64, 16, 16))),
+        (18, Some((16, 12, 8))),
+        (19, Some((32, 12, 8))),
+        (20, Some((16, 12, 16))),
+        (21, Some((16, 12, 12))),
+        (22, Some((16, 12, 8))),
+        (23, Some((24, 12, 16))),
+        (24, Some((24, 12, 12))),
+        (25, Some((24, 12, 8))),
+        (26, Some((32, 12, 16))),
+        (27, Some((32, 12, 12))),
+        (28, Some((32, 12, 8))),
+        (29, Some((32, 12, 16))),
+        (30, Some((16, 12, 16))),
+        (31, Some((32, 12, 16))),
+    ];
+
+    for &(id, sizes) in registry {
+        let algorithm = Algorithm::from_iana_id(id);
+        assert_eq!(
+            algorithm.map(|a| (a.key_size(), a.nonce_size(), a.tag_size())),
+            sizes,
+            "IANA identifier {}",
+            id
+        );
+
+        if let Some(algorithm) = algorithm {
+            assert_eq!(algorithm.iana_id(), Some(id));
+        }
+    }
+
+    assert_eq!(
+        Algorithm::from_iana_id(any_aead::iana::AEAD_CHACHA20_POLY1305),
+        Some(Algorithm::ChaCha20Poly1305)
+    );
+    assert_eq!(Algorithm::XChaCha20Poly1305.iana_id(), None);
+    assert_eq!(Algorithm::from_iana_id(0), None);
+    assert_eq!(Algorithm::from_iana_id(32), None);
+}
+
+#[test]
+fn unknown_name() {
+    assert!("AES-512-GCM".parse::<Algorithm>().is_err());
+    assert!("".parse::<Algorithm>().is_err());
+}
@@ -0,0 +1,198 @@
+//! Ascon-AEAD128 (NIST SP 800-232)
+
+use crate::{permutation::State, Key, Nonce, Tag};
+use aead::{
+    consts::{U0, U16},
+    AeadCore, AeadInPlace, Error, NewAead,
+};
+use core::convert::TryInto;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// Initialization vector encoding the algorithm identifier, the rate and the
+/// number of rounds of `p^a` and `p^b`
+const IV: u64 = 0x0000_1000_808c_0001;
+
+/// Number of bytes absorbed between two permutation calls
+const RATE: usize = 16;
+
+/// Ascon-AEAD128: 128-bit key, 128-bit nonce, 128-bit tag.
+///
+/// This is the parameterization standardized in NIST SP 800-232. Bytes are
+/// loaded into the state words in little-endian order, so its output differs
+/// from the Ascon-128 and Ascon-128a variants submitted to CAESAR.
+#[derive(Clone)]
+pub struct AsconAead128 {
+    /// Secret key as two 64-bit words
+    key: [u64; 2],
+}
+
+impl NewAead for AsconAead128 {
+    type KeySize = U16;
+
+    fn new(key: &Key) -> Self {
+        Self {
+            key: [load(&key[..8]), load(&key[8..])],
+        }
+    }
+}
+
+impl AeadCore for AsconAead128 {
+    type NonceSize = U16;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl AeadInPlace for AsconAead128 {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        let mut state = self.init(nonce);
+        absorb_associated_data(&mut state, associated_data);
+
+        let mut chunks = buffer.chunks_exact_mut(RATE);
+        for chunk in &mut chunks {
+            absorb(&mut state, chunk);
+            squeeze(&state, chunk);
+            state.permute_8();
+        }
+
+        let rem = chunks.into_remainder();
+        let mut block = pad(rem);
+        absorb(&mut state, &block);
+        squeeze(&state, &mut block);
+        rem.copy_from_slice(&block[..rem.len()]);
+        block.zeroize();
+
+        Ok(self.finalize(state))
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        let mut state = self.init(nonce);
+        absorb_associated_data(&mut state, associated_data);
+
+        let mut chunks = buffer.chunks_exact_mut(RATE);
+        for chunk in &mut chunks {
+            let mut keystream = [0u8; RATE];
+            squeeze(&state, &mut keystream);
+            xor_in_place(chunk, &keystream);
+            absorb(&mut state, chunk);
+            state.permute_8();
+        }
+
+        // Absorbing the padded plaintext replaces the leading state bytes by
+        // the ciphertext and adds the padding, as required
+        let rem = chunks.into_remainder();
+        let mut keystream = [0u8; RATE];
+        squeeze(&state, &mut keystream);
+        xor_in_place(rem, &keystream);
+        let mut block = pad(rem);
+        absorb(&mut state, &block);
+        block.zeroize();
+
+        let expected_tag = self.finalize(state);
+
+        if expected_tag.ct_eq(tag).unwrap_u8() == 1 {
+            Ok(())
+        } else {
+            // Don't release unauthenticated plaintext
+            buffer.iter_mut().for_each(|b| *b = 0);
+            Err(Error)
+        }
+    }
+}
+
+impl AsconAead128 {
+    /// Initialize the state with the key and nonce
+    fn init(&self, nonce: &Nonce) -> State {
+        let [k0, k1] = self.key;
+        let mut state = State::new([IV, k0, k1, load(&nonce[..8]), load(&nonce[8..])]);
+        state.permute_12();
+        state.x[3] ^= k0;
+        state.x[4] ^= k1;
+        state
+    }
+
+    /// Compute the authentication tag
+    fn finalize(&self, mut state: State) -> Tag {
+        let [k0, k1] = self.key;
+        state.x[2] ^= k0;
+        state.x[3] ^= k1;
+        state.permute_12();
+
+        let mut tag = Tag::default();
+        tag[..8].copy_from_slice(&(state.x[3] ^ k0).to_le_bytes());
+        tag[8..].copy_from_slice(&(state.x[4] ^ k1).to_le_bytes());
+        tag
+    }
+}
+
+impl Drop for AsconAead128 {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+/// Absorb the associated data followed by the domain separation bit.
+///
+/// Empty associated data is skipped entirely, including its padding block.
+fn absorb_associated_data(state: &mut State, associated_data: &[u8]) {
+    if !associated_data.is_empty() {
+        let mut chunks = associated_data.chunks_exact(RATE);
+        for chunk in &mut chunks {
+            absorb(state, chunk);
+            state.permute_8();
+        }
+
+        absorb(state, &pad(chunks.remainder()));
+        state.permute_8();
+    }
+
+    state.x[4] ^= 1 << 63;
+}
+
+/// XOR a full block into the rate words
+#[inline(always)]
+fn absorb(state: &mut State, block: &[u8]) {
+    state.x[0] ^= load(&block[..8]);
+    state.x[1] ^= load(&block[8..]);
+}
+
+/// Write the rate words to `block`
+#[inline(always)]
+fn squeeze(state: &State, block: &mut [u8]) {
+    block[..8].copy_from_slice(&state.x[0].to_le_bytes());
+    block[8..].copy_from_slice(&state.x[1].to_le_bytes());
+}
+
+/// Pad a partial block with a single `0x01` byte followed by zeroes
+#[inline(always)]
+fn pad(partial: &[u8]) -> [u8; RATE] {
+    let mut block = [0u8; RATE];
+    block[..partial.len()].copy_from_slice(partial);
+    block[partial.len()] = 0x01;
+    block
+}
+
+/// Load a little-endian 64-bit word
+#[inline(always)]
+fn load(bytes: &[u8]) -> u64 {
+    u64::from_le_bytes(bytes.try_into().unwrap())
+}
+
+/// XOR the prefix of `rhs` into `lhs`
+#[inline(always)]
+fn xor_in_place(lhs: &mut [u8], rhs: &[u8]) {
+    for (a, b) in lhs.iter_mut().zip(rhs) {
+        *a ^= b;
+    }
+}
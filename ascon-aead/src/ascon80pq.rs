@@ -0,0 +1,182 @@
+//! Ascon-80pq
+
+use crate::{permutation::State, Key, Nonce, Tag};
+use aead::{
+    consts::{U0, U16, U20},
+    AeadCore, AeadInPlace, Error, NewAead,
+};
+use core::convert::TryInto;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// Initialization vector encoding the key size, the rate and the number of
+/// rounds of `p^a` and `p^b`, followed by room for the first 32 key bits
+const IV: u64 = 0xa040_0c06_0000_0000;
+
+/// Number of bytes absorbed between two permutation calls
+const RATE: usize = 8;
+
+/// Ascon-80pq: 160-bit key, 128-bit nonce, 128-bit tag.
+///
+/// The larger key of this variant of Ascon-128 gives additional margin
+/// against key search with quantum computers. It follows the Ascon v1.2
+/// specification submitted to the NIST lightweight cryptography process:
+/// bytes are loaded into the state words in big-endian order and the state is
+/// updated every 64 bits with the 6-round permutation `p^6`.
+#[derive(Clone)]
+pub struct Ascon80pq {
+    /// Secret key: the leading 32 bits followed by two 64-bit words
+    key: [u64; 3],
+}
+
+impl NewAead for Ascon80pq {
+    type KeySize = U20;
+
+    fn new(key: &Key<U20>) -> Self {
+        let mut k0 = [0u8; 8];
+        k0[4..].copy_from_slice(&key[..4]);
+
+        Self {
+            key: [u64::from_be_bytes(k0), load(&key[4..12]), load(&key[12..])],
+        }
+    }
+}
+
+impl AeadCore for Ascon80pq {
+    type NonceSize = U16;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl AeadInPlace for Ascon80pq {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        let mut state = self.init(nonce);
+        absorb_associated_data(&mut state, associated_data);
+
+        let mut chunks = buffer.chunks_exact_mut(RATE);
+        for chunk in &mut chunks {
+            state.x[0] ^= load(chunk);
+            chunk.copy_from_slice(&state.x[0].to_be_bytes());
+            state.permute_6();
+        }
+
+        let rem = chunks.into_remainder();
+        state.x[0] ^= load_padded(rem);
+        rem.copy_from_slice(&state.x[0].to_be_bytes()[..rem.len()]);
+
+        Ok(self.finalize(state))
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        let mut state = self.init(nonce);
+        absorb_associated_data(&mut state, associated_data);
+
+        let mut chunks = buffer.chunks_exact_mut(RATE);
+        for chunk in &mut chunks {
+            let c = load(chunk);
+            chunk.copy_from_slice(&(state.x[0] ^ c).to_be_bytes());
+            state.x[0] = c;
+            state.permute_6();
+        }
+
+        // Absorbing the padded plaintext replaces the leading state bytes by
+        // the ciphertext and adds the padding, as required
+        let rem = chunks.into_remainder();
+        let keystream = state.x[0].to_be_bytes();
+        for (b, k) in rem.iter_mut().zip(keystream.iter()) {
+            *b ^= k;
+        }
+        state.x[0] ^= load_padded(rem);
+
+        let expected_tag = self.finalize(state);
+
+        if expected_tag.ct_eq(tag).unwrap_u8() == 1 {
+            Ok(())
+        } else {
+            // Don't release unauthenticated plaintext
+            buffer.iter_mut().for_each(|b| *b = 0);
+            Err(Error)
+        }
+    }
+}
+
+impl Ascon80pq {
+    /// Initialize the state with the key and nonce
+    fn init(&self, nonce: &Nonce) -> State {
+        let [k0, k1, k2] = self.key;
+        let mut state = State::new([IV | k0, k1, k2, load(&nonce[..8]), load(&nonce[8..])]);
+        state.permute_12();
+        state.x[2] ^= k0;
+        state.x[3] ^= k1;
+        state.x[4] ^= k2;
+        state
+    }
+
+    /// Compute the authentication tag
+    fn finalize(&self, mut state: State) -> Tag {
+        // The key is added right after the 64-bit rate, so it straddles the
+        // word boundaries
+        let [k0, k1, k2] = self.key;
+        state.x[1] ^= k0 << 32 | k1 >> 32;
+        state.x[2] ^= k1 << 32 | k2 >> 32;
+        state.x[3] ^= k2 << 32;
+        state.permute_12();
+
+        let mut tag = Tag::default();
+        tag[..8].copy_from_slice(&(state.x[3] ^ k1).to_be_bytes());
+        tag[8..].copy_from_slice(&(state.x[4] ^ k2).to_be_bytes());
+        tag
+    }
+}
+
+impl Drop for Ascon80pq {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+/// Absorb the associated data followed by the domain separation bit.
+///
+/// Empty associated data is skipped entirely, including its padding block.
+fn absorb_associated_data(state: &mut State, associated_data: &[u8]) {
+    if !associated_data.is_empty() {
+        let mut chunks = associated_data.chunks_exact(RATE);
+        for chunk in &mut chunks {
+            state.x[0] ^= load(chunk);
+            state.permute_6();
+        }
+
+        state.x[0] ^= load_padded(chunks.remainder());
+        state.permute_6();
+    }
+
+    state.x[4] ^= 1;
+}
+
+/// Load a partial block padded with a single `0x80` byte followed by zeroes
+#[inline(always)]
+fn load_padded(partial: &[u8]) -> u64 {
+    let mut block = [0u8; RATE];
+    block[..partial.len()].copy_from_slice(partial);
+    block[partial.len()] = 0x80;
+    let word = u64::from_be_bytes(block);
+    block.zeroize();
+    word
+}
+
+/// Load a big-endian 64-bit word
+#[inline(always)]
+fn load(bytes: &[u8]) -> u64 {
+    u64::from_be_bytes(bytes.try_into().unwrap())
+}
@@ -0,0 +1,118 @@
+//! [Ascon-AEAD128][1]: the lightweight [Authenticated Encryption with
+//! Associated Data (AEAD)][2] cipher standardized by NIST in SP 800-232.
+//!
+//! Ascon is built on a 320-bit permutation using only bitwise operations on
+//! 64-bit words, which makes it compact in hardware and fast in software on
+//! constrained devices.
+//!
+//! The following variants are provided, all sharing the same permutation:
+//!
+//! - [`AsconAead128`]: 128-bit key, as standardized in NIST SP 800-232.
+//! - [`Ascon80pq`]: 160-bit key, from the Ascon v1.2 submission, for users
+//!   who want a larger key for long-term protection of data.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! [`AsconAead128`] follows the final NIST parameterization, which is not
+//! compatible with the Ascon-128 and Ascon-128a variants from the CAESAR
+//! competition. [`Ascon80pq`] is not covered by SP 800-232.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! ```
+//! use ascon_aead::{AsconAead128, Key, Nonce};
+//! use ascon_aead::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = AsconAead128::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce 128"); // 128-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! Note that if you enable the `heapless` feature of this crate,
+//! you will receive an impl of [`aead::Buffer`] for `heapless::Vec`
+//! (re-exported from the [`aead`] crate as [`aead::heapless::Vec`]),
+//! which can then be passed as the `buffer` parameter to the in-place encrypt
+//! and decrypt methods:
+//!
+//! ```
+//! # #[cfg(feature = "heapless")]
+//! # {
+//! use ascon_aead::{AsconAead128, Key, Nonce};
+//! use ascon_aead::aead::{AeadInPlace, NewAead};
+//! use ascon_aead::aead::heapless::Vec;
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = AsconAead128::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce 128"); // 128-bits; unique per message
+//!
+//! let mut buffer: Vec<u8, 128> = Vec::new(); // Buffer needs 16-bytes overhead for the tag
+//! buffer.extend_from_slice(b"plaintext message");
+//!
+//! // Encrypt `buffer` in-place, replacing the plaintext contents with ciphertext
+//! cipher.encrypt_in_place(nonce, b"", &mut buffer).expect("encryption failure!");
+//!
+//! // `buffer` now contains the message ciphertext
+//! assert_ne!(&buffer, b"plaintext message");
+//!
+//! // Decrypt `buffer` in-place, replacing its ciphertext context with the original plaintext
+//! cipher.decrypt_in_place(nonce, b"", &mut buffer).expect("decryption failure!");
+//! assert_eq!(&buffer, b"plaintext message");
+//! # }
+//! ```
+//!
+//! [1]: https://doi.org/10.6028/NIST.SP.800-232
+//! [2]: https://en.wikipedia.org/wiki/Authenticated_encryption
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![deny(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use aead::{self, consts, AeadCore, AeadInPlace, Error, NewAead};
+
+mod aead128;
+mod ascon80pq;
+mod permutation;
+
+pub use aead128::AsconAead128;
+pub use ascon80pq::Ascon80pq;
+
+use aead::{consts::U16, generic_array::GenericArray};
+
+/// Ascon keys (128-bit, or 160-bit for [`Ascon80pq`])
+pub type Key<KeySize = U16> = GenericArray<u8, KeySize>;
+
+/// Ascon nonces (128-bit)
+pub type Nonce = GenericArray<u8, U16>;
+
+/// Ascon tags (128-bit)
+pub type Tag = GenericArray<u8, U16>;
@@ -0,0 +1,128 @@
+//! Ascon tests
+
+#[macro_use]
+extern crate hex_literal;
+
+use ascon_aead::aead::{generic_array::GenericArray, Aead, NewAead, Payload};
+use ascon_aead::{Ascon80pq, AsconAead128};
+
+// Known-answer tests from the NIST SP 800-232 reference implementation
+aead::new_test!(ascon_aead128, "ascon_aead128", AsconAead128);
+
+// Known-answer tests from the Ascon v1.2 reference implementation
+aead::new_test!(ascon80pq, "ascon80pq", Ascon80pq);
+
+/// Test vectors
+#[derive(Debug)]
+pub struct TestVector {
+    pub key: &'static [u8; 16],
+    pub nonce: &'static [u8; 16],
+    pub aad: &'static [u8],
+    pub plaintext: &'static [u8],
+    pub ciphertext: &'static [u8],
+    pub tag: &'static [u8; 16],
+}
+
+/// Ascon-AEAD128 test vectors, selected from the known-answer tests to cover
+/// empty, partial and multi-block inputs
+const TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("4427d64b8e1e1451fc445960f0839bb0"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!(""),
+        plaintext: &hex!("00"),
+        ciphertext: &hex!("e7"),
+        tag: &hex!("9f58f1f541fc51b5d438f8e1dd03f147"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!("00"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f"),
+        ciphertext: &hex!("25fbe48ac155c103927e59c60c88a56b"),
+        tag: &hex!("bcae1d932eee3d3463dc8caa44f3ef5b"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!("000102030405060708090a0b0c0d0e"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f10111213141516"),
+        ciphertext: &hex!("b03e607317a251b08b30f744b71965b0f1be7a356fff03"),
+        tag: &hex!("aee6806fa02008a5736199f6e8404238"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        ciphertext: &hex!("4c086d27a3b51a2333cfc7f22172a9bcad88b8d4d77e50622d788345fa7bee44"),
+        tag: &hex!("68915d3f9422289f2349d6a3b4160397"),
+    },
+];
+
+#[test]
+fn encrypt() {
+    for vector in TEST_VECTORS {
+        let key = GenericArray::from_slice(vector.key);
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let payload = Payload {
+            msg: vector.plaintext,
+            aad: vector.aad,
+        };
+
+        let cipher = AsconAead128::new(key);
+        let ciphertext = cipher.encrypt(nonce, payload).unwrap();
+        let (ct, tag) = ciphertext.split_at(vector.plaintext.len());
+        assert_eq!(vector.ciphertext, ct);
+        assert_eq!(vector.tag, tag);
+    }
+}
+
+#[test]
+fn decrypt() {
+    for vector in TEST_VECTORS {
+        let key = GenericArray::from_slice(vector.key);
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let mut ciphertext = Vec::from(vector.ciphertext);
+        ciphertext.extend_from_slice(vector.tag);
+
+        let payload = Payload {
+            msg: &ciphertext,
+            aad: vector.aad,
+        };
+
+        let cipher = AsconAead128::new(key);
+        let plaintext = cipher.decrypt(nonce, payload).unwrap();
+
+        assert_eq!(vector.plaintext, plaintext.as_slice());
+    }
+}
+
+#[test]
+fn decrypt_modified() {
+    let vector = &TEST_VECTORS[4];
+    let key = GenericArray::from_slice(vector.key);
+    let nonce = GenericArray::from_slice(vector.nonce);
+
+    let mut ciphertext = Vec::from(vector.ciphertext);
+    ciphertext.extend_from_slice(vector.tag);
+
+    // Tweak the first byte
+    ciphertext[0] ^= 0xaa;
+
+    let payload = Payload {
+        msg: &ciphertext,
+        aad: vector.aad,
+    };
+
+    let cipher = AsconAead128::new(key);
+    assert!(cipher.decrypt(nonce, payload).is_err());
+}
@@ -46,6 +46,9 @@
 pub use aead;
 pub use aead::consts;
 
+#[cfg(feature = "sm4")]
+pub use sm4;
+
 use aead::{
     consts::{U0, U16},
     generic_array::{typenum::Unsigned, ArrayLength, GenericArray},
@@ -55,8 +58,11 @@ use cipher::{Block, BlockCipher, BlockEncrypt, NewBlockCipher};
 use core::marker::PhantomData;
 use subtle::ConstantTimeEq;
 
+mod star;
 mod traits;
 
+pub use star::CcmStar;
+
 use traits::{NonceSize, TagSize};
 
 /// CCM nonces
@@ -65,14 +71,20 @@ pub type Nonce<NonceSize> = GenericArray<u8, NonceSize>;
 /// CCM tags
 pub type Tag<TagSize> = GenericArray<u8, TagSize>;
 
+/// SM4-CCM with a 128-bit tag and 96-bit nonce, as used by the TLS 1.3
+/// ShangMi cipher suites ([RFC 8998](https://tools.ietf.org/html/rfc8998))
+#[cfg(feature = "sm4")]
+pub type Sm4Ccm = Ccm<sm4::Sm4, U16, consts::U12>;
+
 /// CCM instance generic over an underlying block cipher.
 ///
 /// Type parameters:
 /// - `C`: block cipher.
 /// - `M`: size of MAC tag, valid values:
-/// `U4`, `U6`, `U8`, `U10`, `U12`, `U14`, `U16`.
+///   `U4`, `U6`, `U8`, `U10`, `U12`, `U14`, `U16`.
 /// - `N`: size of nonce, valid values:
-/// `U7`, `U8`, `U9`, `U10`, `U11`, `U12`, `U13`.
+///   `U7`, `U8`, `U9`, `U10`, `U11`, `U12`, `U13`.
+#[derive(Clone)]
 pub struct Ccm<C, M, N>
 where
     C: BlockCipher<BlockSize = U16> + BlockEncrypt,
@@ -99,98 +111,129 @@ where
             _nonce_size: Default::default(),
         }
     }
+}
+
+/// XOR data in `buf` of length equal or smaller than block size with
+/// a keystream block computed for given `nonce` and `i`.
+#[inline(always)]
+fn apply_ks_block<C, N>(cipher: &C, buf: &mut [u8], nonce: &Nonce<N>, i: usize)
+where
+    C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+    N: ArrayLength<u8> + NonceSize,
+{
+    let mut block = Block::<C>::default();
+    block[0] = N::get_l() - 1;
+    let n = 1 + N::to_usize();
+    block[1..n].copy_from_slice(nonce);
+    be_copy(&mut block[n..], i);
+    cipher.encrypt_block(&mut block);
+    xor(buf, &block);
+}
 
-    /// XOR data in `buf` of length equal or smaller than block size with
-    /// a keystream block computed for given `nonce` and `i`.
-    #[inline(always)]
-    fn apply_ks_block(&self, buf: &mut [u8], nonce: &Nonce<N>, i: usize) {
-        let mut block = Block::<C>::default();
-        block[0] = N::get_l() - 1;
-        let n = 1 + N::to_usize();
-        block[1..n].copy_from_slice(nonce);
-        be_copy(&mut block[n..], i);
-        self.cipher.encrypt_block(&mut block);
-        xor(buf, &block);
+/// Encrypt or decrypt `buffer` in counter mode, starting from counter 1.
+fn apply_ks<C, N>(cipher: &C, nonce: &Nonce<N>, buffer: &mut [u8])
+where
+    C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+    N: ArrayLength<u8> + NonceSize,
+{
+    let mut iter = buffer.chunks_exact_mut(C::BlockSize::to_usize());
+    let mut i = 1;
+    for chunk in &mut iter {
+        apply_ks_block(cipher, chunk, nonce, i);
+        i += 1;
     }
+    let rem = iter.into_remainder();
+    if !rem.is_empty() {
+        apply_ks_block(cipher, rem, nonce, i);
+    }
+}
 
-    fn calc_mac(
-        &self,
-        nonce: &Nonce<N>,
-        adata: &[u8],
-        buffer: &[u8],
-    ) -> Result<Tag<C::BlockSize>, Error> {
-        let bs = C::BlockSize::to_usize();
-        let is_ad = !adata.is_empty();
-        let l = N::get_l();
-        let flags = 64 * (is_ad as u8) + 8 * M::get_m_tick() + (l - 1);
-
-        if buffer.len() > N::get_max_len() {
-            return Err(Error);
-        }
+/// Compute the CBC-MAC of the message with the encoded tag size `m_tick`,
+/// and encrypt it with the keystream block of counter 0.
+fn calc_mac<C, N>(
+    cipher: &C,
+    m_tick: u8,
+    nonce: &Nonce<N>,
+    adata: &[u8],
+    buffer: &[u8],
+) -> Result<Tag<C::BlockSize>, Error>
+where
+    C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+    N: ArrayLength<u8> + NonceSize,
+{
+    let bs = C::BlockSize::to_usize();
+    let is_ad = !adata.is_empty();
+    let l = N::get_l();
+    let flags = 64 * (is_ad as u8) + 8 * m_tick + (l - 1);
+
+    if buffer.len() > N::get_max_len() {
+        return Err(Error);
+    }
 
-        let mut b0 = Block::<C>::default();
-        b0[0] = flags;
-        let n = 1 + N::to_usize();
-        b0[1..n].copy_from_slice(&nonce);
-        be_copy(&mut b0[n..], buffer.len());
-
-        let la = adata.len();
-        let mut b = Block::<C>::default();
-        let n = if la == 0 {
-            0
-        } else if la < (1 << 16) - (1 << 8) {
-            be_copy(&mut b[..2], adata.len());
-            2
-        } else if la <= core::u32::MAX as usize {
-            b[0] = 0xFF;
-            b[1] = 0xFE;
-            be_copy(&mut b[2..6], adata.len());
-            6
+    let mut b0 = Block::<C>::default();
+    b0[0] = flags;
+    let n = 1 + N::to_usize();
+    b0[1..n].copy_from_slice(nonce);
+    be_copy(&mut b0[n..], buffer.len());
+
+    let la = adata.len();
+    let mut b = Block::<C>::default();
+    let n = if la == 0 {
+        0
+    } else if la < (1 << 16) - (1 << 8) {
+        be_copy(&mut b[..2], adata.len());
+        2
+    } else if la <= core::u32::MAX as usize {
+        b[0] = 0xFF;
+        b[1] = 0xFE;
+        be_copy(&mut b[2..6], adata.len());
+        6
+    } else {
+        b[0] = 0xFF;
+        b[1] = 0xFF;
+        be_copy(&mut b[2..10], adata.len());
+        10
+    };
+
+    let mut mac = CbcMac::from_cipher(cipher);
+    mac.update(&b0);
+
+    if n != 0 {
+        if b.len() - n >= adata.len() {
+            b[n..n + adata.len()].copy_from_slice(adata);
+            mac.update(&b);
         } else {
-            b[0] = 0xFF;
-            b[1] = 0xFF;
-            be_copy(&mut b[2..10], adata.len());
-            10
-        };
-
-        let mut mac = CbcMac::from_cipher(&self.cipher);
-        mac.update(&b0);
-
-        if n != 0 {
-            if b.len() - n >= adata.len() {
-                b[n..n + adata.len()].copy_from_slice(adata);
-                mac.update(&b);
-            } else {
-                let (l, r) = adata.split_at(b.len() - n);
-                b[n..].copy_from_slice(l);
-                mac.update(&b);
-
-                let mut chunks = r.chunks_exact(bs);
-                for chunk in &mut chunks {
-                    mac.update(Block::<C>::from_slice(chunk));
-                }
-                let rem = chunks.remainder();
-                if !rem.is_empty() {
-                    let mut bn = Block::<C>::default();
-                    bn[..rem.len()].copy_from_slice(rem);
-                    mac.update(&bn)
-                }
-            }
-        }
+            let (l, r) = adata.split_at(b.len() - n);
+            b[n..].copy_from_slice(l);
+            mac.update(&b);
 
-        let mut chunks = buffer.chunks_exact(bs);
-        for chunk in &mut chunks {
-            mac.update(Block::<C>::from_slice(chunk));
-        }
-        let rem = chunks.remainder();
-        if !rem.is_empty() {
-            let mut bn = Block::<C>::default();
-            bn[..rem.len()].copy_from_slice(rem);
-            mac.update(&bn);
+            let mut chunks = r.chunks_exact(bs);
+            for chunk in &mut chunks {
+                mac.update(Block::<C>::from_slice(chunk));
+            }
+            let rem = chunks.remainder();
+            if !rem.is_empty() {
+                let mut bn = Block::<C>::default();
+                bn[..rem.len()].copy_from_slice(rem);
+                mac.update(&bn)
+            }
         }
+    }
 
-        Ok(mac.finalize())
+    let mut chunks = buffer.chunks_exact(bs);
+    for chunk in &mut chunks {
+        mac.update(Block::<C>::from_slice(chunk));
+    }
+    let rem = chunks.remainder();
+    if !rem.is_empty() {
+        let mut bn = Block::<C>::default();
+        bn[..rem.len()].copy_from_slice(rem);
+        mac.update(&bn);
     }
+
+    let mut full_tag = mac.finalize();
+    apply_ks_block(cipher, &mut full_tag, nonce, 0);
+    Ok(full_tag)
 }
 
 impl<C, M, N> NewAead for Ccm<C, M, N>
@@ -232,19 +275,8 @@ where
         adata: &[u8],
         buffer: &mut [u8],
     ) -> Result<Tag<Self::TagSize>, Error> {
-        let mut full_tag = self.calc_mac(nonce, adata, buffer)?;
-        self.apply_ks_block(&mut full_tag, nonce, 0);
-
-        let mut iter = buffer.chunks_exact_mut(C::BlockSize::to_usize());
-        let mut i = 1;
-        for chunk in &mut iter {
-            self.apply_ks_block(chunk, nonce, i);
-            i += 1;
-        }
-        let rem = iter.into_remainder();
-        if !rem.is_empty() {
-            self.apply_ks_block(rem, nonce, i);
-        }
+        let full_tag = calc_mac(&self.cipher, M::get_m_tick(), nonce, adata, buffer)?;
+        apply_ks(&self.cipher, nonce, buffer);
 
         let tag = Tag::<M>::clone_from_slice(&full_tag[..M::to_usize()]);
         Ok(tag)
@@ -257,19 +289,9 @@ where
         buffer: &mut [u8],
         tag: &Tag<Self::TagSize>,
     ) -> Result<(), Error> {
-        let mut iter = buffer.chunks_exact_mut(C::BlockSize::to_usize());
-        let mut i = 1;
-        for chunk in &mut iter {
-            self.apply_ks_block(chunk, nonce, i);
-            i += 1;
-        }
-        let rem = iter.into_remainder();
-        if !rem.is_empty() {
-            self.apply_ks_block(rem, nonce, i);
-        }
+        apply_ks(&self.cipher, nonce, buffer);
 
-        let mut full_tag = self.calc_mac(nonce, adata, buffer)?;
-        self.apply_ks_block(&mut full_tag, nonce, 0);
+        let full_tag = calc_mac(&self.cipher, M::get_m_tick(), nonce, adata, buffer)?;
         let n = tag.len();
         if full_tag[..n].ct_eq(tag).unwrap_u8() == 0 {
             buffer.iter_mut().for_each(|v| *v = 0);
@@ -0,0 +1,181 @@
+//! CCM* mode, as specified in IEEE 802.15.4
+
+use crate::{apply_ks, calc_mac, traits::NonceSize, Nonce};
+use aead::{
+    consts::{U13, U16},
+    generic_array::ArrayLength,
+    Error, Key,
+};
+use cipher::{Block, BlockCipher, BlockEncrypt, NewBlockCipher};
+use core::marker::PhantomData;
+use subtle::ConstantTimeEq;
+
+/// CCM* instance generic over an underlying block cipher, with the length of
+/// the MIC (tag) selected for each message.
+///
+/// CCM* extends CCM with encryption-only messages, which have an empty MIC.
+/// It's used by IEEE 802.15.4 and the protocols built on it (e.g. Zigbee and
+/// Thread), where the security level of each frame determines whether it is
+/// encrypted and the length of its MIC:
+///
+/// | Security level  | Encryption | MIC length |
+/// |-----------------|------------|------------|
+/// | 1 (MIC-32)      | No         | 4          |
+/// | 2 (MIC-64)      | No         | 8          |
+/// | 3 (MIC-128)     | No         | 16         |
+/// | 4 (ENC)         | Yes        | 0          |
+/// | 5 (ENC-MIC-32)  | Yes        | 4          |
+/// | 6 (ENC-MIC-64)  | Yes        | 8          |
+/// | 7 (ENC-MIC-128) | Yes        | 16         |
+///
+/// Frames which aren't encrypted are authenticated by passing their payload
+/// as associated data along with an empty buffer.
+///
+/// Since the MIC length isn't part of the type, this doesn't implement
+/// [`aead::AeadInPlace`], and the MIC is given as a slice whose length is 0,
+/// or any of the tag sizes supported by CCM: 4, 6, 8, 10, 12, 14 or 16 bytes.
+///
+/// The MIC length is authenticated for every length but 0. Messages without
+/// a MIC aren't authenticated at all, so the associated data is ignored and
+/// their ciphertext can be modified undetectably.
+///
+/// Type parameters:
+/// - `C`: block cipher.
+/// - `N`: size of nonce, valid values:
+///   `U7`, `U8`, `U9`, `U10`, `U11`, `U12`, `U13`. Defaults to the `U13`
+///   used by IEEE 802.15.4.
+///
+/// # Example
+///
+/// ```
+/// use ccm::{CcmStar, aead::{NewAead, generic_array::GenericArray}};
+/// use aes::Aes128;
+///
+/// let key = GenericArray::from_slice(b"very secret key.");
+/// let cipher = CcmStar::<Aes128>::new(key);
+///
+/// // Extended source address, frame counter and security level
+/// let nonce = GenericArray::from_slice(b"source\0\0\0\0\0\x05\x06");
+///
+/// // Security level 6 (ENC-MIC-64)
+/// let mut buffer = *b"frame payload";
+/// let mut mic = [0u8; 8];
+/// cipher.encrypt_in_place_detached(nonce, b"frame header", &mut buffer, &mut mic)
+///     .expect("encryption failure!");
+///
+/// cipher.decrypt_in_place_detached(nonce, b"frame header", &mut buffer, &mic)
+///     .expect("decryption failure!");
+/// assert_eq!(&buffer, b"frame payload");
+///
+/// // Security level 4 (ENC): no MIC
+/// cipher.encrypt_in_place_detached(nonce, b"", &mut buffer, &mut [])
+///     .expect("encryption failure!");
+/// assert_ne!(&buffer, b"frame payload");
+/// ```
+#[derive(Clone)]
+pub struct CcmStar<C, N = U13>
+where
+    C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+    C::ParBlocks: ArrayLength<Block<C>>,
+    N: ArrayLength<u8> + NonceSize,
+{
+    cipher: C,
+    _nonce_size: PhantomData<N>,
+}
+
+impl<C, N> CcmStar<C, N>
+where
+    C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+    C::ParBlocks: ArrayLength<Block<C>>,
+    N: ArrayLength<u8> + NonceSize,
+{
+    /// Encrypt the given `buffer` in-place, writing its MIC to `mic`.
+    ///
+    /// The length of `mic` selects the MIC length of the message, and 0 skips
+    /// authentication (see the [type-level documentation](CcmStar)).
+    pub fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<N>,
+        adata: &[u8],
+        buffer: &mut [u8],
+        mic: &mut [u8],
+    ) -> Result<(), Error> {
+        let m_tick = get_m_tick(mic.len())?;
+
+        if !mic.is_empty() {
+            let full_tag = calc_mac(&self.cipher, m_tick, nonce, adata, buffer)?;
+            mic.copy_from_slice(&full_tag[..mic.len()]);
+        } else if buffer.len() > N::get_max_len() {
+            return Err(Error);
+        }
+
+        apply_ks(&self.cipher, nonce, buffer);
+        Ok(())
+    }
+
+    /// Decrypt the given `buffer` in-place and verify its MIC.
+    ///
+    /// The length of `mic` must be the MIC length of the message. On failure,
+    /// the buffer is zeroed.
+    pub fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<N>,
+        adata: &[u8],
+        buffer: &mut [u8],
+        mic: &[u8],
+    ) -> Result<(), Error> {
+        let m_tick = get_m_tick(mic.len())?;
+
+        if buffer.len() > N::get_max_len() {
+            return Err(Error);
+        }
+
+        apply_ks(&self.cipher, nonce, buffer);
+
+        if !mic.is_empty() {
+            let full_tag = calc_mac(&self.cipher, m_tick, nonce, adata, buffer)?;
+            if full_tag[..mic.len()].ct_eq(mic).unwrap_u8() == 0 {
+                buffer.iter_mut().for_each(|v| *v = 0);
+                return Err(Error);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<C, N> From<C> for CcmStar<C, N>
+where
+    C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+    C::ParBlocks: ArrayLength<Block<C>>,
+    N: ArrayLength<u8> + NonceSize,
+{
+    fn from(cipher: C) -> Self {
+        Self {
+            cipher,
+            _nonce_size: PhantomData,
+        }
+    }
+}
+
+impl<C, N> aead::NewAead for CcmStar<C, N>
+where
+    C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+    C::ParBlocks: ArrayLength<Block<C>>,
+    N: ArrayLength<u8> + NonceSize,
+{
+    type KeySize = C::KeySize;
+
+    fn new(key: &Key<Self>) -> Self {
+        C::new(key).into()
+    }
+}
+
+/// Encode a MIC length `m` as `M' = (m - 2) / 2`, or 0 for an empty MIC
+fn get_m_tick(m: usize) -> Result<u8, Error> {
+    match m {
+        0 => Ok(0),
+        4 | 6 | 8 | 10 | 12 | 14 | 16 => Ok((m as u8 - 2) / 2),
+        _ => Err(Error),
+    }
+}
@@ -145,3 +145,156 @@ aead::new_test!(cavp_ccm_aes256_16_10, "cavp_ccm_aes256_16_10", Ccm<Aes256, U16,
 aead::new_test!(cavp_ccm_aes256_16_11, "cavp_ccm_aes256_16_11", Ccm<Aes256, U16, U11>);
 aead::new_test!(cavp_ccm_aes256_16_12, "cavp_ccm_aes256_16_12", Ccm<Aes256, U16, U12>);
 aead::new_test!(cavp_ccm_aes256_16_13, "cavp_ccm_aes256_16_13", Ccm<Aes256, U16, U13>);
+
+/// Example of SM4-CCM from RFC 8998, Appendix A.2
+#[cfg(feature = "sm4")]
+#[test]
+fn rfc8998_sm4_ccm() {
+    use ccm::Sm4Ccm;
+
+    let key = hex!("0123456789ABCDEFFEDCBA9876543210");
+    let nonce = hex!("00001234567800000000ABCD");
+    let pt = hex!(
+        "
+        AAAAAAAAAAAAAAAABBBBBBBBBBBBBBBBCCCCCCCCCCCCCCCCDDDDDDDDDDDDDDDD
+        EEEEEEEEEEEEEEEEFFFFFFFFFFFFFFFFEEEEEEEEEEEEEEEEAAAAAAAAAAAAAAAA
+    "
+    );
+    let aad = hex!("FEEDFACEDEADBEEFFEEDFACEDEADBEEFABADDAD2");
+    let ct = hex!(
+        "
+        48AF93501FA62ADBCD414CCE6034D895DDA1BF8F132F042098661572E7483094
+        FD12E518CE062C98ACEE28D95DF4416BED31A2F04476C18BB40C84A74B97DC5B
+        16842D4FA186F56AB33256971FA110F4
+    "
+    );
+
+    let c = Sm4Ccm::new(GenericArray::from_slice(&key));
+    let nonce = GenericArray::from_slice(&nonce);
+    let res = c
+        .encrypt(
+            nonce,
+            Payload {
+                aad: &aad,
+                msg: &pt,
+            },
+        )
+        .unwrap();
+    assert_eq!(res, ct.as_ref());
+    let res = c
+        .decrypt(
+            nonce,
+            Payload {
+                aad: &aad,
+                msg: &ct,
+            },
+        )
+        .unwrap();
+    assert_eq!(res, pt.as_ref());
+}
+
+/// CCM* examples from IEEE 802.15.4-2006, Annex C.2
+#[test]
+#[rustfmt::skip]
+fn ieee802154_ccm_star_examples() {
+    use ccm::CcmStar;
+
+    macro_rules! check {
+        (
+            nonce: $nonce:expr, adata: $adata:expr, pt: $pt:expr, ct: $ct:expr, mic: $mic:expr,
+        ) => {
+            let key = hex!("C0C1C2C3 C4C5C6C7 C8C9CACB CCCDCECF");
+            let c = CcmStar::<Aes128>::new(GenericArray::from_slice(&key));
+            let nonce = GenericArray::from_slice(&$nonce);
+
+            let mut buf = $pt;
+            let mut mic = [0u8; $mic.len()];
+            c.encrypt_in_place_detached(nonce, &$adata, &mut buf, &mut mic).unwrap();
+            assert_eq!(buf, $ct);
+            assert_eq!(mic, $mic);
+
+            c.decrypt_in_place_detached(nonce, &$adata, &mut buf, &mic).unwrap();
+            assert_eq!(buf, $pt);
+        };
+    }
+
+    // MAC beacon frame, security level 2 (MIC-64)
+    check!(
+        nonce: hex!("ACDE4800 00000001 00000005 02"),
+        adata: hex!("
+            08D08421 43010000 000048DE AC020500
+            000055CF 00005152 5354
+        "),
+        pt: [0u8; 0],
+        ct: [0u8; 0],
+        mic: hex!("223BC1EC 841AB553"),
+    );
+
+    // MAC data frame, security level 4 (ENC)
+    check!(
+        nonce: hex!("ACDE4800 00000001 00000005 04"),
+        adata: hex!("
+            69DC8421 43020000 000048DE AC010000
+            000048DE AC040500 0000
+        "),
+        pt: hex!("61626364"),
+        ct: hex!("D43E022B"),
+        mic: [0u8; 0],
+    );
+
+    // MAC command frame, security level 6 (ENC-MIC-64)
+    check!(
+        nonce: hex!("ACDE4800 00000001 00000005 06"),
+        adata: hex!("
+            2BDC8421 43020000 000048DE ACFFFF01
+            00000000 48DEAC06 05000000 01
+        "),
+        pt: hex!("CE"),
+        ct: hex!("D8"),
+        mic: hex!("4FDE5290 61F9C6F1"),
+    );
+}
+
+#[test]
+fn ccm_star_errors() {
+    use ccm::CcmStar;
+
+    let key = hex!("C0C1C2C3C4C5C6C7C8C9CACBCCCDCECF");
+    let nonce = hex!("ACDE4800000000010000000506");
+    let c = CcmStar::<Aes128>::new(GenericArray::from_slice(&key));
+    let nonce = GenericArray::from_slice(&nonce);
+
+    // Invalid MIC lengths
+    for &len in &[1, 2, 3, 5, 17] {
+        let mut buf = *b"payload";
+        let mut mic = [0u8; 17];
+        let res = c.encrypt_in_place_detached(nonce, b"header", &mut buf, &mut mic[..len]);
+        assert!(res.is_err());
+        let res = c.decrypt_in_place_detached(nonce, b"header", &mut buf, &mic[..len]);
+        assert!(res.is_err());
+    }
+
+    // Decryption of modified messages fails and zeroes the buffer
+    let mut buf = *b"payload";
+    let mut mic = [0u8; 4];
+    c.encrypt_in_place_detached(nonce, b"header", &mut buf, &mut mic)
+        .unwrap();
+
+    let mut bad_buf = buf;
+    bad_buf[0] ^= 1;
+    let res = c.decrypt_in_place_detached(nonce, b"header", &mut bad_buf, &mic);
+    assert!(res.is_err());
+    assert_eq!(bad_buf, [0u8; 7]);
+
+    let mut bad_buf = buf;
+    let res = c.decrypt_in_place_detached(nonce, b"Header", &mut bad_buf, &mic);
+    assert!(res.is_err());
+
+    // The MIC length is authenticated, so a truncated MIC doesn't verify
+    let mut buf = *b"payload";
+    let mut mic = [0u8; 8];
+    c.encrypt_in_place_detached(nonce, b"header", &mut buf, &mut mic)
+        .unwrap();
+    let res = c.decrypt_in_place_detached(nonce, b"header", &mut buf, &mic[..4]);
+    assert!(res.is_err());
+}
@@ -8,9 +8,10 @@ use poly1305::{
     universal_hash::{NewUniversalHash, UniversalHash},
     Poly1305,
 };
+use subtle::ConstantTimeEq;
 use zeroize::Zeroize;
 
-use super::Tag;
+use super::{ChaChaPolyTagSize, Tag};
 
 /// Size of a ChaCha20 block in bytes
 const BLOCK_SIZE: usize = 64;
@@ -36,8 +37,8 @@ where
     pub(crate) fn new(mut cipher: C) -> Self {
         // Derive Poly1305 key from the first 32-bytes of the ChaCha20 keystream
         let mut mac_key = poly1305::Key::default();
-        cipher.apply_keystream(&mut *mac_key);
-        let mac = Poly1305::new(GenericArray::from_slice(&*mac_key));
+        cipher.apply_keystream(&mut mac_key);
+        let mac = Poly1305::new(GenericArray::from_slice(&mac_key));
         mac_key.zeroize();
 
         // Set ChaCha20 counter to 1
@@ -69,12 +70,32 @@ where
 
     /// Decrypt the given message, first authenticating ciphertext integrity
     /// and returning an error if it's been tampered with.
-    pub(crate) fn decrypt_in_place_detached(
-        mut self,
+    ///
+    /// The `tag` may be truncated, in which case it is compared with the
+    /// same number of leading bytes of the Poly1305 tag.
+    pub(crate) fn decrypt_in_place_detached<TagSize: ChaChaPolyTagSize>(
+        self,
         associated_data: &[u8],
         buffer: &mut [u8],
-        tag: &Tag,
+        tag: &Tag<TagSize>,
     ) -> Result<(), Error> {
+        let mut cipher = self.verify_detached(associated_data, buffer, tag)?;
+
+        // TODO(tarcieri): interleave decryption with Poly1305
+        // See: <https://github.com/RustCrypto/AEADs/issues/74>
+        cipher.apply_keystream(buffer);
+        Ok(())
+    }
+
+    /// Authenticate the given ciphertext without decrypting it, returning an
+    /// error if it's been tampered with, and the stream cipher positioned at
+    /// the start of the message otherwise.
+    pub(crate) fn verify_detached<TagSize: ChaChaPolyTagSize>(
+        mut self,
+        associated_data: &[u8],
+        buffer: &[u8],
+        tag: &Tag<TagSize>,
+    ) -> Result<C, Error> {
         if buffer.len() / BLOCK_SIZE >= MAX_BLOCKS {
             return Err(Error);
         }
@@ -83,12 +104,11 @@ where
         self.mac.update_padded(buffer);
         self.authenticate_lengths(associated_data, buffer)?;
 
+        let expected_tag = self.mac.finalize().into_bytes();
+
         // This performs a constant-time comparison using the `subtle` crate
-        if self.mac.verify(tag).is_ok() {
-            // TODO(tarcieri): interleave decryption with Poly1305
-            // See: <https://github.com/RustCrypto/AEADs/issues/74>
-            self.cipher.apply_keystream(buffer);
-            Ok(())
+        if expected_tag[..TagSize::to_usize()].ct_eq(tag).unwrap_u8() == 1 {
+            Ok(self.cipher)
         } else {
             Err(Error)
         }
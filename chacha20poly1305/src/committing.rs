@@ -0,0 +1,248 @@
+//! Key-committing (X)ChaCha20Poly1305, using the "padding fix".
+//!
+//! See [`KeyCommitting`] documentation for usage.
+
+use crate::private::SealedCommitting;
+use aead::{
+    consts::{U16, U32},
+    generic_array::{typenum::Unsigned, GenericArray},
+    AeadCore, AeadInPlace, Buffer, Error, NewAead,
+};
+use subtle::ConstantTimeEq;
+
+#[cfg(feature = "alloc")]
+use aead::{Aead, AeadMut, Payload};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Length of the block of zeros prepended to the plaintext (256 bits)
+pub const COMMITMENT_SIZE: usize = 32;
+
+/// Key-committing wrapper around [`ChaCha20Poly1305`](crate::ChaCha20Poly1305)
+/// or `XChaCha20Poly1305`, implementing the "padding fix" of Albertini et al.,
+/// [*How to Abuse and Fix Authenticated Encryption Without Key Commitment*][1].
+///
+/// Poly1305 is not a commitment to the key: an attacker can craft a single
+/// ciphertext which decrypts successfully under several keys of their
+/// choosing (the "invisible salamanders" and partitioning oracle attacks),
+/// which matters when keys are derived from passwords or chosen by
+/// untrusted parties.
+///
+/// The padding fix prepends [`COMMITMENT_SIZE`] zero bytes to the plaintext
+/// before encrypting it, and checks on decryption that they decrypt to zeros
+/// again. The ciphertext of this block is the start of the keystream of the
+/// key and nonce, so finding two keys which make the same ciphertext pass
+/// is as hard as finding a collision on 256 bits of ChaCha20 output.
+///
+/// This is done automatically by [`KeyCommitting::encrypt_in_place`] and
+/// [`KeyCommitting::decrypt_in_place`] (and by the [`Aead`](aead::Aead) and
+/// [`AeadMut`](aead::AeadMut) methods), which makes ciphertexts
+/// [`COMMITMENT_SIZE`] bytes longer. The ciphertexts are those of the wrapped
+/// AEAD for `0^256 || plaintext`, so they can be decrypted by any
+/// implementation of it which checks the prefix by hand.
+///
+/// [`AeadInPlace`] is not implemented: its detached methods encrypt and
+/// decrypt a buffer of a fixed length, which has no room for the block of
+/// zeros, and Poly1305 must authenticate that block along with the message.
+///
+/// With the `committing-aead` feature, it implements
+/// `committing_aead::CommittingAead`, to compute and check the commitment of
+/// a nonce separately from decryption.
+///
+/// # Usage
+///
+/// ```
+/// use chacha20poly1305::{CommittingChaCha20Poly1305, Key, Nonce};
+/// use chacha20poly1305::aead::{Aead, NewAead};
+///
+/// let key = Key::from_slice(b"an example very very secret key."); // 32-bytes
+/// let cipher = CommittingChaCha20Poly1305::new(key);
+///
+/// let nonce = Nonce::from_slice(b"unique nonce"); // 12-bytes; unique per message
+///
+/// let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+///     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+///
+/// // 32 bytes of commitment, then the message, then the 16-byte tag
+/// assert_eq!(ciphertext.len(), 32 + 17 + 16);
+///
+/// let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+///     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+///
+/// assert_eq!(&plaintext, b"plaintext message");
+/// ```
+///
+/// [1]: https://www.usenix.org/conference/usenixsecurity22/presentation/albertini
+#[derive(Clone)]
+pub struct KeyCommitting<A>
+where
+    A: AeadInPlace<TagSize = U16> + SealedCommitting,
+{
+    /// Wrapped AEAD
+    aead: A,
+}
+
+impl<A> NewAead for KeyCommitting<A>
+where
+    A: NewAead + AeadInPlace<TagSize = U16> + SealedCommitting,
+{
+    type KeySize = A::KeySize;
+
+    fn new(key: &GenericArray<u8, A::KeySize>) -> Self {
+        Self { aead: A::new(key) }
+    }
+}
+
+impl<A> AeadCore for KeyCommitting<A>
+where
+    A: AeadInPlace<TagSize = U16> + SealedCommitting,
+{
+    type NonceSize = A::NonceSize;
+    type TagSize = U16;
+    type CiphertextOverhead = U32;
+}
+
+impl<A> KeyCommitting<A>
+where
+    A: AeadInPlace<TagSize = U16> + SealedCommitting,
+{
+    /// Encrypt the given buffer containing a plaintext message in-place,
+    /// prepending the encrypted block of zeros and appending the tag.
+    pub fn encrypt_in_place(
+        &self,
+        nonce: &GenericArray<u8, A::NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        let msg_len = buffer.len();
+        buffer.extend_from_slice(&[0u8; COMMITMENT_SIZE])?;
+
+        // 0^256 || plaintext
+        let padded = buffer.as_mut();
+        padded.copy_within(..msg_len, COMMITMENT_SIZE);
+        padded[..COMMITMENT_SIZE].iter_mut().for_each(|b| *b = 0);
+
+        self.aead.encrypt_in_place(nonce, associated_data, buffer)
+    }
+
+    /// Decrypt the given buffer containing a ciphertext in-place, returning
+    /// an error if its tag is invalid or its first block doesn't decrypt to
+    /// zeros.
+    ///
+    /// On success, the buffer only contains the plaintext.
+    pub fn decrypt_in_place(
+        &self,
+        nonce: &GenericArray<u8, A::NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        if buffer.len() < COMMITMENT_SIZE + A::TagSize::USIZE {
+            return Err(Error);
+        }
+
+        self.aead.decrypt_in_place(nonce, associated_data, buffer)?;
+
+        let padded = buffer.as_mut();
+        if padded[..COMMITMENT_SIZE]
+            .ct_eq(&[0u8; COMMITMENT_SIZE])
+            .unwrap_u8()
+            == 0
+        {
+            // Don't release plaintext decrypted with the wrong key
+            padded.iter_mut().for_each(|b| *b = 0);
+            return Err(Error);
+        }
+
+        let msg_len = padded.len() - COMMITMENT_SIZE;
+        padded.copy_within(COMMITMENT_SIZE.., 0);
+        buffer.truncate(msg_len);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<A> Aead for KeyCommitting<A>
+where
+    A: AeadInPlace<TagSize = U16> + SealedCommitting,
+{
+    fn encrypt<'msg, 'aad>(
+        &self,
+        nonce: &GenericArray<u8, A::NonceSize>,
+        plaintext: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<Vec<u8>, Error> {
+        let payload = plaintext.into();
+        let mut buffer =
+            Vec::with_capacity(COMMITMENT_SIZE + payload.msg.len() + A::TagSize::USIZE);
+        buffer.extend_from_slice(payload.msg);
+        self.encrypt_in_place(nonce, payload.aad, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    fn decrypt<'msg, 'aad>(
+        &self,
+        nonce: &GenericArray<u8, A::NonceSize>,
+        ciphertext: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<Vec<u8>, Error> {
+        let payload = ciphertext.into();
+        let mut buffer = Vec::from(payload.msg);
+        self.decrypt_in_place(nonce, payload.aad, &mut buffer)?;
+        Ok(buffer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<A> AeadMut for KeyCommitting<A>
+where
+    A: AeadInPlace<TagSize = U16> + SealedCommitting,
+{
+    fn encrypt<'msg, 'aad>(
+        &mut self,
+        nonce: &GenericArray<u8, A::NonceSize>,
+        plaintext: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<Vec<u8>, Error> {
+        Aead::encrypt(self, nonce, plaintext)
+    }
+
+    fn decrypt<'msg, 'aad>(
+        &mut self,
+        nonce: &GenericArray<u8, A::NonceSize>,
+        ciphertext: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<Vec<u8>, Error> {
+        Aead::decrypt(self, nonce, ciphertext)
+    }
+}
+
+/// The commitment is the first [`COMMITMENT_SIZE`] bytes of the ciphertext,
+/// i.e. the encryption of the zero block under the key and nonce. It doesn't
+/// depend on the associated data.
+#[cfg(feature = "committing-aead")]
+#[cfg_attr(docsrs, doc(cfg(feature = "committing-aead")))]
+impl<A> committing_aead::CommittingAead for KeyCommitting<A>
+where
+    A: AeadInPlace<TagSize = U16> + SealedCommitting,
+{
+    type CommitmentSize = U32;
+
+    fn commitment(
+        &self,
+        nonce: &GenericArray<u8, A::NonceSize>,
+        _associated_data: &[u8],
+    ) -> GenericArray<u8, U32> {
+        let mut commitment = GenericArray::default();
+        self.aead
+            .encrypt_in_place_detached(nonce, b"", &mut commitment)
+            .expect("the zero block is never too long");
+        commitment
+    }
+
+    fn ciphertext_commitment(ciphertext: &[u8]) -> Result<&GenericArray<u8, U32>, Error> {
+        if ciphertext.len() < COMMITMENT_SIZE + A::TagSize::USIZE {
+            return Err(Error);
+        }
+
+        Ok(GenericArray::from_slice(&ciphertext[..COMMITMENT_SIZE]))
+    }
+}
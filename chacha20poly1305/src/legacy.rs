@@ -0,0 +1,183 @@
+//! ChaCha20Poly1305Legacy is the original construction of ChaCha20Poly1305,
+//! with a 64-bit nonce.
+//!
+//! See [`ChaCha20Poly1305Legacy`] documentation for usage.
+
+use crate::{Key, Tag};
+use ::cipher::{NewCipher, StreamCipher, StreamCipherSeek};
+use aead::{
+    consts::{U0, U16, U32, U8},
+    generic_array::GenericArray,
+    AeadCore, AeadInPlace, Error, NewAead,
+};
+use chacha20::ChaCha20Legacy;
+use poly1305::{
+    universal_hash::{NewUniversalHash, UniversalHash},
+    Poly1305,
+};
+use zeroize::Zeroize;
+
+/// Nonce type for [`ChaCha20Poly1305Legacy`] (64-bits/8-bytes).
+pub type LegacyNonce = GenericArray<u8, U8>;
+
+/// Size of a Poly1305 block in bytes
+const POLY1305_BLOCK_SIZE: usize = 16;
+
+/// ChaCha20Poly1305 as originally specified in
+/// [draft-agl-tls-chacha20poly1305][1], with a 64-bit (8-byte) nonce.
+///
+/// The `legacy` Cargo feature must be enabled in order to use this.
+///
+/// This is the construction used by TLS stacks which implemented ChaCha20Poly1305
+/// cipher suites before [RFC 7905], and which libsodium exposes as
+/// `crypto_aead_chacha20poly1305`. It differs from [RFC 8439][2]:
+///
+/// - the nonce is 64-bit and used with the original "djb" ChaCha20, so a key
+///   can encrypt up to 2<sup>64</sup> blocks per message;
+/// - Poly1305 authenticates `AAD || LE64(len(AAD)) || C || LE64(len(C))`,
+///   without padding either input to a multiple of 16 bytes.
+///
+/// New protocols should use [`ChaCha20Poly1305`](crate::ChaCha20Poly1305),
+/// or `XChaCha20Poly1305` if random nonces are needed: 64 bits are too short
+/// to pick nonces at random.
+///
+/// # Usage
+///
+/// ```
+/// use chacha20poly1305::{ChaCha20Poly1305Legacy, Key, LegacyNonce};
+/// use chacha20poly1305::aead::{Aead, NewAead};
+///
+/// let key = Key::from_slice(b"an example very very secret key."); // 32-bytes
+/// let aead = ChaCha20Poly1305Legacy::new(key);
+///
+/// let nonce = LegacyNonce::from_slice(b"unique!!"); // 8-bytes; unique per message
+/// let ciphertext = aead.encrypt(nonce, b"plaintext message".as_ref()).expect("encryption failure!");
+/// let plaintext = aead.decrypt(nonce, ciphertext.as_ref()).expect("decryption failure!");
+/// assert_eq!(&plaintext, b"plaintext message");
+/// ```
+///
+/// [1]: https://tools.ietf.org/html/draft-agl-tls-chacha20poly1305-04
+/// [2]: https://tools.ietf.org/html/rfc8439
+/// [RFC 7905]: https://tools.ietf.org/html/rfc7905
+#[derive(Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "legacy")))]
+pub struct ChaCha20Poly1305Legacy {
+    /// Secret key
+    key: Key,
+}
+
+impl NewAead for ChaCha20Poly1305Legacy {
+    type KeySize = U32;
+
+    fn new(key: &Key) -> Self {
+        ChaCha20Poly1305Legacy { key: *key }
+    }
+}
+
+impl AeadCore for ChaCha20Poly1305Legacy {
+    type NonceSize = U8;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl AeadInPlace for ChaCha20Poly1305Legacy {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &LegacyNonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        let (mut cipher, mac) = self.init(nonce);
+        cipher.apply_keystream(buffer);
+        Ok(mac.compute_tag(associated_data, buffer).into_bytes())
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &LegacyNonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        let (mut cipher, mac) = self.init(nonce);
+
+        // This performs a constant-time comparison using the `subtle` crate
+        if mac.compute_tag(associated_data, buffer) == poly1305::Tag::new(*tag) {
+            cipher.apply_keystream(buffer);
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+}
+
+impl ChaCha20Poly1305Legacy {
+    /// Instantiate ChaCha20 at block 1, along with Poly1305 keyed with the
+    /// first 32 bytes of its keystream.
+    fn init(&self, nonce: &LegacyNonce) -> (ChaCha20Legacy, UnpaddedMac) {
+        let mut cipher = ChaCha20Legacy::new(&self.key, nonce);
+
+        let mut mac_key = poly1305::Key::default();
+        cipher.apply_keystream(&mut mac_key);
+        let mac = UnpaddedMac::new(&mac_key);
+        mac_key.zeroize();
+
+        // Set ChaCha20 counter to 1
+        cipher.seek(64u64);
+
+        (cipher, mac)
+    }
+}
+
+impl Drop for ChaCha20Poly1305Legacy {
+    fn drop(&mut self) {
+        self.key.as_mut_slice().zeroize();
+    }
+}
+
+/// Poly1305 over the concatenation of several inputs, without padding them
+struct UnpaddedMac {
+    mac: Poly1305,
+
+    /// Input which doesn't fill a Poly1305 block yet
+    buffer: poly1305::Block,
+
+    /// Number of bytes in `buffer`
+    pos: usize,
+}
+
+impl UnpaddedMac {
+    /// Initialize Poly1305 with the given key
+    fn new(key: &poly1305::Key) -> Self {
+        Self {
+            mac: Poly1305::new(key),
+            buffer: Default::default(),
+            pos: 0,
+        }
+    }
+
+    /// Compute `Poly1305(AAD || LE64(len(AAD)) || C || LE64(len(C)))`
+    fn compute_tag(mut self, associated_data: &[u8], buffer: &[u8]) -> poly1305::Tag {
+        self.input(associated_data);
+        self.input(&(associated_data.len() as u64).to_le_bytes());
+        self.input(buffer);
+        self.input(&(buffer.len() as u64).to_le_bytes());
+
+        self.mac.compute_unpadded(&self.buffer[..self.pos])
+    }
+
+    /// Input data, which can end with a partial block
+    fn input(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let n = core::cmp::min(POLY1305_BLOCK_SIZE - self.pos, data.len());
+            self.buffer[self.pos..self.pos + n].copy_from_slice(&data[..n]);
+            self.pos += n;
+            data = &data[n..];
+
+            if self.pos == POLY1305_BLOCK_SIZE {
+                self.mac.update(&self.buffer);
+                self.pos = 0;
+            }
+        }
+    }
+}
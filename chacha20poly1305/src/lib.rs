@@ -11,6 +11,18 @@
 //!   (gated under the `reduced-round` Cargo feature). See the [Too Much Crypto][5]
 //!   paper for background and rationale on when these constructions could be used.
 //!   When in doubt, prefer `ChaCha20Poly1305`.
+//! - [`ChaCha20Poly1305Legacy`] - the original ChaCha20Poly1305 construction with a 64-bit
+//!   (8-byte) nonce, from before RFC 7539 (gated under the `legacy` Cargo feature).
+//! - [`OpenSshChaCha20Poly1305`] - the `chacha20-poly1305@openssh.com` variant used by
+//!   the SSH transport protocol (gated under the `openssh` Cargo feature).
+//! - [`ChaCha20Poly1305Tag64`] / [`ChaCha20Poly1305Tag96`] - ChaCha20Poly1305 with the
+//!   tag truncated to 64 or 96 bits, for links where every byte counts. See the
+//!   [`ChaChaPoly1305`] documentation for the caveats.
+//! - [`CommittingChaCha20Poly1305`] / [`CommittingXChaCha20Poly1305`] - ciphertexts which
+//!   commit to the key, using the zero-padding fix against "invisible salamanders" and
+//!   partitioning oracle attacks. See the [`KeyCommitting`] documentation.
+//! - [`secretstream`] - libsodium's `crypto_secretstream_xchacha20poly1305` streams of
+//!   messages, byte-compatible with libsodium (gated under the `secretstream` Cargo feature).
 //!
 //! ## Security Notes
 //!
@@ -103,21 +115,47 @@
 )]
 #![warn(missing_docs, rust_2018_idioms)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 mod cipher;
+mod committing;
+
+#[cfg(feature = "legacy")]
+mod legacy;
+
+#[cfg(feature = "oid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
+pub mod oid;
+
+#[cfg(feature = "openssh")]
+mod openssh;
+
+#[cfg(feature = "secretstream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "secretstream")))]
+pub mod secretstream;
 
 #[cfg(feature = "xchacha20poly1305")]
 mod xchacha20poly1305;
 
 pub use aead;
 
+pub use committing::{KeyCommitting, COMMITMENT_SIZE};
+
+#[cfg(feature = "legacy")]
+pub use legacy::{ChaCha20Poly1305Legacy, LegacyNonce};
+
+#[cfg(feature = "openssh")]
+pub use openssh::{OpenSshChaCha20Poly1305, OpenSshKey, OpenSshNonce};
+
 #[cfg(feature = "xchacha20poly1305")]
 pub use xchacha20poly1305::{XChaCha20Poly1305, XNonce};
 
 use self::cipher::Cipher;
 use ::cipher::{NewCipher, StreamCipher, StreamCipherSeek};
 use aead::{
-    consts::{U0, U12, U16, U32},
-    generic_array::GenericArray,
+    consts::{U0, U12, U16, U32, U8},
+    generic_array::{ArrayLength, GenericArray},
     AeadCore, AeadInPlace, Error, NewAead,
 };
 use core::marker::PhantomData;
@@ -145,13 +183,67 @@ pub type Nonce = GenericArray<u8, U12>;
 /// Poly1305 tag.
 ///
 /// Implemented as an alias for [`GenericArray`].
-pub type Tag = GenericArray<u8, U16>;
+pub type Tag<TagSize = U16> = GenericArray<u8, TagSize>;
+
+mod private {
+    // Sealed traits stop other crates from implementing any traits that use it.
+    pub trait SealedTag {}
+
+    impl SealedTag for super::U8 {}
+    impl SealedTag for super::U12 {}
+    impl SealedTag for super::U16 {}
+
+    /// AEADs whose ciphertexts commit to the key with the padding fix of
+    /// [`KeyCommitting`](super::KeyCommitting): the stream cipher must start
+    /// the message with fresh keystream.
+    pub trait SealedCommitting {}
+
+    impl<C> SealedCommitting for super::ChaChaPoly1305<C, super::U16> where
+        C: super::NewCipher<KeySize = super::U32, NonceSize = super::U12>
+            + super::StreamCipher
+            + super::StreamCipherSeek
+    {
+    }
+
+    #[cfg(feature = "xchacha20poly1305")]
+    impl SealedCommitting for super::XChaCha20Poly1305 {}
+}
+
+/// Tag sizes supported by [`ChaChaPoly1305`]: the full 128-bit Poly1305 tag
+/// (`U16`), or its first 96 (`U12`) or 64 bits (`U8`).
+pub trait ChaChaPolyTagSize: ArrayLength<u8> + private::SealedTag {}
+
+impl ChaChaPolyTagSize for U8 {}
+impl ChaChaPolyTagSize for U12 {}
+impl ChaChaPolyTagSize for U16 {}
 
 /// ChaCha20Poly1305 Authenticated Encryption with Additional Data (AEAD).
 #[cfg(feature = "chacha20")]
 #[cfg_attr(docsrs, doc(cfg(feature = "chacha20")))]
 pub type ChaCha20Poly1305 = ChaChaPoly1305<ChaCha20>;
 
+/// ChaCha20Poly1305 with a 64-bit tag.
+#[cfg(feature = "chacha20")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chacha20")))]
+pub type ChaCha20Poly1305Tag64 = ChaChaPoly1305<ChaCha20, U8>;
+
+/// ChaCha20Poly1305 with a 96-bit tag.
+#[cfg(feature = "chacha20")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chacha20")))]
+pub type ChaCha20Poly1305Tag96 = ChaChaPoly1305<ChaCha20, U12>;
+
+/// ChaCha20Poly1305 with its ciphertexts committing to the key (see
+/// [`KeyCommitting`]).
+#[cfg(feature = "chacha20")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chacha20")))]
+pub type CommittingChaCha20Poly1305 = KeyCommitting<ChaCha20Poly1305>;
+
+/// XChaCha20Poly1305 with its ciphertexts committing to the key (see
+/// [`KeyCommitting`]).
+#[cfg(feature = "xchacha20poly1305")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xchacha20poly1305")))]
+pub type CommittingXChaCha20Poly1305 = KeyCommitting<XChaCha20Poly1305>;
+
 /// ChaCha8Poly1305 (reduced round variant) Authenticated Encryption with Additional Data (AEAD).
 #[cfg(feature = "reduced-round")]
 #[cfg_attr(docsrs, doc(cfg(feature = "reduced-round")))]
@@ -165,20 +257,38 @@ pub type ChaCha12Poly1305 = ChaChaPoly1305<ChaCha12>;
 /// Generic ChaCha+Poly1305 Authenticated Encryption with Additional Data (AEAD) construction.
 ///
 /// See the [toplevel documentation](index.html) for a usage example.
-pub struct ChaChaPoly1305<C>
+///
+/// The tag is the full 128-bit Poly1305 tag by default. With a `TagSize` of
+/// `U12` or `U8`, it is truncated to its first 96 or 64 bits, and only these
+/// are checked on decryption, in constant time.
+///
+/// # ⚠️ Truncated tags
+///
+/// A forgery attempt against a `TagSize`-byte tag succeeds with probability
+/// about 2<sup>-8·TagSize</sup>, and unlike with the full tag, an attacker
+/// who can submit many forgeries may succeed in practice: with 64-bit tags,
+/// cap the number of failed decryptions per key, and rekey when it is hit.
+/// Truncated tags are not part of RFC 8439, so they won't interoperate with
+/// other implementations unless they truncate the tag the same way.
+pub struct ChaChaPoly1305<C, TagSize = U16>
 where
     C: NewCipher<KeySize = U32, NonceSize = U12> + StreamCipher + StreamCipherSeek,
+    TagSize: ChaChaPolyTagSize,
 {
     /// Secret key
     key: GenericArray<u8, U32>,
 
     /// ChaCha stream cipher
     stream_cipher: PhantomData<C>,
+
+    /// Tag size
+    tag_size: PhantomData<TagSize>,
 }
 
-impl<C> NewAead for ChaChaPoly1305<C>
+impl<C, TagSize> NewAead for ChaChaPoly1305<C, TagSize>
 where
     C: NewCipher<KeySize = U32, NonceSize = U12> + StreamCipher + StreamCipherSeek,
+    TagSize: ChaChaPolyTagSize,
 {
     type KeySize = U32;
 
@@ -186,30 +296,35 @@ where
         Self {
             key: *key,
             stream_cipher: PhantomData,
+            tag_size: PhantomData,
         }
     }
 }
 
-impl<C> AeadCore for ChaChaPoly1305<C>
+impl<C, TagSize> AeadCore for ChaChaPoly1305<C, TagSize>
 where
     C: NewCipher<KeySize = U32, NonceSize = U12> + StreamCipher + StreamCipherSeek,
+    TagSize: ChaChaPolyTagSize,
 {
     type NonceSize = U12;
-    type TagSize = U16;
+    type TagSize = TagSize;
     type CiphertextOverhead = U0;
 }
 
-impl<C> AeadInPlace for ChaChaPoly1305<C>
+impl<C, TagSize> AeadInPlace for ChaChaPoly1305<C, TagSize>
 where
     C: NewCipher<KeySize = U32, NonceSize = U12> + StreamCipher + StreamCipherSeek,
+    TagSize: ChaChaPolyTagSize,
 {
     fn encrypt_in_place_detached(
         &self,
         nonce: &Nonce,
         associated_data: &[u8],
         buffer: &mut [u8],
-    ) -> Result<Tag, Error> {
-        Cipher::new(C::new(&self.key, nonce)).encrypt_in_place_detached(associated_data, buffer)
+    ) -> Result<Tag<TagSize>, Error> {
+        let tag = Cipher::new(C::new(&self.key, nonce))
+            .encrypt_in_place_detached(associated_data, buffer)?;
+        Ok(Tag::clone_from_slice(&tag[..TagSize::to_usize()]))
     }
 
     fn decrypt_in_place_detached(
@@ -217,7 +332,7 @@ where
         nonce: &Nonce,
         associated_data: &[u8],
         buffer: &mut [u8],
-        tag: &Tag,
+        tag: &Tag<TagSize>,
     ) -> Result<(), Error> {
         Cipher::new(C::new(&self.key, nonce)).decrypt_in_place_detached(
             associated_data,
@@ -227,21 +342,65 @@ where
     }
 }
 
-impl<C> Clone for ChaChaPoly1305<C>
+impl<C, TagSize> ChaChaPoly1305<C, TagSize>
+where
+    C: NewCipher<KeySize = U32, NonceSize = U12> + StreamCipher + StreamCipherSeek,
+    TagSize: ChaChaPolyTagSize,
+{
+    /// Check the authenticity of a ciphertext followed by its tag, as
+    /// produced by [`AeadInPlace::encrypt_in_place`], without decrypting it.
+    ///
+    /// Poly1305 is computed over the ciphertext, so messages can be verified
+    /// without producing their plaintext, e.g. by relays which must never
+    /// see it.
+    pub fn verify(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        ciphertext: &[u8],
+    ) -> Result<(), Error> {
+        let tag_pos = ciphertext
+            .len()
+            .checked_sub(TagSize::to_usize())
+            .ok_or(Error)?;
+
+        let (ciphertext, tag) = ciphertext.split_at(tag_pos);
+        self.verify_detached(nonce, associated_data, ciphertext, Tag::from_slice(tag))
+    }
+
+    /// Check the authenticity of a ciphertext and its detached tag without
+    /// decrypting it.
+    pub fn verify_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        ciphertext: &[u8],
+        tag: &Tag<TagSize>,
+    ) -> Result<(), Error> {
+        Cipher::new(C::new(&self.key, nonce))
+            .verify_detached(associated_data, ciphertext, tag)
+            .map(|_| ())
+    }
+}
+
+impl<C, TagSize> Clone for ChaChaPoly1305<C, TagSize>
 where
     C: NewCipher<KeySize = U32, NonceSize = U12> + StreamCipher + StreamCipherSeek,
+    TagSize: ChaChaPolyTagSize,
 {
     fn clone(&self) -> Self {
         Self {
             key: self.key,
             stream_cipher: PhantomData,
+            tag_size: PhantomData,
         }
     }
 }
 
-impl<C> Drop for ChaChaPoly1305<C>
+impl<C, TagSize> Drop for ChaChaPoly1305<C, TagSize>
 where
     C: NewCipher<KeySize = U32, NonceSize = U12> + StreamCipher + StreamCipherSeek,
+    TagSize: ChaChaPolyTagSize,
 {
     fn drop(&mut self) {
         self.key.as_mut_slice().zeroize();
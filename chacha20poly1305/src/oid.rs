@@ -0,0 +1,59 @@
+//! Object identifier (OID) of ChaCha20Poly1305, as used by the
+//! Cryptographic Message Syntax ([RFC 8103]).
+//!
+//! With the `der` feature, [`nonce_from_algorithm_identifier`] and
+//! [`encode_algorithm_identifier`] decode and encode the
+//! `AlgorithmIdentifier` of the algorithm, whose parameters are the nonce:
+//!
+//! ```text
+//! AEADChaCha20Poly1305Nonce ::= OCTET STRING (SIZE(12))
+//! ```
+//!
+//! [RFC 8103]: https://tools.ietf.org/html/rfc8103
+
+pub use const_oid::ObjectIdentifier;
+
+#[cfg(feature = "der")]
+#[cfg_attr(docsrs, doc(cfg(feature = "der")))]
+pub use spki::{der, AlgorithmIdentifier};
+
+#[cfg(feature = "der")]
+use crate::Nonce;
+#[cfg(feature = "der")]
+use der::{asn1::OctetString, ErrorKind, Tag};
+
+/// `id-alg-AEADChaCha20Poly1305`
+pub const CHACHA20_POLY1305: ObjectIdentifier = ObjectIdentifier::new("1.2.840.113549.1.9.16.3.18");
+
+/// Decode the nonce of a ChaCha20Poly1305 `AlgorithmIdentifier`.
+///
+/// Returns an error if its OID is not [`CHACHA20_POLY1305`], or if the
+/// parameters are not a 12-byte `OCTET STRING`.
+#[cfg(feature = "der")]
+#[cfg_attr(docsrs, doc(cfg(feature = "der")))]
+pub fn nonce_from_algorithm_identifier<'a>(
+    algorithm: &AlgorithmIdentifier<'a>,
+) -> der::Result<&'a Nonce> {
+    algorithm.assert_algorithm_oid(CHACHA20_POLY1305)?;
+
+    let nonce = algorithm.parameters_any()?.octet_string()?.as_bytes();
+    if nonce.len() != 12 {
+        return Err(ErrorKind::Length {
+            tag: Tag::OctetString,
+        }
+        .into());
+    }
+
+    Ok(Nonce::from_slice(nonce))
+}
+
+/// Encode the ChaCha20Poly1305 `AlgorithmIdentifier` for the given nonce
+/// into `buf`, returning the encoded bytes.
+#[cfg(feature = "der")]
+#[cfg_attr(docsrs, doc(cfg(feature = "der")))]
+pub fn encode_algorithm_identifier<'a>(nonce: &Nonce, buf: &'a mut [u8]) -> der::Result<&'a [u8]> {
+    let nonce = OctetString::new(nonce.as_slice())?;
+    let mut encoder = der::Encoder::new(buf);
+    encoder.message(&[&CHACHA20_POLY1305, &nonce])?;
+    encoder.finish()
+}
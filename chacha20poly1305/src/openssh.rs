@@ -0,0 +1,183 @@
+//! `chacha20-poly1305@openssh.com`: the ChaCha20Poly1305 variant used by the
+//! SSH transport protocol.
+//!
+//! See [`OpenSshChaCha20Poly1305`] documentation for usage.
+
+use crate::Tag;
+use ::cipher::{NewCipher, StreamCipher, StreamCipherSeek};
+use aead::{
+    consts::{U0, U16, U64, U8},
+    generic_array::GenericArray,
+    AeadCore, AeadInPlace, Error, NewAead,
+};
+use chacha20::{ChaCha20Legacy, Key};
+use poly1305::{universal_hash::NewUniversalHash, Poly1305};
+use zeroize::Zeroize;
+
+/// Key type for [`OpenSshChaCha20Poly1305`] (512-bits/64-bytes): `K_2 || K_1`.
+pub type OpenSshKey = GenericArray<u8, U64>;
+
+/// Nonce type for [`OpenSshChaCha20Poly1305`] (64-bits/8-bytes): the packet
+/// sequence number as a big endian `u64`.
+pub type OpenSshNonce = GenericArray<u8, U8>;
+
+/// Size of the encrypted packet length in bytes
+const LENGTH_SIZE: usize = 4;
+
+/// ChaCha20Poly1305 variant used by OpenSSH, as described in
+/// [PROTOCOL.chacha20poly1305][1].
+///
+/// The `openssh` Cargo feature must be enabled in order to use this.
+///
+/// It takes 512 bits of key material from the SSH key exchange, which form
+/// two keys for the original "djb" ChaCha20, with a 64-bit nonce: the first
+/// 256 bits (`K_2`) encrypt the packet and key Poly1305, and the second 256
+/// bits (`K_1`) only encrypt the 4-byte packet length, so that it can be
+/// decrypted before the rest of the packet has been received.
+///
+/// The buffer holds a whole SSH binary packet, starting with its encrypted
+/// length, and the sequence number of the packet is used as the nonce. The
+/// tag is computed over the whole ciphertext, length included, without
+/// padding or lengths, so associated data isn't supported and must be empty.
+///
+/// # Usage
+///
+/// ```
+/// use chacha20poly1305::{OpenSshChaCha20Poly1305, OpenSshKey, OpenSshNonce};
+/// use chacha20poly1305::aead::{AeadInPlace, NewAead};
+///
+/// let key = OpenSshKey::from_slice(&[0x42; 64]); // from the key exchange
+/// let cipher = OpenSshChaCha20Poly1305::new(key);
+///
+/// let seq = 3u64;
+/// let nonce = OpenSshNonce::from(seq.to_be_bytes());
+///
+/// // packet_length || padding_length || payload || padding
+/// let mut packet = *b"\x00\x00\x00\x0c\x04plaintext\x00\x00\x00\x00";
+/// let tag = cipher.encrypt_in_place_detached(&nonce, b"", &mut packet)
+///     .expect("encryption failure!");
+///
+/// // The receiver decrypts the length to know how much it needs to read
+/// let length = cipher.decrypt_length(&nonce, &packet[..4]).unwrap();
+/// assert_eq!(length, 12);
+///
+/// cipher.decrypt_in_place_detached(&nonce, b"", &mut packet, &tag)
+///     .expect("decryption failure!");
+/// assert_eq!(&packet, b"\x00\x00\x00\x0c\x04plaintext\x00\x00\x00\x00");
+/// ```
+///
+/// [1]: https://cvsweb.openbsd.org/src/usr.bin/ssh/PROTOCOL.chacha20poly1305
+#[derive(Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "openssh")))]
+pub struct OpenSshChaCha20Poly1305 {
+    /// Secret key: `K_2 || K_1`
+    key: OpenSshKey,
+}
+
+impl NewAead for OpenSshChaCha20Poly1305 {
+    type KeySize = U64;
+
+    fn new(key: &OpenSshKey) -> Self {
+        OpenSshChaCha20Poly1305 { key: *key }
+    }
+}
+
+impl AeadCore for OpenSshChaCha20Poly1305 {
+    type NonceSize = U8;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl AeadInPlace for OpenSshChaCha20Poly1305 {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &OpenSshNonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        if !associated_data.is_empty() || buffer.len() < LENGTH_SIZE {
+            return Err(Error);
+        }
+
+        let (length, packet) = buffer.split_at_mut(LENGTH_SIZE);
+        let (mut cipher, mac) = self.packet_cipher(nonce);
+
+        self.length_cipher(nonce).apply_keystream(length);
+        cipher.apply_keystream(packet);
+
+        Ok(mac.compute_unpadded(buffer).into_bytes())
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &OpenSshNonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        if !associated_data.is_empty() || buffer.len() < LENGTH_SIZE {
+            return Err(Error);
+        }
+
+        let (mut cipher, mac) = self.packet_cipher(nonce);
+
+        // This performs a constant-time comparison using the `subtle` crate
+        if mac.compute_unpadded(buffer) == poly1305::Tag::new(*tag) {
+            let (length, packet) = buffer.split_at_mut(LENGTH_SIZE);
+            self.length_cipher(nonce).apply_keystream(length);
+            cipher.apply_keystream(packet);
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+}
+
+impl OpenSshChaCha20Poly1305 {
+    /// Decrypt the packet length from its first 4 bytes, which must be given
+    /// as `encrypted_length`.
+    ///
+    /// The length isn't authenticated until the whole packet is decrypted,
+    /// so it should only be used to determine how many bytes to read.
+    pub fn decrypt_length(
+        &self,
+        nonce: &OpenSshNonce,
+        encrypted_length: &[u8],
+    ) -> Result<u32, Error> {
+        if encrypted_length.len() != LENGTH_SIZE {
+            return Err(Error);
+        }
+
+        let mut length = [0u8; LENGTH_SIZE];
+        length.copy_from_slice(encrypted_length);
+        self.length_cipher(nonce).apply_keystream(&mut length);
+        Ok(u32::from_be_bytes(length))
+    }
+
+    /// Instantiate ChaCha20 keyed with `K_1`, which encrypts the length
+    fn length_cipher(&self, nonce: &OpenSshNonce) -> ChaCha20Legacy {
+        ChaCha20Legacy::new(Key::from_slice(&self.key[32..]), nonce)
+    }
+
+    /// Instantiate ChaCha20 keyed with `K_2` at block 1, along with Poly1305
+    /// keyed with the first 32 bytes of its keystream.
+    fn packet_cipher(&self, nonce: &OpenSshNonce) -> (ChaCha20Legacy, Poly1305) {
+        let mut cipher = ChaCha20Legacy::new(Key::from_slice(&self.key[..32]), nonce);
+
+        let mut mac_key = poly1305::Key::default();
+        cipher.apply_keystream(&mut mac_key);
+        let mac = Poly1305::new(&mac_key);
+        mac_key.zeroize();
+
+        // Set ChaCha20 counter to 1
+        cipher.seek(64u64);
+
+        (cipher, mac)
+    }
+}
+
+impl Drop for OpenSshChaCha20Poly1305 {
+    fn drop(&mut self) {
+        self.key.as_mut_slice().zeroize();
+    }
+}
@@ -0,0 +1,331 @@
+//! libsodium's `crypto_secretstream_xchacha20poly1305`: a stream of messages
+//! encrypted with ChaCha20Poly1305 under a key derived with HChaCha20.
+//!
+//! See [`Encryptor`] documentation for usage.
+
+use crate::{Key, Nonce, Tag};
+use ::cipher::{NewCipher, StreamCipher, StreamCipherSeek};
+use aead::{consts::U24, generic_array::GenericArray, Buffer, Error};
+use chacha20::{hchacha, ChaCha20, R20};
+use poly1305::{
+    universal_hash::{NewUniversalHash, UniversalHash},
+    Poly1305,
+};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// Size of a [`Header`] in bytes
+pub const HEADER_SIZE: usize = 24;
+
+/// Number of bytes each message grows by when encrypted: its encrypted
+/// [`MessageTag`] byte and the Poly1305 tag (libsodium's `ABYTES`).
+pub const CIPHERTEXT_OVERHEAD: usize = 17;
+
+/// Maximum length of a message in bytes
+pub const MESSAGE_MAX: u64 = 64 * ((1 << 32) - 2);
+
+/// Stream header which is sent before the first message (192-bits/24-bytes).
+///
+/// It MUST be random, and unique for each stream encrypted with a key.
+pub type Header = GenericArray<u8, U24>;
+
+/// Size of a ChaCha20 block in bytes
+const BLOCK_SIZE: usize = 64;
+
+/// Tag attached to every message of a stream, and authenticated with it.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum MessageTag {
+    /// Most common tag, which doesn't add any information about the nature
+    /// of the message (`TAG_MESSAGE`)
+    Message = 0,
+
+    /// The message marks the end of a set of messages, but not the end of
+    /// the stream (`TAG_PUSH`)
+    Push = 1,
+
+    /// The keys are ratcheted after this message, so that previous messages
+    /// can't be decrypted with the new state (`TAG_REKEY`)
+    Rekey = 2,
+
+    /// The message marks the end of the stream, and the keys are ratcheted
+    /// as with [`MessageTag::Rekey`] (`TAG_FINAL`)
+    Final = 3,
+}
+
+impl MessageTag {
+    /// Bit of the tags after which the keys are ratcheted
+    const REKEY: u8 = 2;
+
+    fn from_u8(tag: u8) -> Option<Self> {
+        match tag {
+            0 => Some(MessageTag::Message),
+            1 => Some(MessageTag::Push),
+            2 => Some(MessageTag::Rekey),
+            3 => Some(MessageTag::Final),
+            _ => None,
+        }
+    }
+}
+
+/// Encryptor of a stream compatible with libsodium's
+/// `crypto_secretstream_xchacha20poly1305_push`.
+///
+/// Each message is given a [`MessageTag`], which becomes its first byte
+/// once encrypted, followed by the ciphertext and a 16-byte Poly1305 tag,
+/// so encrypted messages are [`CIPHERTEXT_OVERHEAD`] bytes longer. Messages
+/// are numbered implicitly, and their Poly1305 tags are mixed into the
+/// nonce of the next one, so the [`Decryptor`] rejects any message which is
+/// reordered, replayed or dropped. Truncation after a whole message is only
+/// detected by checking that the last message is tagged
+/// [`MessageTag::Final`].
+///
+/// The keys are ratcheted automatically after every [`MessageTag::Rekey`]
+/// or [`MessageTag::Final`] message, and before the 32-bit message counter
+/// overflows.
+///
+/// # Usage
+///
+/// ```
+/// use chacha20poly1305::Key;
+/// use chacha20poly1305::secretstream::{Decryptor, Encryptor, Header, MessageTag};
+///
+/// let key = Key::from_slice(b"an example very very secret key."); // 32-bytes
+/// let header = Header::from_slice(b"random header, 24 bytes!"); // MUST be random
+///
+/// let mut encryptor = Encryptor::new(key, header);
+/// let mut first = b"first message".to_vec();
+/// encryptor.push(MessageTag::Message, b"", &mut first).expect("encryption failure!");
+/// let mut last = b"last message".to_vec();
+/// encryptor.push(MessageTag::Final, b"", &mut last).expect("encryption failure!");
+///
+/// // The header is sent in the clear, before the messages
+/// let mut decryptor = Decryptor::new(key, header);
+/// let tag = decryptor.pull(b"", &mut first).expect("decryption failure!");
+/// assert_eq!((tag, first.as_slice()), (MessageTag::Message, &b"first message"[..]));
+/// let tag = decryptor.pull(b"", &mut last).expect("decryption failure!");
+/// assert_eq!((tag, last.as_slice()), (MessageTag::Final, &b"last message"[..]));
+/// ```
+pub struct Encryptor {
+    state: State,
+}
+
+impl Encryptor {
+    /// Start encrypting a stream with the given key and random header
+    /// (`crypto_secretstream_xchacha20poly1305_init_push`).
+    pub fn new(key: &Key, header: &Header) -> Self {
+        Self {
+            state: State::new(key, header),
+        }
+    }
+
+    /// Encrypt the next message of the stream in-place, with the given tag
+    /// and associated data.
+    pub fn push(
+        &mut self,
+        tag: MessageTag,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        let msg_len = buffer.len();
+        if msg_len as u64 > MESSAGE_MAX {
+            return Err(Error);
+        }
+
+        let (mut cipher, mut mac) = self.state.init();
+        mac.update_padded(associated_data);
+
+        let mut block = [0u8; BLOCK_SIZE];
+        block[0] = tag as u8;
+        cipher.apply_keystream(&mut block);
+        mac.update_padded(&block);
+
+        // tag || message || Poly1305 tag
+        buffer.extend_from_slice(&[0u8; CIPHERTEXT_OVERHEAD])?;
+        let out = buffer.as_mut();
+        out.copy_within(..msg_len, 1);
+        out[0] = block[0];
+
+        let ciphertext = &mut out[1..=msg_len];
+        cipher.apply_keystream(ciphertext);
+
+        let mac_tag = compute_tag(mac, associated_data, ciphertext);
+        out[msg_len + 1..].copy_from_slice(&mac_tag);
+        self.state.advance(&mac_tag, tag as u8);
+        Ok(())
+    }
+
+    /// Ratchet the keys explicitly, without sending a
+    /// [`MessageTag::Rekey`] message
+    /// (`crypto_secretstream_xchacha20poly1305_rekey`).
+    ///
+    /// The [`Decryptor`] must do so at the same point of the stream.
+    pub fn rekey(&mut self) {
+        self.state.rekey();
+    }
+}
+
+/// Decryptor of a stream compatible with libsodium's
+/// `crypto_secretstream_xchacha20poly1305_pull`.
+///
+/// See [`Encryptor`] documentation for usage.
+pub struct Decryptor {
+    state: State,
+}
+
+impl Decryptor {
+    /// Start decrypting a stream with the given key and the header received
+    /// first (`crypto_secretstream_xchacha20poly1305_init_pull`).
+    pub fn new(key: &Key, header: &Header) -> Self {
+        Self {
+            state: State::new(key, header),
+        }
+    }
+
+    /// Decrypt the next message of the stream in-place, with the given
+    /// associated data, returning its tag.
+    ///
+    /// On error, the buffer and the state of the decryptor are unchanged.
+    pub fn pull(
+        &mut self,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<MessageTag, Error> {
+        let msg_len = match buffer.len().checked_sub(CIPHERTEXT_OVERHEAD) {
+            Some(len) if len as u64 <= MESSAGE_MAX => len,
+            _ => return Err(Error),
+        };
+
+        let (mut cipher, mut mac) = self.state.init();
+        mac.update_padded(associated_data);
+
+        let input = buffer.as_mut();
+        let mut block = [0u8; BLOCK_SIZE];
+        block[0] = input[0];
+        cipher.apply_keystream(&mut block);
+        let tag = block[0];
+        block[0] = input[0];
+        mac.update_padded(&block);
+
+        let (ciphertext, expected_tag) = input[1..].split_at_mut(msg_len);
+        let mac_tag = compute_tag(mac, associated_data, ciphertext);
+
+        if mac_tag.ct_eq(expected_tag).unwrap_u8() == 0 {
+            return Err(Error);
+        }
+
+        // libsodium only defines these tags
+        let message_tag = MessageTag::from_u8(tag).ok_or(Error)?;
+
+        cipher.apply_keystream(ciphertext);
+        input.copy_within(1..=msg_len, 0);
+        buffer.truncate(msg_len);
+
+        self.state.advance(&mac_tag, tag);
+        Ok(message_tag)
+    }
+
+    /// Ratchet the keys explicitly, at the point of the stream where the
+    /// [`Encryptor`] did so (`crypto_secretstream_xchacha20poly1305_rekey`).
+    pub fn rekey(&mut self) {
+        self.state.rekey();
+    }
+}
+
+/// State of a stream: the key, and the nonce of the next message, made of a
+/// 32-bit little endian counter and a 64-bit "inonce".
+struct State {
+    key: Key,
+    nonce: Nonce,
+}
+
+impl State {
+    fn new(key: &Key, header: &Header) -> Self {
+        let key = hchacha::<R20>(key, GenericArray::from_slice(&header[..16]));
+
+        let mut nonce = Nonce::default();
+        nonce[4..].copy_from_slice(&header[16..]);
+
+        let mut state = Self { key, nonce };
+        state.reset_counter();
+        state
+    }
+
+    /// Get ChaCha20 positioned at block 1, and Poly1305 keyed with block 0
+    fn init(&self) -> (ChaCha20, Poly1305) {
+        let mut cipher = ChaCha20::new(&self.key, &self.nonce);
+
+        let mut mac_key = poly1305::Key::default();
+        cipher.apply_keystream(&mut mac_key);
+        let mac = Poly1305::new(&mac_key);
+        mac_key.zeroize();
+
+        cipher.seek(BLOCK_SIZE as u64);
+        (cipher, mac)
+    }
+
+    /// Move to the next message, after one with the given tags
+    fn advance(&mut self, mac_tag: &Tag, tag: u8) {
+        for (a, b) in self.nonce[4..].iter_mut().zip(mac_tag.iter()) {
+            *a ^= b;
+        }
+
+        let counter =
+            u32::from_le_bytes([self.nonce[0], self.nonce[1], self.nonce[2], self.nonce[3]])
+                .wrapping_add(1);
+        self.nonce[..4].copy_from_slice(&counter.to_le_bytes());
+
+        if tag & MessageTag::REKEY != 0 || counter == 0 {
+            self.rekey();
+        }
+    }
+
+    /// Replace the key and inonce with their encryption under the current
+    /// state
+    fn rekey(&mut self) {
+        let mut buf = [0u8; 40];
+        buf[..32].copy_from_slice(&self.key);
+        buf[32..].copy_from_slice(&self.nonce[4..]);
+
+        ChaCha20::new(&self.key, &self.nonce).apply_keystream(&mut buf);
+
+        self.key.copy_from_slice(&buf[..32]);
+        self.nonce[4..].copy_from_slice(&buf[32..]);
+        buf.zeroize();
+        self.reset_counter();
+    }
+
+    fn reset_counter(&mut self) {
+        self.nonce[..4].copy_from_slice(&1u32.to_le_bytes());
+    }
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        self.key.as_mut_slice().zeroize();
+        self.nonce.as_mut_slice().zeroize();
+    }
+}
+
+/// Finish computing the Poly1305 tag of a message, after its associated
+/// data and tag block
+fn compute_tag(mut mac: Poly1305, associated_data: &[u8], ciphertext: &[u8]) -> Tag {
+    let full_blocks = ciphertext.len() - ciphertext.len() % 16;
+    for block in ciphertext[..full_blocks].chunks_exact(16) {
+        mac.update(GenericArray::from_slice(block));
+    }
+
+    // libsodium pads the ciphertext with `len % 16` zeros rather than up to
+    // the next block, so the lengths may not be block-aligned
+    let remainder = &ciphertext[full_blocks..];
+    let mut tail = [0u8; 48];
+    tail[..remainder.len()].copy_from_slice(remainder);
+
+    let lengths = remainder.len() * 2;
+    let associated_data_len = associated_data.len() as u64;
+    let message_len = (BLOCK_SIZE + ciphertext.len()) as u64;
+    tail[lengths..lengths + 8].copy_from_slice(&associated_data_len.to_le_bytes());
+    tail[lengths + 8..lengths + 16].copy_from_slice(&message_len.to_le_bytes());
+
+    mac.compute_unpadded(&tail[..lengths + 16]).into_bytes()
+}
@@ -102,6 +102,37 @@ impl AeadInPlace for XChaCha20Poly1305 {
     }
 }
 
+impl XChaCha20Poly1305 {
+    /// Check the authenticity of a ciphertext followed by its tag, as
+    /// produced by [`AeadInPlace::encrypt_in_place`], without decrypting it.
+    ///
+    /// See [`ChaChaPoly1305::verify`](crate::ChaChaPoly1305::verify).
+    pub fn verify(
+        &self,
+        nonce: &XNonce,
+        associated_data: &[u8],
+        ciphertext: &[u8],
+    ) -> Result<(), Error> {
+        let tag_pos = ciphertext.len().checked_sub(16).ok_or(Error)?;
+        let (ciphertext, tag) = ciphertext.split_at(tag_pos);
+        self.verify_detached(nonce, associated_data, ciphertext, Tag::from_slice(tag))
+    }
+
+    /// Check the authenticity of a ciphertext and its detached tag without
+    /// decrypting it.
+    pub fn verify_detached(
+        &self,
+        nonce: &XNonce,
+        associated_data: &[u8],
+        ciphertext: &[u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        Cipher::new(XChaCha20::new(&self.key, nonce))
+            .verify_detached(associated_data, ciphertext, tag)
+            .map(|_| ())
+    }
+}
+
 impl Drop for XChaCha20Poly1305 {
     fn drop(&mut self) {
         self.key.as_mut_slice().zeroize();
@@ -28,7 +28,7 @@ macro_rules! impl_tests {
 
             let ciphertext = <$cipher>::new(key).encrypt(nonce, payload).unwrap();
 
-            let tag_begins = ciphertext.len() - 16;
+            let tag_begins = ciphertext.len() - $tag.len();
             assert_eq!($ciphertext, &ciphertext[..tag_begins]);
             assert_eq!($tag, &ciphertext[tag_begins..]);
         }
@@ -72,6 +72,43 @@ macro_rules! impl_tests {
     };
 }
 
+macro_rules! impl_verify_tests {
+    ($cipher:ty, $key:expr, $nonce:expr, $aad:expr, $ciphertext:expr, $tag:expr) => {
+        #[test]
+        fn verify() {
+            let cipher = <$cipher>::new(GenericArray::from_slice($key));
+            let nonce = GenericArray::from_slice($nonce);
+
+            let mut ciphertext = Vec::from($ciphertext);
+            ciphertext.extend_from_slice($tag);
+            assert!(cipher.verify(nonce, $aad, &ciphertext).is_ok());
+
+            let tag = GenericArray::from_slice($tag);
+            assert!(cipher
+                .verify_detached(nonce, $aad, $ciphertext, tag)
+                .is_ok());
+        }
+
+        #[test]
+        fn verify_modified() {
+            let cipher = <$cipher>::new(GenericArray::from_slice($key));
+            let nonce = GenericArray::from_slice($nonce);
+
+            let mut ciphertext = Vec::from($ciphertext);
+            ciphertext.extend_from_slice($tag);
+
+            // Tweak the first byte
+            ciphertext[0] ^= 0xaa;
+            assert!(cipher.verify(nonce, $aad, &ciphertext).is_err());
+
+            // Too short to hold a tag
+            assert!(cipher
+                .verify(nonce, $aad, &ciphertext[..$tag.len() - 1])
+                .is_err());
+        }
+    };
+}
+
 //
 // Test vectors common to RFC 8439 and `draft-arciszewski-xchacha`
 //
@@ -98,11 +135,11 @@ mod chacha20 {
     use chacha20poly1305::aead::{Aead, NewAead, Payload};
     use chacha20poly1305::ChaCha20Poly1305;
 
-    const NONCE: &[u8; 12] = &[
+    pub(super) const NONCE: &[u8; 12] = &[
         0x07, 0x00, 0x00, 0x00, 0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47,
     ];
 
-    const CIPHERTEXT: &[u8] = &[
+    pub(super) const CIPHERTEXT: &[u8] = &[
         0xd3, 0x1a, 0x8d, 0x34, 0x64, 0x8e, 0x60, 0xdb, 0x7b, 0x86, 0xaf, 0xbc, 0x53, 0xef, 0x7e,
         0xc2, 0xa4, 0xad, 0xed, 0x51, 0x29, 0x6e, 0x08, 0xfe, 0xa9, 0xe2, 0xb5, 0xa7, 0x36, 0xee,
         0x62, 0xd6, 0x3d, 0xbe, 0xa4, 0x5e, 0x8c, 0xa9, 0x67, 0x12, 0x82, 0xfa, 0xfb, 0x69, 0xda,
@@ -113,7 +150,7 @@ mod chacha20 {
         0x76, 0xd2, 0x65, 0x86, 0xce, 0xc6, 0x4b, 0x61, 0x16,
     ];
 
-    const TAG: &[u8] = &[
+    pub(super) const TAG: &[u8] = &[
         0x1a, 0xe1, 0x0b, 0x59, 0x4f, 0x09, 0xe2, 0x6a, 0x7e, 0x90, 0x2e, 0xcb, 0xd0, 0x60, 0x06,
         0x91,
     ];
@@ -128,12 +165,199 @@ mod chacha20 {
         TAG
     );
 
+    impl_verify_tests!(ChaCha20Poly1305, KEY, NONCE, AAD, CIPHERTEXT, TAG);
+
     #[test]
     fn clone_impl() {
         let _ = ChaCha20Poly1305::new(GenericArray::from_slice(KEY)).clone();
     }
 }
 
+/// ChaCha20Poly1305 with truncated tags: the RFC 8439 test vector with the
+/// tag truncated to its first 64 or 96 bits.
+mod chacha20_truncated {
+    use super::chacha20::{CIPHERTEXT, NONCE, TAG};
+    use super::{AAD, KEY, PLAINTEXT};
+    use chacha20poly1305::aead::generic_array::GenericArray;
+    use chacha20poly1305::aead::{Aead, AeadInPlace, NewAead, Payload};
+    use chacha20poly1305::{ChaCha20Poly1305Tag64, ChaCha20Poly1305Tag96};
+
+    mod tag64 {
+        use super::*;
+
+        impl_tests!(
+            ChaCha20Poly1305Tag64,
+            KEY,
+            NONCE,
+            AAD,
+            PLAINTEXT,
+            CIPHERTEXT,
+            &TAG[..8]
+        );
+
+        impl_verify_tests!(
+            ChaCha20Poly1305Tag64,
+            KEY,
+            NONCE,
+            AAD,
+            CIPHERTEXT,
+            &TAG[..8]
+        );
+    }
+
+    mod tag96 {
+        use super::*;
+
+        impl_tests!(
+            ChaCha20Poly1305Tag96,
+            KEY,
+            NONCE,
+            AAD,
+            PLAINTEXT,
+            CIPHERTEXT,
+            &TAG[..12]
+        );
+
+        impl_verify_tests!(
+            ChaCha20Poly1305Tag96,
+            KEY,
+            NONCE,
+            AAD,
+            CIPHERTEXT,
+            &TAG[..12]
+        );
+    }
+
+    #[test]
+    fn decrypt_modified_tag() {
+        let cipher = ChaCha20Poly1305Tag64::new(GenericArray::from_slice(KEY));
+        let nonce = GenericArray::from_slice(NONCE);
+
+        // The last bytes of the full tag aren't a valid truncated tag
+        let mut buffer = CIPHERTEXT.to_vec();
+        let tag = GenericArray::from_slice(&TAG[8..]);
+        assert!(cipher
+            .decrypt_in_place_detached(nonce, AAD, &mut buffer, tag)
+            .is_err());
+        assert_eq!(buffer, CIPHERTEXT);
+    }
+}
+
+/// ChaCha20Poly1305 with the padding fix for key commitment: the RFC 8439
+/// inputs with 32 zero bytes prepended to the plaintext, encrypted with
+/// libsodium's `crypto_aead_chacha20poly1305_ietf_encrypt`.
+mod committing {
+    use super::chacha20::NONCE;
+    use super::{AAD, KEY, PLAINTEXT};
+    use chacha20poly1305::aead::generic_array::GenericArray;
+    use chacha20poly1305::aead::{Aead, AeadMut, NewAead, Payload};
+    use chacha20poly1305::{ChaCha20Poly1305, CommittingChaCha20Poly1305};
+
+    const CIPHERTEXT: &[u8] = &[
+        0x9f, 0x7b, 0xe9, 0x5d, 0x01, 0xfd, 0x40, 0xba, 0x15, 0xe2, 0x8f, 0xfb, 0x36, 0x81, 0x0a,
+        0xae, 0xc1, 0xc0, 0x88, 0x3f, 0x09, 0x01, 0x6e, 0xde, 0xdd, 0x8a, 0xd0, 0x87, 0x55, 0x82,
+        0x03, 0xa5, 0x02, 0xff, 0xaf, 0x51, 0xc9, 0xfd, 0x7e, 0x4a, 0xd6, 0xbe, 0x92, 0x48, 0x9f,
+        0xb5, 0x26, 0x84, 0x10, 0x69, 0xd7, 0x00, 0x9e, 0x06, 0x0b, 0x6f, 0x14, 0xcc, 0xe0, 0xef,
+        0x72, 0xd4, 0x7a, 0x2a, 0x8f, 0x91, 0xab, 0x39, 0x62, 0x3e, 0xd7, 0x95, 0xd6, 0x4a, 0x97,
+        0xa5, 0x6e, 0x2f, 0x49, 0x1b, 0xe1, 0xae, 0x2d, 0xa0, 0xbc, 0xcc, 0x67, 0x87, 0x42, 0x92,
+        0x8c, 0xd2, 0x54, 0x31, 0x99, 0xa0, 0x32, 0xea, 0xc2, 0xb5, 0x8f, 0x05, 0x68, 0xd2, 0xe4,
+        0x73, 0xc6, 0x65, 0x82, 0xc4, 0x94, 0x02, 0x61, 0x50, 0x69, 0x23, 0xa8, 0x40, 0x18, 0xa2,
+        0xd4, 0x13, 0x4f, 0xdb, 0xae, 0x9a, 0x91, 0x17, 0x16, 0x86, 0x1a, 0x33, 0x17, 0xbe, 0xba,
+        0xac, 0x2a, 0x00, 0x42, 0x7f, 0x33, 0x26, 0x50, 0x4a, 0xb4, 0xa7,
+    ];
+
+    const TAG: &[u8] = &[
+        0xc8, 0x81, 0x33, 0xc5, 0xc6, 0xe8, 0xe8, 0x83, 0x14, 0x38, 0xcf, 0x04, 0x97, 0xa4, 0x2b,
+        0x0b,
+    ];
+
+    impl_tests!(
+        CommittingChaCha20Poly1305,
+        KEY,
+        NONCE,
+        AAD,
+        PLAINTEXT,
+        CIPHERTEXT,
+        TAG
+    );
+
+    #[test]
+    fn decrypt_nonzero_commitment() {
+        let key = GenericArray::from_slice(KEY);
+        let nonce = GenericArray::from_slice(NONCE);
+
+        // Authentic under the key, but doesn't start with 32 zero bytes
+        let ciphertext = ChaCha20Poly1305::new(key)
+            .encrypt(nonce, PLAINTEXT.as_ref())
+            .unwrap();
+
+        let cipher = CommittingChaCha20Poly1305::new(key);
+        assert!(cipher.decrypt(nonce, ciphertext.as_ref()).is_err());
+        assert!(cipher.decrypt(nonce, &ciphertext[..47]).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "committing-aead")]
+    fn commitment() {
+        use committing_aead::CommittingAead;
+
+        let cipher = CommittingChaCha20Poly1305::new(GenericArray::from_slice(KEY));
+        let nonce = GenericArray::from_slice(NONCE);
+
+        let commitment = cipher.commitment(nonce, AAD);
+        assert_eq!(&commitment[..], &CIPHERTEXT[..32]);
+        assert_eq!(
+            &commitment,
+            CommittingChaCha20Poly1305::ciphertext_commitment(CIPHERTEXT).unwrap()
+        );
+        assert!(cipher.verify_commitment(nonce, b"", &commitment).is_ok());
+        assert!(CommittingChaCha20Poly1305::ciphertext_commitment(&CIPHERTEXT[..47]).is_err());
+
+        let mut wrong_key = KEY.to_vec();
+        wrong_key[0] ^= 1;
+        let cipher = CommittingChaCha20Poly1305::new(GenericArray::from_slice(&wrong_key));
+        assert!(cipher.verify_commitment(nonce, AAD, &commitment).is_err());
+    }
+
+    #[test]
+    fn in_place() {
+        let cipher = CommittingChaCha20Poly1305::new(GenericArray::from_slice(KEY));
+        let nonce = GenericArray::from_slice(NONCE);
+
+        let mut buffer = PLAINTEXT.to_vec();
+        cipher.encrypt_in_place(nonce, AAD, &mut buffer).unwrap();
+        assert_eq!(&buffer[..CIPHERTEXT.len()], CIPHERTEXT);
+        assert_eq!(&buffer[CIPHERTEXT.len()..], TAG);
+
+        cipher.decrypt_in_place(nonce, AAD, &mut buffer).unwrap();
+        assert_eq!(&buffer, PLAINTEXT);
+
+        // Too short to hold the block of zeros and the tag
+        let mut buffer = CIPHERTEXT[..47].to_vec();
+        assert!(cipher.decrypt_in_place(nonce, AAD, &mut buffer).is_err());
+    }
+
+    #[test]
+    fn aead_mut() {
+        let mut cipher = CommittingChaCha20Poly1305::new(GenericArray::from_slice(KEY));
+        let nonce = GenericArray::from_slice(NONCE);
+        let payload = Payload {
+            msg: PLAINTEXT,
+            aad: AAD,
+        };
+
+        let ciphertext = AeadMut::encrypt(&mut cipher, nonce, payload).unwrap();
+        assert_eq!(&ciphertext[..CIPHERTEXT.len()], CIPHERTEXT);
+
+        let payload = Payload {
+            msg: &ciphertext,
+            aad: AAD,
+        };
+        let plaintext = AeadMut::decrypt(&mut cipher, nonce, payload).unwrap();
+        assert_eq!(&plaintext, PLAINTEXT);
+    }
+}
+
 /// XChaCha20Poly1305 test vectors.
 ///
 /// From <https://tools.ietf.org/html/draft-arciszewski-xchacha-03#appendix-A.1>
@@ -174,4 +398,356 @@ mod xchacha20 {
         CIPHERTEXT,
         TAG
     );
+
+    impl_verify_tests!(XChaCha20Poly1305, KEY, NONCE, AAD, CIPHERTEXT, TAG);
+}
+
+/// ChaCha20Poly1305Legacy test vectors.
+///
+/// The RFC 8439 inputs with the last 8 bytes of its nonce, encrypted with
+/// libsodium's `crypto_aead_chacha20poly1305_encrypt`.
+#[cfg(feature = "legacy")]
+mod legacy {
+    use super::{AAD, KEY, PLAINTEXT};
+    use chacha20poly1305::aead::generic_array::GenericArray;
+    use chacha20poly1305::aead::{Aead, NewAead, Payload};
+    use chacha20poly1305::ChaCha20Poly1305Legacy;
+
+    const NONCE: &[u8; 8] = &[0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47];
+
+    const CIPHERTEXT: &[u8] = &[
+        0xa4, 0x79, 0xcb, 0x54, 0x62, 0x89, 0x46, 0xd6, 0xf4, 0x04, 0x2a, 0x8e, 0x38, 0x4e, 0xf4,
+        0xbd, 0x2f, 0xbc, 0x73, 0x30, 0xb8, 0xbe, 0x55, 0xeb, 0x2d, 0x8d, 0xc1, 0x8a, 0xaa, 0x51,
+        0xd6, 0x6a, 0x8e, 0xc1, 0xf8, 0xd3, 0x61, 0x9a, 0x25, 0x8d, 0xb0, 0xac, 0x56, 0x95, 0x60,
+        0x15, 0xb7, 0xb4, 0x93, 0x7e, 0x9b, 0x8e, 0x6a, 0xa9, 0x57, 0xb3, 0xdc, 0x02, 0x14, 0xd8,
+        0x03, 0xd7, 0x76, 0x60, 0xaa, 0xbc, 0x91, 0x30, 0x92, 0x97, 0x1d, 0xa8, 0xf2, 0x07, 0x17,
+        0x1c, 0xe7, 0x84, 0x36, 0x08, 0x16, 0x2e, 0x2e, 0x75, 0x9d, 0x8e, 0xfc, 0x25, 0xd8, 0xd0,
+        0x93, 0x69, 0x90, 0xaf, 0x63, 0xc8, 0x20, 0xba, 0x87, 0xe8, 0xa9, 0x55, 0xb5, 0xc8, 0x27,
+        0x4e, 0xf7, 0xd1, 0x0f, 0x6f, 0xaf, 0xd0, 0x46, 0x47,
+    ];
+
+    const TAG: &[u8] = &[
+        0x0f, 0x54, 0xae, 0x6c, 0x8d, 0x92, 0x02, 0x3f, 0xbb, 0x15, 0x1b, 0x42, 0x06, 0xee, 0x8e,
+        0x95,
+    ];
+
+    impl_tests!(
+        ChaCha20Poly1305Legacy,
+        KEY,
+        NONCE,
+        AAD,
+        PLAINTEXT,
+        CIPHERTEXT,
+        TAG
+    );
+
+    /// From draft-agl-tls-chacha20poly1305-04 Section 7:
+    /// <https://tools.ietf.org/html/draft-agl-tls-chacha20poly1305-04#section-7>
+    #[test]
+    fn draft_agl() {
+        let key = GenericArray::from_slice(&[
+            0x42, 0x90, 0xbc, 0xb1, 0x54, 0x17, 0x35, 0x31, 0xf3, 0x14, 0xaf, 0x57, 0xf3, 0xbe,
+            0x3b, 0x50, 0x06, 0xda, 0x37, 0x1e, 0xce, 0x27, 0x2a, 0xfa, 0x1b, 0x5d, 0xbd, 0xd1,
+            0x10, 0x0a, 0x10, 0x07,
+        ]);
+        let nonce = GenericArray::from_slice(&[0xcd, 0x7c, 0xf6, 0x7b, 0xe3, 0x9c, 0x79, 0x4a]);
+        let payload = Payload {
+            msg: &[0x86, 0xd0, 0x99, 0x74, 0x84, 0x0b, 0xde, 0xd2, 0xa5, 0xca],
+            aad: &[0x87, 0xe2, 0x29, 0xd4, 0x50, 0x08, 0x45, 0xa0, 0x79, 0xc0],
+        };
+
+        let ciphertext = ChaCha20Poly1305Legacy::new(key)
+            .encrypt(nonce, payload)
+            .unwrap();
+
+        assert_eq!(
+            ciphertext,
+            &[
+                0xe3, 0xe4, 0x46, 0xf7, 0xed, 0xe9, 0xa1, 0x9b, 0x62, 0xa4, 0x67, 0x7d, 0xab, 0xf4,
+                0xe3, 0xd2, 0x4b, 0x87, 0x6b, 0xb2, 0x84, 0x75, 0x38, 0x96, 0xe1, 0xd6,
+            ]
+        );
+    }
+}
+
+/// chacha20-poly1305@openssh.com test vectors.
+///
+/// There are no official test vectors for this construction, so these were
+/// generated with a model of OpenSSH's `cipher-chachapoly.c`, for an
+/// `SSH_MSG_CHANNEL_DATA` packet with sequence number 7.
+#[cfg(feature = "openssh")]
+mod openssh {
+    use chacha20poly1305::aead::generic_array::GenericArray;
+    use chacha20poly1305::aead::{Aead, AeadInPlace, NewAead, Payload};
+    use chacha20poly1305::OpenSshChaCha20Poly1305;
+
+    const KEY: &[u8; 64] = &[
+        0x8b, 0xbf, 0xf6, 0x96, 0x71, 0x78, 0xb8, 0x4f, 0x9e, 0x2d, 0xa2, 0x34, 0x79, 0x51, 0xf5,
+        0x07, 0x9c, 0xd7, 0x89, 0xf7, 0xc0, 0xb6, 0x84, 0xdd, 0x26, 0xf7, 0x77, 0xdc, 0x46, 0x23,
+        0xcd, 0x5d, 0x41, 0x5c, 0xdd, 0x8e, 0xc5, 0x77, 0x3b, 0xa8, 0x3f, 0x3e, 0x34, 0x4a, 0x34,
+        0x7d, 0x40, 0xb8, 0x64, 0x69, 0x4d, 0x7a, 0xd2, 0x6c, 0xdf, 0xaa, 0x82, 0x96, 0x9b, 0x9a,
+        0xd9, 0xaa, 0x97, 0x6a,
+    ];
+
+    const NONCE: &[u8; 8] = &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07];
+
+    const AAD: &[u8] = b"";
+
+    const PLAINTEXT: &[u8] = &[
+        0x00, 0x00, 0x00, 0x20, 0x05, 0x5e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x11, 0x70,
+        0x6c, 0x61, 0x69, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67,
+        0x65, 0x01, 0x02, 0x03, 0x04, 0x05,
+    ];
+
+    const CIPHERTEXT: &[u8] = &[
+        0x4b, 0x5c, 0x24, 0xa2, 0x8f, 0x43, 0xe2, 0x7a, 0x19, 0xd4, 0x6f, 0x67, 0x93, 0x60, 0x43,
+        0x44, 0xfd, 0x33, 0x44, 0x5e, 0x6c, 0x17, 0xea, 0xa4, 0xc5, 0xcd, 0xb5, 0x8a, 0x02, 0x0b,
+        0x4c, 0x71, 0x61, 0x28, 0xc3, 0x33,
+    ];
+
+    const TAG: &[u8] = &[
+        0xbc, 0x75, 0xa0, 0xa5, 0xd5, 0x19, 0xa2, 0x73, 0x69, 0x5d, 0xd4, 0xf9, 0x63, 0x29, 0x32,
+        0xcf,
+    ];
+
+    impl_tests!(
+        OpenSshChaCha20Poly1305,
+        KEY,
+        NONCE,
+        AAD,
+        PLAINTEXT,
+        CIPHERTEXT,
+        TAG
+    );
+
+    #[test]
+    fn decrypt_length() {
+        let cipher = OpenSshChaCha20Poly1305::new(GenericArray::from_slice(KEY));
+        let nonce = GenericArray::from_slice(NONCE);
+
+        let length = cipher.decrypt_length(nonce, &CIPHERTEXT[..4]).unwrap();
+        assert_eq!(length as usize, PLAINTEXT.len() - 4);
+        assert!(cipher.decrypt_length(nonce, CIPHERTEXT).is_err());
+    }
+
+    #[test]
+    fn associated_data_unsupported() {
+        let cipher = OpenSshChaCha20Poly1305::new(GenericArray::from_slice(KEY));
+        let nonce = GenericArray::from_slice(NONCE);
+
+        let mut buffer = PLAINTEXT.to_vec();
+        assert!(cipher
+            .encrypt_in_place_detached(nonce, b"aad", &mut buffer)
+            .is_err());
+
+        // Packets must at least contain their length
+        assert!(cipher
+            .encrypt_in_place_detached(nonce, b"", &mut [0; 3])
+            .is_err());
+    }
+}
+
+/// `AlgorithmIdentifier` encoding from RFC 8103
+#[cfg(feature = "der")]
+mod oid {
+    use chacha20poly1305::oid::{
+        der::Decodable, encode_algorithm_identifier, nonce_from_algorithm_identifier,
+        AlgorithmIdentifier, CHACHA20_POLY1305,
+    };
+    use chacha20poly1305::Nonce;
+
+    const NONCE: &[u8; 12] = &[
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b,
+    ];
+
+    const ALGID: &[u8] = &[
+        0x30, 0x1b, 0x06, 0x0b, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x09, 0x10, 0x03, 0x12,
+        0x04, 0x0c, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b,
+    ];
+
+    #[test]
+    fn decode() {
+        let algorithm = AlgorithmIdentifier::from_der(ALGID).unwrap();
+        assert_eq!(algorithm.oid, CHACHA20_POLY1305);
+
+        let nonce = nonce_from_algorithm_identifier(&algorithm).unwrap();
+        assert_eq!(nonce.as_slice(), NONCE);
+    }
+
+    #[test]
+    fn decode_wrong_nonce_len() {
+        let mut der = [0u8; 28];
+        der[..17].copy_from_slice(&ALGID[..17]);
+        der[1] = 0x1a;
+        der[16] = 0x0b;
+
+        let algorithm = AlgorithmIdentifier::from_der(&der).unwrap();
+        assert!(nonce_from_algorithm_identifier(&algorithm).is_err());
+    }
+
+    #[test]
+    fn encode() {
+        let mut buf = [0u8; 64];
+        let der = encode_algorithm_identifier(Nonce::from_slice(NONCE), &mut buf).unwrap();
+        assert_eq!(der, ALGID);
+    }
+}
+
+/// libsodium secretstream test vectors.
+///
+/// Generated with libsodium's `crypto_secretstream_xchacha20poly1305_push`,
+/// calling `crypto_secretstream_xchacha20poly1305_rekey` after the
+/// `MessageTag::Rekey` message.
+#[cfg(feature = "secretstream")]
+mod secretstream {
+    use super::KEY;
+    use chacha20poly1305::secretstream::{Decryptor, Encryptor, Header, MessageTag};
+    use chacha20poly1305::Key;
+
+    const HEADER: &[u8; 24] = &[
+        0xce, 0x56, 0xd5, 0xd9, 0xcc, 0xc4, 0x42, 0xf8, 0x70, 0x66, 0x30, 0xfb, 0x07, 0x79, 0x93,
+        0xcf, 0xb3, 0x14, 0xeb, 0xe2, 0x35, 0x49, 0x10, 0x64,
+    ];
+
+    /// Message of the stream, and its ciphertext
+    struct Message {
+        tag: MessageTag,
+        plaintext: &'static [u8],
+        aad: &'static [u8],
+        ciphertext: &'static [u8],
+    }
+
+    const MESSAGES: &[Message] = &[
+        Message {
+            tag: MessageTag::Message,
+            plaintext: b"",
+            aad: &[],
+            ciphertext: &[
+                0xac, 0x69, 0x38, 0xeb, 0xf4, 0x8f, 0x23, 0x80, 0xfa, 0x4a, 0xeb, 0xf8, 0x50, 0x2f,
+                0x59, 0x41, 0x24,
+            ],
+        },
+        Message {
+            tag: MessageTag::Message,
+            plaintext: b"Ladies and Gentlemen of the class of '99",
+            aad: &[
+                0x50, 0x51, 0x52, 0x53, 0xc0, 0xc1, 0xc2, 0xc3, 0xc4, 0xc5, 0xc6, 0xc7,
+            ],
+            ciphertext: &[
+                0xd8, 0xcb, 0xbc, 0x95, 0x2a, 0xb6, 0x80, 0x72, 0x30, 0x4f, 0x8f, 0x4a, 0xc1, 0xa9,
+                0xe9, 0xd9, 0x70, 0xa8, 0x46, 0x7f, 0xc8, 0xbd, 0x72, 0xfb, 0x3c, 0x1e, 0x00, 0x69,
+                0xa3, 0x63, 0x91, 0x17, 0x27, 0x04, 0x2a, 0xc8, 0x7a, 0xc6, 0x7e, 0xda, 0xba, 0x8e,
+                0x3a, 0x71, 0x87, 0x41, 0x06, 0x18, 0xde, 0x76, 0xe8, 0x59, 0xdc, 0x3e, 0xac, 0xc0,
+                0x0a,
+            ],
+        },
+        Message {
+            tag: MessageTag::Push,
+            plaintext: b"I",
+            aad: &[],
+            ciphertext: &[
+                0x0f, 0x4f, 0x1e, 0xb0, 0xcf, 0x4a, 0x0c, 0x8b, 0x4a, 0x12, 0x91, 0xbf, 0x96, 0x0d,
+                0x19, 0xe9, 0xb2, 0x46,
+            ],
+        },
+        Message {
+            tag: MessageTag::Rekey,
+            plaintext: b"If I could offer you only one tip for the future, sunscreen would be it.",
+            aad: &[],
+            ciphertext: &[
+                0xd0, 0x1c, 0x8f, 0x88, 0x0a, 0x42, 0xb1, 0xdb, 0x31, 0xa8, 0xfd, 0xa3, 0x1a, 0xbb,
+                0x26, 0x14, 0xb2, 0x17, 0xd6, 0x6b, 0x0e, 0x4d, 0x08, 0xf7, 0xd2, 0x20, 0x73, 0xc7,
+                0x56, 0x1f, 0xff, 0xf4, 0x0c, 0x05, 0xd1, 0x96, 0xc5, 0x7e, 0xe3, 0xd3, 0x7c, 0x03,
+                0x7f, 0x33, 0x8b, 0x1a, 0xe8, 0x1c, 0x00, 0xbf, 0xb7, 0x55, 0xec, 0x68, 0xb0, 0xb0,
+                0x24, 0xca, 0x5f, 0xd9, 0x61, 0xaf, 0xf9, 0xb3, 0x89, 0x03, 0x4e, 0x99, 0x94, 0x21,
+                0xd1, 0xe5, 0x98, 0xec, 0x4b, 0x27, 0x5a, 0xfd, 0x1e, 0x8c, 0x01, 0x2e, 0x23, 0x56,
+                0x3d, 0x31, 0x44, 0xeb, 0x3c,
+            ],
+        },
+        Message {
+            tag: MessageTag::Message,
+            plaintext: b"sixteen bytes!!!",
+            aad: &[0x61, 0x64],
+            ciphertext: &[
+                0xd5, 0x40, 0xc1, 0xc8, 0xa6, 0xfe, 0x25, 0x3e, 0x76, 0x6d, 0xc4, 0xb0, 0x77, 0xf7,
+                0x9f, 0xda, 0x2b, 0x0e, 0x02, 0x78, 0x38, 0x6f, 0x52, 0x42, 0x0c, 0xe7, 0xa2, 0x35,
+                0xcf, 0xbe, 0x92, 0x0a, 0x31,
+            ],
+        },
+        Message {
+            tag: MessageTag::Final,
+            plaintext: b"The long term benefits of sunscreen have been proved by scientists",
+            aad: &[],
+            ciphertext: &[
+                0xc7, 0xf7, 0x76, 0x14, 0xc4, 0xde, 0xf8, 0xc9, 0xcd, 0x01, 0x0f, 0x67, 0x2e, 0xa7,
+                0xb3, 0xca, 0xb7, 0x14, 0x92, 0x8a, 0x0e, 0xd8, 0xce, 0x44, 0x47, 0x33, 0x08, 0xb8,
+                0xec, 0x6a, 0x86, 0xad, 0xb8, 0x04, 0x3f, 0xc4, 0x7d, 0xf1, 0x3f, 0x16, 0xd1, 0xcd,
+                0x3d, 0x0a, 0xb3, 0x04, 0xc1, 0x3b, 0x31, 0x3b, 0xe2, 0x91, 0xa3, 0x04, 0x75, 0x8d,
+                0x2a, 0xb5, 0x5c, 0x7d, 0xf5, 0x23, 0xe6, 0xd0, 0x2e, 0xa8, 0xac, 0x0c, 0xdc, 0x6d,
+                0xfe, 0x34, 0x19, 0x21, 0x28, 0x0c, 0xfd, 0xaf, 0xea, 0x5e, 0x32, 0x6b, 0xbe,
+            ],
+        },
+    ];
+
+    /// Index of the message after which the stream is rekeyed explicitly
+    const REKEY_AFTER: usize = 3;
+
+    #[test]
+    fn push() {
+        let mut encryptor = Encryptor::new(Key::from_slice(KEY), Header::from_slice(HEADER));
+
+        for (i, message) in MESSAGES.iter().enumerate() {
+            let mut buffer = message.plaintext.to_vec();
+            encryptor
+                .push(message.tag, message.aad, &mut buffer)
+                .unwrap();
+            assert_eq!(buffer, message.ciphertext);
+
+            if i == REKEY_AFTER {
+                encryptor.rekey();
+            }
+        }
+    }
+
+    #[test]
+    fn pull() {
+        let mut decryptor = Decryptor::new(Key::from_slice(KEY), Header::from_slice(HEADER));
+
+        for (i, message) in MESSAGES.iter().enumerate() {
+            let mut buffer = message.ciphertext.to_vec();
+            assert_eq!(
+                decryptor.pull(message.aad, &mut buffer).unwrap(),
+                message.tag
+            );
+            assert_eq!(buffer, message.plaintext);
+
+            if i == REKEY_AFTER {
+                decryptor.rekey();
+            }
+        }
+    }
+
+    #[test]
+    fn pull_modified() {
+        let mut decryptor = Decryptor::new(Key::from_slice(KEY), Header::from_slice(HEADER));
+
+        // Messages can't be skipped
+        let second = &MESSAGES[1];
+        let mut buffer = second.ciphertext.to_vec();
+        assert!(decryptor.pull(second.aad, &mut buffer).is_err());
+        assert_eq!(buffer, second.ciphertext);
+
+        // Nor modified, and a failure doesn't change the state
+        let first = &MESSAGES[0];
+        let mut buffer = first.ciphertext.to_vec();
+        buffer[0] ^= 1;
+        assert!(decryptor.pull(first.aad, &mut buffer).is_err());
+
+        let mut buffer = first.ciphertext.to_vec();
+        assert_eq!(decryptor.pull(first.aad, &mut buffer).unwrap(), first.tag);
+        assert_eq!(buffer, first.plaintext);
+
+        // Too short to hold a tag
+        assert!(decryptor.pull(b"", &mut vec![0u8; 16]).is_err());
+    }
 }
@@ -0,0 +1,405 @@
+//! Generic key-committing wrapper for [Authenticated Encryption with
+//! Associated Data (AEAD)][1] ciphers.
+//!
+//! AES-GCM, ChaCha20Poly1305 and most other AEADs are not committing: an
+//! attacker can craft a single ciphertext which decrypts successfully under
+//! several keys of their choosing. This enables the "invisible salamanders"
+//! and [partitioning oracle][2] attacks when keys are derived from passwords
+//! or chosen by untrusted parties.
+//!
+//! [`Committing`] makes any AEAD committing, in the style of the UtC
+//! ("Universal-then-Commit") and HtE ("Hash-then-Encrypt") transforms of
+//! Bellare and Hoang, [*Efficient Schemes for Committing Authenticated
+//! Encryption*][3]. HMAC-SHA-256 keyed with the key `K` is used as a
+//! committing PRF over the nonce `N` and associated data `A`. It derives a
+//! commitment `P`, and a fresh key `L` for the wrapped AEAD:
+//!
+//! ```text
+//! P = HMAC-SHA-256(K, N || A || 0x00)
+//! L = HMAC-SHA-256(K, N || A || 0x01) || HMAC-SHA-256(K, N || A || 0x02) || ...
+//! C || T = AEAD(L, N, A, M)
+//! ```
+//!
+//! where `L` is truncated to the key size of the wrapped AEAD. The
+//! ciphertext is `C || T || P`: the tag of [`Committing`] is the tag of the
+//! wrapped AEAD followed by the 256-bit commitment, which is checked in
+//! constant time before anything is decrypted.
+//!
+//! The [`CommittingAead`] trait computes and checks commitments separately
+//! from decryption, and gets the commitment carried by a ciphertext, for
+//! protocols which transport or compare them. It is also implemented by the
+//! `KeyCommitting` wrapper of `chacha20poly1305`, with its `committing-aead`
+//! feature.
+//!
+//! Finding two different keys, nonces or associated data with the same
+//! commitment requires a collision on HMAC-SHA-256, and `L` depends on all of
+//! them, so a ciphertext only decrypts under the key, nonce, associated data
+//! and message it was produced with (CMT-4 security).
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! The construction is specific to this crate: its ciphertexts can only be
+//! decrypted by implementations of it, not by the wrapped AEAD.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! Simple usage (allocating, no associated data):
+//!
+//! ```
+//! use aes_gcm::{Aes256Gcm, Key, Nonce};
+//! use committing_aead::Committing;
+//! use committing_aead::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = Committing::<Aes256Gcm>::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce"); // 96-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! // The message, then the 16-byte AES-GCM tag and the 32-byte commitment
+//! assert_eq!(ciphertext.len(), 17 + 16 + 32);
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! Note that if you enable the `heapless` feature of this crate,
+//! you will receive an impl of [`aead::Buffer`] for `heapless::Vec`
+//! (re-exported from the [`aead`] crate as [`aead::heapless::Vec`]),
+//! which can then be passed as the `buffer` parameter to the in-place encrypt
+//! and decrypt methods:
+//!
+//! ```
+//! # #[cfg(feature = "heapless")]
+//! # {
+//! use aes_gcm::{Aes256Gcm, Key, Nonce};
+//! use committing_aead::Committing;
+//! use committing_aead::aead::{AeadInPlace, NewAead};
+//! use committing_aead::aead::heapless::Vec;
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = Committing::<Aes256Gcm>::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce"); // 96-bits; unique per message
+//!
+//! let mut buffer: Vec<u8, 128> = Vec::new();
+//! buffer.extend_from_slice(b"plaintext message");
+//!
+//! // Encrypt `buffer` in-place, replacing the plaintext contents with ciphertext
+//! cipher.encrypt_in_place(nonce, b"", &mut buffer).expect("encryption failure!");
+//!
+//! // `buffer` now contains the message ciphertext
+//! assert_ne!(&buffer, b"plaintext message");
+//!
+//! // Decrypt `buffer` in-place, replacing its ciphertext context with the original plaintext
+//! cipher.decrypt_in_place(nonce, b"", &mut buffer).expect("decryption failure!");
+//! assert_eq!(&buffer, b"plaintext message");
+//! # }
+//! ```
+//!
+//! [1]: https://en.wikipedia.org/wiki/Authenticated_encryption
+//! [2]: https://www.usenix.org/conference/usenixsecurity21/presentation/len
+//! [3]: https://eprint.iacr.org/2022/268
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+pub use aead::{self, AeadCore, AeadInPlace, Error, NewAead};
+
+use aead::{
+    consts::U32,
+    generic_array::{
+        typenum::{Sum, Unsigned},
+        ArrayLength, GenericArray,
+    },
+    Buffer,
+};
+use core::{marker::PhantomData, ops::Add};
+use hmac::{Hmac, Mac, NewMac};
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// Size of a [`Commitment`] in bytes (256 bits)
+pub const COMMITMENT_SIZE: usize = 32;
+
+/// Commitment to the key, nonce and associated data of a message
+pub type Commitment = GenericArray<u8, U32>;
+
+/// [`Committing`] tags: the tag of the wrapped AEAD followed by the
+/// [`Commitment`]
+pub type Tag<TagSize> = GenericArray<u8, Sum<TagSize, U32>>;
+
+/// AEADs whose ciphertexts carry a commitment to the key they were
+/// encrypted with, which can be computed and checked separately from
+/// decryption (e.g. to be transported or compared by a protocol).
+///
+/// What a commitment binds besides the key and nonce depends on the scheme:
+/// see the documentation of its implementations.
+pub trait CommittingAead: AeadCore {
+    /// Size of a commitment in bytes
+    type CommitmentSize: ArrayLength<u8>;
+
+    /// Compute the commitment of messages encrypted with the given nonce and
+    /// associated data.
+    fn commitment(
+        &self,
+        nonce: &GenericArray<u8, Self::NonceSize>,
+        associated_data: &[u8],
+    ) -> GenericArray<u8, Self::CommitmentSize>;
+
+    /// Get the commitment carried by a ciphertext with its tag attached, as
+    /// produced by `encrypt_in_place`.
+    ///
+    /// Returns an error if the ciphertext is too short to hold one.
+    fn ciphertext_commitment(
+        ciphertext: &[u8],
+    ) -> Result<&GenericArray<u8, Self::CommitmentSize>, Error>;
+
+    /// Check in constant time that a commitment was made with this key and
+    /// the given nonce and associated data.
+    fn verify_commitment(
+        &self,
+        nonce: &GenericArray<u8, Self::NonceSize>,
+        associated_data: &[u8],
+        commitment: &GenericArray<u8, Self::CommitmentSize>,
+    ) -> Result<(), Error> {
+        let expected = self.commitment(nonce, associated_data);
+
+        if expected.ct_eq(commitment).unwrap_u8() == 1 {
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+}
+
+/// Key-committing wrapper around an AEAD.
+///
+/// See the [toplevel documentation](index.html) for details of the
+/// construction and a usage example.
+///
+/// The key and nonce sizes are those of the wrapped AEAD `A`, and its tag is
+/// [`COMMITMENT_SIZE`] bytes longer.
+pub struct Committing<A>
+where
+    A: NewAead + AeadInPlace,
+{
+    /// HMAC-SHA-256, keyed with the key
+    mac: Hmac<Sha256>,
+
+    /// Wrapped AEAD
+    aead: PhantomData<A>,
+}
+
+impl<A> NewAead for Committing<A>
+where
+    A: NewAead + AeadInPlace,
+{
+    type KeySize = A::KeySize;
+
+    fn new(key: &GenericArray<u8, A::KeySize>) -> Self {
+        Self {
+            mac: Hmac::new_from_slice(key).expect("HMAC accepts keys of any length"),
+            aead: PhantomData,
+        }
+    }
+}
+
+impl<A> AeadCore for Committing<A>
+where
+    A: NewAead + AeadInPlace,
+    A::TagSize: Add<U32>,
+    Sum<A::TagSize, U32>: ArrayLength<u8>,
+{
+    type NonceSize = A::NonceSize;
+    type TagSize = Sum<A::TagSize, U32>;
+    type CiphertextOverhead = A::CiphertextOverhead;
+}
+
+impl<A> AeadInPlace for Committing<A>
+where
+    A: NewAead + AeadInPlace,
+    A::TagSize: Add<U32>,
+    Sum<A::TagSize, U32>: ArrayLength<u8>,
+{
+    fn encrypt_in_place(
+        &self,
+        nonce: &GenericArray<u8, A::NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        let (commitment, aead) = self.derive(nonce, associated_data);
+        aead.encrypt_in_place(nonce, associated_data, buffer)?;
+        buffer.extend_from_slice(&commitment)
+    }
+
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &GenericArray<u8, A::NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<A::TagSize>, Error> {
+        let (commitment, aead) = self.derive(nonce, associated_data);
+        let aead_tag = aead.encrypt_in_place_detached(nonce, associated_data, buffer)?;
+
+        let mut tag = Tag::<A::TagSize>::default();
+        let (tag_aead, tag_commitment) = tag.split_at_mut(A::TagSize::to_usize());
+        tag_aead.copy_from_slice(&aead_tag);
+        tag_commitment.copy_from_slice(&commitment);
+        Ok(tag)
+    }
+
+    fn decrypt_in_place(
+        &self,
+        nonce: &GenericArray<u8, A::NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        let commitment_pos = buffer.len().checked_sub(COMMITMENT_SIZE).ok_or(Error)?;
+
+        let (commitment, aead) = self.derive(nonce, associated_data);
+        if commitment
+            .ct_eq(&buffer.as_ref()[commitment_pos..])
+            .unwrap_u8()
+            == 0
+        {
+            return Err(Error);
+        }
+
+        buffer.truncate(commitment_pos);
+        aead.decrypt_in_place(nonce, associated_data, buffer)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &GenericArray<u8, A::NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<A::TagSize>,
+    ) -> Result<(), Error> {
+        let (tag_aead, tag_commitment) = tag.split_at(A::TagSize::to_usize());
+
+        let (commitment, aead) = self.derive(nonce, associated_data);
+        if commitment.ct_eq(tag_commitment).unwrap_u8() == 0 {
+            return Err(Error);
+        }
+
+        aead.decrypt_in_place_detached(
+            nonce,
+            associated_data,
+            buffer,
+            GenericArray::from_slice(tag_aead),
+        )
+    }
+}
+
+/// The [`Commitment`] binds the key, nonce and associated data, and is the
+/// last [`COMMITMENT_SIZE`] bytes of the ciphertext.
+impl<A> CommittingAead for Committing<A>
+where
+    A: NewAead + AeadInPlace,
+    A::TagSize: Add<U32>,
+    Sum<A::TagSize, U32>: ArrayLength<u8>,
+{
+    type CommitmentSize = U32;
+
+    fn commitment(
+        &self,
+        nonce: &GenericArray<u8, A::NonceSize>,
+        associated_data: &[u8],
+    ) -> Commitment {
+        prf(&self.keyed_prf(nonce, associated_data), 0)
+    }
+
+    fn ciphertext_commitment(ciphertext: &[u8]) -> Result<&Commitment, Error> {
+        let commitment_pos = ciphertext.len().checked_sub(COMMITMENT_SIZE).ok_or(Error)?;
+
+        Ok(Commitment::from_slice(&ciphertext[commitment_pos..]))
+    }
+}
+
+impl<A> Committing<A>
+where
+    A: NewAead + AeadInPlace,
+{
+    /// Derive the commitment of a message and the wrapped AEAD keyed with
+    /// its key.
+    fn derive(
+        &self,
+        nonce: &GenericArray<u8, A::NonceSize>,
+        associated_data: &[u8],
+    ) -> (Commitment, A) {
+        let mac = self.keyed_prf(nonce, associated_data);
+        let commitment = prf(&mac, 0);
+
+        // The counter can't wrap around: AEAD keys are far shorter than 255
+        // HMAC-SHA-256 outputs
+        let mut key = GenericArray::<u8, A::KeySize>::default();
+        for (i, chunk) in key.chunks_mut(32).enumerate() {
+            let mut block = prf(&mac, i as u8 + 1);
+            chunk.copy_from_slice(&block[..chunk.len()]);
+            block.as_mut_slice().zeroize();
+        }
+
+        let aead = A::new(&key);
+        key.as_mut_slice().zeroize();
+        (commitment, aead)
+    }
+
+    /// Get HMAC-SHA-256 keyed with the key, which has been given `N || A`
+    fn keyed_prf(
+        &self,
+        nonce: &GenericArray<u8, A::NonceSize>,
+        associated_data: &[u8],
+    ) -> Hmac<Sha256> {
+        let mut mac = self.mac.clone();
+        mac.update(nonce);
+        mac.update(associated_data);
+        mac
+    }
+}
+
+impl<A> Clone for Committing<A>
+where
+    A: NewAead + AeadInPlace,
+{
+    fn clone(&self) -> Self {
+        Self {
+            mac: self.mac.clone(),
+            aead: PhantomData,
+        }
+    }
+}
+
+/// Finish computing `HMAC-SHA-256(K, N || A || counter)`, for an HMAC
+/// instance which has already been given `N || A`
+fn prf(mac: &Hmac<Sha256>, counter: u8) -> GenericArray<u8, U32> {
+    let mut mac = mac.clone();
+    mac.update(&[counter]);
+    mac.finalize().into_bytes()
+}
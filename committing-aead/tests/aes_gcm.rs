@@ -0,0 +1,168 @@
+//! Tests of `Committing` wrapping AES-GCM
+//!
+//! The expected ciphertexts were computed independently, with Python's
+//! `hmac` module and the AES-GCM implementation of `cryptography`.
+
+#[macro_use]
+extern crate hex_literal;
+
+use aes_gcm::{Aes128Gcm, Aes256Gcm};
+use committing_aead::aead::{generic_array::GenericArray, Aead, AeadInPlace, NewAead, Payload};
+use committing_aead::{Committing, CommittingAead, COMMITMENT_SIZE};
+
+/// Nonce of all the test cases
+const NONCE: [u8; 12] = hex!("000102030405060708090a0b");
+
+/// Associated data of the second test case of each AEAD
+const AAD: &[u8] = b"associated data";
+
+/// Plaintext of the second test case of each AEAD
+const PLAINTEXT: &[u8] = b"In the beginning the Universe was created.";
+
+macro_rules! tests {
+    ($name:ident, $aead:ty, $key:expr, $empty:expr, $ciphertext:expr) => {
+        mod $name {
+            use super::*;
+
+            const KEY: &[u8] = &$key;
+
+            /// Ciphertext of the empty message with no associated data
+            const EMPTY: &[u8] = &$empty;
+
+            /// Ciphertext of `PLAINTEXT` with `AAD`
+            const CIPHERTEXT: &[u8] = &$ciphertext;
+
+            fn cipher() -> Committing<$aead> {
+                Committing::new(GenericArray::from_slice(KEY))
+            }
+
+            #[test]
+            fn encrypt() {
+                let nonce = NONCE.into();
+                assert_eq!(cipher().encrypt(&nonce, b"".as_ref()).unwrap(), EMPTY);
+
+                let payload = Payload {
+                    msg: PLAINTEXT,
+                    aad: AAD,
+                };
+                assert_eq!(cipher().encrypt(&nonce, payload).unwrap(), CIPHERTEXT);
+            }
+
+            #[test]
+            fn decrypt() {
+                let nonce = NONCE.into();
+                assert!(cipher().decrypt(&nonce, EMPTY).unwrap().is_empty());
+
+                let payload = Payload {
+                    msg: CIPHERTEXT,
+                    aad: AAD,
+                };
+                assert_eq!(cipher().decrypt(&nonce, payload).unwrap(), PLAINTEXT);
+            }
+
+            #[test]
+            fn decrypt_modified() {
+                let mut ciphertext = CIPHERTEXT.to_vec();
+
+                // Tweak the first byte of the ciphertext, the first byte of the
+                // AES-GCM tag and the last byte of the commitment
+                let tag_pos = PLAINTEXT.len();
+                for &i in &[0, tag_pos, ciphertext.len() - 1] {
+                    ciphertext[i] ^= 1;
+                    let payload = Payload {
+                        msg: &ciphertext,
+                        aad: AAD,
+                    };
+                    assert!(cipher().decrypt(&NONCE.into(), payload).is_err());
+                    ciphertext[i] ^= 1;
+                }
+            }
+
+            #[test]
+            fn decrypt_wrong_key() {
+                let mut key = KEY.to_vec();
+                key[0] ^= 1;
+                let cipher = Committing::<$aead>::new(GenericArray::from_slice(&key));
+
+                let payload = Payload {
+                    msg: CIPHERTEXT,
+                    aad: AAD,
+                };
+                assert!(cipher.decrypt(&NONCE.into(), payload).is_err());
+            }
+
+            #[test]
+            fn decrypt_truncated() {
+                let payload = Payload {
+                    msg: &CIPHERTEXT[..COMMITMENT_SIZE - 1],
+                    aad: AAD,
+                };
+                assert!(cipher().decrypt(&NONCE.into(), payload).is_err());
+            }
+
+            #[test]
+            fn commitment() {
+                let nonce = NONCE.into();
+                let commitment = cipher().commitment(&nonce, AAD);
+                assert_eq!(
+                    &commitment,
+                    Committing::<$aead>::ciphertext_commitment(CIPHERTEXT).unwrap()
+                );
+
+                assert!(cipher().verify_commitment(&nonce, AAD, &commitment).is_ok());
+                assert!(cipher()
+                    .verify_commitment(&nonce, b"", &commitment)
+                    .is_err());
+                assert!(Committing::<$aead>::ciphertext_commitment(&[0; 31]).is_err());
+            }
+
+            #[test]
+            fn detached() {
+                let nonce = NONCE.into();
+                let mut buffer = PLAINTEXT.to_vec();
+                let tag = cipher()
+                    .encrypt_in_place_detached(&nonce, AAD, &mut buffer)
+                    .unwrap();
+                assert_eq!(&buffer, &CIPHERTEXT[..PLAINTEXT.len()]);
+                assert_eq!(tag.as_slice(), &CIPHERTEXT[PLAINTEXT.len()..]);
+
+                cipher()
+                    .decrypt_in_place_detached(&nonce, AAD, &mut buffer, &tag)
+                    .unwrap();
+                assert_eq!(&buffer, PLAINTEXT);
+            }
+        }
+    };
+}
+
+tests!(
+    aes128gcm,
+    Aes128Gcm,
+    hex!("000102030405060708090a0b0c0d0e0f"),
+    hex!(
+        "83876139465e7890f2058305797c189a
+         a95a64674cbd7cc925a043141d6b2d50c1e79670d4f2c18e435fda24db1dc76d"
+    ),
+    hex!(
+        "1354ef824a86d3f4f690888ffcd4e84e678bf09147397650489aad3b765fa8ab
+         c3f8cd87803b59da61f8
+         f673470b8d6909201b9cdac350cb6421
+         23d0b68c8ac39e5556ca0d02f25424d4a56bf5e2c726826667064d18e84aeed4"
+    )
+);
+
+tests!(
+    aes256gcm,
+    Aes256Gcm,
+    hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+    hex!(
+        "0d3cd69c0fb0607d56cfd6798d1a4a6d
+         6daf30bed850dc38cbda6165511c90b5c3f839521dab1ea5fe7c235fb3ad0953"
+    ),
+    hex!(
+        "93af462099ba69cab2e26c7712175524b3206a5bec1bf548bac5cd4cb95bba16
+         4819fd14a678e98062a9
+         5479e739be0edbd1b2eb3061d278437c
+         bf95cb795614d3f57b40547ec5446f13d7e4a24cc50af555dc8e4b7314147f65"
+    )
+);
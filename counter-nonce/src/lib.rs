@@ -0,0 +1,167 @@
+//! Counter nonces for [Authenticated Encryption with Associated Data
+//! (AEAD)][1] ciphers, which return an error when the counter is exhausted
+//! instead of wrapping around.
+//!
+//! [`CounterNonce`] hands out the nonces of an AEAD as a fixed prefix, e.g.
+//! identifying the sender, followed by a big-endian counter filling the rest
+//! of the nonce, as recommended by [RFC 5116 Section 3.2][2]. The counter
+//! starts at zero, and once its last value has been handed out
+//! [`CounterNonce::next_nonce`] returns [`Error::Exhausted`], so the key can
+//! be replaced before a nonce repeats under it.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed.
+//!
+//! Nonces are only unique as long as a single [`CounterNonce`] is used per
+//! key and prefix, and its state is never reset, e.g. by restarting a
+//! process with the same key. For that reason [`CounterNonce`] doesn't
+//! implement `Clone`.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! ```
+//! use chacha20poly1305::{ChaCha20Poly1305, Key};
+//! use chacha20poly1305::aead::{Aead, NewAead};
+//! use counter_nonce::CounterNonce;
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = ChaCha20Poly1305::new(key);
+//!
+//! // A 32-bit sender ID, followed by a 64-bit counter
+//! let mut nonces = CounterNonce::<ChaCha20Poly1305>::new(&[0, 0, 0, 1])
+//!     .expect("prefix too long");
+//!
+//! let nonce = nonces.next_nonce()
+//!     .expect("nonces exhausted"); // NOTE: rekey when the nonces are exhausted
+//! assert_eq!(nonce.as_slice(), &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0]);
+//!
+//! let ciphertext = cipher.encrypt(&nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(&nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! assert_eq!(nonces.next_nonce().unwrap()[11], 1);
+//! ```
+//!
+//! [1]: https://en.wikipedia.org/wiki/Authenticated_encryption
+//! [2]: https://www.rfc-editor.org/rfc/rfc5116#section-3.2
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+pub use aead::{self, AeadCore};
+
+use aead::{generic_array::typenum::Unsigned, Nonce};
+use core::fmt;
+
+/// Errors of [`CounterNonce`]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The prefix leaves no room for the counter in the nonce
+    PrefixLength,
+
+    /// Every value of the counter has been handed out
+    Exhausted,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::PrefixLength => "nonce prefix leaves no room for the counter",
+            Error::Exhausted => "nonce counter exhausted",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
+
+/// Manager of the nonces of an AEAD `A`: a fixed prefix followed by a
+/// big-endian counter.
+///
+/// See the [toplevel documentation](index.html) for a usage example.
+pub struct CounterNonce<A>
+where
+    A: AeadCore,
+{
+    /// Next nonce to hand out
+    nonce: Nonce<A>,
+
+    /// Length of the prefix of the nonces
+    prefix_len: usize,
+
+    /// Whether every value of the counter has been handed out
+    exhausted: bool,
+}
+
+impl<A> CounterNonce<A>
+where
+    A: AeadCore,
+{
+    /// Create a manager of the nonces starting with `prefix`, whose counter
+    /// takes the remaining bytes of the nonce.
+    ///
+    /// Returns [`Error::PrefixLength`] unless the prefix is shorter than the
+    /// nonce.
+    pub fn new(prefix: &[u8]) -> Result<Self, Error> {
+        if prefix.len() >= A::NonceSize::to_usize() {
+            return Err(Error::PrefixLength);
+        }
+
+        let mut nonce = Nonce::<A>::default();
+        nonce[..prefix.len()].copy_from_slice(prefix);
+
+        Ok(Self {
+            nonce,
+            prefix_len: prefix.len(),
+            exhausted: false,
+        })
+    }
+
+    /// Size of the counter in bytes
+    pub fn counter_size(&self) -> usize {
+        A::NonceSize::to_usize() - self.prefix_len
+    }
+
+    /// Hand out the next nonce, or return [`Error::Exhausted`] once every
+    /// value of the counter has been handed out.
+    pub fn next_nonce(&mut self) -> Result<Nonce<A>, Error> {
+        if self.exhausted {
+            return Err(Error::Exhausted);
+        }
+
+        let nonce = self.nonce.clone();
+
+        // Increment the counter, which is exhausted if it would wrap around
+        self.exhausted = true;
+        for byte in self.nonce[self.prefix_len..].iter_mut().rev() {
+            let (sum, carry) = byte.overflowing_add(1);
+            *byte = sum;
+            if !carry {
+                self.exhausted = false;
+                break;
+            }
+        }
+
+        Ok(nonce)
+    }
+
+    /// Whether every value of the counter has been handed out
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+}
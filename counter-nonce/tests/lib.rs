@@ -0,0 +1,84 @@
+//! Tests of `CounterNonce`
+
+use aes_gcm::Aes128Gcm;
+use chacha20poly1305::aead::{Aead, NewAead};
+use chacha20poly1305::{ChaCha20Poly1305, XChaCha20Poly1305};
+use counter_nonce::{CounterNonce, Error};
+
+#[test]
+fn first_nonces() {
+    let mut nonces = CounterNonce::<ChaCha20Poly1305>::new(&[0xa0, 0xa1, 0xa2, 0xa3]).unwrap();
+    assert_eq!(nonces.counter_size(), 8);
+
+    for i in 0..3 {
+        let nonce = nonces.next_nonce().unwrap();
+        assert_eq!(
+            nonce.as_slice(),
+            &[0xa0, 0xa1, 0xa2, 0xa3, 0, 0, 0, 0, 0, 0, 0, i]
+        );
+    }
+}
+
+#[test]
+fn carry() {
+    let mut nonces = CounterNonce::<ChaCha20Poly1305>::new(&[0x42; 10]).unwrap();
+
+    for _ in 0..255 {
+        nonces.next_nonce().unwrap();
+    }
+    assert_eq!(&nonces.next_nonce().unwrap()[10..], &[0x00, 0xff]);
+    assert_eq!(&nonces.next_nonce().unwrap()[10..], &[0x01, 0x00]);
+    assert_eq!(&nonces.next_nonce().unwrap()[..10], &[0x42; 10]);
+}
+
+/// A counter of one byte hands out 256 nonces, and then stays exhausted
+/// instead of wrapping around
+#[test]
+fn exhausted() {
+    let mut nonces = CounterNonce::<ChaCha20Poly1305>::new(&[0xff; 11]).unwrap();
+    assert_eq!(nonces.counter_size(), 1);
+
+    for i in 0..=255 {
+        assert!(!nonces.is_exhausted());
+        assert_eq!(nonces.next_nonce().unwrap()[11], i);
+    }
+
+    assert!(nonces.is_exhausted());
+    assert_eq!(nonces.next_nonce(), Err(Error::Exhausted));
+    assert_eq!(nonces.next_nonce(), Err(Error::Exhausted));
+}
+
+#[test]
+fn prefix_length() {
+    assert!(CounterNonce::<Aes128Gcm>::new(&[0; 12]).is_err());
+    assert!(CounterNonce::<Aes128Gcm>::new(&[0; 13]).is_err());
+    assert_eq!(
+        CounterNonce::<XChaCha20Poly1305>::new(&[0; 24]).err(),
+        Some(Error::PrefixLength)
+    );
+
+    let mut nonces = CounterNonce::<XChaCha20Poly1305>::new(&[]).unwrap();
+    assert_eq!(nonces.counter_size(), 24);
+    assert_eq!(nonces.next_nonce().unwrap().as_slice(), &[0; 24]);
+}
+
+#[test]
+fn encrypt() {
+    let cipher = Aes128Gcm::new(&[0x42; 16].into());
+    let mut nonces = CounterNonce::<Aes128Gcm>::new(&[0, 0, 0, 7]).unwrap();
+
+    let nonce_a = nonces.next_nonce().unwrap();
+    let nonce_b = nonces.next_nonce().unwrap();
+    let a = cipher
+        .encrypt(&nonce_a, b"attack at dawn".as_ref())
+        .unwrap();
+    let b = cipher
+        .encrypt(&nonce_b, b"attack at dawn".as_ref())
+        .unwrap();
+    assert_ne!(a, b);
+
+    assert_eq!(
+        cipher.decrypt(&nonce_b, b.as_ref()).unwrap(),
+        b"attack at dawn"
+    );
+}
@@ -0,0 +1,222 @@
+//! Deoxys-BC: the AES-based tweakable block cipher Deoxys is built upon.
+//!
+//! The 128-bit tweak is the first word `TK1` of the tweakey, and the key
+//! makes up the remaining words: `TK2` for Deoxys-BC-256, and `TK3 || TK2`
+//! for Deoxys-BC-384. As only `TK1` depends on the tweak, the contribution
+//! of the key and of the round constants to each subtweakey is precomputed
+//! when the cipher is instantiated.
+
+use aead::{
+    consts::{U16, U32},
+    generic_array::{ArrayLength, GenericArray},
+};
+use aes::hazmat::{cipher_round, equiv_inv_cipher_round, inv_mix_columns, mix_columns};
+use zeroize::Zeroize;
+
+/// Deoxys-BC blocks (128-bit)
+pub type Block = GenericArray<u8, U16>;
+
+/// Deoxys-BC tweaks (128-bit)
+pub type Tweak = GenericArray<u8, U16>;
+
+/// Maximum number of subtweakeys: 16 rounds of Deoxys-BC-384, plus the
+/// initial one
+const MAX_SUBTWEAKEYS: usize = 17;
+
+/// Tweakey permutation `h`
+const H: [usize; 16] = [1, 6, 11, 12, 5, 10, 15, 0, 9, 14, 3, 4, 13, 2, 7, 8];
+
+/// Round constants
+const RCON: [u8; MAX_SUBTWEAKEYS] = [
+    0x2f, 0x5e, 0xbc, 0x63, 0xc6, 0x97, 0x35, 0x6a, 0xd4, 0xb3, 0x7d, 0xfa, 0xef, 0xc5, 0x91, 0x39,
+    0x72,
+];
+
+mod private {
+    // Sealed traits stop other crates from implementing any traits that use it.
+    pub trait SealedBc {}
+}
+
+/// Deoxys-BC tweakable block ciphers with a 128-bit tweak. This trait is
+/// sealed, and implemented by [`DeoxysBc256`] and [`DeoxysBc384`].
+pub trait DeoxysBc: private::SealedBc + Clone {
+    /// Size of the key
+    type KeySize: ArrayLength<u8>;
+
+    /// Create a new cipher instance from a key.
+    fn new(key: &GenericArray<u8, Self::KeySize>) -> Self;
+
+    /// Encrypt a block in-place under `tweak`.
+    fn encrypt_block(&self, tweak: &Tweak, block: &mut Block);
+
+    /// Decrypt a block in-place under `tweak`.
+    fn decrypt_block(&self, tweak: &Tweak, block: &mut Block);
+}
+
+macro_rules! impl_deoxys_bc {
+    ($name:ident, $key_size:ident, $rounds:expr, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone)]
+        pub struct $name {
+            /// Key and round constant part of each subtweakey
+            subkeys: [[u8; 16]; $rounds + 1],
+        }
+
+        impl private::SealedBc for $name {}
+
+        impl DeoxysBc for $name {
+            type KeySize = $key_size;
+
+            fn new(key: &GenericArray<u8, $key_size>) -> Self {
+                let mut subkeys = [[0; 16]; $rounds + 1];
+                key_schedule(key, &mut subkeys);
+                Self { subkeys }
+            }
+
+            fn encrypt_block(&self, tweak: &Tweak, block: &mut Block) {
+                encrypt(&self.subkeys, tweak, block);
+            }
+
+            fn decrypt_block(&self, tweak: &Tweak, block: &mut Block) {
+                decrypt(&self.subkeys, tweak, block);
+            }
+        }
+
+        impl Drop for $name {
+            fn drop(&mut self) {
+                for subkey in self.subkeys.iter_mut() {
+                    subkey.zeroize();
+                }
+            }
+        }
+    };
+}
+
+impl_deoxys_bc!(
+    DeoxysBc256,
+    U16,
+    14,
+    "Deoxys-BC-256: 128-bit key, 128-bit tweak and 14 rounds"
+);
+impl_deoxys_bc!(
+    DeoxysBc384,
+    U32,
+    16,
+    "Deoxys-BC-384: 256-bit key, 128-bit tweak and 16 rounds"
+);
+
+/// Expand a 128-bit key (`TK2`) or a 256-bit key (`TK3 || TK2`) into the
+/// key part of `subkeys`.
+fn key_schedule(key: &[u8], subkeys: &mut [[u8; 16]]) {
+    let mut tk2 = [0u8; 16];
+    let mut tk3 = [0u8; 16];
+
+    if key.len() == 16 {
+        tk2.copy_from_slice(key);
+    } else {
+        tk3.copy_from_slice(&key[..16]);
+        tk2.copy_from_slice(&key[16..]);
+    }
+
+    for (i, subkey) in subkeys.iter_mut().enumerate() {
+        if i != 0 {
+            h(&mut tk2);
+            tk2.iter_mut().for_each(|b| *b = lfsr2(*b));
+
+            if key.len() == 32 {
+                h(&mut tk3);
+                tk3.iter_mut().for_each(|b| *b = lfsr3(*b));
+            }
+        }
+
+        let rc = RCON[i];
+        let rcon = [1, 2, 4, 8, rc, rc, rc, rc, 0, 0, 0, 0, 0, 0, 0, 0];
+
+        for (j, b) in subkey.iter_mut().enumerate() {
+            *b = tk2[j] ^ tk3[j] ^ rcon[j];
+        }
+    }
+
+    tk2.zeroize();
+    tk3.zeroize();
+}
+
+/// Compute the subtweakeys for `tweak`.
+fn subtweakeys(subkeys: &[[u8; 16]], tweak: &Tweak, stks: &mut [Block; MAX_SUBTWEAKEYS]) {
+    let mut tk1 = [0u8; 16];
+    tk1.copy_from_slice(tweak);
+
+    for (i, (stk, subkey)) in stks.iter_mut().zip(subkeys).enumerate() {
+        if i != 0 {
+            h(&mut tk1);
+        }
+
+        for (j, b) in stk.iter_mut().enumerate() {
+            *b = tk1[j] ^ subkey[j];
+        }
+    }
+}
+
+fn encrypt(subkeys: &[[u8; 16]], tweak: &Tweak, block: &mut Block) {
+    let mut stks = [Block::default(); MAX_SUBTWEAKEYS];
+    subtweakeys(subkeys, tweak, &mut stks);
+
+    for (b, k) in block.iter_mut().zip(stks[0].iter()) {
+        *b ^= k;
+    }
+
+    for stk in &stks[1..subkeys.len()] {
+        cipher_round(block, stk);
+    }
+
+    zeroize_blocks(&mut stks);
+}
+
+/// Every round of Deoxys-BC includes `MixColumns`, so the equivalent inverse
+/// cipher rounds of AES are used with `InvMixColumns` applied to the
+/// subtweakeys, along with an additional `InvMixColumns` at the start and
+/// `MixColumns` at the end.
+fn decrypt(subkeys: &[[u8; 16]], tweak: &Tweak, block: &mut Block) {
+    let mut stks = [Block::default(); MAX_SUBTWEAKEYS];
+    subtweakeys(subkeys, tweak, &mut stks);
+
+    let (last, stks_rev) = stks[..subkeys.len()].split_last_mut().unwrap();
+
+    for (b, k) in block.iter_mut().zip(last.iter()) {
+        *b ^= k;
+    }
+    inv_mix_columns(block);
+
+    for stk in stks_rev.iter_mut().rev() {
+        inv_mix_columns(stk);
+        equiv_inv_cipher_round(block, stk);
+    }
+    mix_columns(block);
+
+    zeroize_blocks(&mut stks);
+}
+
+/// Tweakey permutation
+fn h(tk: &mut [u8; 16]) {
+    let mut permuted = [0u8; 16];
+    for (b, &i) in permuted.iter_mut().zip(H.iter()) {
+        *b = tk[i];
+    }
+    *tk = permuted;
+}
+
+/// LFSR applied to each byte of `TK2`
+fn lfsr2(b: u8) -> u8 {
+    (b << 1) | (((b >> 7) ^ (b >> 5)) & 1)
+}
+
+/// LFSR applied to each byte of `TK3`
+fn lfsr3(b: u8) -> u8 {
+    (b >> 1) | (((b << 7) ^ (b << 1)) & 0x80)
+}
+
+fn zeroize_blocks(blocks: &mut [Block]) {
+    for block in blocks.iter_mut() {
+        block.as_mut_slice().zeroize();
+    }
+}
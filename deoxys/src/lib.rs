@@ -0,0 +1,436 @@
+//! [Deoxys][1]: [Authenticated Encryption with Associated Data (AEAD)][2]
+//! ciphers built on the Deoxys-BC tweakable block cipher.
+//!
+//! Deoxys-II was selected as the first choice for defense in depth in the
+//! final portfolio of the [CAESAR competition][3]. The following variants
+//! are implemented:
+//!
+//! - [`DeoxysI128`] and [`DeoxysI256`]: Deoxys-I, with 64-bit nonces
+//! - [`DeoxysII128`] and [`DeoxysII256`]: Deoxys-II, with 120-bit nonces
+//!
+//! All of them produce a 128-bit authentication tag, and take a 128-bit or a
+//! 256-bit key depending on the Deoxys-BC variant they use.
+//!
+//! ## Tweakable Block Cipher
+//!
+//! The Deoxys-BC-256 and Deoxys-BC-384 tweakable block ciphers are available
+//! as [`DeoxysBc256`] and [`DeoxysBc384`], through the [`DeoxysBc`] trait,
+//! to build other modes of operation upon them (e.g. for disk encryption):
+//!
+//! ```
+//! use deoxys::{Block, DeoxysBc, DeoxysBc384, Tweak};
+//! use deoxys::aead::generic_array::GenericArray;
+//!
+//! let key = GenericArray::from_slice(b"an example very very secret key.");
+//! let cipher = DeoxysBc384::new(key);
+//!
+//! let tweak = Tweak::from(42u128.to_be_bytes()); // e.g. a sector number
+//! let mut block = Block::clone_from_slice(b"a 128-bit block!");
+//!
+//! cipher.encrypt_block(&tweak, &mut block);
+//! assert_ne!(block.as_slice(), b"a 128-bit block!");
+//!
+//! cipher.decrypt_block(&tweak, &mut block);
+//! assert_eq!(block.as_slice(), b"a 128-bit block!");
+//! ```
+//!
+//! ## Performance Notes
+//!
+//! The AES round function is provided by the [`aes`] crate's `hazmat` API,
+//! which uses AES-NI on x86/x86_64 when it is detected at runtime and falls
+//! back to a portable bitsliced implementation otherwise.
+//!
+//! On aarch64 the ARMv8 Cryptography Extensions can be used by enabling the
+//! nightly-only `armv8` feature of this crate.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! Deoxys-I is not nonce misuse-resistant: reusing a nonce under the same key
+//! breaks the confidentiality and the authenticity of the messages encrypted
+//! with it. Deoxys-II only leaks whether the messages encrypted with the same
+//! nonce are equal, and shares prefixes of their keystream.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! ```
+//! use deoxys::{DeoxysII256, Key, Nonce}; // Or `DeoxysI128`, `DeoxysI256` or `DeoxysII128`
+//! use deoxys::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = DeoxysII256::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce120"); // 64-bits for Deoxys-I or 120-bits for Deoxys-II; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! Note that if you enable the `heapless` feature of this crate,
+//! you will receive an impl of [`aead::Buffer`] for `heapless::Vec`
+//! (re-exported from the [`aead`] crate as [`aead::heapless::Vec`]),
+//! which can then be passed as the `buffer` parameter to the in-place encrypt
+//! and decrypt methods:
+//!
+//! ```
+//! # #[cfg(feature = "heapless")]
+//! # {
+//! use deoxys::{DeoxysII256, Key, Nonce};
+//! use deoxys::aead::{AeadInPlace, NewAead};
+//! use deoxys::aead::heapless::Vec;
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = DeoxysII256::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce120"); // 120-bits; unique per message
+//!
+//! let mut buffer: Vec<u8, 128> = Vec::new(); // Buffer needs 16-bytes overhead for the tag
+//! buffer.extend_from_slice(b"plaintext message");
+//!
+//! // Encrypt `buffer` in-place, replacing the plaintext contents with ciphertext
+//! cipher.encrypt_in_place(nonce, b"", &mut buffer).expect("encryption failure!");
+//!
+//! // `buffer` now contains the message ciphertext
+//! assert_ne!(&buffer, b"plaintext message");
+//!
+//! // Decrypt `buffer` in-place, replacing its ciphertext context with the original plaintext
+//! cipher.decrypt_in_place(nonce, b"", &mut buffer).expect("decryption failure!");
+//! assert_eq!(&buffer, b"plaintext message");
+//! # }
+//! ```
+//!
+//! [1]: https://competitions.cr.yp.to/round3/deoxysv141.pdf
+//! [2]: https://en.wikipedia.org/wiki/Authenticated_encryption
+//! [3]: https://competitions.cr.yp.to/caesar-submissions.html
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![deny(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+mod deoxys_bc;
+
+pub use aead::{self, consts, AeadCore, AeadInPlace, Error, NewAead};
+pub use deoxys_bc::{Block, DeoxysBc, DeoxysBc256, DeoxysBc384, Tweak};
+
+use aead::{
+    consts::{U0, U15, U16, U8},
+    generic_array::GenericArray,
+};
+use subtle::ConstantTimeEq;
+
+/// Deoxys keys
+pub type Key<KeySize> = GenericArray<u8, KeySize>;
+
+/// Deoxys nonces
+pub type Nonce<NonceSize> = GenericArray<u8, NonceSize>;
+
+/// Deoxys tags (128-bit)
+pub type Tag = GenericArray<u8, U16>;
+
+/// Deoxys-I with Deoxys-BC-256: 128-bit key
+pub type DeoxysI128 = DeoxysI<DeoxysBc256>;
+
+/// Deoxys-I with Deoxys-BC-384: 256-bit key
+pub type DeoxysI256 = DeoxysI<DeoxysBc384>;
+
+/// Deoxys-II with Deoxys-BC-256: 128-bit key
+pub type DeoxysII128 = DeoxysII<DeoxysBc256>;
+
+/// Deoxys-II with Deoxys-BC-384: 256-bit key
+pub type DeoxysII256 = DeoxysII<DeoxysBc384>;
+
+/// Tweak prefix of associated data blocks
+const TWEAK_AD: u8 = 0b0010;
+
+/// Tweak prefix of the last, partial, associated data block
+const TWEAK_AD_LAST: u8 = 0b0110;
+
+/// Tweak prefix of message blocks
+const TWEAK_M: u8 = 0b0000;
+
+/// Tweak prefix of the last, partial, message block
+const TWEAK_M_LAST: u8 = 0b0100;
+
+/// Tweak prefix of the tag
+const TWEAK_TAG: u8 = 0b0001;
+
+/// Tweak prefix of the checksum of a message ending with a partial block
+const TWEAK_CHKSUM: u8 = 0b0101;
+
+/// Deoxys-I: the nonce-respecting mode, with 64-bit nonces.
+#[derive(Clone)]
+pub struct DeoxysI<B: DeoxysBc> {
+    cipher: B,
+}
+
+impl<B: DeoxysBc> NewAead for DeoxysI<B> {
+    type KeySize = B::KeySize;
+
+    fn new(key: &Key<B::KeySize>) -> Self {
+        Self {
+            cipher: B::new(key),
+        }
+    }
+}
+
+impl<B: DeoxysBc> AeadCore for DeoxysI<B> {
+    type NonceSize = U8;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl<B: DeoxysBc> AeadInPlace for DeoxysI<B> {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<U8>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        let nonce = nonce_offset(nonce);
+        let mut tag = authenticate(&self.cipher, TWEAK_AD, TWEAK_AD_LAST, associated_data);
+        let mut checksum = Block::default();
+
+        let mut chunks = buffer.chunks_exact_mut(16);
+        let mut i = 0;
+        for chunk in &mut chunks {
+            xor(&mut checksum, chunk);
+
+            let block = Block::from_mut_slice(chunk);
+            self.cipher.encrypt_block(&tweak(TWEAK_M, nonce | i), block);
+            i += 1;
+        }
+
+        let rem = chunks.into_remainder();
+        let prefix = if rem.is_empty() {
+            TWEAK_TAG
+        } else {
+            let mut pad = Block::default();
+            self.cipher
+                .encrypt_block(&tweak(TWEAK_M_LAST, nonce | i), &mut pad);
+
+            xor(&mut checksum, &padded(rem));
+            xor(rem, &pad);
+            i += 1;
+            TWEAK_CHKSUM
+        };
+
+        self.cipher
+            .encrypt_block(&tweak(prefix, nonce | i), &mut checksum);
+        xor(&mut tag, &checksum);
+        Ok(tag)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<U8>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        let nonce = nonce_offset(nonce);
+        let mut expected_tag = authenticate(&self.cipher, TWEAK_AD, TWEAK_AD_LAST, associated_data);
+        let mut checksum = Block::default();
+
+        let mut chunks = buffer.chunks_exact_mut(16);
+        let mut i = 0;
+        for chunk in &mut chunks {
+            let block = Block::from_mut_slice(chunk);
+            self.cipher.decrypt_block(&tweak(TWEAK_M, nonce | i), block);
+            i += 1;
+
+            xor(&mut checksum, chunk);
+        }
+
+        let rem = chunks.into_remainder();
+        let prefix = if rem.is_empty() {
+            TWEAK_TAG
+        } else {
+            let mut pad = Block::default();
+            self.cipher
+                .encrypt_block(&tweak(TWEAK_M_LAST, nonce | i), &mut pad);
+
+            xor(rem, &pad);
+            xor(&mut checksum, &padded(rem));
+            i += 1;
+            TWEAK_CHKSUM
+        };
+
+        self.cipher
+            .encrypt_block(&tweak(prefix, nonce | i), &mut checksum);
+        xor(&mut expected_tag, &checksum);
+
+        if expected_tag.ct_eq(tag).into() {
+            Ok(())
+        } else {
+            // Don't release the unauthenticated plaintext
+            buffer.iter_mut().for_each(|b| *b = 0);
+            Err(Error)
+        }
+    }
+}
+
+/// Deoxys-II: the nonce misuse-resistant mode, with 120-bit nonces.
+///
+/// The tag is computed over the associated data and the plaintext, and then
+/// used as the tweak of the counter mode encrypting the plaintext.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone)]
+pub struct DeoxysII<B: DeoxysBc> {
+    cipher: B,
+}
+
+impl<B: DeoxysBc> NewAead for DeoxysII<B> {
+    type KeySize = B::KeySize;
+
+    fn new(key: &Key<B::KeySize>) -> Self {
+        Self {
+            cipher: B::new(key),
+        }
+    }
+}
+
+impl<B: DeoxysBc> AeadCore for DeoxysII<B> {
+    type NonceSize = U15;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl<B: DeoxysBc> AeadInPlace for DeoxysII<B> {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<U15>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        let tag = self.compute_tag(nonce, associated_data, buffer);
+        self.apply_keystream(nonce, &tag, buffer);
+        Ok(tag)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<U15>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        self.apply_keystream(nonce, tag, buffer);
+        let expected_tag = self.compute_tag(nonce, associated_data, buffer);
+
+        if expected_tag.ct_eq(tag).into() {
+            Ok(())
+        } else {
+            // Don't release the unauthenticated plaintext
+            buffer.iter_mut().for_each(|b| *b = 0);
+            Err(Error)
+        }
+    }
+}
+
+impl<B: DeoxysBc> DeoxysII<B> {
+    /// Authenticate the associated data and the plaintext, and encrypt the
+    /// result under the nonce.
+    fn compute_tag(&self, nonce: &Nonce<U15>, associated_data: &[u8], buffer: &[u8]) -> Tag {
+        let mut tag = authenticate(&self.cipher, TWEAK_AD, TWEAK_AD_LAST, associated_data);
+        xor(
+            &mut tag,
+            &authenticate(&self.cipher, TWEAK_M, TWEAK_M_LAST, buffer),
+        );
+
+        let mut tweak = Tweak::default();
+        tweak[0] = TWEAK_TAG << 4;
+        tweak[1..].copy_from_slice(nonce);
+        self.cipher.encrypt_block(&tweak, &mut tag);
+        tag
+    }
+
+    /// Counter mode over `0^8 || nonce`, with the tag (with its first bit set)
+    /// XORed with the block number as the tweak.
+    fn apply_keystream(&self, nonce: &Nonce<U15>, tag: &Tag, buffer: &mut [u8]) {
+        let mut tag_bytes = [0u8; 16];
+        tag_bytes.copy_from_slice(tag);
+        let tweak = u128::from_be_bytes(tag_bytes) | 1 << 127;
+
+        for (i, chunk) in buffer.chunks_mut(16).enumerate() {
+            let mut block = Block::default();
+            block[1..].copy_from_slice(nonce);
+
+            self.cipher
+                .encrypt_block(&Tweak::from((tweak ^ i as u128).to_be_bytes()), &mut block);
+            xor(chunk, &block);
+        }
+    }
+}
+
+/// The hash shared by the associated data of both modes and the plaintext of
+/// Deoxys-II: the sum of the encryptions of each block, under a tweak made of
+/// `prefix` and the block number, or `prefix_last` for the last partial
+/// block, which is padded with `10*`.
+fn authenticate<B: DeoxysBc>(cipher: &B, prefix: u8, prefix_last: u8, data: &[u8]) -> Tag {
+    let mut sum = Tag::default();
+
+    for (i, chunk) in data.chunks(16).enumerate() {
+        let (prefix, mut block) = if chunk.len() == 16 {
+            (prefix, Block::clone_from_slice(chunk))
+        } else {
+            (prefix_last, padded(chunk))
+        };
+
+        cipher.encrypt_block(&tweak(prefix, i as u128), &mut block);
+        xor(&mut sum, &block);
+    }
+
+    sum
+}
+
+/// Deoxys-I nonce, positioned after the 4-bit prefix of the tweak, followed
+/// by the 60-bit block number
+fn nonce_offset(nonce: &Nonce<U8>) -> u128 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(nonce);
+    u128::from(u64::from_be_bytes(bytes)) << 60
+}
+
+/// Tweak made of a 4-bit `prefix` followed by `value`
+fn tweak(prefix: u8, value: u128) -> Tweak {
+    Tweak::from((u128::from(prefix) << 124 | value).to_be_bytes())
+}
+
+/// Pad a partial block with `10*`
+fn padded(data: &[u8]) -> Block {
+    let mut block = Block::default();
+    block[..data.len()].copy_from_slice(data);
+    block[data.len()] = 0x80;
+    block
+}
+
+#[inline]
+fn xor(out: &mut [u8], data: &[u8]) {
+    for (a, b) in out.iter_mut().zip(data) {
+        *a ^= b;
+    }
+}
@@ -0,0 +1,750 @@
+//! Deoxys tests
+
+#[macro_use]
+extern crate hex_literal;
+
+use deoxys::aead::{generic_array::GenericArray, Aead, NewAead, Payload};
+use deoxys::{
+    Block, DeoxysBc, DeoxysBc256, DeoxysBc384, DeoxysI128, DeoxysI256, DeoxysII128, DeoxysII256,
+    Tweak,
+};
+
+/// Test vectors
+#[derive(Debug)]
+struct TestVector {
+    key: &'static [u8],
+    nonce: &'static [u8],
+    aad: &'static [u8],
+    plaintext: &'static [u8],
+    ciphertext: &'static [u8],
+}
+
+// Test vectors of the Deoxys v1.41 submission to CAESAR
+
+const DEOXYS_I_128_VECTORS: &[TestVector] = &[
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f"),
+        nonce: &hex!("2021222324252627"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!("eec87dce98d29d4078598abd16d550ff"),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f"),
+        nonce: &hex!("2021222324252627"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        plaintext: &hex!(""),
+        ciphertext: &hex!("b507e4aee5f9d7cb9eaebd8370f25a98"),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f"),
+        nonce: &hex!("2021222324252627"),
+        aad: &hex!(
+            "
+            0429974cda6665fb9bb4b67d50859258dd69883d50c1eff4bd5962bf4038ad04
+            97
+            "
+        ),
+        plaintext: &hex!(""),
+        ciphertext: &hex!("fbb9c589e3a54df11e8573d94e6b1000"),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f"),
+        nonce: &hex!("2021222324252627"),
+        aad: &hex!(""),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        ciphertext: &hex!(
+            "
+            4bf8c5ecec375b25acabd687aa605f1a8bb296face74f82527d4944dbb11b757
+            f32754de1727da4909413815a64e6a69
+            "
+        ),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f"),
+        nonce: &hex!("2021222324252627"),
+        aad: &hex!(""),
+        plaintext: &hex!(
+            "
+            5a4c652cb880808707230679224b11799b5883431292973215e9bd03cf3bc32f
+            e4
+            "
+        ),
+        ciphertext: &hex!(
+            "
+            cded5a43d3c76e942277c2a1517530ad66037897c985305ede345903ed7585a6
+            26cbf5faa6b8398c47f4278d2019161776
+            "
+        ),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f"),
+        nonce: &hex!("2021222324252627"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        ciphertext: &hex!(
+            "
+            4bf8c5ecec375b25acabd687aa605f1a8bb296face74f82527d4944dbb11b757
+            a1b897f1901e5d98e17936ec1b4d85b3
+            "
+        ),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f"),
+        nonce: &hex!("2021222324252627"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f10"),
+        plaintext: &hex!(
+            "
+            ee8f487e01f5a101dee6cfd5915d6b5b2c5b6305c782bc7e727bd08096e42082
+            16
+            "
+        ),
+        ciphertext: &hex!(
+            "
+            09af865850abc0bce7d35f664a63e41b1475d0385e31a6551edf69ea9f2f8b8e
+            d49326c6c2a0b7f065e591eb9050169603
+            "
+        ),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f"),
+        nonce: &hex!("2021222324252627"),
+        aad: &hex!(
+            "
+            d4e7fc007c9f462d3c2f3ee1c2b92597a838be68930fcc770d3f4a6e8d3f2455
+            67c28772c7891c8a605e3f64dd584c264685794c23458c0faf8bbfc5925fe827
+            8eaa1f35322b78c27fcfad42da7f1e9c4ab3aea98c236846690eeb63a26eb60f
+            4cdaef83c3941b57b81529704e404444ed541269428baecd17f4e7f3bde62566
+            b65b578eba069990e8fb10696d94e925ec41b9142de25cd30750cabd41d0a100
+            bebe5eeada44caabff9ede3c251bb57bb48dfb90f7bb9f7d82f131ee20788ff3
+            d9435f8c4f1590cd3cf2dbda143d8a6bcec5e95834578d46561ea209b4d29b1b
+            b74c2c5d1f1bb765cd1d3a1e95984e7f257f4a8a91b3d3d587b43a4023593948
+            d0a58fb1be920f493e5615abd2ecd38f45ed8c440c427a0d2eb76f91adee4c11
+            9ac980f28d87585a68039761dbea738a006ec0d9a7dde2ea873c4cf27c8b3565
+            d776473f247b30198e62d4bc722b84d6260bb9e4b8c36dbf1ce6a2b91211bc25
+            d1c0797c5b992920810e78ea6e474f69c9f14550eac375e896a2e5facebcf97b
+            bf5bfdb547ef202222693b4c3120fe8a9559bee514e0b6d9a711a632a7d55398
+            ddd8de66ef3b6f8dd8fa468d27ca455a5fcda20dd12aa426053e9f8454d9598e
+            2d6a528aa4ffe272a4f1341e695dbb1b43bd720ab87ba62290e2d3f78a497a20
+            d1bb0ed72430698b857774d6414ca856019660aba783ff9794d395c82de41a03
+            1a
+            "
+        ),
+        plaintext: &hex!(
+            "
+            1857d4edf080e8e2c83aa9e794ebf90d1ea0ccb977287a019aca3daa7af2ad57
+            09d63f05b5b00f4b004b56e802d298ea78afd5d21fd2619248a0897b8e141dc6
+            e1f8b49056d570571a294152a7d7387dbac1ab9ff799dbe0e6c3ae23a14908a3
+            e48eb224824eee8ea4ee3b4ab1bd12a81e3a393ca1344fd9ca5309b116ab2e49
+            e12020f1d6d3bbf608c4e33472c33c6a8d088124c0de4161d94833d75a9bfde9
+            08d57d182675c992ad8545198ad2565bac43ce1786e92ec01961c424c1b4c23b
+            c97959ed185193c08e49c6741061e300c94216e505569bcc528f4ced786d1939
+            b4568be157a4b9231b1baf19fc90ee35e97dbfb2965468c2882f1706c6ccec31
+            be7759640c4a2a8a22ecac433eba2223d9685215a8e12bf262f4a72a8bb85ef4
+            181b1d513218a657a24f2903da166f06abd27fbd757ad87473deb844c24e7f7a
+            9295299580bdb1a99acf53a2cc3b1234fb9b0976b6b0ae42605536f46239d1eb
+            d1283adf41f250761d54280e65d79e16200b16d899702530314c6eb5bcb0f1de
+            6d61eaa7ea4c097075ac691754cb1eebbe7ff8cfc39000d9eca154ea37a9d635
+            385b1e132ac3a0d3ffdc362b4333db6b56960cd0d86d02f08ea6e6e1e20a12b7
+            d0b0fe897ab2fcb43f44afb2d42326b2d8d0531e6c9c64aae896caa74299c6d8
+            e10a45360d67373aae7326a1b0484aa42e970510ecb02ca739c38183a43881e6
+            "
+        ),
+        ciphertext: &hex!(
+            "
+            f86ecad0d69d2c573cdeee96c90f37ac3c861bd5f4d82ac7396dda102adfa7a9
+            4f1daab1e537f03b2a6665eaa8ee057eee403db7ced61adbd77b5c286b7afc5e
+            c23f3f9333773f02d533b0c49ecfc6bcd359bc8a3db6ab16b423efc93e2591e5
+            485a5b21a8cf9312a10d76c840bd1a7e9f5a9954cb636b01ebc8e91a550a0123
+            a50883627d5535f0f6a7960f005d5f340e054ea145dd756e37efd91bc774f93d
+            385da7135372bc51d0401e6499784618da55c31e0b7ad1aa09a3e002f3021ce0
+            2926c79741992d9d0252761a7ca6667a56f78e81eaf08cf36d4117d9b2349262
+            d411bef955d7408562ed040e1ea85e3aa3dcf942ea5205edec164dbd6304f90d
+            a59b9fb4f8fdeb2c2df473f90494cf09c6af69d191abd7baf97058a3694872d0
+            1f63afc225e3796251375a7520a5f755b24b8fd153f362ff09c7e85f02e789ed
+            8cf8adabfcde4c764ebdd703dee39b4e90a91ab0377e0bebc61b2ec9b3c4e3ac
+            7fd893e13c5d0e303e7e625281c988a48dcfd9ee4b698a1c2a82927168e754c9
+            9338ea24d24b9bba11cdb4472badc038ab01f250d359c4ade703329062c6260d
+            8fcfda3a6b50b641f9e1e5f2107fd6ca77140dba9048919cab4ea21e4178fde0
+            8e7213bf0b730c0415331775039e99f11146b0ebb99a8f5f2d2c4e1767b6fed9
+            c7140dfcf01c793e88889cf34b4ecb044fc740f3d4a2cad1f93455cc36b9a0c6
+            5c89d78dbef3d727013b59af859f17da
+            "
+        ),
+    },
+];
+
+const DEOXYS_I_256_VECTORS: &[TestVector] = &[
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f"),
+        nonce: &hex!("0001020304050607"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!("50b0deaa3c3129d1ea1ef96b7c8db67f"),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f"),
+        nonce: &hex!("0001020304050607"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        plaintext: &hex!(""),
+        ciphertext: &hex!("0e641b45bcffb3c07fa7f7d31edc37d2"),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f"),
+        nonce: &hex!("0001020304050607"),
+        aad: &hex!(
+            "
+            52d15808134c3c2e8acbc154299df5c6f86f48ec5dafa5363989b33ba7e02995
+            65
+            "
+        ),
+        plaintext: &hex!(""),
+        ciphertext: &hex!("f343b91c303180ae2ae4f379022087fa"),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f"),
+        nonce: &hex!("0001020304050607"),
+        aad: &hex!(""),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        ciphertext: &hex!(
+            "
+            2c36c041fa3b1436c5153214131d493be9d014689a6a1e93e4a50989f0342941
+            ae66f78a3abf1bb7608c6fe949effb57
+            "
+        ),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f"),
+        nonce: &hex!("0001020304050607"),
+        aad: &hex!(""),
+        plaintext: &hex!(
+            "
+            9d63bc34aceebe70b21768e4f1cfd87bacbcae1e2577b6018de1d72707a42b25
+            69
+            "
+        ),
+        ciphertext: &hex!(
+            "
+            fd1ea6745fb5b435751d92be58f5973b84c7589501fcfaff6ce07e2a0e9a72c2
+            3ee957add57b7c5924d9a22db6fe03cce7
+            "
+        ),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f"),
+        nonce: &hex!("0001020304050607"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        ciphertext: &hex!(
+            "
+            2c36c041fa3b1436c5153214131d493be9d014689a6a1e93e4a50989f0342941
+            6da67607bad9cdd34d702325d52abcdd
+            "
+        ),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f"),
+        nonce: &hex!("0001020304050607"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f10"),
+        plaintext: &hex!(
+            "
+            8a968861ccb4aa1b7744ffff4812e001d1a749df3f66497c1c717681c43987b4
+            eb
+            "
+        ),
+        ciphertext: &hex!(
+            "
+            705f9db5d50ec6ff0ae28557a5640d32b19504833d5fc6de3baf638cef4cda50
+            bc88f06bac360362824401c8f1385073a8
+            "
+        ),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f"),
+        nonce: &hex!("0001020304050607"),
+        aad: &hex!(
+            "
+            bbc9aa3017a7ee71293eb2ea451f2efa6794e41c55b7505df1f2073f5babe332
+            a479619f855a39e45ef469b0c6329a786dbfc2b05b27983683d5edf26949cf96
+            4d75d7110bd4dba14a76f88353e3c652b46aca2f661d37dc7ffcf8da13c7aa48
+            f25095ce16c8834c3d2c9c813197926d47c9f73895fdf70f2574d7f8539a9ef2
+            aba78e80ec138ef1f702daf007ba337e1e0dfc49e6bd3f3eb4ff5a5c4e0ca2bd
+            cf3e5b6fea5401dfaa40e66db6fc63a6e306755492684bbc6021e2a1bbf12454
+            22377664475b22cdd83960e47852b474da196e67db018b87839966ffe52c665c
+            abe0c021df68d0c1454505b0458fe3dc3acc6b8400ec04a3129266ae9368c15b
+            bf13aabd05f859e2e9ea7cc937e899cc5c5bd72d2b72bd16d9024db4706fcf51
+            95bbe25eb807fea01840f4b572f0fadb8a4246d6895547a37cd8a9b756425b31
+            872a1d51c0ef2d53ca000711388228b76490780e3a10389c72ccc0deded32a5d
+            9e723aa31dddd3344b068bcde9d483c9249375a88dce482a819361993fde5556
+            03cea01cede77fe64190906157ded418c3c21bc5274034b8d9edf09daf2aa90f
+            b3b5f7d3b7da5c018144e54af9737227d2c13210c861fd5b4246d1a290fd054f
+            d15d59d2e08894239d000b1076055771f7a7da54b2fcd7cd1f5a9e1da5a25a0d
+            dbaa8d4397d74828a2b75a8da4730b87ac6c2fc5ef4985b9915320ea4942690d
+            f6
+            "
+        ),
+        plaintext: &hex!(
+            "
+            d18db1b44ad16fe5623ccd73c250c27240daf512e97de1ca770983f262d36e1c
+            9eafdf925c9786aeb556c1d058e1d3d0d92b8a5fed45bff46204f7cc1db8b23e
+            69f271593f4c8427ee5660fad6edf209f903921c1eba5c884777be45ca7875c7
+            2e5b44b550dc30ee875798a19a0d61965bf9ec6a17bdebb91b9e503dbf70e5ec
+            314e67d199296cd6375c510b04dbe30ac3b6a083f655627ab3859c168263babf
+            bd5ca2f9c33df7deefd46f37693ba4350b69e3ddbde6b0d5711c4a0a7c8dcadf
+            8b2340ed7a0748c3e9ef6ae72022fd3799b0561f00b255cdde1199b1c2def3b6
+            324508f28b1f1935aeb1083072598d8cea7e420ad8ce090922fca2be67b68e0b
+            8fe5db2f06faca945480f4831a6fd9bbeb40084403a8a2617184f8c9d3340c27
+            20b19f838d64a82eff4b2020ee92a72291102487788d8f774a32d5b1a6752cd8
+            0118f400806fe613d312d8d65cc21f4af83b50407fedec7ed4972a54b8d2260c
+            b652f3d9f3868d3081b20a719a1ff8611fe19ec41dc92570b74688506746cf96
+            c7f5db878446b0fdcc554a1c3e7fa62b611077a65e29bb460699a6187fa4c52b
+            91f58cc103a7dce86d3feefd9dcbc86fa5bf67b13fc0157c6da22d5dda3f0443
+            a05b2d7b286b5da2372013f18a361cab696219d84f8677588d8500b7ebb34b29
+            b1520258bcaa19f77229ddab6fcba75faaf4e09ecaa590e77e027477f5399b47
+            "
+        ),
+        ciphertext: &hex!(
+            "
+            e94c5c6df7c19474bbdd292baa2555fdbd5e90a35fb94627cdd7dd3b424ca47d
+            6779f3e6997809204263bdbd4825b7d6510995b1c371e582942bd7f6ab909f99
+            3cd5b7db5f95e8b8b56e4cdf016f5cab37f662329b32801fda4403f731fa61f7
+            aa16b9a23f2637b1f75fa0b36ced90ce6a1f73aafbb5adca756e0d59b8ae6661
+            f2d3fc409c88d8baf3836fac55df78b9ba522221345f42bd794c26d5d1a83fed
+            0114d1d1b04d3c3b77ff0083647710b316e17896b2081d9375fde1f2fe063e66
+            423a0d413919ffa6b5754d10de8de64d32ede0d02ebe8f8791d8e9f59462b615
+            f4122dd8c3b97671a8c156eb32ebebb3fb91832fd01f6afee9d4ab045fea83ec
+            87743823ea3bd18f7826229c312ad8a4bc9e2f6d1ad520e6d850bd189b4538d1
+            0005abf5a7c50f4f8ded6a62b18cd2a7e6bd3159edc3e9b553cbddd419af540d
+            a10576e9ea7d49e2fd0dc1c5ee7693504b63b928e4e23b1753147a3d0ad00cc2
+            e6390fba10e925dc536db4eb30cf152ddb0420f8e8eaa8460feb9a7f0be589cc
+            b877732d8d606085536c405c2ba6c03cb68e12f7d14609587a6c478e2a327942
+            90ba35ce6dba21784d8f6faf401920bfc2aa172c3b4d9bea2eae8542b18410d3
+            a40414247a406379855cb78c28e82ab67b62433a4016b15c4abf4f01c372ba4f
+            1562596531cb0337117ad769eaa666b497b7822eba924e358693bc48cf555f70
+            e404257c9cf7eb9774fc288a9ef1592e
+            "
+        ),
+    },
+];
+
+const DEOXYS_II_128_VECTORS: &[TestVector] = &[
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f"),
+        nonce: &hex!("202122232425262728292a2b2c2d2e"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!("97d951f2fd129001483e831f2a6821e9"),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f"),
+        nonce: &hex!("202122232425262728292a2b2c2d2e"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        plaintext: &hex!(""),
+        ciphertext: &hex!("3c197ca5317af5a2b95b178a60553132"),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f"),
+        nonce: &hex!("202122232425262728292a2b2c2d2e"),
+        aad: &hex!(
+            "
+            a754f3387be992ffee5bee80e18b151900c6d69ec59786fb12d2eadb0750f82c
+            f5
+            "
+        ),
+        plaintext: &hex!(""),
+        ciphertext: &hex!("0a989ed78fa16776cd6c691ea734d874"),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f"),
+        nonce: &hex!("202122232425262728292a2b2c2d2e"),
+        aad: &hex!(""),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        ciphertext: &hex!(
+            "
+            fa22f8eb84ee6d2388bdb16150232e856cd5fa3508bc589dad16d284208048c9
+            a381b06ef16db99df089e738c3b4064a
+            "
+        ),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f"),
+        nonce: &hex!("202122232425262728292a2b2c2d2e"),
+        aad: &hex!(""),
+        plaintext: &hex!(
+            "
+            06ac1756eccece62bd743fa80c299f7baa3872b556130f52265919494bdc136d
+            b3
+            "
+        ),
+        ciphertext: &hex!(
+            "
+            82bf241958b324ed053555d23315d3cc20935527fc970ff34a9f521a95e30213
+            6d0eadc8612d5208c491e93005195e9769
+            "
+        ),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f"),
+        nonce: &hex!("202122232425262728292a2b2c2d2e"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        ciphertext: &hex!(
+            "
+            9cdb554dfc03bff4feeb94df7736038361a76532b6b5a9c0bdb64a74dee983ff
+            bc1a7b5b8e961e65ceff6877ef9e4a98
+            "
+        ),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f"),
+        nonce: &hex!("202122232425262728292a2b2c2d2e"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f10"),
+        plaintext: &hex!(
+            "
+            039ca0907aa315a0d5ba020c84378840023d4ad3ba639787d3f6f46cb446bd63
+            dc
+            "
+        ),
+        ciphertext: &hex!(
+            "
+            801f1b81878faca562c8c6c0859b166c2669fbc54b1784be637827b4905729bd
+            f9fe4e9bcd26b96647350eda1e550cc994
+            "
+        ),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f"),
+        nonce: &hex!("202122232425262728292a2b2c2d2e"),
+        aad: &hex!(
+            "
+            5b187979e145d7b5beebbc0e689e759a027b5588059419b06b1afe4224f8f56e
+            cccb2bfe2cef9ecf103eb382172320a17c19dce14a3e38030d3443697845b992
+            ff1e871c02e788d7b40264f52ef0733791dc82dacdfa987685b33423bed0c05e
+            0a65bce48ce1006d16628ea21b4390e75be72e043f299d6290289f90007474bf
+            4e9ffb6c774d762afec8f3a01b2db545611772c32386fe6c7332125f0750c498
+            7988d1e0e727c3c295bc743a34d3196d5e2d14f11bf2c884265ba901e77144a4
+            b5a77864ad082e945727786f376bfcae99048ee7a994a2ea87584cd2e7e83ffd
+            0310cf9cdb2cff5cf8c9cc09c94becb3f37fb9b071a76ee7ae115a49f0d95b1a
+            9ec97e5b62bcae2c3cf47a3d2cb1b3d3dcd1729c33266ad7b0899654949a6f09
+            086b74297cb48227e566e1f401109495ea05d636a5025104cd04c2a3c59f396b
+            858f7f025825baf667b29b4f7f692f3a6c0c8956575a8dd183d1d03bd372c214
+            e005d6e1090d89f2d950b8ac856465943568bc320602f52bf67d30f0d8ec7a95
+            50dcdef99a43404a6d32d8f6b537b3eed568e32ab7ee63e16be63009702995d4
+            d9300114638ba4c874f02039f3f67e2df64946030edef1930f30d4e6b9ca9588
+            7539d1af2036c8f5cf129c54d5734224e09b3daab5fb0e74c848af70a49c1499
+            a5e56bc5eea90395df5bfd3e84a1c0a5be02dd3f2e2353e5522aeadaafdbf444
+            44
+            "
+        ),
+        plaintext: &hex!(
+            "
+            95330042c3d48419798f9285fbd8d24968d7cee311f637463f8c0a1778f79d75
+            8a84e35b7d4a9fde2ed56fa796ad5a0f7004490ed32664ad69069678f53dfd7e
+            e92e00a8ee34776b4d758536dc725ec4d48e2c11d0c5a16e4a2ce6c0e91604ad
+            b33a11127f50a46ea3cf5353d88a7a244c0f4337f449e68bf7c31feab02346d3
+            c84c2335b8a06dc7df89dab05b6496fe428133c210c3bac68e18f026daa56662
+            a41c36f9b55787fc1c5382d70b86e33be8555fd924606d2572c30a6ab6da71ec
+            cd4744ceb4e729519eef42ef4260db0e015832bfb0e742201fac36c711969a61
+            243b08a77c372e44f76646fd1e9c9c06570447aa30527339baceb1d002e24e6e
+            e3114f5a5daf0062bd372f824a60eebd74afc4fecffe74541933411b575295e2
+            7891abc71fc0e9597f65fc51be21962eea0aec96214b40a1a8ef32329df02a8b
+            0ef038c48a1d5b2529ed01a820a6f262488de7791b07c5f941126be7893f7dad
+            fb9639892264bc01af40402aa87a44df1754ce4e17226c41a8e3f05e4883d6ef
+            4511e96378067f455f3a7275215622bfc71bb4db398b03b08e4bf6c54b2b6396
+            c5b501fa26782fc36ad22044f5eb6a8f83efc8850d70ae4525d4e798f2aa1894
+            621803394415f34cd4d002a2b3d393efa7d57f687b753830ff04798c240f05f5
+            81ce706f7d151417f09f17174cb87eff0e042c1860342b4ace069e1691e092e3
+            "
+        ),
+        ciphertext: &hex!(
+            "
+            b8eddddb8d0042bb42fdf675bae285e504b90e4d73e02f99f790b2ffe7815dba
+            40fe4c7bc886ce44505f6ac53d3bba5d3c73efd98daf4b7a5af250a5d100ff55
+            58c211cb03a28d9519502d7d0fc85a6d73e618feb6b503af12cb0330bb9c5743
+            b19996174a84dbf5bac38d10d207067e4ab211a62ad0f85dd8245dfb07744301
+            7b7847996fe7ed547b9e02051f1cbe39128e21486b4f73399d0a50d9a1111bed
+            11ebb0547454d0a922633c83f0bba784571f63f55dc33f92e09862471945312d
+            99e40b4ed739556f102afd43055497739a4b22d107e867cc652a5d96974ff785
+            976c82bc1ff89731c780e84a257bb885cd23e00a7bdc7a68e0a1668516fb9727
+            21a777429c76cfd4adb45afa554d44a8932d133af8c9254fd3fef2bd0bb65801
+            f2ffbf752f14eaa783e53c2342f021863598e88b20232a0c44e963dd8943e9a5
+            4213ffbb174b90e38b55aa9b223e9596acb1517ff21b7458b7694488047797c5
+            21883c00762e7227f1e8a5e3f11a43962bdccde8dc4009aef7628a96efa8793d
+            6080982f9b00a7b97d93fd5928702e78427f34eb434e2286de00216b405c3610
+            5dc2e8dae68c3342a23274b32a6d2d8ac85239a8fa2947126f505a517fb18847
+            104b21b0326b7fd67efb54f5d0b12b311ef998ebaf14939b7cdb44b35435eedf
+            1ba5b07eea99533f1857b8cc1538290a8dbd44ca696c6bc2f1105451032a650c
+            e68a5de27beaeb6472611dfa9783602a
+            "
+        ),
+    },
+];
+
+const DEOXYS_II_256_VECTORS: &[TestVector] = &[
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f"),
+        nonce: &hex!("202122232425262728292a2b2c2d2e"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!("2b97bd77712f0cde975309959dfe1d7c"),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f"),
+        nonce: &hex!("202122232425262728292a2b2c2d2e"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        plaintext: &hex!(""),
+        ciphertext: &hex!("54708ae5565a71f147bdb94d7ba3aed7"),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f"),
+        nonce: &hex!("202122232425262728292a2b2c2d2e"),
+        aad: &hex!(
+            "
+            f495c9c03d29989695d98ff5d430650125805c1e0576d06f26cbda42b1f82238
+            b8
+            "
+        ),
+        plaintext: &hex!(""),
+        ciphertext: &hex!("3277689dc4208cc1ff59d15434a1baf1"),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f"),
+        nonce: &hex!("202122232425262728292a2b2c2d2e"),
+        aad: &hex!(""),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        ciphertext: &hex!(
+            "
+            9da20db1c2781f6669257d87e2a4d9be1970f7581bef2c995e1149331e5e8cc1
+            92ce3aec3a4b72ff9eab71c2a93492fa
+            "
+        ),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f"),
+        nonce: &hex!("202122232425262728292a2b2c2d2e"),
+        aad: &hex!(""),
+        plaintext: &hex!(
+            "
+            15cd77732f9d0c4c6e581ef400876ad9188c5b8850ebd38224da95d7cdc99f7a
+            cc
+            "
+        ),
+        ciphertext: &hex!(
+            "
+            e5ffd2abc5b459a73667756eda6443ede86c0883fc51dd75d22bb14992c68461
+            8c5fa78d57308f19d0252072ee39df5ecc
+            "
+        ),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f"),
+        nonce: &hex!("202122232425262728292a2b2c2d2e"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        ciphertext: &hex!(
+            "
+            109f8a168b36dfade02628a9e129d5257f03cc7912aefa79729b67b186a2b08f
+            6549f9bf10acba0a451dbb2484a60d90
+            "
+        ),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f"),
+        nonce: &hex!("202122232425262728292a2b2c2d2e"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f10"),
+        plaintext: &hex!(
+            "
+            422857fb165af0a35c03199fb895604dca9cea6d788954962c419e0d5c225c03
+            27
+            "
+        ),
+        ciphertext: &hex!(
+            "
+            7d772203fa38be296d8d20d805163130c69aba8cb16ed845c2296c61a8f34b39
+            4e0b3f10e3933c78190b24b33008bf80e9
+            "
+        ),
+    },
+    TestVector {
+        key: &hex!("101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f"),
+        nonce: &hex!("202122232425262728292a2b2c2d2e"),
+        aad: &hex!(
+            "
+            3290bb8441279dc6083a43e9048c3dc08966ab30d7a6b35759e7a13339f12491
+            8f3b5ab1affa65e6c0e3680eb33a6ec82424ab1ce5a40b8654e13d845c29b138
+            96a1466a75fc875acba4527ded37ed00c600a357c9a6e586c74cf3d85cd3258c
+            813218f319d12b82480e5124ff19ec00bda1fbb8bd25eeb3de9fcbf3296deba2
+            50caf7e9f4ef0be1918e24221dd0be888c59c166ad761d7b58462a1b1d44b042
+            65b45827172c133dd5b6c870b9af7b21368d12a88f4efa1751047543d584382d
+            9ec22e7550d50ecddba27d1f65453f1f3398de54ee8c1f4ac8e16f5523d89641
+            e99a632380af0f0b1e6b0e192ec29bf1d8714978ff9fbfb93604142393e9a82c
+            3aaebbbe15e3b4e5cfd18bdfe309315c9f9f830deebe2edcdc24f8eca90fda49
+            f6646e789c5041fb5be933fa843278e95f3a54f8eb41f14777ea949d5ea442b0
+            1249e64816151a325769e264ed4acd5c3f21700ca755d5bc0c2c5f9453419510
+            bc74f2d71621dcecb9efc9c24791b4bb560fb70a8231521d6560af89d8d50144
+            d9c080863f043781153bcd59030e60bd17a6d7aa083211b67b581fa4f74cce4d
+            030d1e8f9429fd725c110040d41eb6989ffb1595c72cbe3c9b78a8ab80d71a6a
+            5283da77b89cae295bb13c14fbe466b617f4da8ad60b085e2ea153f6713ae004
+            6aa31e0ba44e43ef36a111bf05c073a4e3624cd35f63a546f9142b35aa81b882
+            6d
+            "
+        ),
+        plaintext: &hex!(
+            "
+            83dab23b1379e090755c99079cfe918cb737e989f2d720ccaff493a744927644
+            fec3653211fa75306a83486e5c34ecfe63870c97251a73e4b9033ae374809711
+            b211ed5d293a592e466a81170f1d85750b5ca025ccd4579947edbae9ec132bfb
+            1a7233ad79fae30006a6699f143893861b975226ed9d3cfb8a240be232fbf4e8
+            3755d59d20bc2faa2ea5e5b0428427485cca5e76a89fe32bdd59ab4177ad7cb1
+            899c101e3c4f7535129591390ebdf30140846078b13867bbb2efd6cf434afe35
+            6eb18d716b21fd664c26c908496534bf2cde6d6b897799016594fb6d9f830ae5
+            f44ccec26d42ff0d1a21b80cdbe8c8c170a5f766fad884abcc781b5b8ebc0f55
+            9bfeaa4557b04d977d51411a7f47bf437d0280cf9f92bc4f9cd6226337a49232
+            0851955adae2cafea22a89c3132dd252e4728328eda05555dff3241404341b8a
+            a502d45c456113af42a8e91a85e4b4e9555028982ec3d144722af0eb04a6d3b8
+            127c3040629de53f5fd187048198e8f8e8cc857afcbae45c693fec12fc2149d5
+            e7587d0121b1717d0147f6979f75e8f085293f705c3399a6cc8df7057bf481e6
+            c374edf0a0af7479f858045357b7fe21021c3fabdaf012652bf2e5db257bd949
+            0ce637a81477bd3f9814a2198fdb9afa9344321f2393798670e588c47a1924d5
+            92cda3eb5a96754dfd92d87ee1ffa9d4ee586c85d7518c5d2db57d0451c33de0
+            "
+        ),
+        ciphertext: &hex!(
+            "
+            88294fcef65a1bdfd7baaa472816c64ef5bef2622b88c1ec5a739396157ef493
+            5f3aa76449e391c32da28ee2857f399ac3dd95aed30cfb26cc0063cd4cd8f743
+            1108176fbf370123856662b000a8348e5925fbb97c9ec0c737758330a7983f06
+            b51590c1d2f5e5faaf0eb58e34e19e5fc85cec03d3926dd46a79ba7026e83dec
+            24e07484c9103dd0cdb0edb505500caca5e1d5dbc71348cf00648821488ebaab
+            7f9d84bbbf91b3c521dbef30110e7bd94f8dad5ab8e0cc5411ca9682d210d5d8
+            0c0c4bdbba8181789a4273d6deb80899fdcd976ca6f3a9770b54305f586a0425
+            6cfbeb4c11254e88559f294db3b9a94b80ab9f9a02cb4c0748de0af781868552
+            1691dba5738be546dba13a56016fb8635af9dff50f25d1b17ad21707db2640a7
+            6a741e65e559b2afaaec0f37e18436bf02008f84dbd7b2698687a22376b65dc7
+            524fca8a28709eee3f3caee3b28ed1173d1e08ee849e2ca63d2c90d555755c8f
+            bafd5d2f4b37f06a1dbd6852ee2ffcfe79d510152e98fc4f3094f740a4aede9e
+            e378b606d34576776bf5f1269f5385a84b3928433bfca177550ccfcd22cd0331
+            bbc595e38c2758b2662476fa66354c4e84c7b360405aa3f5b2a48621bdca1a90
+            c69b21789c91b5b8c568e3c741d99e22f6d7e26f2abed045f1d578b782ab4a5c
+            f2af636d842b3012e180e4b045d8d15b057b69c92398a517053daf9be7c2935e
+            a616f0c218e18b526cf2a3f8c115e262
+            "
+        ),
+    },
+];
+
+macro_rules! tests {
+    ($name:ident, $aead:ty, $vectors:expr) => {
+        mod $name {
+            use super::*;
+
+            fn cipher(vector: &TestVector) -> $aead {
+                <$aead>::new(GenericArray::from_slice(vector.key))
+            }
+
+            #[test]
+            fn encrypt() {
+                for vector in $vectors {
+                    let payload = Payload {
+                        msg: vector.plaintext,
+                        aad: vector.aad,
+                    };
+
+                    let nonce = GenericArray::from_slice(vector.nonce);
+                    let ciphertext = cipher(vector).encrypt(nonce, payload).unwrap();
+                    assert_eq!(vector.ciphertext, ciphertext.as_slice());
+                }
+            }
+
+            #[test]
+            fn decrypt() {
+                for vector in $vectors {
+                    let payload = Payload {
+                        msg: vector.ciphertext,
+                        aad: vector.aad,
+                    };
+
+                    let nonce = GenericArray::from_slice(vector.nonce);
+                    let plaintext = cipher(vector).decrypt(nonce, payload).unwrap();
+                    assert_eq!(vector.plaintext, plaintext.as_slice());
+                }
+            }
+
+            #[test]
+            fn decrypt_modified() {
+                for vector in $vectors {
+                    let nonce = GenericArray::from_slice(vector.nonce);
+                    let mut ciphertext = Vec::from(vector.ciphertext);
+
+                    // Tweak the first and the last byte
+                    for &i in &[0, ciphertext.len() - 1] {
+                        ciphertext[i] ^= 1;
+                        let payload = Payload {
+                            msg: &ciphertext,
+                            aad: vector.aad,
+                        };
+                        assert!(cipher(vector).decrypt(nonce, payload).is_err());
+                        ciphertext[i] ^= 1;
+                    }
+                }
+            }
+        }
+    };
+}
+
+tests!(deoxys_i_128, DeoxysI128, DEOXYS_I_128_VECTORS);
+tests!(deoxys_i_256, DeoxysI256, DEOXYS_I_256_VECTORS);
+tests!(deoxys_ii_128, DeoxysII128, DEOXYS_II_128_VECTORS);
+tests!(deoxys_ii_256, DeoxysII256, DEOXYS_II_256_VECTORS);
+
+/// The tag of an empty message without associated data is the encryption of
+/// the zero block under the tweak `0001 || 0^4 || nonce`, which gives
+/// Deoxys-BC test vectors from the Deoxys-II ones.
+macro_rules! bc_tests {
+    ($name:ident, $bc:ty, $vector:expr) => {
+        mod $name {
+            use super::*;
+
+            fn setup() -> ($bc, Tweak) {
+                let vector = &$vector;
+                let cipher = <$bc>::new(GenericArray::from_slice(vector.key));
+
+                let mut tweak = Tweak::default();
+                tweak[0] = 0x10;
+                tweak[1..].copy_from_slice(vector.nonce);
+                (cipher, tweak)
+            }
+
+            #[test]
+            fn encrypt_block() {
+                let (cipher, tweak) = setup();
+                let mut block = Block::default();
+                cipher.encrypt_block(&tweak, &mut block);
+                assert_eq!(block.as_slice(), $vector.ciphertext);
+            }
+
+            #[test]
+            fn decrypt_block() {
+                let (cipher, tweak) = setup();
+                let mut block = Block::clone_from_slice($vector.ciphertext);
+                cipher.decrypt_block(&tweak, &mut block);
+                assert_eq!(block, Block::default());
+            }
+
+            #[test]
+            fn tweaks() {
+                let (cipher, mut tweak) = setup();
+                let mut block = Block::default();
+                tweak[15] ^= 1;
+                cipher.encrypt_block(&tweak, &mut block);
+                assert_ne!(block.as_slice(), $vector.ciphertext);
+
+                cipher.decrypt_block(&tweak, &mut block);
+                assert_eq!(block, Block::default());
+            }
+        }
+    };
+}
+
+bc_tests!(deoxys_bc_256, DeoxysBc256, DEOXYS_II_128_VECTORS[0]);
+bc_tests!(deoxys_bc_384, DeoxysBc384, DEOXYS_II_256_VECTORS[0]);
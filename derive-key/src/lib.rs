@@ -0,0 +1,215 @@
+//! Generic derive-key wrapper for [Authenticated Encryption with Associated
+//! Data (AEAD)][1] ciphers, which encrypts each message under a subkey derived
+//! from the key and nonce.
+//!
+//! The amount of data an AEAD key can safely encrypt is limited, and random
+//! 96-bit nonces are likely to repeat after about 2<sup>48</sup> messages.
+//! [`DeriveKey`] extends the nonce of the wrapped AEAD by 128 bits, which
+//! select a subkey, in the style of the nonce-based key derivation of Gueron
+//! and Lindell, [*Better Bounds for Block Cipher Modes of Operation via
+//! Nonce-Based Key Derivation*][2]. Each subkey only encrypts the few
+//! messages whose nonces share its 128 bits, so random nonces can be used
+//! for far more messages under the same key.
+//!
+//! A nonce of [`DeriveKey`] is `R || N`, where `R` is 128 bits and `N` is a
+//! nonce of the wrapped AEAD. HMAC-SHA-256 keyed with the key `K` derives the
+//! subkey `L` of each message:
+//!
+//! ```text
+//! L = HMAC-SHA-256(K, R || 0x01) || HMAC-SHA-256(K, R || 0x02) || ...
+//! C || T = AEAD(L, N, A, M)
+//! ```
+//!
+//! where `L` is truncated to the key size of the wrapped AEAD. The
+//! ciphertext `C || T` is that of the wrapped AEAD, under the subkey.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! A subkey is derived, and the wrapped AEAD is keyed with it, for each
+//! message, which is slower than the wrapped AEAD alone for short messages.
+//! Whole nonces must still be unique per message.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! ```
+//! use chacha20poly1305::{ChaCha20Poly1305, Key};
+//! use derive_key::DeriveKey;
+//! use derive_key::aead::{Aead, NewAead, generic_array::GenericArray};
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = DeriveKey::<ChaCha20Poly1305>::new(key);
+//!
+//! // 128 bits selecting the subkey, then a ChaCha20Poly1305 nonce: random or
+//! // otherwise unique per message
+//! let nonce = GenericArray::from_slice(b"random 224-bit nonce here!!!");
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! [1]: https://en.wikipedia.org/wiki/Authenticated_encryption
+//! [2]: https://eprint.iacr.org/2017/702
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+pub use aead::{self, AeadCore, AeadInPlace, Error, NewAead};
+
+use aead::{
+    consts::U16,
+    generic_array::{typenum::Sum, ArrayLength, GenericArray},
+    Tag,
+};
+use core::{marker::PhantomData, ops::Add};
+use hmac::{Hmac, Mac, NewMac};
+use sha2::Sha256;
+use zeroize::Zeroize;
+
+/// Size of the part of the nonce which selects the subkey
+const SUBKEY_NONCE_SIZE: usize = 16;
+
+/// [`DeriveKey`] nonces: 128 bits selecting the subkey, followed by a nonce
+/// of the wrapped AEAD
+pub type Nonce<NonceSize> = GenericArray<u8, Sum<U16, NonceSize>>;
+
+/// Wrapper around an AEAD which encrypts each message under a subkey derived
+/// from the key and nonce.
+///
+/// See the [toplevel documentation](index.html) for details of the
+/// construction and a usage example.
+///
+/// The key and tag sizes are those of the wrapped AEAD `A`, and its nonce is
+/// longer by 128 bits.
+pub struct DeriveKey<A>
+where
+    A: NewAead + AeadInPlace,
+{
+    /// HMAC-SHA-256, keyed with the key
+    mac: Hmac<Sha256>,
+
+    /// The wrapped AEAD is keyed for each message
+    aead: PhantomData<A>,
+}
+
+impl<A> NewAead for DeriveKey<A>
+where
+    A: NewAead + AeadInPlace,
+{
+    type KeySize = A::KeySize;
+
+    fn new(key: &GenericArray<u8, A::KeySize>) -> Self {
+        Self {
+            mac: Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length"),
+            aead: PhantomData,
+        }
+    }
+}
+
+impl<A> AeadCore for DeriveKey<A>
+where
+    A: NewAead + AeadInPlace,
+    U16: Add<A::NonceSize>,
+    Sum<U16, A::NonceSize>: ArrayLength<u8>,
+{
+    type NonceSize = Sum<U16, A::NonceSize>;
+    type TagSize = A::TagSize;
+    type CiphertextOverhead = A::CiphertextOverhead;
+}
+
+impl<A> AeadInPlace for DeriveKey<A>
+where
+    A: NewAead + AeadInPlace,
+    U16: Add<A::NonceSize>,
+    Sum<U16, A::NonceSize>: ArrayLength<u8>,
+{
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<A::NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<A>, Error> {
+        let (subkey_nonce, aead_nonce) = nonce.split_at(SUBKEY_NONCE_SIZE);
+        self.subkey_aead(subkey_nonce).encrypt_in_place_detached(
+            GenericArray::from_slice(aead_nonce),
+            associated_data,
+            buffer,
+        )
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<A::NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<A>,
+    ) -> Result<(), Error> {
+        let (subkey_nonce, aead_nonce) = nonce.split_at(SUBKEY_NONCE_SIZE);
+        self.subkey_aead(subkey_nonce).decrypt_in_place_detached(
+            GenericArray::from_slice(aead_nonce),
+            associated_data,
+            buffer,
+            tag,
+        )
+    }
+}
+
+impl<A> DeriveKey<A>
+where
+    A: NewAead + AeadInPlace,
+{
+    /// Key the wrapped AEAD with the subkey selected by `subkey_nonce`
+    fn subkey_aead(&self, subkey_nonce: &[u8]) -> A {
+        // The counter can't wrap around: AEAD keys are far shorter than 255
+        // HMAC-SHA-256 outputs
+        let mut subkey = GenericArray::<u8, A::KeySize>::default();
+        for (i, chunk) in subkey.chunks_mut(32).enumerate() {
+            let mut block = self.mac.clone();
+            block.update(subkey_nonce);
+            block.update(&[i as u8 + 1]);
+            let mut block = block.finalize().into_bytes();
+            chunk.copy_from_slice(&block[..chunk.len()]);
+            block.as_mut_slice().zeroize();
+        }
+
+        let aead = A::new(&subkey);
+        subkey.as_mut_slice().zeroize();
+        aead
+    }
+}
+
+impl<A> Clone for DeriveKey<A>
+where
+    A: NewAead + AeadInPlace,
+{
+    fn clone(&self) -> Self {
+        Self {
+            mac: self.mac.clone(),
+            aead: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,173 @@
+//! Tests of `DeriveKey`
+//!
+//! The expected ciphertexts were computed independently, with Python's
+//! `hmac` module and the AES-GCM and ChaCha20Poly1305 implementations of
+//! `cryptography`.
+
+#[macro_use]
+extern crate hex_literal;
+
+use aes_gcm::{Aes128Gcm, Aes256Gcm};
+use chacha20poly1305::ChaCha20Poly1305;
+use derive_key::aead::{generic_array::GenericArray, Aead, AeadInPlace, NewAead, Payload};
+use derive_key::DeriveKey;
+
+/// Nonce of all the test cases: 128 bits selecting the subkey, followed by a
+/// 96-bit nonce of the wrapped AEAD
+const NONCE: [u8; 28] = hex!("404142434445464748494a4b4c4d4e4f 505152535455565758595a5b");
+
+/// Associated data of the second test case of each AEAD
+const AAD: &[u8] = b"associated data";
+
+/// Plaintext of the second test case of each AEAD
+const PLAINTEXT: &[u8] = b"In the beginning the Universe was created.";
+
+macro_rules! tests {
+    ($name:ident, $aead:ty, $key:expr, $empty:expr, $ciphertext:expr) => {
+        mod $name {
+            use super::*;
+
+            const KEY: &[u8] = &$key;
+
+            /// Ciphertext of the empty message with no associated data
+            const EMPTY: &[u8] = &$empty;
+
+            /// Ciphertext of `PLAINTEXT` with `AAD`
+            const CIPHERTEXT: &[u8] = &$ciphertext;
+
+            fn cipher() -> DeriveKey<$aead> {
+                DeriveKey::new(GenericArray::from_slice(KEY))
+            }
+
+            #[test]
+            fn encrypt() {
+                let nonce = GenericArray::from_slice(&NONCE);
+                assert_eq!(cipher().encrypt(nonce, b"".as_ref()).unwrap(), EMPTY);
+
+                let payload = Payload {
+                    msg: PLAINTEXT,
+                    aad: AAD,
+                };
+                assert_eq!(cipher().encrypt(nonce, payload).unwrap(), CIPHERTEXT);
+            }
+
+            #[test]
+            fn decrypt() {
+                let nonce = GenericArray::from_slice(&NONCE);
+                assert!(cipher().decrypt(nonce, EMPTY).unwrap().is_empty());
+
+                let payload = Payload {
+                    msg: CIPHERTEXT,
+                    aad: AAD,
+                };
+                assert_eq!(cipher().decrypt(nonce, payload).unwrap(), PLAINTEXT);
+            }
+
+            #[test]
+            fn decrypt_modified() {
+                let nonce = GenericArray::from_slice(&NONCE);
+                let mut ciphertext = CIPHERTEXT.to_vec();
+
+                // Tweak the first byte of the ciphertext and the last byte of
+                // the tag
+                for &i in &[0, ciphertext.len() - 1] {
+                    ciphertext[i] ^= 1;
+                    let payload = Payload {
+                        msg: &ciphertext,
+                        aad: AAD,
+                    };
+                    assert!(cipher().decrypt(nonce, payload).is_err());
+                    ciphertext[i] ^= 1;
+                }
+            }
+
+            /// Both parts of the nonce are bound to the ciphertext
+            #[test]
+            fn decrypt_wrong_nonce() {
+                // The first byte selects the subkey, the last one is a nonce
+                // of the wrapped AEAD
+                for &i in &[0, NONCE.len() - 1] {
+                    let mut nonce = NONCE;
+                    nonce[i] ^= 1;
+
+                    let payload = Payload {
+                        msg: CIPHERTEXT,
+                        aad: AAD,
+                    };
+                    let nonce = GenericArray::from_slice(&nonce);
+                    assert!(cipher().decrypt(nonce, payload).is_err());
+                }
+            }
+
+            #[test]
+            fn detached() {
+                let nonce = GenericArray::from_slice(&NONCE);
+                let mut buffer = PLAINTEXT.to_vec();
+                let tag = cipher()
+                    .encrypt_in_place_detached(nonce, AAD, &mut buffer)
+                    .unwrap();
+                assert_eq!(&buffer, &CIPHERTEXT[..PLAINTEXT.len()]);
+                assert_eq!(tag.as_slice(), &CIPHERTEXT[PLAINTEXT.len()..]);
+
+                cipher()
+                    .decrypt_in_place_detached(nonce, AAD, &mut buffer, &tag)
+                    .unwrap();
+                assert_eq!(&buffer, PLAINTEXT);
+            }
+        }
+    };
+}
+
+tests!(
+    chacha20poly1305_ietf,
+    ChaCha20Poly1305,
+    hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+    hex!("a57653af0b4f666103c732ab52587af5"),
+    hex!(
+        "9095c5717221be776efbb696833fc189451fe6d58c6d676330c0c6d1019d4d71
+         bcde70974e1784ff25a4
+         60df92d9c5ba1f0a85e59883ef591bd5"
+    )
+);
+
+tests!(
+    aes128gcm,
+    Aes128Gcm,
+    hex!("000102030405060708090a0b0c0d0e0f"),
+    hex!("a88c987184f7f6ffded0fd0a426b874b"),
+    hex!(
+        "1e34b7a3ab5c1ca6d42d729c423ee85a1879f1eb87b1fb26f5db0b4e71c1a039
+         545fd50cb0dc42be1a9c
+         b8aef12c94113fc92a2e6677ff0c03a3"
+    )
+);
+
+tests!(
+    aes256gcm,
+    Aes256Gcm,
+    hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+    hex!("7f795b8048681d06293e1659039a05f7"),
+    hex!(
+        "41451f4b235ba7ec0830e558f820f8fe30281cd7cea07ef1740e2a547a09e86f
+         b2cd768f2a3e3aff4e46
+         674c6b37769731f07521192b1c95be48"
+    )
+);
+
+/// Messages whose nonces only differ in the part selecting the subkey are
+/// encrypted under different subkeys, with the same nonce of the wrapped
+/// AEAD
+#[test]
+fn subkeys() {
+    let cipher = DeriveKey::<ChaCha20Poly1305>::new(&[0x42; 32].into());
+
+    let mut nonce = NONCE;
+    let a = cipher
+        .encrypt(GenericArray::from_slice(&nonce), PLAINTEXT)
+        .unwrap();
+    nonce[15] ^= 1;
+    let b = cipher
+        .encrypt(GenericArray::from_slice(&nonce), PLAINTEXT)
+        .unwrap();
+    assert_ne!(a[..PLAINTEXT.len()], b[..PLAINTEXT.len()]);
+}
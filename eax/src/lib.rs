@@ -104,6 +104,38 @@
 //! # }
 //! ```
 //!
+//! ## EAX'
+//!
+//! [`EaxPrime`] implements EAX' (EAX-prime), the variant of EAX used by
+//! ANSI C12.22 smart meters, for interoperability with them only: see its
+//! documentation for its caveats.
+//!
+//! ```
+//! use aes::Aes128;
+//! use eax::EaxPrime;
+//! use eax::aead::{Aead, NewAead, Payload, generic_array::GenericArray};
+//!
+//! let key = GenericArray::from_slice(b"very secret key.");
+//! let cipher = EaxPrime::<Aes128>::new(key);
+//!
+//! // EAX' has no nonce: the cleartext must be unique per message instead
+//! let nonce = GenericArray::default();
+//! let cleartext = b"calling and called ApTitles, invocation ID...";
+//!
+//! let payload = Payload { msg: b"meter reading", aad: cleartext };
+//! let ciphertext = cipher.encrypt(&nonce, payload)
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! // The tag is only 4 bytes long
+//! assert_eq!(ciphertext.len(), b"meter reading".len() + 4);
+//!
+//! let payload = Payload { msg: &ciphertext, aad: cleartext };
+//! let plaintext = cipher.decrypt(&nonce, payload)
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"meter reading");
+//! ```
+//!
 //! [1]: https://en.wikipedia.org/wiki/Authenticated_encryption
 
 #![no_std]
@@ -149,6 +181,10 @@ pub type Tag<TagSize> = GenericArray<u8, TagSize>;
 
 pub mod online;
 
+mod prime;
+
+pub use prime::{EaxPrime, EAX_PRIME_A_MAX, EAX_PRIME_C_MAX, EAX_PRIME_P_MAX};
+
 /// EAX: generic over an underlying block cipher implementation.
 ///
 /// This type is generic to support substituting alternative cipher
@@ -246,6 +282,60 @@ where
         buffer: &mut [u8],
         tag: &Tag<M>,
     ) -> Result<(), Error> {
+        let n = self.authenticate(nonce, associated_data, buffer, tag)?;
+
+        // Decrypt
+        let mut cipher = ctr::Ctr128BE::<Cipher>::from_block_cipher(Cipher::new(&self.key), &n);
+        cipher.apply_keystream(buffer);
+        Ok(())
+    }
+}
+
+impl<Cipher, M> Eax<Cipher, M>
+where
+    Cipher: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher + Clone,
+    Cipher::ParBlocks: ArrayLength<Block<Cipher>>,
+    M: TagSize,
+{
+    /// Check the authenticity of a ciphertext followed by its tag, as
+    /// produced by [`AeadInPlace::encrypt_in_place`], without decrypting it.
+    ///
+    /// The OMAC of EAX is computed over the ciphertext, so messages can be
+    /// verified without producing their plaintext, e.g. by relays which must
+    /// never see it.
+    pub fn verify(
+        &self,
+        nonce: &Nonce<Cipher::BlockSize>,
+        associated_data: &[u8],
+        ciphertext: &[u8],
+    ) -> Result<(), Error> {
+        let tag_pos = ciphertext.len().checked_sub(M::to_usize()).ok_or(Error)?;
+        let (ciphertext, tag) = ciphertext.split_at(tag_pos);
+        self.verify_detached(nonce, associated_data, ciphertext, Tag::from_slice(tag))
+    }
+
+    /// Check the authenticity of a ciphertext and its detached tag without
+    /// decrypting it.
+    pub fn verify_detached(
+        &self,
+        nonce: &Nonce<Cipher::BlockSize>,
+        associated_data: &[u8],
+        ciphertext: &[u8],
+        tag: &Tag<M>,
+    ) -> Result<(), Error> {
+        self.authenticate(nonce, associated_data, ciphertext, tag)
+            .map(|_| ())
+    }
+
+    /// Check the tag of a ciphertext, returning `n = OMAC(0 || Nonce)`, the
+    /// initial counter block for decrypting it, if it is valid.
+    fn authenticate(
+        &self,
+        nonce: &Nonce<Cipher::BlockSize>,
+        associated_data: &[u8],
+        buffer: &[u8],
+        tag: &Tag<M>,
+    ) -> Result<GenericArray<u8, Cipher::BlockSize>, Error> {
         if buffer.len() as u64 > C_MAX || associated_data.len() as u64 > A_MAX {
             return Err(Error);
         }
@@ -268,22 +358,12 @@ where
         // Check mac using secure comparison
         use subtle::ConstantTimeEq;
         if expected_tag.ct_eq(tag).unwrap_u8() == 1 {
-            // Decrypt
-            let mut cipher = ctr::Ctr128BE::<Cipher>::from_block_cipher(Cipher::new(&self.key), &n);
-            cipher.apply_keystream(buffer);
-            Ok(())
+            Ok(n)
         } else {
             Err(Error)
         }
     }
-}
 
-impl<Cipher, M> Eax<Cipher, M>
-where
-    Cipher: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher + Clone,
-    Cipher::ParBlocks: ArrayLength<Block<Cipher>>,
-    M: TagSize,
-{
     /// CMAC/OMAC1
     ///
     /// To avoid constructing new buffers on the heap, an iv encoded into 16
@@ -0,0 +1,214 @@
+//! EAX' (EAX-prime): the variant of EAX specified by ANSI C12.22 (and
+//! IEEE 1703) for smart meter communications.
+//!
+//! EAX' departs from EAX in the following ways:
+//! - there is no separate nonce: the associated data, called the
+//!   *cleartext*, doubles as the nonce,
+//! - the `OMAC^t` tweaks `[0]` and `[2]` are replaced by XORing the CMAC
+//!   subkeys `D` and `Q` into the first block of the cleartext and of the
+//!   ciphertext respectively,
+//! - bits 31 and 15 of the initial counter are cleared,
+//! - the tag is the last 32 bits of `N' ^ C'`.
+
+use crate::Tag;
+use aead::{AeadCore, AeadInPlace, Error, NewAead};
+use cipher::{
+    consts::{U0, U16, U4},
+    generic_array::{ArrayLength, GenericArray},
+    Block, BlockCipher, BlockCipherKey, BlockEncrypt, FromBlockCipher, NewBlockCipher,
+    StreamCipher,
+};
+use subtle::ConstantTimeEq;
+
+/// Maximum length of the cleartext
+pub const EAX_PRIME_A_MAX: u64 = 1 << 36;
+
+/// Maximum length of plaintext: the 32-bit counter, whose bit 31 is cleared,
+/// won't wrap around
+pub const EAX_PRIME_P_MAX: u64 = 1 << 35;
+
+/// Maximum length of ciphertext
+pub const EAX_PRIME_C_MAX: u64 = (1 << 35) + 4;
+
+/// EAX' ("EAX-prime"), as used by ANSI C12.22: generic over an underlying
+/// block cipher implementation, which is AES-128 in ANSI C12.22.
+///
+/// The associated data passed to the [`AeadInPlace`] methods is the
+/// *cleartext* of EAX', which also serves as the nonce: the nonce is empty,
+/// and the cleartext must be unique for each message encrypted under a key.
+///
+/// # ⚠️ Caveats
+///
+/// EAX' is only provided for interoperability with existing devices, and
+/// should not be used for anything else:
+///
+/// - the tag is only 32 bits long, so a forgery succeeds with probability
+///   2<sup>-32</sup> per attempt,
+/// - unlike EAX, it has no security proof, and it is broken when the
+///   cleartext is a single block (Minematsu, Lucks, Morita and Iwata,
+///   *Attacks and Security Proofs of EAX-Prime*, FSE 2013): e.g. the
+///   cleartext `80 00 … 00` with an empty message is authenticated by the zero
+///   tag under any key. The cleartexts of ANSI C12.22 are always longer.
+#[derive(Clone)]
+pub struct EaxPrime<Cipher>
+where
+    Cipher: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher + Clone,
+    Cipher::ParBlocks: ArrayLength<Block<Cipher>>,
+{
+    /// Block cipher
+    cipher: Cipher,
+
+    /// CMAC subkey for complete blocks: `D = 2 * E_K(0)`
+    d: Block<Cipher>,
+
+    /// CMAC subkey for padded blocks: `Q = 4 * E_K(0)`
+    q: Block<Cipher>,
+}
+
+impl<Cipher> NewAead for EaxPrime<Cipher>
+where
+    Cipher: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher + Clone,
+    Cipher::ParBlocks: ArrayLength<Block<Cipher>>,
+{
+    type KeySize = Cipher::KeySize;
+
+    fn new(key: &BlockCipherKey<Cipher>) -> Self {
+        let cipher = Cipher::new(key);
+
+        let mut l = Block::<Cipher>::default();
+        cipher.encrypt_block(&mut l);
+        let d = dbl(&l);
+        let q = dbl(&d);
+
+        Self { cipher, d, q }
+    }
+}
+
+impl<Cipher> AeadCore for EaxPrime<Cipher>
+where
+    Cipher: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher + Clone,
+    Cipher::ParBlocks: ArrayLength<Block<Cipher>>,
+{
+    type NonceSize = U0;
+    type TagSize = U4;
+    type CiphertextOverhead = U0;
+}
+
+impl<Cipher> AeadInPlace for EaxPrime<Cipher>
+where
+    Cipher: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher + Clone,
+    Cipher::ParBlocks: ArrayLength<Block<Cipher>>,
+{
+    fn encrypt_in_place_detached(
+        &self,
+        _nonce: &GenericArray<u8, U0>,
+        cleartext: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<U4>, Error> {
+        if buffer.len() as u64 > EAX_PRIME_P_MAX || cleartext.len() as u64 > EAX_PRIME_A_MAX {
+            return Err(Error);
+        }
+
+        // 1. N' ← CMAC'(D, cleartext)
+        let n = self.cmac_prime(&self.d, cleartext);
+
+        // 2. C ← CTR(M), starting from N' with bits 31 and 15 cleared
+        self.ctr(&n, buffer);
+
+        // 3. C' ← CMAC'(Q, C)
+        let c = self.cmac_prime(&self.q, buffer);
+
+        // 4. tag ← last 32 bits of N' ^ C'
+        Ok(tag(&n, &c))
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        _nonce: &GenericArray<u8, U0>,
+        cleartext: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<U4>,
+    ) -> Result<(), Error> {
+        if buffer.len() as u64 > EAX_PRIME_C_MAX || cleartext.len() as u64 > EAX_PRIME_A_MAX {
+            return Err(Error);
+        }
+
+        let n = self.cmac_prime(&self.d, cleartext);
+        let c = self.cmac_prime(&self.q, buffer);
+        let expected_tag = self::tag(&n, &c);
+
+        // Check mac using secure comparison
+        if expected_tag.ct_eq(tag).unwrap_u8() == 1 {
+            self.ctr(&n, buffer);
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+}
+
+impl<Cipher> EaxPrime<Cipher>
+where
+    Cipher: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher + Clone,
+    Cipher::ParBlocks: ArrayLength<Block<Cipher>>,
+{
+    /// CMAC' with `tweak` XORed into the first block: CMAC of `data`, with
+    /// `D` XORed into the last block if it is complete, and `Q` otherwise.
+    fn cmac_prime(&self, tweak: &Block<Cipher>, data: &[u8]) -> Block<Cipher> {
+        let last_len = match data.len() % 16 {
+            0 if !data.is_empty() => 16,
+            n => n,
+        };
+        let (blocks, last) = data.split_at(data.len() - last_len);
+
+        let mut state = *tweak;
+        for block in blocks.chunks_exact(16) {
+            xor(&mut state, block);
+            self.cipher.encrypt_block(&mut state);
+        }
+
+        xor(&mut state, last);
+        if last.len() == 16 {
+            xor(&mut state, &self.d);
+        } else {
+            state[last.len()] ^= 0x80;
+            xor(&mut state, &self.q);
+        }
+        self.cipher.encrypt_block(&mut state);
+
+        state
+    }
+
+    /// Counter mode with a 32-bit counter, starting from `n` with bits 31
+    /// and 15 cleared.
+    fn ctr(&self, n: &Block<Cipher>, buffer: &mut [u8]) {
+        let mut counter = *n;
+        counter[12] &= 0x7f;
+        counter[14] &= 0x7f;
+
+        let mut cipher = ctr::Ctr32BE::<Cipher>::from_block_cipher(self.cipher.clone(), &counter);
+        cipher.apply_keystream(buffer);
+    }
+}
+
+/// Last 32 bits of `n ^ c`
+fn tag(n: &GenericArray<u8, U16>, c: &GenericArray<u8, U16>) -> Tag<U4> {
+    let mut tag = Tag::<U4>::clone_from_slice(&n[12..]);
+    xor(&mut tag, &c[12..]);
+    tag
+}
+
+/// Multiply by `x` in GF(2<sup>128</sup>)
+fn dbl(block: &GenericArray<u8, U16>) -> GenericArray<u8, U16> {
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(block);
+    let x = u128::from_be_bytes(bytes);
+    GenericArray::from(((x << 1) ^ ((x >> 127) * 0x87)).to_be_bytes())
+}
+
+#[inline]
+fn xor(out: &mut [u8], data: &[u8]) {
+    for (a, b) in out.iter_mut().zip(data) {
+        *a ^= b;
+    }
+}
@@ -4,3 +4,55 @@ use aes::Aes128;
 use eax::Eax;
 
 aead::new_test!(aes128eax, "aes128eax", Eax<Aes128>);
+
+mod verify {
+    use aes::Aes128;
+    use eax::aead::{generic_array::GenericArray, NewAead};
+    use eax::Eax;
+
+    // Second test vector of Appendix G
+    const KEY: &[u8; 16] = &[
+        0x91, 0x94, 0x5d, 0x3f, 0x4d, 0xcb, 0xee, 0x0b, 0xf4, 0x5e, 0xf5, 0x22, 0x55, 0xf0, 0x95,
+        0xa4,
+    ];
+
+    const NONCE: &[u8; 16] = &[
+        0xbe, 0xca, 0xf0, 0x43, 0xb0, 0xa2, 0x3d, 0x84, 0x31, 0x94, 0xba, 0x97, 0x2c, 0x66, 0xde,
+        0xbd,
+    ];
+
+    const AAD: &[u8] = &[0xfa, 0x3b, 0xfd, 0x48, 0x06, 0xeb, 0x53, 0xfa];
+
+    const CIPHERTEXT: &[u8] = &[
+        0x19, 0xdd, 0x5c, 0x4c, 0x93, 0x31, 0x04, 0x9d, 0x0b, 0xda, 0xb0, 0x27, 0x74, 0x08, 0xf6,
+        0x79, 0x67, 0xe5,
+    ];
+
+    fn cipher() -> Eax<Aes128> {
+        Eax::new(GenericArray::from_slice(KEY))
+    }
+
+    #[test]
+    fn verify() {
+        let nonce = GenericArray::from_slice(NONCE);
+        assert!(cipher().verify(nonce, AAD, CIPHERTEXT).is_ok());
+
+        let (ciphertext, tag) = CIPHERTEXT.split_at(2);
+        assert!(cipher()
+            .verify_detached(nonce, AAD, ciphertext, GenericArray::from_slice(tag))
+            .is_ok());
+    }
+
+    #[test]
+    fn verify_modified() {
+        let nonce = GenericArray::from_slice(NONCE);
+
+        let mut ciphertext = CIPHERTEXT.to_vec();
+        ciphertext[0] ^= 0xaa;
+        assert!(cipher().verify(nonce, AAD, &ciphertext).is_err());
+
+        // Too short to hold a tag
+        assert!(cipher().verify(nonce, AAD, &CIPHERTEXT[..15]).is_err());
+        assert!(cipher().verify(nonce, &[], CIPHERTEXT).is_err());
+    }
+}
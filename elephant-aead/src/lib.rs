@@ -0,0 +1,313 @@
+//! [Elephant][1]: a lightweight [Authenticated Encryption with Associated
+//! Data (AEAD)][2] cipher built on an unkeyed permutation.
+//!
+//! Elephant was a finalist of the NIST lightweight cryptography process. It
+//! encrypts in counter mode and authenticates the associated data and
+//! ciphertext with a Wegman-Carter-like MAC, the blocks of both being masked
+//! with values derived from the key by a linear feedback shift register. Each
+//! block is processed independently, which makes Elephant parallelizable and
+//! easy to pipeline in hardware.
+//!
+//! This crate implements the second version of the specification, with the
+//! following instances:
+//!
+//! - [`Dumbo`]: Spongent-π\[160\], the primary member of the family.
+//! - [`Jumbo`]: Spongent-π\[176\], for a higher security level.
+//!
+//! The permutation is implemented closely following its bit-oriented
+//! specification, which makes this crate a readable reference rather than a
+//! fast implementation.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! Both instances have a 64-bit tag, so each forgery attempt succeeds with
+//! probability 2^-64. Applications which can't limit the number of decryption
+//! attempts should prefer an AEAD with a longer tag.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! ```
+//! use elephant_aead::{Dumbo, Key, Nonce};
+//! use elephant_aead::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = Dumbo::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce"); // 96-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! Note that if you enable the `heapless` feature of this crate,
+//! you will receive an impl of [`aead::Buffer`] for `heapless::Vec`
+//! (re-exported from the [`aead`] crate as [`aead::heapless::Vec`]),
+//! which can then be passed as the `buffer` parameter to the in-place encrypt
+//! and decrypt methods:
+//!
+//! ```
+//! # #[cfg(feature = "heapless")]
+//! # {
+//! use elephant_aead::{Dumbo, Key, Nonce};
+//! use elephant_aead::aead::{AeadInPlace, NewAead};
+//! use elephant_aead::aead::heapless::Vec;
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = Dumbo::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce"); // 96-bits; unique per message
+//!
+//! let mut buffer: Vec<u8, 128> = Vec::new(); // Buffer needs 8-bytes overhead for the tag
+//! buffer.extend_from_slice(b"plaintext message");
+//!
+//! // Encrypt `buffer` in-place, replacing the plaintext contents with ciphertext
+//! cipher.encrypt_in_place(nonce, b"", &mut buffer).expect("encryption failure!");
+//!
+//! // `buffer` now contains the message ciphertext
+//! assert_ne!(&buffer, b"plaintext message");
+//!
+//! // Decrypt `buffer` in-place, replacing its ciphertext context with the original plaintext
+//! cipher.decrypt_in_place(nonce, b"", &mut buffer).expect("decryption failure!");
+//! assert_eq!(&buffer, b"plaintext message");
+//! # }
+//! ```
+//!
+//! [1]: https://csrc.nist.gov/projects/lightweight-cryptography/finalists
+//! [2]: https://en.wikipedia.org/wiki/Authenticated_encryption
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![deny(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use aead::{self, consts, AeadCore, AeadInPlace, Error, NewAead};
+
+mod spongent;
+
+use aead::{
+    consts::{U0, U12, U16, U8},
+    generic_array::GenericArray,
+};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// Elephant keys (128-bit)
+pub type Key = GenericArray<u8, U16>;
+
+/// Elephant nonces (96-bit)
+pub type Nonce = GenericArray<u8, U12>;
+
+/// Elephant tags (64-bit)
+pub type Tag = GenericArray<u8, U8>;
+
+macro_rules! impl_elephant {
+    ($name:ident, $block:expr, $rounds:expr, $iv:expr, $phi:ident, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone)]
+        pub struct $name {
+            /// Expanded key: the permutation applied to the zero-padded key
+            key: [u8; $block],
+        }
+
+        impl $name {
+            fn permute(block: &mut [u8; $block]) {
+                spongent::permute(block, $rounds, $iv);
+            }
+
+            /// Encrypt or decrypt `buffer` in counter mode, block `i` being
+            /// masked with `φ(L_i) ⊕ L_i = L_{i + 1} ⊕ L_i`
+            fn apply_keystream(&self, nonce: &Nonce, buffer: &mut [u8]) {
+                let mut mask = self.key;
+
+                for chunk in buffer.chunks_mut($block) {
+                    let next = $phi(&mask);
+
+                    let mut block = [0u8; $block];
+                    block[..nonce.len()].copy_from_slice(nonce);
+                    xor(&mut block, &mask);
+                    xor(&mut block, &next);
+                    Self::permute(&mut block);
+                    xor(&mut block, &mask);
+                    xor(&mut block, &next);
+
+                    xor(chunk, &block);
+                    mask = next;
+                }
+            }
+
+            /// Compute the tag of the nonce, associated data and ciphertext
+            fn compute_tag(&self, nonce: &Nonce, associated_data: &[u8], ciphertext: &[u8]) -> Tag {
+                // The first block of the padded nonce and associated data is
+                // only added to the final block
+                let mut tag = [0u8; $block];
+                tag[..nonce.len()].copy_from_slice(nonce);
+                load_padded(&mut tag[nonce.len()..], associated_data);
+
+                // Remaining associated data blocks (`A_i` masked with `L_i`)
+                let mut mask = self.key;
+                let ad_blocks = 1 + (nonce.len() + associated_data.len()) / $block;
+                for i in 1..ad_blocks {
+                    mask = $phi(&mask);
+
+                    let mut block = [0u8; $block];
+                    load_padded(&mut block, &associated_data[i * $block - nonce.len()..]);
+                    xor(&mut block, &mask);
+                    Self::permute(&mut block);
+                    xor(&mut block, &mask);
+                    xor(&mut tag, &block);
+                }
+
+                // Padded ciphertext blocks (`C_i` masked with `L_{i + 2} ⊕ L_i`)
+                let mut masks = (self.key, $phi(&self.key));
+                for i in 0..=ciphertext.len() / $block {
+                    let next = $phi(&masks.1);
+
+                    let mut block = [0u8; $block];
+                    load_padded(&mut block, &ciphertext[i * $block..]);
+                    xor(&mut block, &masks.0);
+                    xor(&mut block, &next);
+                    Self::permute(&mut block);
+                    xor(&mut block, &masks.0);
+                    xor(&mut block, &next);
+                    xor(&mut tag, &block);
+
+                    masks = (masks.1, next);
+                }
+
+                xor(&mut tag, &self.key);
+                Self::permute(&mut tag);
+                xor(&mut tag, &self.key);
+                Tag::clone_from_slice(&tag[..8])
+            }
+        }
+
+        impl NewAead for $name {
+            type KeySize = U16;
+
+            fn new(key: &Key) -> Self {
+                let mut expanded = [0u8; $block];
+                expanded[..key.len()].copy_from_slice(key);
+                Self::permute(&mut expanded);
+                Self { key: expanded }
+            }
+        }
+
+        impl AeadCore for $name {
+            type NonceSize = U12;
+            type TagSize = U8;
+            type CiphertextOverhead = U0;
+        }
+
+        impl AeadInPlace for $name {
+            fn encrypt_in_place_detached(
+                &self,
+                nonce: &Nonce,
+                associated_data: &[u8],
+                buffer: &mut [u8],
+            ) -> Result<Tag, Error> {
+                self.apply_keystream(nonce, buffer);
+                Ok(self.compute_tag(nonce, associated_data, buffer))
+            }
+
+            fn decrypt_in_place_detached(
+                &self,
+                nonce: &Nonce,
+                associated_data: &[u8],
+                buffer: &mut [u8],
+                tag: &Tag,
+            ) -> Result<(), Error> {
+                let expected_tag = self.compute_tag(nonce, associated_data, buffer);
+
+                if expected_tag.ct_eq(tag).unwrap_u8() == 1 {
+                    self.apply_keystream(nonce, buffer);
+                    Ok(())
+                } else {
+                    Err(Error)
+                }
+            }
+        }
+
+        impl Drop for $name {
+            fn drop(&mut self) {
+                self.key.zeroize();
+            }
+        }
+    };
+}
+
+impl_elephant!(
+    Dumbo,
+    20,
+    80,
+    0x75,
+    phi_dumbo,
+    "Dumbo: Elephant with Spongent-π\\[160\\], 128-bit key, 96-bit nonce, 64-bit tag."
+);
+impl_elephant!(
+    Jumbo,
+    22,
+    90,
+    0x45,
+    phi_jumbo,
+    "Jumbo: Elephant with Spongent-π\\[176\\], 128-bit key, 96-bit nonce, 64-bit tag."
+);
+
+/// Mask LFSR of Dumbo: `(x_0, ..., x_19) ↦ (x_1, ..., x_19, (x_0 ⋘ 3) ⊕ (x_3 ≪ 7) ⊕ (x_13 ≫ 7))`
+fn phi_dumbo(x: &[u8; 20]) -> [u8; 20] {
+    let mut y = [0u8; 20];
+    y[..19].copy_from_slice(&x[1..]);
+    y[19] = x[0].rotate_left(3) ^ x[3] << 7 ^ x[13] >> 7;
+    y
+}
+
+/// Mask LFSR of Jumbo: `(x_0, ..., x_21) ↦ (x_1, ..., x_21, (x_0 ⋘ 1) ⊕ (x_3 ≪ 7) ⊕ (x_19 ≫ 7))`
+fn phi_jumbo(x: &[u8; 22]) -> [u8; 22] {
+    let mut y = [0u8; 22];
+    y[..21].copy_from_slice(&x[1..]);
+    y[21] = x[0].rotate_left(1) ^ x[3] << 7 ^ x[19] >> 7;
+    y
+}
+
+/// Copy `data` into `block`, truncated to its length, or padded with a one
+/// byte followed by zeroes if it's shorter
+#[inline(always)]
+fn load_padded(block: &mut [u8], data: &[u8]) {
+    let len = block.len().min(data.len());
+    block[..len].copy_from_slice(&data[..len]);
+
+    if len < block.len() {
+        block[len] = 0x01;
+    }
+}
+
+/// XOR `src` into `dst`, up to the shorter of the two lengths
+#[inline(always)]
+fn xor(dst: &mut [u8], src: &[u8]) {
+    for (a, b) in dst.iter_mut().zip(src) {
+        *a ^= b;
+    }
+}
@@ -0,0 +1,49 @@
+//! Spongent-π permutations
+//!
+//! This follows the bit-oriented specification closely rather than being
+//! optimized, so it can serve as a reference for other implementations.
+
+/// The 4-bit S-box of Spongent
+const SBOX: [u8; 16] = [
+    0xe, 0xd, 0xb, 0x0, 0x2, 0x1, 0x4, 0xf, 0x7, 0xa, 0x8, 0x5, 0x9, 0xc, 0x3, 0x6,
+];
+
+/// Largest state supported, in bytes
+const MAX_BYTES: usize = 22;
+
+/// Apply `rounds` rounds of Spongent-π[`8 * state.len()`] to `state`, the
+/// round counter LFSR starting from `iv`. Bit `j` of byte `i` is state bit
+/// `8 * i + j`.
+pub(crate) fn permute(state: &mut [u8], rounds: usize, mut iv: u8) {
+    debug_assert!(state.len() <= MAX_BYTES);
+    let bits = 8 * state.len();
+
+    for _ in 0..rounds {
+        // The counter goes into the lowest bits, and reversed into the highest
+        state[0] ^= iv;
+        state[state.len() - 1] ^= iv.reverse_bits();
+        iv = counter_step(iv);
+
+        for byte in state.iter_mut() {
+            *byte = SBOX[usize::from(*byte & 0xf)] | SBOX[usize::from(*byte >> 4)] << 4;
+        }
+
+        // Bit `j` moves to `j * bits / 4 mod (bits - 1)`, except the last one
+        let mut tmp = [0u8; MAX_BYTES];
+        for j in 0..bits {
+            let k = if j == bits - 1 {
+                j
+            } else {
+                j * bits / 4 % (bits - 1)
+            };
+            tmp[k / 8] |= (state[j / 8] >> (j % 8) & 1) << (k % 8);
+        }
+        state.copy_from_slice(&tmp[..state.len()]);
+    }
+}
+
+/// Step the 7-bit round counter LFSR
+#[inline(always)]
+fn counter_step(iv: u8) -> u8 {
+    (iv << 1 | ((iv >> 6) ^ (iv >> 5)) & 1) & 0x7f
+}
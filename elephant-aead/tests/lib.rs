@@ -0,0 +1,154 @@
+//! Elephant tests
+
+#[macro_use]
+extern crate hex_literal;
+
+use elephant_aead::aead::{generic_array::GenericArray, Aead, NewAead, Payload};
+use elephant_aead::{Dumbo, Jumbo};
+
+// Known-answer tests in the format of the NIST LWC submissions
+aead::new_test!(dumbo, "dumbo", Dumbo);
+aead::new_test!(jumbo, "jumbo", Jumbo);
+
+/// Test vectors
+#[derive(Debug)]
+pub struct TestVector {
+    pub key: &'static [u8; 16],
+    pub nonce: &'static [u8; 12],
+    pub aad: &'static [u8],
+    pub plaintext: &'static [u8],
+    pub ciphertext: &'static [u8],
+    pub tag: &'static [u8; 8],
+}
+
+/// Dumbo test vectors, selected from the known-answer tests to cover empty,
+/// partial and multi-block inputs
+const TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("6655b717736adff3"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b"),
+        aad: &hex!("00"),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("b6925c1c8ca1058e"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b"),
+        aad: &hex!("00"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f"),
+        ciphertext: &hex!("0867290ad29d219c4bf3bf0bd652099b"),
+        tag: &hex!("657dc3fe6a0d23b8"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b"),
+        aad: &hex!("000102030405060708090a0b0c0d0e"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f10111213141516"),
+        ciphertext: &hex!("0867290ad29d219c4bf3bf0bd652099b499b5b9cd7401b"),
+        tag: &hex!("c8bf47d81f8e525e"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        ciphertext: &hex!("0867290ad29d219c4bf3bf0bd652099b499b5b9cd7401b7ecfe8b7d30f5e05bd"),
+        tag: &hex!("0a3a2361885dbe3b"),
+    },
+];
+
+#[test]
+fn encrypt() {
+    for vector in TEST_VECTORS {
+        let key = GenericArray::from_slice(vector.key);
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let payload = Payload {
+            msg: vector.plaintext,
+            aad: vector.aad,
+        };
+
+        let cipher = Dumbo::new(key);
+        let ciphertext = cipher.encrypt(nonce, payload).unwrap();
+        let (ct, tag) = ciphertext.split_at(vector.plaintext.len());
+        assert_eq!(vector.ciphertext, ct);
+        assert_eq!(vector.tag, tag);
+    }
+}
+
+#[test]
+fn decrypt() {
+    for vector in TEST_VECTORS {
+        let key = GenericArray::from_slice(vector.key);
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let mut ciphertext = Vec::from(vector.ciphertext);
+        ciphertext.extend_from_slice(vector.tag);
+
+        let payload = Payload {
+            msg: &ciphertext,
+            aad: vector.aad,
+        };
+
+        let cipher = Dumbo::new(key);
+        let plaintext = cipher.decrypt(nonce, payload).unwrap();
+
+        assert_eq!(vector.plaintext, plaintext.as_slice());
+    }
+}
+
+#[test]
+fn decrypt_modified() {
+    let vector = &TEST_VECTORS[4];
+    let key = GenericArray::from_slice(vector.key);
+    let nonce = GenericArray::from_slice(vector.nonce);
+
+    let mut ciphertext = Vec::from(vector.ciphertext);
+    ciphertext.extend_from_slice(vector.tag);
+
+    // Tweak the first byte
+    ciphertext[0] ^= 0xaa;
+
+    let payload = Payload {
+        msg: &ciphertext,
+        aad: vector.aad,
+    };
+
+    let cipher = Dumbo::new(key);
+    assert!(cipher.decrypt(nonce, payload).is_err());
+}
+
+/// Messages and associated data spanning several blocks of both instances
+#[test]
+fn multi_block() {
+    let key = GenericArray::from_slice(TEST_VECTORS[0].key);
+    let nonce = GenericArray::from_slice(TEST_VECTORS[0].nonce);
+    let data = (0..100).map(|i| i as u8).collect::<Vec<_>>();
+    let payload = || Payload {
+        msg: &data,
+        aad: &data,
+    };
+
+    let ciphertext = Dumbo::new(key).encrypt(nonce, payload()).unwrap();
+    let (ct, tag) = ciphertext.split_at(data.len());
+    assert_eq!(ct, &hex!("0867290ad29d219c4bf3bf0bd652099b499b5b9cd7401b7ecfe8b7d30f5e05bdf4d27fac07819cb2324573c0fcd5c6fb57f68e050af5ee987262ec7cfbd6078029b64c7cc3b752a7560a549ab8fd3a0553e62e75771604b0f148671174c9d5cdf770c4c7")[..]);
+    assert_eq!(tag, hex!("6643fe4c60b28169"));
+
+    let ciphertext = Jumbo::new(key).encrypt(nonce, payload()).unwrap();
+    let (ct, tag) = ciphertext.split_at(data.len());
+    assert_eq!(ct, &hex!("ae5d4f2bfae6d432a1b6e92eb8955a7f2fd61692b269cd725e51aada102ec84283e1b9f5d2bc1f8bc2a17d5b1fd224c483aac0eec27dd8a3e0eb71b41e70edce4154856b237b0fd1a7bd9e4900c3fb52d8b23f5aa6de2032b570231ec61b3c8b2c41d767")[..]);
+    assert_eq!(tag, hex!("ce16fbae26a9a120"));
+
+    let payload = Payload {
+        msg: &ciphertext,
+        aad: &data,
+    };
+    assert_eq!(Jumbo::new(key).decrypt(nonce, payload).unwrap(), data);
+}
@@ -0,0 +1,289 @@
+//! [GIFT-COFB][1]: a lightweight [Authenticated Encryption with Associated
+//! Data (AEAD)][2] cipher built on the GIFT-128 block cipher.
+//!
+//! GIFT-COFB was a finalist of the NIST lightweight cryptography process. It
+//! uses the COmbined FeedBack mode, in which each block cipher call both
+//! encrypts a block and authenticates it, with only a 64-bit offset and the
+//! block cipher state as additional memory. Each block of associated data or
+//! message costs a single call to GIFT-128.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! ```
+//! use gift_cofb::{GiftCofb, Key, Nonce};
+//! use gift_cofb::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = GiftCofb::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"my unique nonce!"); // 128-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! Note that if you enable the `heapless` feature of this crate,
+//! you will receive an impl of [`aead::Buffer`] for `heapless::Vec`
+//! (re-exported from the [`aead`] crate as [`aead::heapless::Vec`]),
+//! which can then be passed as the `buffer` parameter to the in-place encrypt
+//! and decrypt methods:
+//!
+//! ```
+//! # #[cfg(feature = "heapless")]
+//! # {
+//! use gift_cofb::{GiftCofb, Key, Nonce};
+//! use gift_cofb::aead::{AeadInPlace, NewAead};
+//! use gift_cofb::aead::heapless::Vec;
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = GiftCofb::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"my unique nonce!"); // 128-bits; unique per message
+//!
+//! let mut buffer: Vec<u8, 128> = Vec::new(); // Buffer needs 16-bytes overhead for the tag
+//! buffer.extend_from_slice(b"plaintext message");
+//!
+//! // Encrypt `buffer` in-place, replacing the plaintext contents with ciphertext
+//! cipher.encrypt_in_place(nonce, b"", &mut buffer).expect("encryption failure!");
+//!
+//! // `buffer` now contains the message ciphertext
+//! assert_ne!(&buffer, b"plaintext message");
+//!
+//! // Decrypt `buffer` in-place, replacing its ciphertext context with the original plaintext
+//! cipher.decrypt_in_place(nonce, b"", &mut buffer).expect("decryption failure!");
+//! assert_eq!(&buffer, b"plaintext message");
+//! # }
+//! ```
+//!
+//! [1]: https://csrc.nist.gov/projects/lightweight-cryptography/finalists
+//! [2]: https://en.wikipedia.org/wiki/Authenticated_encryption
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![deny(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use aead::{self, consts, AeadCore, AeadInPlace, Error, NewAead};
+
+mod gift;
+
+use aead::{
+    consts::{U0, U16},
+    generic_array::GenericArray,
+};
+use core::convert::TryInto;
+use gift::Gift128;
+use subtle::ConstantTimeEq;
+
+/// GIFT-COFB keys (128-bit)
+pub type Key = GenericArray<u8, U16>;
+
+/// GIFT-COFB nonces (128-bit)
+pub type Nonce = GenericArray<u8, U16>;
+
+/// GIFT-COFB tags (128-bit)
+pub type Tag = GenericArray<u8, U16>;
+
+/// Size of a block in bytes
+const BLOCK_SIZE: usize = 16;
+
+/// GIFT-COFB: 128-bit key, 128-bit nonce, 128-bit tag.
+#[derive(Clone)]
+pub struct GiftCofb {
+    cipher: Gift128,
+}
+
+impl NewAead for GiftCofb {
+    type KeySize = U16;
+
+    fn new(key: &Key) -> Self {
+        Self {
+            cipher: Gift128::new(&(*key).into()),
+        }
+    }
+}
+
+impl AeadCore for GiftCofb {
+    type NonceSize = U16;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl AeadInPlace for GiftCofb {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        Ok(self.crypt(nonce, associated_data, buffer, true))
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        let expected_tag = self.crypt(nonce, associated_data, buffer, false);
+
+        if expected_tag.ct_eq(tag).unwrap_u8() == 1 {
+            Ok(())
+        } else {
+            // Don't release unauthenticated plaintext
+            buffer.iter_mut().for_each(|b| *b = 0);
+            Err(Error)
+        }
+    }
+}
+
+impl GiftCofb {
+    /// Encrypt or decrypt `buffer` in-place and return the tag
+    fn crypt(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        encrypting: bool,
+    ) -> Tag {
+        let mut y = self.cipher.encrypt(u128::from_be_bytes((*nonce).into()));
+        let mut offset = (y >> 64) as u64;
+
+        // The last block is processed separately even if it is full, and
+        // empty associated data is one empty block
+        let (ad_blocks, ad_last) = split_last_block(associated_data);
+
+        for block in ad_blocks.chunks_exact(BLOCK_SIZE) {
+            offset = double(offset);
+            y = self.feedback(y, offset, load(block));
+        }
+
+        offset = triple(offset);
+        if ad_last.len() < BLOCK_SIZE {
+            offset = triple(offset);
+        }
+        if buffer.is_empty() {
+            offset = triple(triple(offset));
+        }
+        y = self.feedback(y, offset, load_padded(ad_last));
+
+        let (blocks, last) = split_last_block_mut(buffer);
+
+        for block in blocks.chunks_exact_mut(BLOCK_SIZE) {
+            offset = double(offset);
+
+            let input = load(block);
+            let m = if encrypting { input } else { input ^ y };
+            block.copy_from_slice(&(input ^ y).to_be_bytes());
+            y = self.feedback(y, offset, m);
+        }
+
+        if !last.is_empty() {
+            offset = triple(offset);
+            if last.len() < BLOCK_SIZE {
+                offset = triple(offset);
+            }
+
+            if encrypting {
+                let m = load_padded(last);
+                xor_keystream(last, y);
+                y = self.feedback(y, offset, m);
+            } else {
+                xor_keystream(last, y);
+                y = self.feedback(y, offset, load_padded(last));
+            }
+        }
+
+        y.to_be_bytes().into()
+    }
+
+    /// Encrypt `G(y) ⊕ m` with the offset added to its upper half
+    #[inline(always)]
+    fn feedback(&self, y: u128, offset: u64, m: u128) -> u128 {
+        self.cipher.encrypt(g(y) ^ m ^ u128::from(offset) << 64)
+    }
+}
+
+/// The feedback function `G(Y1 || Y2) = Y2 || (Y1 ⋘ 1)` on 64-bit halves
+#[inline(always)]
+fn g(y: u128) -> u128 {
+    y << 64 | u128::from(((y >> 64) as u64).rotate_left(1))
+}
+
+/// Multiply the offset by `x` in GF(2^64) = GF(2)\[x\] / (x^64 + x^4 + x^3 + x + 1)
+#[inline(always)]
+fn double(offset: u64) -> u64 {
+    (offset << 1) ^ ((offset >> 63) * 0x1b)
+}
+
+/// Multiply the offset by `x + 1`
+#[inline(always)]
+fn triple(offset: u64) -> u64 {
+    offset ^ double(offset)
+}
+
+/// Split `data` before its last, possibly full, block
+#[inline(always)]
+fn split_last_block(data: &[u8]) -> (&[u8], &[u8]) {
+    data.split_at(data.len().saturating_sub(1) / BLOCK_SIZE * BLOCK_SIZE)
+}
+
+/// Split `data` before its last, possibly full, block
+#[inline(always)]
+fn split_last_block_mut(data: &mut [u8]) -> (&mut [u8], &mut [u8]) {
+    data.split_at_mut(data.len().saturating_sub(1) / BLOCK_SIZE * BLOCK_SIZE)
+}
+
+/// XOR the first `bytes.len()` bytes of the big-endian block `y` into `bytes`
+#[inline(always)]
+fn xor_keystream(bytes: &mut [u8], y: u128) {
+    for (byte, k) in bytes.iter_mut().zip(y.to_be_bytes().iter()) {
+        *byte ^= k;
+    }
+}
+
+/// Load a full block as a big-endian integer
+#[inline(always)]
+fn load(block: &[u8]) -> u128 {
+    u128::from_be_bytes(block.try_into().unwrap())
+}
+
+/// Load a block, padding it with a one bit and zeroes if it is partial
+#[inline(always)]
+fn load_padded(bytes: &[u8]) -> u128 {
+    let mut block = [0u8; BLOCK_SIZE];
+    block[..bytes.len()].copy_from_slice(bytes);
+
+    if bytes.len() < BLOCK_SIZE {
+        block[bytes.len()] = 0x80;
+    }
+
+    u128::from_be_bytes(block)
+}
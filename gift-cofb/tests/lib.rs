@@ -0,0 +1,148 @@
+//! GIFT-COFB tests
+
+#[macro_use]
+extern crate hex_literal;
+
+use gift_cofb::aead::{generic_array::GenericArray, Aead, NewAead, Payload};
+use gift_cofb::GiftCofb;
+
+// Known-answer tests in the format of the NIST LWC submissions
+aead::new_test!(gift_cofb, "gift_cofb", GiftCofb);
+
+/// Test vectors
+#[derive(Debug)]
+pub struct TestVector {
+    pub key: &'static [u8; 16],
+    pub nonce: &'static [u8; 16],
+    pub aad: &'static [u8],
+    pub plaintext: &'static [u8],
+    pub ciphertext: &'static [u8],
+    pub tag: &'static [u8; 16],
+}
+
+/// GIFT-COFB test vectors, selected from the known-answer tests to cover empty,
+/// partial and multi-block inputs
+const TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("368965836d36614de2fc24d0f801b9af"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!("00"),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("ae5dcdd1285d5177fe251deb99d727dc"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!("00"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f"),
+        ciphertext: &hex!("26e9e1e6ca70a3aaeeefdb32d4ff5d58"),
+        tag: &hex!("40cf17e201be49b1206cfa841c406828"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!("000102030405060708090a0b0c0d0e"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f10111213141516"),
+        ciphertext: &hex!("63c244a171d6f7a407c8d8f90dcd2fb74b236d391a9846"),
+        tag: &hex!("86dd4b177831c6dd85ab98b65a195f09"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        ciphertext: &hex!("baf563c60fbeddc5662995f4c678be80a7f7de9b3ad8c97aa6ca17016d2ae650"),
+        tag: &hex!("8e6fb3f79b412a1627ab7dfa755e0a22"),
+    },
+];
+
+#[test]
+fn encrypt() {
+    for vector in TEST_VECTORS {
+        let key = GenericArray::from_slice(vector.key);
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let payload = Payload {
+            msg: vector.plaintext,
+            aad: vector.aad,
+        };
+
+        let cipher = GiftCofb::new(key);
+        let ciphertext = cipher.encrypt(nonce, payload).unwrap();
+        let (ct, tag) = ciphertext.split_at(vector.plaintext.len());
+        assert_eq!(vector.ciphertext, ct);
+        assert_eq!(vector.tag, tag);
+    }
+}
+
+#[test]
+fn decrypt() {
+    for vector in TEST_VECTORS {
+        let key = GenericArray::from_slice(vector.key);
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let mut ciphertext = Vec::from(vector.ciphertext);
+        ciphertext.extend_from_slice(vector.tag);
+
+        let payload = Payload {
+            msg: &ciphertext,
+            aad: vector.aad,
+        };
+
+        let cipher = GiftCofb::new(key);
+        let plaintext = cipher.decrypt(nonce, payload).unwrap();
+
+        assert_eq!(vector.plaintext, plaintext.as_slice());
+    }
+}
+
+#[test]
+fn decrypt_modified() {
+    let vector = &TEST_VECTORS[4];
+    let key = GenericArray::from_slice(vector.key);
+    let nonce = GenericArray::from_slice(vector.nonce);
+
+    let mut ciphertext = Vec::from(vector.ciphertext);
+    ciphertext.extend_from_slice(vector.tag);
+
+    // Tweak the first byte
+    ciphertext[0] ^= 0xaa;
+
+    let payload = Payload {
+        msg: &ciphertext,
+        aad: vector.aad,
+    };
+
+    let cipher = GiftCofb::new(key);
+    assert!(cipher.decrypt(nonce, payload).is_err());
+}
+
+#[test]
+fn multi_block() {
+    let key = GenericArray::from_slice(TEST_VECTORS[0].key);
+    let nonce = GenericArray::from_slice(TEST_VECTORS[0].nonce);
+    let data = (0..100).map(|i| i as u8).collect::<Vec<_>>();
+    let payload = Payload {
+        msg: &data,
+        aad: &data,
+    };
+
+    let cipher = GiftCofb::new(key);
+    let ciphertext = cipher.encrypt(nonce, payload).unwrap();
+    let (ct, tag) = ciphertext.split_at(data.len());
+    assert_eq!(ct, &hex!("4ca9c982c93a39a097de41d7669f0d283470ed3bc1f5d209ec3fdac2fa78e7635b2b223e232968f4cd784319c48bd81e31c04df1614774c001f53b743f01aff526de5d6c9b65e37fd5a8b7aef2b13d2c96d1cc2e1f4ab664ee3d990b5085de6291d2e2cb")[..]);
+    assert_eq!(tag, hex!("b0df36f77afa9319cdf5ce8ae24233fa"));
+
+    let payload = Payload {
+        msg: &ciphertext,
+        aad: &data,
+    };
+    assert_eq!(cipher.decrypt(nonce, payload).unwrap(), data);
+}
@@ -0,0 +1,199 @@
+//! Grain-128AEADv2 keystream generator and authenticator
+
+use crate::{Key, Nonce, Tag};
+use core::convert::TryInto;
+use zeroize::Zeroize;
+
+/// Keystream generator and MAC state
+pub(crate) struct Grain {
+    /// Linear feedback shift register, bit `i` being `s_i`
+    lfsr: u128,
+
+    /// Nonlinear feedback shift register, bit `i` being `b_i`
+    nfsr: u128,
+
+    /// Authentication accumulator
+    acc: u64,
+
+    /// Authentication shift register
+    reg: u64,
+}
+
+impl Grain {
+    /// Load the key and nonce and run the initialization, which also sets up
+    /// the authenticator
+    pub(crate) fn new(key: &Key, nonce: &Nonce) -> Self {
+        let key = u128::from_le_bytes(key.as_slice().try_into().unwrap());
+
+        // The LFSR is the nonce followed by 31 ones and a zero
+        let mut iv = [0xff; 16];
+        iv[..12].copy_from_slice(nonce);
+        iv[15] = 0x7f;
+
+        let mut grain = Self {
+            lfsr: u128::from_le_bytes(iv),
+            nfsr: key,
+            acc: 0,
+            reg: 0,
+        };
+
+        // 320 clocks feeding the pre-output back into both registers
+        for _ in 0..10 {
+            let y = u128::from(grain.clock(32)) << 96;
+            grain.lfsr ^= y;
+            grain.nfsr ^= y;
+        }
+
+        // 64 clocks additionally feeding the key back
+        for i in 0..2 {
+            let y = grain.clock(32);
+            grain.lfsr ^= u128::from(y ^ (key >> (64 + 32 * i)) as u32) << 96;
+            grain.nfsr ^= u128::from(y ^ (key >> (32 * i)) as u32) << 96;
+        }
+
+        let lo = grain.clock(32);
+        let hi = grain.clock(32);
+        grain.acc = u64::from(hi) << 32 | u64::from(lo);
+
+        let lo = grain.clock(32);
+        let hi = grain.clock(32);
+        grain.reg = u64::from(hi) << 32 | u64::from(lo);
+
+        grain
+    }
+
+    /// Authenticate the DER encoding of the associated data length followed
+    /// by the associated data
+    pub(crate) fn absorb(&mut self, associated_data: &[u8]) {
+        let len = associated_data.len() as u64;
+
+        if len < 0x80 {
+            self.absorb_byte(len as u8);
+        } else {
+            let skip = len.leading_zeros() as usize / 8;
+            self.absorb_byte(0x80 | (8 - skip) as u8);
+
+            for &byte in &len.to_be_bytes()[skip..] {
+                self.absorb_byte(byte);
+            }
+        }
+
+        for &byte in associated_data {
+            self.absorb_byte(byte);
+        }
+    }
+
+    /// Encrypt `buffer` in-place
+    pub(crate) fn encrypt(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            let (keystream, auth) = self.next_byte();
+            self.authenticate(*byte, auth);
+            *byte ^= keystream;
+        }
+    }
+
+    /// Decrypt `buffer` in-place
+    pub(crate) fn decrypt(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            let (keystream, auth) = self.next_byte();
+            *byte ^= keystream;
+            self.authenticate(*byte, auth);
+        }
+    }
+
+    /// Authenticate the final padding bit and return the accumulator
+    pub(crate) fn finalize(mut self) -> Tag {
+        self.acc ^= self.reg;
+        self.acc.to_le_bytes().into()
+    }
+
+    /// Authenticate a byte of associated data, discarding the keystream
+    fn absorb_byte(&mut self, byte: u8) {
+        let (_, auth) = self.next_byte();
+        self.authenticate(byte, auth);
+    }
+
+    /// Clock 16 times and split the pre-output into the even bits used as
+    /// keystream and the odd bits used for authentication
+    #[inline(always)]
+    fn next_byte(&mut self) -> (u8, u8) {
+        let y = u32::from(self.clock(16) as u16);
+        (compress(y), compress(y >> 1))
+    }
+
+    /// Update the authenticator with the bits of `byte`, least significant
+    /// first, and shift in the bits of `auth`
+    #[inline(always)]
+    fn authenticate(&mut self, byte: u8, auth: u8) {
+        for i in 0..8 {
+            let m = u64::from((byte >> i) & 1);
+            self.acc ^= self.reg & m.wrapping_neg();
+            self.reg = self.reg >> 1 | u64::from((auth >> i) & 1) << 63;
+        }
+    }
+
+    /// Clock both registers `n <= 32` times. The low `n` bits of the result
+    /// are the pre-output bits.
+    #[inline(always)]
+    fn clock(&mut self, n: u32) -> u32 {
+        let (lfsr, nfsr) = (self.lfsr, self.nfsr);
+        let s = |i: u32| (lfsr >> i) as u32;
+        let b = |i: u32| (nfsr >> i) as u32;
+
+        let y = (b(12) & s(8))
+            ^ (s(13) & s(20))
+            ^ (b(95) & s(42))
+            ^ (s(60) & s(79))
+            ^ (b(12) & b(95) & s(94))
+            ^ s(93)
+            ^ b(2)
+            ^ b(15)
+            ^ b(36)
+            ^ b(45)
+            ^ b(64)
+            ^ b(73)
+            ^ b(89);
+
+        let f = s(0) ^ s(7) ^ s(38) ^ s(70) ^ s(81) ^ s(96);
+
+        let g = s(0)
+            ^ b(0)
+            ^ b(26)
+            ^ b(56)
+            ^ b(91)
+            ^ b(96)
+            ^ (b(3) & b(67))
+            ^ (b(11) & b(13))
+            ^ (b(17) & b(18))
+            ^ (b(27) & b(59))
+            ^ (b(40) & b(48))
+            ^ (b(61) & b(65))
+            ^ (b(68) & b(84))
+            ^ (b(22) & b(24) & b(25))
+            ^ (b(70) & b(78) & b(82))
+            ^ (b(88) & b(92) & b(93) & b(95));
+
+        self.lfsr = lfsr >> n | u128::from(f) << (128 - n);
+        self.nfsr = nfsr >> n | u128::from(g) << (128 - n);
+        y
+    }
+}
+
+impl Drop for Grain {
+    fn drop(&mut self) {
+        self.lfsr.zeroize();
+        self.nfsr.zeroize();
+        self.acc.zeroize();
+        self.reg.zeroize();
+    }
+}
+
+/// Gather the even bits of the low 16 bits of `x` into a byte
+#[inline(always)]
+fn compress(x: u32) -> u8 {
+    let mut x = x & 0x5555;
+    x = (x | x >> 1) & 0x3333;
+    x = (x | x >> 2) & 0x0f0f;
+    x = (x | x >> 4) & 0x00ff;
+    x as u8
+}
@@ -0,0 +1,178 @@
+//! [Grain-128AEADv2][1]: a lightweight [Authenticated Encryption with
+//! Associated Data (AEAD)][2] cipher built on a bit-oriented stream cipher.
+//!
+//! Grain-128AEADv2 was a finalist of the NIST lightweight cryptography
+//! process. A 128-bit LFSR and a 128-bit NFSR produce one pre-output bit per
+//! clock, with the even bits used as keystream and the odd bits used to
+//! update a 64-bit authentication accumulator. The cipher is extremely small
+//! in hardware, and its predecessor Grain-128a is standardized for RFID air
+//! interfaces in ISO/IEC 29167-13.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! Grain-128AEADv2 has a 64-bit tag, so each forgery attempt succeeds with
+//! probability 2^-64. Applications which can't limit the number of decryption
+//! attempts should prefer an AEAD with a longer tag.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! ```
+//! use grain_128aead::{Grain128Aead, Key, Nonce};
+//! use grain_128aead::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = Grain128Aead::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce"); // 96-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! Note that if you enable the `heapless` feature of this crate,
+//! you will receive an impl of [`aead::Buffer`] for `heapless::Vec`
+//! (re-exported from the [`aead`] crate as [`aead::heapless::Vec`]),
+//! which can then be passed as the `buffer` parameter to the in-place encrypt
+//! and decrypt methods:
+//!
+//! ```
+//! # #[cfg(feature = "heapless")]
+//! # {
+//! use grain_128aead::{Grain128Aead, Key, Nonce};
+//! use grain_128aead::aead::{AeadInPlace, NewAead};
+//! use grain_128aead::aead::heapless::Vec;
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = Grain128Aead::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce"); // 96-bits; unique per message
+//!
+//! let mut buffer: Vec<u8, 128> = Vec::new(); // Buffer needs 8-bytes overhead for the tag
+//! buffer.extend_from_slice(b"plaintext message");
+//!
+//! // Encrypt `buffer` in-place, replacing the plaintext contents with ciphertext
+//! cipher.encrypt_in_place(nonce, b"", &mut buffer).expect("encryption failure!");
+//!
+//! // `buffer` now contains the message ciphertext
+//! assert_ne!(&buffer, b"plaintext message");
+//!
+//! // Decrypt `buffer` in-place, replacing its ciphertext context with the original plaintext
+//! cipher.decrypt_in_place(nonce, b"", &mut buffer).expect("decryption failure!");
+//! assert_eq!(&buffer, b"plaintext message");
+//! # }
+//! ```
+//!
+//! [1]: https://csrc.nist.gov/projects/lightweight-cryptography/finalists
+//! [2]: https://en.wikipedia.org/wiki/Authenticated_encryption
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![deny(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use aead::{self, consts, AeadCore, AeadInPlace, Error, NewAead};
+
+mod grain;
+
+use aead::{
+    consts::{U0, U12, U16, U8},
+    generic_array::GenericArray,
+};
+use grain::Grain;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// Grain-128AEADv2 keys (128-bit)
+pub type Key = GenericArray<u8, U16>;
+
+/// Grain-128AEADv2 nonces (96-bit)
+pub type Nonce = GenericArray<u8, U12>;
+
+/// Grain-128AEADv2 tags (64-bit)
+pub type Tag = GenericArray<u8, U8>;
+
+/// Grain-128AEADv2: 128-bit key, 96-bit nonce, 64-bit tag.
+#[derive(Clone)]
+pub struct Grain128Aead {
+    /// Secret key
+    key: Key,
+}
+
+impl NewAead for Grain128Aead {
+    type KeySize = U16;
+
+    fn new(key: &Key) -> Self {
+        Self { key: *key }
+    }
+}
+
+impl AeadCore for Grain128Aead {
+    type NonceSize = U12;
+    type TagSize = U8;
+    type CiphertextOverhead = U0;
+}
+
+impl AeadInPlace for Grain128Aead {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        let mut grain = Grain::new(&self.key, nonce);
+        grain.absorb(associated_data);
+        grain.encrypt(buffer);
+        Ok(grain.finalize())
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        let mut grain = Grain::new(&self.key, nonce);
+        grain.absorb(associated_data);
+        grain.decrypt(buffer);
+        let expected_tag = grain.finalize();
+
+        if expected_tag.ct_eq(tag).unwrap_u8() == 1 {
+            Ok(())
+        } else {
+            // Don't release unauthenticated plaintext
+            buffer.iter_mut().for_each(|b| *b = 0);
+            Err(Error)
+        }
+    }
+}
+
+impl Drop for Grain128Aead {
+    fn drop(&mut self) {
+        self.key.as_mut_slice().zeroize();
+    }
+}
@@ -0,0 +1,150 @@
+//! Grain-128AEADv2 tests
+
+#[macro_use]
+extern crate hex_literal;
+
+use grain_128aead::aead::{generic_array::GenericArray, Aead, NewAead, Payload};
+use grain_128aead::Grain128Aead;
+
+// Known-answer tests in the format of the NIST LWC submissions
+aead::new_test!(grain128aead, "grain128aead", Grain128Aead);
+
+/// Test vectors
+#[derive(Debug)]
+pub struct TestVector {
+    pub key: &'static [u8; 16],
+    pub nonce: &'static [u8; 12],
+    pub aad: &'static [u8],
+    pub plaintext: &'static [u8],
+    pub ciphertext: &'static [u8],
+    pub tag: &'static [u8; 8],
+}
+
+/// Grain-128AEADv2 test vectors. The first two are from the specification,
+/// the remaining ones are selected from the known-answer tests to cover
+/// empty, partial and multi-block inputs
+const TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        key: &hex!("00000000000000000000000000000000"),
+        nonce: &hex!("000000000000000000000000"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("7137d5998c2de4a5"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b"),
+        aad: &hex!("0001020304050607"),
+        plaintext: &hex!("0001020304050607"),
+        ciphertext: &hex!("96d1bda7ae11f0ba"),
+        tag: &hex!("22b0c12039a20e28"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("d51fd5d16177b434"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b"),
+        aad: &hex!(""),
+        plaintext: &hex!("00"),
+        ciphertext: &hex!("21"),
+        tag: &hex!("aaa5a068ea941db3"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f1011"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f10111213141516"),
+        ciphertext: &hex!("39e0889589ac78b84ecf55b84fe7d903e64c5e2fddebbc"),
+        tag: &hex!("143ea573356aade7"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        ciphertext: &hex!("d70df45e4839cff9a2c139c719805cfcaab5ab651b99a751fbf4b8d75abd6d97"),
+        tag: &hex!("f543fe1cfbe56f72"),
+    },
+];
+
+#[test]
+fn encrypt() {
+    for vector in TEST_VECTORS {
+        let key = GenericArray::from_slice(vector.key);
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let payload = Payload {
+            msg: vector.plaintext,
+            aad: vector.aad,
+        };
+
+        let cipher = Grain128Aead::new(key);
+        let ciphertext = cipher.encrypt(nonce, payload).unwrap();
+        let (ct, tag) = ciphertext.split_at(vector.plaintext.len());
+        assert_eq!(vector.ciphertext, ct);
+        assert_eq!(vector.tag, tag);
+    }
+}
+
+#[test]
+fn decrypt() {
+    for vector in TEST_VECTORS {
+        let key = GenericArray::from_slice(vector.key);
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let mut ciphertext = Vec::from(vector.ciphertext);
+        ciphertext.extend_from_slice(vector.tag);
+
+        let payload = Payload {
+            msg: &ciphertext,
+            aad: vector.aad,
+        };
+
+        let cipher = Grain128Aead::new(key);
+        let plaintext = cipher.decrypt(nonce, payload).unwrap();
+
+        assert_eq!(vector.plaintext, plaintext.as_slice());
+    }
+}
+
+#[test]
+fn decrypt_modified() {
+    let vector = &TEST_VECTORS[5];
+    let key = GenericArray::from_slice(vector.key);
+    let nonce = GenericArray::from_slice(vector.nonce);
+
+    let mut ciphertext = Vec::from(vector.ciphertext);
+    ciphertext.extend_from_slice(vector.tag);
+
+    // Tweak the first byte
+    ciphertext[0] ^= 0xaa;
+
+    let payload = Payload {
+        msg: &ciphertext,
+        aad: vector.aad,
+    };
+
+    let cipher = Grain128Aead::new(key);
+    assert!(cipher.decrypt(nonce, payload).is_err());
+}
+
+/// Associated data of 128 bytes or more has a multi-byte length encoding
+#[test]
+fn long_associated_data() {
+    let key = GenericArray::from_slice(TEST_VECTORS[2].key);
+    let nonce = GenericArray::from_slice(TEST_VECTORS[2].nonce);
+    let aad = (0..200).map(|i| i as u8).collect::<Vec<_>>();
+    let payload = Payload {
+        msg: &hex!("000102"),
+        aad: &aad,
+    };
+
+    let cipher = Grain128Aead::new(key);
+    let ciphertext = cipher.encrypt(nonce, payload).unwrap();
+    assert_eq!(ciphertext, hex!("0751a057fe044679c002a4"));
+}
@@ -0,0 +1,280 @@
+//! Generic hidden-nonce wrapper for [Authenticated Encryption with Associated
+//! Data (AEAD)][1] ciphers, which encrypts the nonce into the ciphertext.
+//!
+//! Sending the nonce of each message in the clear is the norm, but counter or
+//! sequence number nonces reveal the order and number of messages to anyone
+//! watching. [`HiddenNonce`] carries the nonce encrypted instead, in the
+//! style of the nonce-hiding transforms of Bellare, Ng and Tackmann,
+//! [*Nonces Are Noticed: AEAD Revisited*][2]. The nonce is masked with a PRF
+//! of the tag of the wrapped AEAD, which is pseudorandom and a function of
+//! the nonce, so it can be unmasked before decryption.
+//!
+//! HMAC-SHA-256 keyed with the key `K` derives the key `L` of the wrapped
+//! AEAD, and the mask of the nonce `N`:
+//!
+//! ```text
+//! L = HMAC-SHA-256(K, 0x01) || HMAC-SHA-256(K, 0x02) || ...
+//! C || T = AEAD(L, N, A, M)
+//! H = N ^ HMAC-SHA-256(K, 0x00 || T)
+//! ```
+//!
+//! where `L` is truncated to the key size of the wrapped AEAD, and the mask
+//! to its nonce size. The ciphertext is `H || C || T`.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! Nonces are only hidden as long as tags don't repeat, so wrap AEADs with
+//! 128-bit tags: the truncated tags of e.g. `ChaCha20Poly1305Tag64` collide
+//! after about 2<sup>32</sup> messages. Nonces must still be unique per
+//! message, as required by the wrapped AEAD. The nonce of a message is
+//! returned by [`HiddenNonce::decrypt_in_place`], to detect replayed or
+//! reordered messages.
+//!
+//! The construction is specific to this crate: its ciphertexts can only be
+//! decrypted by implementations of it, not by the wrapped AEAD.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! ```
+//! use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+//! use hidden_nonce::HiddenNonce;
+//! use hidden_nonce::aead::NewAead;
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = HiddenNonce::<ChaCha20Poly1305>::new(key);
+//!
+//! let nonce = Nonce::from_slice(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]); // sequence number 1
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! // The hidden nonce, then the message and the 16-byte tag
+//! assert_eq!(ciphertext.len(), 12 + 17 + 16);
+//! assert_ne!(&ciphertext[..12], nonce.as_slice());
+//!
+//! // Nonces aren't passed to decryption
+//! let plaintext = cipher.decrypt(ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`HiddenNonce::encrypt_in_place`] and [`HiddenNonce::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! [1]: https://en.wikipedia.org/wiki/Authenticated_encryption
+//! [2]: https://eprint.iacr.org/2019/624
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub use aead::{self, AeadCore, AeadInPlace, Error, NewAead};
+
+use aead::{
+    consts::{True, U32},
+    generic_array::{
+        typenum::{IsLessOrEqual, Unsigned},
+        GenericArray,
+    },
+    Buffer, Nonce, Tag,
+};
+use hmac::{Hmac, Mac, NewMac};
+use sha2::Sha256;
+use zeroize::Zeroize;
+
+#[cfg(feature = "alloc")]
+use {aead::Payload, alloc::vec::Vec};
+
+/// Wrapper around an AEAD which encrypts the nonce into the ciphertext.
+///
+/// See the [toplevel documentation](index.html) for details of the
+/// construction and a usage example.
+///
+/// The key and nonce sizes are those of the wrapped AEAD `A`, whose nonces
+/// must be at most 256 bits, and ciphertexts are longer by its nonce size.
+pub struct HiddenNonce<A>
+where
+    A: NewAead + AeadInPlace,
+{
+    /// HMAC-SHA-256, keyed with the key
+    mac: Hmac<Sha256>,
+
+    /// Wrapped AEAD, keyed with the derived key
+    aead: A,
+}
+
+impl<A> NewAead for HiddenNonce<A>
+where
+    A: NewAead + AeadInPlace,
+{
+    type KeySize = A::KeySize;
+
+    fn new(key: &GenericArray<u8, A::KeySize>) -> Self {
+        let mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+
+        // The counter can't wrap around: AEAD keys are far shorter than 255
+        // HMAC-SHA-256 outputs
+        let mut aead_key = GenericArray::<u8, A::KeySize>::default();
+        for (i, chunk) in aead_key.chunks_mut(32).enumerate() {
+            let mut block = mac.clone();
+            block.update(&[i as u8 + 1]);
+            let mut block = block.finalize().into_bytes();
+            chunk.copy_from_slice(&block[..chunk.len()]);
+            block.as_mut_slice().zeroize();
+        }
+
+        let aead = A::new(&aead_key);
+        aead_key.as_mut_slice().zeroize();
+        Self { mac, aead }
+    }
+}
+
+impl<A> HiddenNonce<A>
+where
+    A: NewAead + AeadInPlace,
+    A::NonceSize: IsLessOrEqual<U32, Output = True>,
+{
+    /// Encrypt the given buffer containing a plaintext message in-place, and
+    /// lay it out as `hidden nonce || ciphertext || tag`.
+    pub fn encrypt_in_place(
+        &self,
+        nonce: &Nonce<A>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        let msg_len = buffer.len();
+        let nonce_size = A::NonceSize::to_usize();
+
+        // Make room in the buffer for the hidden nonce, which needs to be
+        // prepended, and the tag
+        buffer.extend_from_slice(&Nonce::<A>::default())?;
+        buffer.extend_from_slice(&Tag::<A>::default())?;
+
+        let buffer = buffer.as_mut();
+        buffer.copy_within(..msg_len, nonce_size);
+
+        let (msg, tag_out) = buffer[nonce_size..].split_at_mut(msg_len);
+        let tag = self
+            .aead
+            .encrypt_in_place_detached(nonce, associated_data, msg)?;
+        tag_out.copy_from_slice(&tag);
+
+        buffer[..nonce_size].copy_from_slice(&self.hide(nonce, &tag));
+        Ok(())
+    }
+
+    /// Decrypt a `hidden nonce || ciphertext || tag` buffer in-place,
+    /// returning the nonce of the message, or an error in the event the tag
+    /// does not match the nonce and ciphertext.
+    ///
+    /// On success, the buffer only contains the plaintext.
+    pub fn decrypt_in_place(
+        &self,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<Nonce<A>, Error> {
+        let nonce_size = A::NonceSize::to_usize();
+        let tag_size = A::TagSize::to_usize();
+
+        if buffer.len() < nonce_size + tag_size {
+            return Err(Error);
+        }
+
+        let tag_pos = buffer.len() - tag_size;
+        let tag = Tag::<A>::clone_from_slice(&buffer.as_ref()[tag_pos..]);
+        let hidden = Nonce::<A>::clone_from_slice(&buffer.as_ref()[..nonce_size]);
+        let nonce = self.hide(&hidden, &tag);
+
+        self.aead.decrypt_in_place_detached(
+            &nonce,
+            associated_data,
+            &mut buffer.as_mut()[nonce_size..tag_pos],
+            &tag,
+        )?;
+
+        buffer.as_mut().copy_within(nonce_size..tag_pos, 0);
+        buffer.truncate(tag_pos - nonce_size);
+        Ok(nonce)
+    }
+
+    /// Encrypt the given plaintext payload, and return the resulting
+    /// `hidden nonce || ciphertext || tag` as a vector of bytes.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn encrypt<'msg, 'aad>(
+        &self,
+        nonce: &Nonce<A>,
+        plaintext: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<Vec<u8>, Error> {
+        let payload = plaintext.into();
+        let mut buffer = Vec::with_capacity(
+            A::NonceSize::to_usize() + payload.msg.len() + A::TagSize::to_usize(),
+        );
+        buffer.extend_from_slice(payload.msg);
+        self.encrypt_in_place(nonce, payload.aad, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Decrypt the given `hidden nonce || ciphertext || tag` payload, and
+    /// return the resulting plaintext as a vector of bytes.
+    ///
+    /// Use [`HiddenNonce::decrypt_in_place`] to also get the nonce.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn decrypt<'msg, 'aad>(
+        &self,
+        ciphertext: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<Vec<u8>, Error> {
+        let payload = ciphertext.into();
+        let mut buffer = Vec::from(payload.msg);
+        self.decrypt_in_place(payload.aad, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// XOR the mask derived from `tag` into `nonce`, which hides or recovers
+    /// it
+    fn hide(&self, nonce: &Nonce<A>, tag: &Tag<A>) -> Nonce<A> {
+        let mut mac = self.mac.clone();
+        mac.update(&[0]);
+        mac.update(tag);
+        let mask = mac.finalize().into_bytes();
+
+        let mut hidden = nonce.clone();
+        for (a, b) in hidden.iter_mut().zip(mask.iter()) {
+            *a ^= b;
+        }
+        hidden
+    }
+}
+
+impl<A> Clone for HiddenNonce<A>
+where
+    A: NewAead + AeadInPlace + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            mac: self.mac.clone(),
+            aead: self.aead.clone(),
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! Tests of `HiddenNonce`
+//!
+//! The expected ciphertexts were computed independently, with Python's
+//! `hmac` module and the AES-GCM and ChaCha20Poly1305 implementations of
+//! `cryptography`.
+
+#[macro_use]
+extern crate hex_literal;
+
+use aes_gcm::Aes256Gcm;
+use chacha20poly1305::ChaCha20Poly1305;
+use hidden_nonce::aead::{generic_array::GenericArray, NewAead, Payload};
+use hidden_nonce::HiddenNonce;
+
+/// Nonce of all the test cases: sequence number 1
+const NONCE: [u8; 12] = hex!("000000000000000000000001");
+
+/// Associated data of the second test case of each AEAD
+const AAD: &[u8] = b"associated data";
+
+/// Plaintext of the second test case of each AEAD
+const PLAINTEXT: &[u8] = b"In the beginning the Universe was created.";
+
+macro_rules! tests {
+    ($name:ident, $aead:ty, $key:expr, $empty:expr, $ciphertext:expr) => {
+        mod $name {
+            use super::*;
+
+            const KEY: &[u8] = &$key;
+
+            /// Ciphertext of the empty message with no associated data
+            const EMPTY: &[u8] = &$empty;
+
+            /// Ciphertext of `PLAINTEXT` with `AAD`
+            const CIPHERTEXT: &[u8] = &$ciphertext;
+
+            fn cipher() -> HiddenNonce<$aead> {
+                HiddenNonce::new(GenericArray::from_slice(KEY))
+            }
+
+            #[test]
+            fn encrypt() {
+                let nonce = NONCE.into();
+                assert_eq!(cipher().encrypt(&nonce, b"".as_ref()).unwrap(), EMPTY);
+
+                let payload = Payload {
+                    msg: PLAINTEXT,
+                    aad: AAD,
+                };
+                assert_eq!(cipher().encrypt(&nonce, payload).unwrap(), CIPHERTEXT);
+            }
+
+            #[test]
+            fn decrypt() {
+                assert!(cipher().decrypt(EMPTY).unwrap().is_empty());
+
+                let payload = Payload {
+                    msg: CIPHERTEXT,
+                    aad: AAD,
+                };
+                assert_eq!(cipher().decrypt(payload).unwrap(), PLAINTEXT);
+            }
+
+            #[test]
+            fn decrypt_in_place() {
+                let mut buffer = CIPHERTEXT.to_vec();
+                let nonce = cipher().decrypt_in_place(AAD, &mut buffer).unwrap();
+                assert_eq!(nonce.as_slice(), &NONCE);
+                assert_eq!(&buffer, PLAINTEXT);
+            }
+
+            #[test]
+            fn decrypt_modified() {
+                let mut ciphertext = CIPHERTEXT.to_vec();
+
+                // Tweak the first byte of the hidden nonce, the first byte of
+                // the ciphertext and the last byte of the tag
+                for &i in &[0, 12, ciphertext.len() - 1] {
+                    ciphertext[i] ^= 1;
+                    let payload = Payload {
+                        msg: &ciphertext,
+                        aad: AAD,
+                    };
+                    assert!(cipher().decrypt(payload).is_err());
+                    ciphertext[i] ^= 1;
+                }
+            }
+
+            #[test]
+            fn decrypt_truncated() {
+                assert!(cipher().decrypt(&EMPTY[..EMPTY.len() - 1]).is_err());
+            }
+
+            /// Consecutive nonces don't show in the ciphertexts
+            #[test]
+            fn hides_sequence_numbers() {
+                let mut next = NONCE;
+                next[11] += 1;
+
+                let a = cipher().encrypt(&NONCE.into(), PLAINTEXT).unwrap();
+                let b = cipher().encrypt(&next.into(), PLAINTEXT).unwrap();
+                assert_ne!(&a[..11], &NONCE[..11]);
+                assert_ne!(&a[..11], &b[..11]);
+            }
+        }
+    };
+}
+
+tests!(
+    chacha20poly1305_ietf,
+    ChaCha20Poly1305,
+    hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+    hex!(
+        "aa7d67155291ea18c309066a
+         2c42a95b474a38038a27f83bf9c17ba3"
+    ),
+    hex!(
+        "ac56ed402207092bcf374a26
+         09796036557cedf1bdc8e38b809fd49544d61800baade0364731708171b7f77e
+         369d5588a38cfc439485
+         3eec3c98696462b2eeaac998deab73fc"
+    )
+);
+
+tests!(
+    aes256gcm,
+    Aes256Gcm,
+    hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+    hex!(
+        "287d629a0e3eb1dd2134d744
+         bf021aa02e96e94016418e5f8d2a07ae"
+    ),
+    hex!(
+        "0ba5429a9a2da4bd3ff1d87f
+         d109509da54d448c3a814ba99551f94e27ef9322e37ea9b116cb608fe1c439ee
+         458670a8b07340575c8d
+         cbe3e0b8d292e6048f0a3a6d011dc682"
+    )
+);
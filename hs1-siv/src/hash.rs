@@ -0,0 +1,201 @@
+//! HS1-Hash: the universal hash of HS1-SIV, composed of NH, a polynomial
+//! hash modulo 2<sup>61</sup> - 1 and, for `t > 4`, an almost strongly
+//! universal hash to shorten its output.
+
+use zeroize::Zeroize;
+
+/// Size of an NH block in bytes (`b`)
+pub(crate) const BLOCK_SIZE: usize = 64;
+
+/// Maximum number of hashes computed in parallel (`t`)
+pub(crate) const MAX_T: usize = 6;
+
+/// Number of 32-bit words of NH key needed for `MAX_T` hashes
+pub(crate) const NH_KEY_WORDS: usize = BLOCK_SIZE / 4 + 4 * (MAX_T - 1);
+
+/// 2<sup>61</sup> - 1
+const P61: u64 = (1 << 61) - 1;
+
+/// 2<sup>60</sup> - 1
+const MASK60: u64 = (1 << 60) - 1;
+
+/// Keys of the `t` hashes.
+///
+/// Only the first `t` polynomial and ASU keys are used, and the ASU keys are
+/// only used when `t > 4`.
+#[derive(Clone)]
+pub(crate) struct HashKey {
+    /// NH key (`kN`), hash `i` uses the words starting at `4i`
+    pub(crate) nh: [u32; NH_KEY_WORDS],
+
+    /// Polynomial hash keys (`kP`), less than 2<sup>60</sup>
+    pub(crate) poly: [u64; MAX_T],
+
+    /// ASU hash keys (`kA`)
+    pub(crate) asu: [[u64; 3]; MAX_T],
+}
+
+impl Default for HashKey {
+    fn default() -> Self {
+        Self {
+            nh: [0; NH_KEY_WORDS],
+            poly: [0; MAX_T],
+            asu: [[0; 3]; MAX_T],
+        }
+    }
+}
+
+impl Drop for HashKey {
+    fn drop(&mut self) {
+        self.nh.zeroize();
+        self.poly.zeroize();
+        for asu in self.asu.iter_mut() {
+            asu.zeroize();
+        }
+    }
+}
+
+/// HS1-Hash computing `t` hashes of the same input
+pub(crate) struct Hasher<'k> {
+    key: &'k HashKey,
+
+    /// Number of hashes (`t`)
+    t: usize,
+
+    /// Polynomial hash states
+    h: [u64; MAX_T],
+
+    /// Input which doesn't fill an NH block yet
+    block: [u8; BLOCK_SIZE],
+
+    /// Number of bytes in `block`
+    pos: usize,
+}
+
+impl<'k> Hasher<'k> {
+    /// Initialize the `t` hashes keyed with `key`
+    pub(crate) fn new(key: &'k HashKey, t: usize) -> Self {
+        debug_assert!(t > 0 && t <= MAX_T);
+
+        Self {
+            key,
+            t,
+            h: [1; MAX_T],
+            block: [0; BLOCK_SIZE],
+            pos: 0,
+        }
+    }
+
+    /// Input data
+    pub(crate) fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            if self.pos == BLOCK_SIZE {
+                self.compute_block();
+            }
+
+            let n = core::cmp::min(BLOCK_SIZE - self.pos, data.len());
+            self.block[self.pos..self.pos + n].copy_from_slice(&data[..n]);
+            self.pos += n;
+            data = &data[n..];
+        }
+    }
+
+    /// Input data, followed by zeroes up to a multiple of 16 bytes
+    pub(crate) fn update_padded(&mut self, data: &[u8]) {
+        self.update(data);
+
+        let rem = self.pos % 16;
+        if rem != 0 {
+            self.update(&[0; 16][..16 - rem]);
+        }
+    }
+
+    /// Finish the hashes, and XOR their concatenation into `out`: 8 bytes
+    /// per hash when `t <= 4`, and 4 bytes otherwise.
+    pub(crate) fn finalize_xor(mut self, out: &mut [u8]) {
+        if self.pos != 0 {
+            self.compute_block();
+        }
+
+        for i in 0..self.t {
+            let h = poly_finalize(self.h[i]);
+
+            if self.t <= 4 {
+                xor(&mut out[8 * i..8 * (i + 1)], &h.to_le_bytes());
+            } else {
+                let [k0, k1, k2] = self.key.asu[i];
+                let y = k0
+                    .wrapping_add(k1.wrapping_mul(h & 0xffff_ffff))
+                    .wrapping_add(k2.wrapping_mul(h >> 32));
+                xor(
+                    &mut out[4 * i..4 * (i + 1)],
+                    &((y >> 32) as u32).to_le_bytes(),
+                );
+            }
+        }
+    }
+
+    /// Hash the `pos` bytes of `block` with NH, and add the result to the
+    /// polynomial hashes.
+    fn compute_block(&mut self) {
+        let len = self.pos;
+        self.block[len..].iter_mut().for_each(|b| *b = 0);
+
+        let mut m = [0u32; BLOCK_SIZE / 4];
+        for (word, bytes) in m.iter_mut().zip(self.block.chunks_exact(4)) {
+            *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+
+        // NH only covers the input padded to a multiple of 16 bytes
+        let words = (len + 15) / 16 * 4;
+
+        for i in 0..self.t {
+            let k = &self.key.nh[4 * i..];
+            let mut nh = 0u64;
+
+            for (m, k) in m[..words].chunks_exact(4).zip(k.chunks_exact(4)) {
+                let a = u64::from(m[0].wrapping_add(k[0]));
+                let b = u64::from(m[1].wrapping_add(k[1]));
+                let c = u64::from(m[2].wrapping_add(k[2]));
+                let d = u64::from(m[3].wrapping_add(k[3]));
+                nh = nh.wrapping_add(a * c).wrapping_add(b * d);
+            }
+
+            let a = nh.wrapping_add((len % 16) as u64) & MASK60;
+            self.h[i] = poly_step(self.h[i], a, self.key.poly[i]);
+        }
+
+        m.zeroize();
+        self.pos = 0;
+    }
+}
+
+impl Drop for Hasher<'_> {
+    fn drop(&mut self) {
+        self.h.zeroize();
+        self.block.zeroize();
+    }
+}
+
+/// `h * k + a`, partially reduced modulo 2<sup>61</sup> - 1
+#[inline]
+fn poly_step(h: u64, a: u64, k: u64) -> u64 {
+    let t = u128::from(h) * u128::from(k);
+    ((t as u64) & P61)
+        .wrapping_add((t >> 61) as u64)
+        .wrapping_add(a)
+}
+
+/// Fully reduce `h` modulo 2<sup>61</sup> - 1
+#[inline]
+fn poly_finalize(h: u64) -> u64 {
+    let h = (h & P61) + (h >> 61);
+    h & u64::from(h == P61).wrapping_sub(1)
+}
+
+#[inline]
+fn xor(out: &mut [u8], data: &[u8]) {
+    for (a, b) in out.iter_mut().zip(data) {
+        *a ^= b;
+    }
+}
@@ -0,0 +1,369 @@
+//! [HS1-SIV][1]: [Authenticated Encryption with Associated Data (AEAD)][2]
+//! ciphers built on the [ChaCha][3] stream cipher and the HS1 universal
+//! hash, which also provide [nonce reuse misuse resistance][4].
+//!
+//! HS1-SIV hashes the associated data and plaintext with HS1, a keyed
+//! hash-based PRF, to produce a synthetic IV which serves as the tag, and
+//! encrypts the plaintext with ChaCha keyed with the hash of the tag. This
+//! crate implements the three parameter sets of the [CAESAR submission][1]:
+//!
+//! | Type          | Name         | ChaCha rounds | `t` | Tag size |
+//! |---------------|--------------|---------------|-----|----------|
+//! | [`Hs1SivLo`]  | hs1-siv-lo   | 8             | 2   | 64-bit   |
+//! | [`Hs1SivMe`]  | hs1-siv      | 12            | 4   | 128-bit  |
+//! | [`Hs1SivHi`]  | hs1-siv-hi   | 20            | 6   | 256-bit  |
+//!
+//! where `t` is the number of hashes HS1 computes in parallel, which
+//! determines its collision resistance.
+//!
+//! All three use 96-bit nonces, and keys of 1 to 32 bytes: [`NewAead::new`]
+//! takes a 32-byte key, and [`NewAead::new_from_slice`] accepts the shorter
+//! ones.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! Reusing a nonce only reveals whether the same message was encrypted with
+//! the same associated data, but nonces should still be unique.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! Simple usage (allocating, no associated data):
+//!
+//! ```
+//! use hs1_siv::{Hs1SivMe, Key, Nonce}; // Or `Hs1SivLo`, `Hs1SivHi`
+//! use hs1_siv::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = Hs1SivMe::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce"); // 96-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! Note that if you enable the `heapless` feature of this crate,
+//! you will receive an impl of [`aead::Buffer`] for `heapless::Vec`
+//! (re-exported from the [`aead`] crate as [`aead::heapless::Vec`]),
+//! which can then be passed as the `buffer` parameter to the in-place encrypt
+//! and decrypt methods:
+//!
+//! ```
+//! # #[cfg(feature = "heapless")]
+//! # {
+//! use hs1_siv::{Hs1SivMe, Key, Nonce}; // Or `Hs1SivLo`, `Hs1SivHi`
+//! use hs1_siv::aead::{AeadInPlace, NewAead};
+//! use hs1_siv::aead::heapless::Vec;
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = Hs1SivMe::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce"); // 96-bits; unique per message
+//!
+//! let mut buffer: Vec<u8, 128> = Vec::new();
+//! buffer.extend_from_slice(b"plaintext message");
+//!
+//! // Encrypt `buffer` in-place, replacing the plaintext contents with ciphertext
+//! cipher.encrypt_in_place(nonce, b"", &mut buffer).expect("encryption failure!");
+//!
+//! // `buffer` now contains the message ciphertext
+//! assert_ne!(&buffer, b"plaintext message");
+//!
+//! // Decrypt `buffer` in-place, replacing its ciphertext context with the original plaintext
+//! cipher.decrypt_in_place(nonce, b"", &mut buffer).expect("decryption failure!");
+//! assert_eq!(&buffer, b"plaintext message");
+//! # }
+//! ```
+//!
+//! [1]: https://competitions.cr.yp.to/round2/hs1sivv2.pdf
+//! [2]: https://en.wikipedia.org/wiki/Authenticated_encryption
+//! [3]: https://cr.yp.to/chacha.html
+//! [4]: https://github.com/miscreant/meta/wiki/Nonce-Reuse-Misuse-Resistance
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+mod hash;
+
+pub use aead::{self, consts, AeadCore, AeadInPlace, Error, NewAead};
+
+use crate::hash::{HashKey, Hasher, BLOCK_SIZE, MAX_T, NH_KEY_WORDS};
+use aead::{
+    consts::{U0, U12, U16, U32, U8},
+    generic_array::{typenum::Unsigned, ArrayLength, GenericArray},
+};
+use chacha20::{ChaCha12, ChaCha20, ChaCha8};
+use cipher::{NewCipher, StreamCipher, StreamCipherSeek};
+use core::marker::PhantomData;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// HS1-SIV keys (256-bit)
+pub type Key = GenericArray<u8, U32>;
+
+/// HS1-SIV nonces (96-bit)
+pub type Nonce = GenericArray<u8, U12>;
+
+/// HS1-SIV tags
+pub type Tag<TagSize> = GenericArray<u8, TagSize>;
+
+/// Maximum length of plaintext: ChaCha's 32-bit block counter, minus the
+/// first block
+pub const P_MAX: u64 = (1 << 38) - 64;
+
+/// hs1-siv-lo: ChaCha8, 2 hashes and 64-bit tags
+pub type Hs1SivLo = Hs1Siv<Lo>;
+
+/// hs1-siv: ChaCha12, 4 hashes and 128-bit tags
+pub type Hs1SivMe = Hs1Siv<Me>;
+
+/// hs1-siv-hi: ChaCha20, 6 hashes and 256-bit tags
+pub type Hs1SivHi = Hs1Siv<Hi>;
+
+mod private {
+    // Sealed traits stop other crates from implementing any traits that use it.
+    pub trait SealedParams {}
+}
+
+/// HS1-SIV parameter sets. This trait is sealed, and implemented by [`Lo`],
+/// [`Me`] and [`Hi`].
+pub trait Hs1Params: private::SealedParams {
+    /// ChaCha variant
+    type Cipher: NewCipher<KeySize = U32, NonceSize = U12> + StreamCipher + StreamCipherSeek;
+
+    /// Size of the tag (`l`)
+    type TagSize: ArrayLength<u8>;
+
+    /// Number of ChaCha rounds (`r`)
+    const ROUNDS: u8;
+
+    /// Number of hashes computed in parallel (`t`)
+    const T: usize;
+}
+
+macro_rules! impl_params {
+    ($name:ident, $cipher:ident, $tag_size:ident, $rounds:expr, $t:expr, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone, Copy, Debug)]
+        pub struct $name;
+
+        impl private::SealedParams for $name {}
+
+        impl Hs1Params for $name {
+            type Cipher = $cipher;
+            type TagSize = $tag_size;
+            const ROUNDS: u8 = $rounds;
+            const T: usize = $t;
+        }
+    };
+}
+
+impl_params!(Lo, ChaCha8, U8, 8, 2, "Parameters of hs1-siv-lo");
+impl_params!(Me, ChaCha12, U16, 12, 4, "Parameters of hs1-siv");
+impl_params!(Hi, ChaCha20, U32, 20, 6, "Parameters of hs1-siv-hi");
+
+/// HS1-SIV: generic over a parameter set.
+///
+/// The key given to [`NewAead`] is expanded into the ChaCha key and the HS1
+/// keys when the cipher is instantiated.
+#[derive(Clone)]
+pub struct Hs1Siv<P: Hs1Params> {
+    /// ChaCha key (`kS`)
+    chacha_key: Key,
+
+    /// HS1-Hash keys
+    hash_key: HashKey,
+
+    params: PhantomData<P>,
+}
+
+impl<P: Hs1Params> NewAead for Hs1Siv<P> {
+    type KeySize = U32;
+
+    fn new(key: &Key) -> Self {
+        Self::subkeygen(key)
+    }
+
+    /// Create a new HS1-SIV instance from a key of 1 to 32 bytes.
+    fn new_from_slice(key: &[u8]) -> Result<Self, Error> {
+        if key.is_empty() || key.len() > 32 {
+            return Err(Error);
+        }
+
+        Ok(Self::subkeygen(key))
+    }
+}
+
+impl<P: Hs1Params> AeadCore for Hs1Siv<P> {
+    type NonceSize = U12;
+    type TagSize = P::TagSize;
+    type CiphertextOverhead = U0;
+}
+
+impl<P: Hs1Params> AeadInPlace for Hs1Siv<P> {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<P::TagSize>, Error> {
+        if buffer.len() as u64 > P_MAX {
+            return Err(Error);
+        }
+
+        let tag = self.compute_tag(nonce, associated_data, buffer);
+        self.cipher(nonce, &tag).apply_keystream(buffer);
+        Ok(tag)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<P::TagSize>,
+    ) -> Result<(), Error> {
+        if buffer.len() as u64 > P_MAX {
+            return Err(Error);
+        }
+
+        self.cipher(nonce, tag).apply_keystream(buffer);
+        let expected_tag = self.compute_tag(nonce, associated_data, buffer);
+
+        if expected_tag.ct_eq(tag).into() {
+            Ok(())
+        } else {
+            // Don't release the unauthenticated plaintext
+            buffer.iter_mut().for_each(|b| *b = 0);
+            Err(Error)
+        }
+    }
+}
+
+impl<P: Hs1Params> Hs1Siv<P> {
+    /// HS1-subkeygen: expand `key` into the ChaCha key and the HS1 keys, with
+    /// ChaCha keyed with `key` repeated to 32 bytes, and a nonce encoding the
+    /// parameters.
+    fn subkeygen(key: &[u8]) -> Self {
+        let mut chacha_key = Key::default();
+        for (a, b) in chacha_key.iter_mut().zip(key.iter().cycle()) {
+            *a = *b;
+        }
+
+        let mut nonce = Nonce::default();
+        nonce[0] = key.len() as u8;
+        nonce[2] = P::TagSize::U8;
+        nonce[4] = P::ROUNDS;
+        nonce[5] = P::T as u8;
+        nonce[6] = BLOCK_SIZE as u8;
+
+        let nh_len = BLOCK_SIZE + 16 * (P::T - 1);
+        let asu_len = if P::T > 4 { 24 * P::T } else { 0 };
+        let mut keystream = [0u8; 32 + 4 * NH_KEY_WORDS + 8 * MAX_T + 24 * MAX_T];
+        let keystream_len = 32 + nh_len + 8 * P::T + asu_len;
+
+        let mut cipher = P::Cipher::new(&chacha_key, &nonce);
+        cipher.apply_keystream(&mut keystream[..keystream_len]);
+        chacha_key.zeroize();
+
+        let (ks, rest) = keystream.split_at(32);
+        let (kn, rest) = rest.split_at(nh_len);
+        let (kp, ka) = rest.split_at(8 * P::T);
+
+        let mut hash_key = HashKey::default();
+
+        for (k, bytes) in hash_key.nh.iter_mut().zip(kn.chunks_exact(4)) {
+            *k = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+
+        for (k, bytes) in hash_key.poly.iter_mut().zip(kp.chunks_exact(8)) {
+            *k = u64_from_le_bytes(bytes) & ((1 << 60) - 1);
+        }
+
+        for (k, bytes) in hash_key.asu.iter_mut().zip(ka[..asu_len].chunks_exact(24)) {
+            for (k, bytes) in k.iter_mut().zip(bytes.chunks_exact(8)) {
+                *k = u64_from_le_bytes(bytes);
+            }
+        }
+
+        let hs1 = Self {
+            chacha_key: *Key::from_slice(ks),
+            hash_key,
+            params: PhantomData,
+        };
+
+        keystream.zeroize();
+        hs1
+    }
+
+    /// Compute the tag: `HS1(K, pad(A) || pad(M) || LE64(|A|) || LE64(|M|), N)`
+    fn compute_tag(&self, nonce: &Nonce, associated_data: &[u8], buffer: &[u8]) -> Tag<P::TagSize> {
+        let mut hasher = Hasher::new(&self.hash_key, P::T);
+        hasher.update_padded(associated_data);
+        hasher.update_padded(buffer);
+        hasher.update(&(associated_data.len() as u64).to_le_bytes());
+        hasher.update(&(buffer.len() as u64).to_le_bytes());
+
+        let mut tag = Tag::default();
+        self.hs1(hasher, nonce).apply_keystream(&mut tag);
+        tag
+    }
+
+    /// Instantiate the cipher encrypting messages with the tag `tag`: HS1 of
+    /// the tag, from the second ChaCha block onwards.
+    fn cipher(&self, nonce: &Nonce, tag: &Tag<P::TagSize>) -> P::Cipher {
+        let mut hasher = Hasher::new(&self.hash_key, P::T);
+        hasher.update(tag);
+
+        let mut cipher = self.hs1(hasher, nonce);
+        cipher.seek(64u64);
+        cipher
+    }
+
+    /// HS1: ChaCha keyed with `kS` XORed with the hashes of the input
+    fn hs1(&self, hasher: Hasher<'_>, nonce: &Nonce) -> P::Cipher {
+        let mut key = self.chacha_key;
+        hasher.finalize_xor(&mut key);
+
+        let cipher = P::Cipher::new(&key, nonce);
+        key.zeroize();
+        cipher
+    }
+}
+
+impl<P: Hs1Params> Drop for Hs1Siv<P> {
+    fn drop(&mut self) {
+        self.chacha_key.zeroize();
+    }
+}
+
+fn u64_from_le_bytes(bytes: &[u8]) -> u64 {
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(bytes);
+    u64::from_le_bytes(buf)
+}
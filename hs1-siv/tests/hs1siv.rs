@@ -0,0 +1,149 @@
+//! HS1-SIV tests
+
+#[macro_use]
+extern crate hex_literal;
+
+use hs1_siv::aead::{Aead, NewAead, Payload};
+use hs1_siv::{Hs1SivHi, Hs1SivLo, Hs1SivMe, Key, Nonce};
+
+/// Key of the test vectors
+const KEY: &[u8; 32] = b"Short keys? Use long for testing";
+
+/// Nonce of the test vectors
+const NONCE: &[u8; 12] = b"Quack quack!";
+
+/// Plaintext of the test vectors
+const PLAINTEXT: &[u8] = b"Hello to the entire wide, round, global globe!";
+
+macro_rules! tests {
+    ($name:ident, $aead:ty, $ciphertext:expr) => {
+        mod $name {
+            use super::*;
+
+            const CIPHERTEXT: &[u8] = &$ciphertext;
+
+            fn cipher() -> $aead {
+                <$aead>::new(Key::from_slice(KEY))
+            }
+
+            #[test]
+            fn encrypt() {
+                let ciphertext = cipher()
+                    .encrypt(Nonce::from_slice(NONCE), PLAINTEXT)
+                    .unwrap();
+                assert_eq!(CIPHERTEXT, ciphertext.as_slice());
+            }
+
+            #[test]
+            fn decrypt() {
+                let plaintext = cipher()
+                    .decrypt(Nonce::from_slice(NONCE), CIPHERTEXT)
+                    .unwrap();
+                assert_eq!(PLAINTEXT, plaintext.as_slice());
+            }
+
+            #[test]
+            fn decrypt_modified() {
+                let mut ciphertext = CIPHERTEXT.to_vec();
+
+                // Tweak the first byte of the ciphertext and the last byte of the tag
+                for &i in &[0, ciphertext.len() - 1] {
+                    ciphertext[i] ^= 1;
+                    let result = cipher().decrypt(Nonce::from_slice(NONCE), ciphertext.as_ref());
+                    assert!(result.is_err());
+                    ciphertext[i] ^= 1;
+                }
+
+                // Modify the associated data
+                let payload = Payload {
+                    msg: &ciphertext,
+                    aad: b"!",
+                };
+                assert!(cipher().decrypt(Nonce::from_slice(NONCE), payload).is_err());
+            }
+
+            #[test]
+            fn round_trip() {
+                // Partial, full and multiple NH blocks, with and without associated data
+                let data = [0x42; 200];
+                for &len in &[0, 1, 15, 16, 63, 64, 65, 128, 200] {
+                    for &aad_len in &[0, 17, 64] {
+                        let payload = Payload {
+                            msg: &data[..len],
+                            aad: &data[..aad_len],
+                        };
+                        let ciphertext =
+                            cipher().encrypt(Nonce::from_slice(NONCE), payload).unwrap();
+
+                        let payload = Payload {
+                            msg: &ciphertext,
+                            aad: &data[..aad_len],
+                        };
+                        let plaintext =
+                            cipher().decrypt(Nonce::from_slice(NONCE), payload).unwrap();
+                        assert_eq!(&data[..len], plaintext.as_slice());
+                    }
+                }
+            }
+
+            #[test]
+            fn short_keys() {
+                // Keys are repeated to 32 bytes, but their length is part of subkeygen
+                let short = <$aead>::new_from_slice(&KEY[..16]).unwrap();
+                let repeated = <$aead>::new_from_slice(&[&KEY[..16], &KEY[..16]].concat()).unwrap();
+
+                let ciphertext = short.encrypt(Nonce::from_slice(NONCE), PLAINTEXT).unwrap();
+                assert_ne!(
+                    ciphertext,
+                    repeated
+                        .encrypt(Nonce::from_slice(NONCE), PLAINTEXT)
+                        .unwrap()
+                );
+
+                let plaintext = short
+                    .decrypt(Nonce::from_slice(NONCE), ciphertext.as_ref())
+                    .unwrap();
+                assert_eq!(PLAINTEXT, plaintext.as_slice());
+
+                assert!(<$aead>::new_from_slice(&[]).is_err());
+                assert!(<$aead>::new_from_slice(&[0; 33]).is_err());
+            }
+        }
+    };
+}
+
+// Vectors generated with the SUPERCOP reference implementations
+
+tests!(
+    hs1siv_lo,
+    Hs1SivLo,
+    hex!(
+        "
+        a8accd910939ac6a1381a3a4bea1c997a7dae65e73d60f2e87cfe720af0d9445
+        aa9b91f2113348c57d0fd8dad79a3dcf63eada327ca6
+    "
+    )
+);
+
+tests!(
+    hs1siv_me,
+    Hs1SivMe,
+    hex!(
+        "
+        1b26404de346b36507a793f36eabb5cb1a997cbfdf6ced15d9d02637f7ccd4b1
+        20ee02523ceecc4104bf42a9fc2e450667a6fe072f00817252a8b0b12ed6
+    "
+    )
+);
+
+tests!(
+    hs1siv_hi,
+    Hs1SivHi,
+    hex!(
+        "
+        bc5dbb495297b8b0ab3a0b69b060d275d14e14738fe3b614b00601964f906e6a
+        6771d071f04bc9f8145430e333b0099747f48cd060ae6840cb58646bf9665f58
+        fadfd050a70043555e63e9893129
+    "
+    )
+);
@@ -0,0 +1,285 @@
+//! [ISAP-A-128a][1]: a leakage-resilient [Authenticated Encryption with
+//! Associated Data (AEAD)][2] cipher built on the Ascon permutation.
+//!
+//! ISAP was a finalist of the NIST lightweight cryptography process. It is
+//! designed to protect against differential power analysis (DPA) by mode-level
+//! means: for every message fresh session keys are derived from the long-term
+//! key by absorbing the nonce (for encryption) or the message digest (for
+//! authentication) one bit at a time, so every permutation call on secret
+//! state can only take one of two possible inputs.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! The leakage resilience of ISAP covers DPA on the mode of operation. Simple
+//! power analysis of the key derivation itself, as well as fault attacks,
+//! still need to be addressed by the platform.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! ```
+//! use isap::{IsapAscon128A, Key, Nonce};
+//! use isap::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = IsapAscon128A::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce 128"); // 128-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! Note that if you enable the `heapless` feature of this crate,
+//! you will receive an impl of [`aead::Buffer`] for `heapless::Vec`
+//! (re-exported from the [`aead`] crate as [`aead::heapless::Vec`]),
+//! which can then be passed as the `buffer` parameter to the in-place encrypt
+//! and decrypt methods:
+//!
+//! ```
+//! # #[cfg(feature = "heapless")]
+//! # {
+//! use isap::{IsapAscon128A, Key, Nonce};
+//! use isap::aead::{AeadInPlace, NewAead};
+//! use isap::aead::heapless::Vec;
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = IsapAscon128A::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce 128"); // 128-bits; unique per message
+//!
+//! let mut buffer: Vec<u8, 128> = Vec::new(); // Buffer needs 16-bytes overhead for the tag
+//! buffer.extend_from_slice(b"plaintext message");
+//!
+//! // Encrypt `buffer` in-place, replacing the plaintext contents with ciphertext
+//! cipher.encrypt_in_place(nonce, b"", &mut buffer).expect("encryption failure!");
+//!
+//! // `buffer` now contains the message ciphertext
+//! assert_ne!(&buffer, b"plaintext message");
+//!
+//! // Decrypt `buffer` in-place, replacing its ciphertext context with the original plaintext
+//! cipher.decrypt_in_place(nonce, b"", &mut buffer).expect("decryption failure!");
+//! assert_eq!(&buffer, b"plaintext message");
+//! # }
+//! ```
+//!
+//! [1]: https://isap.iaik.tugraz.at/
+//! [2]: https://en.wikipedia.org/wiki/Authenticated_encryption
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![deny(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use aead::{self, consts, AeadCore, AeadInPlace, Error, NewAead};
+
+mod permutation;
+
+use aead::{
+    consts::{U0, U16},
+    generic_array::GenericArray,
+};
+use core::convert::TryInto;
+use permutation::State;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// ISAP-A-128a keys (128-bit)
+pub type Key = GenericArray<u8, U16>;
+
+/// ISAP-A-128a nonces (128-bit)
+pub type Nonce = GenericArray<u8, U16>;
+
+/// ISAP-A-128a tags (128-bit)
+pub type Tag = GenericArray<u8, U16>;
+
+/// Parameters shared by all initialization vectors: the key size, the rates
+/// `r_H` and `r_B`, and the round numbers `s_H`, `s_B`, `s_E` and `s_K`
+const PARAMS: u64 = 0x0080_4001_0c01_060c;
+
+/// Initialization vector of the MAC
+const IV_A: u64 = 0x01 << 56 | PARAMS;
+
+/// Initialization vector of the MAC session key derivation
+const IV_KA: u64 = 0x02 << 56 | PARAMS;
+
+/// Initialization vector of the encryption session key derivation
+const IV_KE: u64 = 0x03 << 56 | PARAMS;
+
+/// Number of bytes absorbed or encrypted per permutation call
+const RATE: usize = 8;
+
+/// ISAP-A-128a: 128-bit key, 128-bit nonce, 128-bit tag.
+///
+/// The tag is computed over the ciphertext and checked before any decryption
+/// takes place, so unauthenticated plaintext is never produced.
+#[derive(Clone)]
+pub struct IsapAscon128A {
+    /// Secret key as two 64-bit words
+    key: [u64; 2],
+}
+
+impl NewAead for IsapAscon128A {
+    type KeySize = U16;
+
+    fn new(key: &Key) -> Self {
+        Self {
+            key: [load(&key[..8]), load(&key[8..])],
+        }
+    }
+}
+
+impl AeadCore for IsapAscon128A {
+    type NonceSize = U16;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl AeadInPlace for IsapAscon128A {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        self.apply_keystream(nonce, buffer);
+        Ok(self.mac(nonce, associated_data, buffer))
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        let expected_tag = self.mac(nonce, associated_data, buffer);
+
+        if expected_tag.ct_eq(tag).unwrap_u8() == 1 {
+            self.apply_keystream(nonce, buffer);
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+}
+
+impl IsapAscon128A {
+    /// Derive a session key state from the long-term key, absorbing the
+    /// 128-bit input `y` one bit per permutation call
+    fn rekey(&self, iv: u64, y: &[u8]) -> State {
+        let [k0, k1] = self.key;
+        let mut state = State::new([k0, k1, iv, 0, 0]);
+        state.permute_12();
+
+        let y = u128::from_be_bytes(y.try_into().unwrap());
+        for i in (0..128).rev() {
+            state.x[0] ^= (((y >> i) & 1) as u64) << 63;
+
+            if i == 0 {
+                state.permute_12();
+            } else {
+                state.permute_1();
+            }
+        }
+
+        state
+    }
+
+    /// Encrypt or decrypt `buffer` with the keystream of the session key
+    /// derived from the nonce
+    fn apply_keystream(&self, nonce: &Nonce, buffer: &mut [u8]) {
+        if buffer.is_empty() {
+            return;
+        }
+
+        // The first 192 bits of the derived state are the session key
+        let mut state = self.rekey(IV_KE, nonce);
+        state.x[3] = load(&nonce[..8]);
+        state.x[4] = load(&nonce[8..]);
+
+        for chunk in buffer.chunks_mut(RATE) {
+            state.permute_6();
+
+            for (b, k) in chunk.iter_mut().zip(state.x[0].to_be_bytes().iter()) {
+                *b ^= k;
+            }
+        }
+    }
+
+    /// Compute the authentication tag over the associated data and the
+    /// ciphertext
+    fn mac(&self, nonce: &Nonce, associated_data: &[u8], ciphertext: &[u8]) -> Tag {
+        let mut state = State::new([load(&nonce[..8]), load(&nonce[8..]), IV_A, 0, 0]);
+        state.permute_12();
+
+        absorb(&mut state, associated_data);
+        state.x[4] ^= 1;
+        absorb(&mut state, ciphertext);
+
+        let mut y = [0u8; 16];
+        y[..8].copy_from_slice(&state.x[0].to_be_bytes());
+        y[8..].copy_from_slice(&state.x[1].to_be_bytes());
+
+        let session_key = self.rekey(IV_KA, &y);
+        state.x[0] = session_key.x[0];
+        state.x[1] = session_key.x[1];
+        state.permute_12();
+
+        let mut tag = Tag::default();
+        tag[..8].copy_from_slice(&state.x[0].to_be_bytes());
+        tag[8..].copy_from_slice(&state.x[1].to_be_bytes());
+        tag
+    }
+}
+
+impl Drop for IsapAscon128A {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+/// Absorb `data` padded with a single `0x80` byte followed by zeroes
+fn absorb(state: &mut State, data: &[u8]) {
+    let mut chunks = data.chunks_exact(RATE);
+    for chunk in &mut chunks {
+        state.x[0] ^= load(chunk);
+        state.permute_12();
+    }
+
+    let rem = chunks.remainder();
+    let mut block = [0u8; RATE];
+    block[..rem.len()].copy_from_slice(rem);
+    block[rem.len()] = 0x80;
+    state.x[0] ^= u64::from_be_bytes(block);
+    state.permute_12();
+}
+
+/// Load a big-endian 64-bit word
+#[inline(always)]
+fn load(bytes: &[u8]) -> u64 {
+    u64::from_be_bytes(bytes.try_into().unwrap())
+}
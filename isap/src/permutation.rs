@@ -0,0 +1,85 @@
+//! Ascon-p permutation used by ISAP-A
+
+use zeroize::Zeroize;
+
+/// Round constants: `p^12` uses all of them, `p^6` and `p^1` the last six and
+/// the last one respectively
+const ROUND_CONSTANTS: [u64; 12] = [
+    0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69, 0x5a, 0x4b,
+];
+
+/// Ascon state: five 64-bit words
+pub(crate) struct State {
+    pub(crate) x: [u64; 5],
+}
+
+impl State {
+    /// Create a state from its five words
+    pub(crate) fn new(x: [u64; 5]) -> Self {
+        Self { x }
+    }
+
+    /// Apply the 12-round permutation `p^12`
+    pub(crate) fn permute_12(&mut self) {
+        self.permute(&ROUND_CONSTANTS);
+    }
+
+    /// Apply the 6-round permutation `p^6`
+    pub(crate) fn permute_6(&mut self) {
+        self.permute(&ROUND_CONSTANTS[6..]);
+    }
+
+    /// Apply the single-round permutation `p^1`
+    pub(crate) fn permute_1(&mut self) {
+        self.permute(&ROUND_CONSTANTS[11..]);
+    }
+
+    /// Apply one round per round constant
+    #[inline(always)]
+    fn permute(&mut self, constants: &[u64]) {
+        for &c in constants {
+            self.round(c);
+        }
+    }
+
+    /// A single round: constant addition, substitution and linear diffusion
+    #[inline(always)]
+    fn round(&mut self, c: u64) {
+        let [mut x0, mut x1, mut x2, mut x3, mut x4] = self.x;
+
+        x2 ^= c;
+
+        // Bitsliced 5-bit S-box
+        x0 ^= x4;
+        x4 ^= x3;
+        x2 ^= x1;
+        let t0 = !x0 & x1;
+        let t1 = !x1 & x2;
+        let t2 = !x2 & x3;
+        let t3 = !x3 & x4;
+        let t4 = !x4 & x0;
+        x0 ^= t1;
+        x1 ^= t2;
+        x2 ^= t3;
+        x3 ^= t4;
+        x4 ^= t0;
+        x1 ^= x0;
+        x0 ^= x4;
+        x3 ^= x2;
+        x2 = !x2;
+
+        self.x = [
+            x0 ^ x0.rotate_right(19) ^ x0.rotate_right(28),
+            x1 ^ x1.rotate_right(61) ^ x1.rotate_right(39),
+            x2 ^ x2.rotate_right(1) ^ x2.rotate_right(6),
+            x3 ^ x3.rotate_right(10) ^ x3.rotate_right(17),
+            x4 ^ x4.rotate_right(7) ^ x4.rotate_right(41),
+        ];
+    }
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        self.x.zeroize();
+    }
+}
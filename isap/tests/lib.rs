@@ -0,0 +1,144 @@
+//! ISAP-A-128a tests
+
+#[macro_use]
+extern crate hex_literal;
+
+use isap::aead::{generic_array::GenericArray, Aead, AeadInPlace, NewAead, Payload};
+use isap::IsapAscon128A;
+
+// Known-answer tests from the NIST LWC submission
+aead::new_test!(isap_a_128a, "isap_a_128a", IsapAscon128A);
+
+/// Test vectors
+#[derive(Debug)]
+pub struct TestVector {
+    pub key: &'static [u8; 16],
+    pub nonce: &'static [u8; 16],
+    pub aad: &'static [u8],
+    pub plaintext: &'static [u8],
+    pub ciphertext: &'static [u8],
+    pub tag: &'static [u8; 16],
+}
+
+/// ISAP-A-128a test vectors, selected from the known-answer tests to cover
+/// empty, partial and multi-block inputs
+const TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("7b94ef35ae55ab272c9c44d6c1cf0102"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!(""),
+        plaintext: &hex!("00"),
+        ciphertext: &hex!("2c"),
+        tag: &hex!("facf138c6fdbbcc8763a7205fd66316d"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!("00"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f"),
+        ciphertext: &hex!("2cde28dbbbd9131ebc568d77725b2593"),
+        tag: &hex!("85a1e58a6f9f47083ea81960996a802a"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!("000102030405060708090a0b0c0d0e"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f10111213141516"),
+        ciphertext: &hex!("2cde28dbbbd9131ebc568d77725b25937cf8edb8a8f50a"),
+        tag: &hex!("1117bdb6e56030726c5555c5e8f1f6a8"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        ciphertext: &hex!("2cde28dbbbd9131ebc568d77725b25937cf8edb8a8f50a2aceda356c3ca3d46b"),
+        tag: &hex!("af83b960928f1e4cc975ea24f488202c"),
+    },
+];
+
+#[test]
+fn encrypt() {
+    for vector in TEST_VECTORS {
+        let key = GenericArray::from_slice(vector.key);
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let payload = Payload {
+            msg: vector.plaintext,
+            aad: vector.aad,
+        };
+
+        let cipher = IsapAscon128A::new(key);
+        let ciphertext = cipher.encrypt(nonce, payload).unwrap();
+        let (ct, tag) = ciphertext.split_at(vector.plaintext.len());
+        assert_eq!(vector.ciphertext, ct);
+        assert_eq!(vector.tag, tag);
+    }
+}
+
+#[test]
+fn decrypt() {
+    for vector in TEST_VECTORS {
+        let key = GenericArray::from_slice(vector.key);
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let mut ciphertext = Vec::from(vector.ciphertext);
+        ciphertext.extend_from_slice(vector.tag);
+
+        let payload = Payload {
+            msg: &ciphertext,
+            aad: vector.aad,
+        };
+
+        let cipher = IsapAscon128A::new(key);
+        let plaintext = cipher.decrypt(nonce, payload).unwrap();
+
+        assert_eq!(vector.plaintext, plaintext.as_slice());
+    }
+}
+
+#[test]
+fn decrypt_modified() {
+    let vector = &TEST_VECTORS[4];
+    let key = GenericArray::from_slice(vector.key);
+    let nonce = GenericArray::from_slice(vector.nonce);
+
+    let mut ciphertext = Vec::from(vector.ciphertext);
+    ciphertext.extend_from_slice(vector.tag);
+
+    // Tweak the first byte
+    ciphertext[0] ^= 0xaa;
+
+    let payload = Payload {
+        msg: &ciphertext,
+        aad: vector.aad,
+    };
+
+    let cipher = IsapAscon128A::new(key);
+    assert!(cipher.decrypt(nonce, payload).is_err());
+}
+
+#[test]
+fn decrypt_modified_leaves_ciphertext() {
+    let vector = &TEST_VECTORS[4];
+    let key = GenericArray::from_slice(vector.key);
+    let nonce = GenericArray::from_slice(vector.nonce);
+    let mut tag = GenericArray::clone_from_slice(vector.tag);
+
+    // Tweak the last byte of the tag
+    tag[15] ^= 0x01;
+
+    // The tag is checked before decrypting, so the buffer is left untouched
+    let mut buffer = Vec::from(vector.ciphertext);
+    let cipher = IsapAscon128A::new(key);
+    assert!(cipher
+        .decrypt_in_place_detached(nonce, vector.aad, &mut buffer, &tag)
+        .is_err());
+    assert_eq!(vector.ciphertext, buffer.as_slice());
+}
@@ -1,5 +1,9 @@
 //! Generic implementation of [Multilinear Galous Mode][1] [AEAD] construction.
 //!
+//! MGM is specified in [RFC 9058], and is the AEAD mode used with the
+//! GOST R 34.12-2015 block ciphers, e.g. Kuznyechik, by Russian standards
+//! and CryptoPro-based implementations.
+//!
 //! # Example
 //! ```
 //! # #[cfg(feature = "alloc")]
@@ -26,6 +30,7 @@
 //! ```
 //!
 //! [1]: https://eprint.iacr.org/2019/123.pdf
+//! [RFC 9058]: https://www.rfc-editor.org/rfc/rfc9058
 //! [AEAD]: https://en.wikipedia.org/wiki/Authenticated_encryption
 #![no_std]
 #![doc(
@@ -1,10 +1,12 @@
+#![allow(clippy::clone_on_copy)]
+
 use aead::{generic_array::GenericArray, AeadInPlace, NewAead};
 use hex_literal::hex;
 use kuznyechik::Kuznyechik;
 use mgm::Mgm;
 
-/// Test vector from:
-/// https://tools.ietf.org/html/draft-smyshlyaev-mgm-17#appendix-A
+/// Kuznyechik example from RFC 9058, Appendix A (unchanged since the draft):
+/// https://www.rfc-editor.org/rfc/rfc9058#appendix-A
 #[test]
 #[rustfmt::skip]
 fn ietf_draft() {
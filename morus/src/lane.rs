@@ -0,0 +1,15 @@
+#[cfg(all(
+    target_feature = "avx2",
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+#[path = "lane/avx2.rs"]
+mod imp;
+
+#[cfg(not(all(
+    target_feature = "avx2",
+    any(target_arch = "x86", target_arch = "x86_64")
+)))]
+#[path = "lane/u64_soft.rs"]
+mod imp;
+
+pub(crate) use imp::Lane;
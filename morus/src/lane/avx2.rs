@@ -0,0 +1,87 @@
+//! Implementation of MORUS-1280 state rows using AVX2 on `x86` and `x86_64`
+//! target architectures, where each row fits in a single 256-bit register.
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+/// 256-bit row of the MORUS-1280 state, as four little-endian 64-bit words
+#[derive(Clone, Copy)]
+pub(crate) struct Lane(__m256i);
+
+impl Lane {
+    #[inline(always)]
+    pub(crate) fn from_words(words: [u64; 4]) -> Self {
+        unsafe {
+            Self(_mm256_set_epi64x(
+                words[3] as i64,
+                words[2] as i64,
+                words[1] as i64,
+                words[0] as i64,
+            ))
+        }
+    }
+
+    #[inline(always)]
+    #[allow(clippy::cast_ptr_alignment)]
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Self {
+        debug_assert_eq!(bytes.len(), 32);
+        unsafe { Self(_mm256_loadu_si256(bytes.as_ptr() as *const __m256i)) }
+    }
+
+    #[inline(always)]
+    #[allow(clippy::cast_ptr_alignment)]
+    pub(crate) fn write(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), 32);
+        unsafe { _mm256_storeu_si256(bytes.as_mut_ptr() as *mut __m256i, self.0) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn xor(&self, other: &Self) -> Self {
+        unsafe { Self(_mm256_xor_si256(self.0, other.0)) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn and(&self, other: &Self) -> Self {
+        unsafe { Self(_mm256_and_si256(self.0, other.0)) }
+    }
+
+    /// Rotate each 64-bit word left by `n` bits
+    #[inline(always)]
+    pub(crate) fn rotl_words(&self, n: u32) -> Self {
+        unsafe {
+            let left = _mm_cvtsi32_si128(n as i32);
+            let right = _mm_cvtsi32_si128(64 - n as i32);
+            Self(_mm256_or_si256(
+                _mm256_sll_epi64(self.0, left),
+                _mm256_srl_epi64(self.0, right),
+            ))
+        }
+    }
+
+    /// Rotate the whole row left by 64 bits
+    #[inline(always)]
+    pub(crate) fn rotl_64(&self) -> Self {
+        unsafe { Self(_mm256_permute4x64_epi64(self.0, 0x93)) }
+    }
+
+    /// Rotate the whole row left by 128 bits
+    #[inline(always)]
+    pub(crate) fn rotl_128(&self) -> Self {
+        unsafe { Self(_mm256_permute4x64_epi64(self.0, 0x4e)) }
+    }
+
+    /// Rotate the whole row left by 192 bits
+    #[inline(always)]
+    pub(crate) fn rotl_192(&self) -> Self {
+        unsafe { Self(_mm256_permute4x64_epi64(self.0, 0x39)) }
+    }
+
+    /// Overwrite the row with zeroes
+    pub(crate) fn zeroize(&mut self) {
+        // Volatile write so the compiler can't elide it
+        unsafe { core::ptr::write_volatile(&mut self.0, _mm256_setzero_si256()) }
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+    }
+}
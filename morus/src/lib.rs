@@ -0,0 +1,399 @@
+//! [MORUS-1280][1]: an [Authenticated Encryption with Associated Data (AEAD)][2]
+//! cipher from the CAESAR competition, with 128-bit or 256-bit keys.
+//!
+//! MORUS only uses bitwise AND, XOR and rotations on a 1280-bit state, which
+//! makes it fast in software on CPUs without AES instructions.
+//!
+//! ## Performance Notes
+//!
+//! Each 256-bit row of the state is held in a single AVX2 register when this
+//! crate is compiled with the `avx2` target feature enabled, e.g. with:
+//!
+//! ```text
+//! RUSTFLAGS="-Ctarget-feature=+avx2"
+//! ```
+//!
+//! Otherwise a portable implementation based on 64-bit words is used.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! MORUS was a CAESAR finalist but was not selected for the final portfolio,
+//! and forgery attacks on MORUS-1280 with complexity below its claimed
+//! security level are known. It is provided for interoperability with
+//! existing deployments and should not be chosen for new designs.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! ```
+//! use morus::{Morus1280_128, Key, Nonce};
+//! use morus::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = Morus1280_128::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce 128"); // 128-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! Note that if you enable the `heapless` feature of this crate,
+//! you will receive an impl of [`aead::Buffer`] for `heapless::Vec`
+//! (re-exported from the [`aead`] crate as [`aead::heapless::Vec`]),
+//! which can then be passed as the `buffer` parameter to the in-place encrypt
+//! and decrypt methods:
+//!
+//! ```
+//! # #[cfg(feature = "heapless")]
+//! # {
+//! use morus::{Morus1280_256, Key, Nonce};
+//! use morus::aead::{AeadInPlace, NewAead};
+//! use morus::aead::heapless::Vec;
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = Morus1280_256::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce 128"); // 128-bits; unique per message
+//!
+//! let mut buffer: Vec<u8, 128> = Vec::new(); // Buffer needs 16-bytes overhead for the tag
+//! buffer.extend_from_slice(b"plaintext message");
+//!
+//! // Encrypt `buffer` in-place, replacing the plaintext contents with ciphertext
+//! cipher.encrypt_in_place(nonce, b"", &mut buffer).expect("encryption failure!");
+//!
+//! // `buffer` now contains the message ciphertext
+//! assert_ne!(&buffer, b"plaintext message");
+//!
+//! // Decrypt `buffer` in-place, replacing its ciphertext context with the original plaintext
+//! cipher.decrypt_in_place(nonce, b"", &mut buffer).expect("decryption failure!");
+//! assert_eq!(&buffer, b"plaintext message");
+//! # }
+//! ```
+//!
+//! [1]: https://competitions.cr.yp.to/round3/morusv2.pdf
+//! [2]: https://en.wikipedia.org/wiki/Authenticated_encryption
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use aead::{self, consts, AeadCore, AeadInPlace, Error, NewAead};
+
+mod lane;
+
+use aead::{
+    consts::{U0, U16, U32},
+    generic_array::{typenum::Unsigned, ArrayLength, GenericArray},
+};
+use core::marker::PhantomData;
+use lane::Lane;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// MORUS-1280 with a 128-bit key
+pub type Morus1280_128 = Morus1280<U16>;
+
+/// MORUS-1280 with a 256-bit key
+pub type Morus1280_256 = Morus1280<U32>;
+
+/// MORUS-1280 keys
+pub type Key<KeySize = U16> = GenericArray<u8, KeySize>;
+
+/// MORUS-1280 nonces (128-bit)
+pub type Nonce = GenericArray<u8, U16>;
+
+/// MORUS-1280 tags (128-bit)
+pub type Tag = GenericArray<u8, U16>;
+
+/// Constants `const0 || const1`: the Fibonacci sequence modulo 256
+const CONST: [u8; 32] = [
+    0x00, 0x01, 0x01, 0x02, 0x03, 0x05, 0x08, 0x0d, 0x15, 0x22, 0x37, 0x59, 0x90, 0xe9, 0x79, 0x62,
+    0xdb, 0x3d, 0x18, 0x55, 0x6d, 0xc2, 0x2f, 0xf1, 0x20, 0x11, 0x31, 0x42, 0x73, 0xb5, 0x28, 0xdd,
+];
+
+/// Number of bytes absorbed by a single state update
+const RATE: usize = 32;
+
+/// Key sizes supported by MORUS-1280: `U16` (128-bit) or `U32` (256-bit).
+pub trait KeySize: ArrayLength<u8> + Unsigned + private::SealedKeySize {}
+
+impl KeySize for U16 {}
+impl KeySize for U32 {}
+
+mod private {
+    // Sealed traits stop other crates from implementing any traits that use it.
+    pub trait SealedKeySize {}
+
+    impl SealedKeySize for super::U16 {}
+    impl SealedKeySize for super::U32 {}
+}
+
+/// MORUS-1280: 128-bit or 256-bit key, 128-bit nonce, 128-bit tag.
+///
+/// Type parameters:
+/// - `K`: size of the key, valid values: `U16`, `U32`.
+#[derive(Clone)]
+pub struct Morus1280<K = U16>
+where
+    K: KeySize,
+{
+    /// Secret key
+    key: Key<K>,
+
+    /// Size of the key
+    key_size: PhantomData<K>,
+}
+
+impl<K> NewAead for Morus1280<K>
+where
+    K: KeySize,
+{
+    type KeySize = K;
+
+    fn new(key: &Key<K>) -> Self {
+        Self {
+            key: key.clone(),
+            key_size: PhantomData,
+        }
+    }
+}
+
+impl<K> AeadCore for Morus1280<K>
+where
+    K: KeySize,
+{
+    type NonceSize = U16;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl<K> AeadInPlace for Morus1280<K>
+where
+    K: KeySize,
+{
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        let mut state = State::new(&self.key, nonce);
+        state.absorb_associated_data(associated_data);
+
+        let mut chunks = buffer.chunks_exact_mut(RATE);
+        for chunk in &mut chunks {
+            state.enc(chunk);
+        }
+
+        let rem = chunks.into_remainder();
+        if !rem.is_empty() {
+            let mut block = [0u8; RATE];
+            block[..rem.len()].copy_from_slice(rem);
+            state.enc(&mut block);
+            rem.copy_from_slice(&block[..rem.len()]);
+        }
+
+        Ok(state.finalize(associated_data.len(), buffer.len()))
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        let mut state = State::new(&self.key, nonce);
+        state.absorb_associated_data(associated_data);
+
+        let mut chunks = buffer.chunks_exact_mut(RATE);
+        for chunk in &mut chunks {
+            state.dec(chunk);
+        }
+
+        let rem = chunks.into_remainder();
+        if !rem.is_empty() {
+            state.dec_partial(rem);
+        }
+
+        let expected_tag = state.finalize(associated_data.len(), buffer.len());
+
+        if expected_tag.ct_eq(tag).unwrap_u8() == 1 {
+            Ok(())
+        } else {
+            // Don't release unauthenticated plaintext
+            buffer.iter_mut().for_each(|b| *b = 0);
+            Err(Error)
+        }
+    }
+}
+
+impl<K> Drop for Morus1280<K>
+where
+    K: KeySize,
+{
+    fn drop(&mut self) {
+        self.key.as_mut_slice().zeroize();
+    }
+}
+
+/// MORUS-1280 state: five 256-bit rows
+struct State {
+    s: [Lane; 5],
+}
+
+impl State {
+    /// Initialize the state with the given key and nonce
+    fn new<K: KeySize>(key: &Key<K>, nonce: &Nonce) -> Self {
+        // 128-bit keys are repeated to fill the 256-bit row
+        let mut k = [0u8; 32];
+        for chunk in k.chunks_exact_mut(K::USIZE) {
+            chunk.copy_from_slice(key);
+        }
+        let key = Lane::from_bytes(&k);
+        k.zeroize();
+
+        let mut iv = [0u8; 32];
+        iv[..16].copy_from_slice(nonce);
+
+        let mut state = Self {
+            s: [
+                Lane::from_bytes(&iv),
+                key,
+                Lane::from_words([!0; 4]),
+                Lane::from_words([0; 4]),
+                Lane::from_bytes(&CONST),
+            ],
+        };
+
+        let zero = Lane::from_words([0; 4]);
+        for _ in 0..16 {
+            state.update(&zero);
+        }
+
+        state.s[1] = state.s[1].xor(&key);
+        state
+    }
+
+    /// Update the state, absorbing the message row `m`
+    #[inline(always)]
+    fn update(&mut self, m: &Lane) {
+        let s = &mut self.s;
+
+        s[0] = s[0].xor(&s[3]).xor(&s[1].and(&s[2])).rotl_words(13);
+        s[3] = s[3].rotl_64();
+
+        s[1] = s[1].xor(m).xor(&s[4]).xor(&s[2].and(&s[3])).rotl_words(46);
+        s[4] = s[4].rotl_128();
+
+        s[2] = s[2].xor(m).xor(&s[0]).xor(&s[3].and(&s[4])).rotl_words(38);
+        s[0] = s[0].rotl_192();
+
+        s[3] = s[3].xor(m).xor(&s[1]).xor(&s[4].and(&s[0])).rotl_words(7);
+        s[1] = s[1].rotl_128();
+
+        s[4] = s[4].xor(m).xor(&s[2]).xor(&s[0].and(&s[1])).rotl_words(4);
+        s[2] = s[2].rotl_64();
+    }
+
+    /// Keystream for the next 256-bit block
+    #[inline(always)]
+    fn keystream(&self) -> Lane {
+        let s = &self.s;
+        s[0].xor(&s[1].rotl_192()).xor(&s[2].and(&s[3]))
+    }
+
+    /// Absorb the associated data, zero-padding the last block
+    fn absorb_associated_data(&mut self, associated_data: &[u8]) {
+        let mut chunks = associated_data.chunks_exact(RATE);
+        for chunk in &mut chunks {
+            self.update(&Lane::from_bytes(chunk));
+        }
+
+        let rem = chunks.remainder();
+        if !rem.is_empty() {
+            let mut block = [0u8; RATE];
+            block[..rem.len()].copy_from_slice(rem);
+            self.update(&Lane::from_bytes(&block));
+        }
+    }
+
+    /// Encrypt a 256-bit block in-place
+    #[inline(always)]
+    fn enc(&mut self, xi: &mut [u8]) {
+        let mut m = Lane::from_bytes(xi);
+        m.xor(&self.keystream()).write(xi);
+        self.update(&m);
+        m.zeroize();
+    }
+
+    /// Decrypt a 256-bit block in-place
+    #[inline(always)]
+    fn dec(&mut self, ci: &mut [u8]) {
+        let mut m = Lane::from_bytes(ci).xor(&self.keystream());
+        m.write(ci);
+        self.update(&m);
+        m.zeroize();
+    }
+
+    /// Decrypt the trailing partial block in-place
+    fn dec_partial(&mut self, cn: &mut [u8]) {
+        let mut block = [0u8; RATE];
+        block[..cn.len()].copy_from_slice(cn);
+        Lane::from_bytes(&block)
+            .xor(&self.keystream())
+            .write(&mut block);
+        cn.copy_from_slice(&block[..cn.len()]);
+
+        // Only the plaintext bytes are absorbed, zero-padded to 256 bits
+        block[cn.len()..].iter_mut().for_each(|b| *b = 0);
+        self.update(&Lane::from_bytes(&block));
+        block.zeroize();
+    }
+
+    /// Compute the authentication tag
+    fn finalize(mut self, ad_len: usize, msg_len: usize) -> Tag {
+        let lengths = Lane::from_words([(ad_len as u64) * 8, (msg_len as u64) * 8, 0, 0]);
+
+        self.s[4] = self.s[4].xor(&self.s[0]);
+        for _ in 0..10 {
+            self.update(&lengths);
+        }
+
+        let mut block = [0u8; RATE];
+        self.keystream().write(&mut block);
+        Tag::clone_from_slice(&block[..16])
+    }
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        for lane in self.s.iter_mut() {
+            lane.zeroize();
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! MORUS-1280 tests
+
+#[macro_use]
+extern crate hex_literal;
+
+use morus::aead::{generic_array::GenericArray, Aead, NewAead, Payload};
+use morus::{Morus1280_128, Morus1280_256};
+
+/// Test vectors
+#[derive(Debug)]
+pub struct TestVector {
+    pub key: &'static [u8; 16],
+    pub nonce: &'static [u8; 16],
+    pub aad: &'static [u8],
+    pub plaintext: &'static [u8],
+    pub ciphertext: &'static [u8],
+    pub tag: &'static [u8; 16],
+}
+
+/// MORUS-1280-128 test vectors
+///
+/// The first three are from the CAESAR submission's known-answer tests, the
+/// remaining ones were cross-checked against an independent implementation.
+const TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        key: &hex!("00000000000000000000000000000000"),
+        nonce: &hex!("00000000000000000000000000000000"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("5bd2cba68ea7e72f6b3d0c155f39f962"),
+    },
+    TestVector {
+        key: &hex!("00000000000000000000000000000000"),
+        nonce: &hex!("00000000000000000000000000000000"),
+        aad: &hex!(""),
+        plaintext: &hex!("00"),
+        ciphertext: &hex!("bb"),
+        tag: &hex!("2b52279dac099a0f79722227d5d5e0b9"),
+    },
+    TestVector {
+        key: &hex!("00000000000000000000000000000000"),
+        nonce: &hex!("00000000000000000000000000000000"),
+        aad: &hex!("00"),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("6acb9248f2e6ecf0f8abc2ffefae2f40"),
+    },
+    TestVector {
+        key: &hex!("59454c4c4f57205355424d4152494e45"),
+        nonce: &hex!("00000000000000000000000000000000"),
+        aad: &hex!("436f6d6d656e74206e756d65726f20756e"),
+        plaintext: &hex!("4c616469657320616e642047656e746c656d656e206f662074686520636c617373206f66202739393a204966204920636f756c64206f6666657220796f75206f6e6c79206f6e652074697020666f7220746865206675747572652c2073756e73637265656e20776f756c642062652069742e"),
+        ciphertext: &hex!("712ae984433ceea0448a6a4f35afd46b42f42d69316e42aa54264dfd8951293b6ed676c9a813e7f42745e6210de9c82c4ac67fde57695c2d1e1f2f302682f118c6895915de8fa63de1bb798c7a178ce3290dfe3527c370a4c65be01ca55b7abb26b573ade9076cbf9b8c06acc750470a4524"),
+        tag: &hex!("fe0bf3ea600b0355eb535ddd35320e1b"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000306090c0f1215181b1e2124272a2d"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"),
+        ciphertext: &hex!("754ba31b60c6a65bdebc149f0b381f03f594eccea636606fc2d0b98d2d1c77b6d57fc408913f0ce45be99586479ddbd05b257843e398419b74234f4c5531b257"),
+        tag: &hex!("4a795a210f0426c4d901a09d0982d6a0"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000306090c0f1215181b1e2124272a2d"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112"),
+        ciphertext: &hex!("354cb37c670eec0671d9ad82e6d4f6733b8d13"),
+        tag: &hex!("e253f21868de1c84f51a8f2e6a74b9c5"),
+    },
+];
+
+#[test]
+fn encrypt() {
+    for vector in TEST_VECTORS {
+        let key = GenericArray::from_slice(vector.key);
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let payload = Payload {
+            msg: vector.plaintext,
+            aad: vector.aad,
+        };
+
+        let cipher = Morus1280_128::new(key);
+        let ciphertext = cipher.encrypt(nonce, payload).unwrap();
+        let (ct, tag) = ciphertext.split_at(vector.plaintext.len());
+        assert_eq!(vector.ciphertext, ct);
+        assert_eq!(vector.tag, tag);
+    }
+}
+
+#[test]
+fn decrypt() {
+    for vector in TEST_VECTORS {
+        let key = GenericArray::from_slice(vector.key);
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let mut ciphertext = Vec::from(vector.ciphertext);
+        ciphertext.extend_from_slice(vector.tag);
+
+        let payload = Payload {
+            msg: &ciphertext,
+            aad: vector.aad,
+        };
+
+        let cipher = Morus1280_128::new(key);
+        let plaintext = cipher.decrypt(nonce, payload).unwrap();
+
+        assert_eq!(vector.plaintext, plaintext.as_slice());
+    }
+}
+
+#[test]
+fn decrypt_modified() {
+    let vector = &TEST_VECTORS[3];
+    let key = GenericArray::from_slice(vector.key);
+    let nonce = GenericArray::from_slice(vector.nonce);
+
+    let mut ciphertext = Vec::from(vector.ciphertext);
+    ciphertext.extend_from_slice(vector.tag);
+
+    // Tweak the first byte
+    ciphertext[0] ^= 0xaa;
+
+    let payload = Payload {
+        msg: &ciphertext,
+        aad: vector.aad,
+    };
+
+    let cipher = Morus1280_128::new(key);
+    assert!(cipher.decrypt(nonce, payload).is_err());
+}
+
+/// MORUS-1280-128 expands its key to `K || K`, so it must agree with
+/// MORUS-1280-256 keyed with the repeated key.
+#[test]
+fn morus1280_256_repeated_key() {
+    for vector in TEST_VECTORS {
+        let mut key = [0u8; 32];
+        key[..16].copy_from_slice(vector.key);
+        key[16..].copy_from_slice(vector.key);
+
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let payload = Payload {
+            msg: vector.plaintext,
+            aad: vector.aad,
+        };
+
+        let cipher = Morus1280_256::new(GenericArray::from_slice(&key));
+        let ciphertext = cipher.encrypt(nonce, payload).unwrap();
+        let (ct, tag) = ciphertext.split_at(vector.plaintext.len());
+        assert_eq!(vector.ciphertext, ct);
+        assert_eq!(vector.tag, tag);
+    }
+}
@@ -0,0 +1,231 @@
+//! Nonce-reuse detection for [Authenticated Encryption with Associated Data
+//! (AEAD)][1] ciphers, for tests and staging.
+//!
+//! Encrypting two messages with the same key and nonce breaks the
+//! confidentiality of most AEADs, and for some, e.g. AES-GCM and
+//! ChaCha20Poly1305, the authenticity of every message under the key. A
+//! [`NonceGuard`] wraps an AEAD, and records the nonces it encrypts with. A
+//! repeated nonce is rejected with an [`Error`], or a panic with
+//! [`NonceGuard::panic_on_reuse`], before anything is encrypted with it.
+//! Decryption isn't checked, since decrypting several messages with the same
+//! nonce is harmless.
+//!
+//! Clones of a [`NonceGuard`] share the record of its nonces, since they
+//! share its key.
+//!
+//! ## Cargo features
+//!
+//! Recording is enabled by the `detect` feature, which is on by default and
+//! requires `std`. Without it, [`NonceGuard`] is a transparent wrapper which
+//! doesn't record anything, so code using it builds unchanged, but the checks
+//! can be left out of production builds, or `no_std` targets.
+//!
+//! ## Security Notes
+//!
+//! This crate is a debugging aid, not a substitute for unique nonces: its
+//! record of nonces grows with every message, is lost when the
+//! [`NonceGuard`] is dropped, and is not shared between processes or
+//! instances created from the same key.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! ```
+//! # #[cfg(feature = "detect")]
+//! # {
+//! use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+//! use nonce_guard::NonceGuard;
+//! use nonce_guard::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = NonceGuard::<ChaCha20Poly1305>::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce"); // 96-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! // Messages can be decrypted any number of times
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//!
+//! // ...but the nonce can't encrypt another message
+//! assert!(cipher.encrypt(nonce, b"another message".as_ref()).is_err());
+//! # }
+//! ```
+//!
+//! [1]: https://en.wikipedia.org/wiki/Authenticated_encryption
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+#[cfg(feature = "detect")]
+extern crate std;
+
+pub use aead::{self, AeadCore, AeadInPlace, Error, NewAead};
+
+use aead::{generic_array::GenericArray, Nonce, Tag};
+
+#[cfg(feature = "detect")]
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+/// Wrapper around an AEAD which rejects repeated nonces.
+///
+/// See the [toplevel documentation](index.html) for details and a usage
+/// example.
+///
+/// The key, nonce and tag sizes are those of the wrapped AEAD `A`, and so are
+/// its ciphertexts.
+pub struct NonceGuard<A>
+where
+    A: AeadInPlace,
+{
+    /// Wrapped AEAD
+    aead: A,
+
+    /// Nonces which have been encrypted with
+    #[cfg(feature = "detect")]
+    used: Arc<Mutex<HashSet<Nonce<A>>>>,
+
+    /// Whether to panic on reuse, instead of returning an error
+    #[cfg(feature = "detect")]
+    panic: bool,
+}
+
+impl<A> NonceGuard<A>
+where
+    A: AeadInPlace,
+{
+    /// Panic when a nonce is reused, instead of returning an error, e.g. to
+    /// fail integration tests which ignore the errors of encryption.
+    pub fn panic_on_reuse(self) -> Self {
+        Self {
+            #[cfg(feature = "detect")]
+            panic: true,
+            ..self
+        }
+    }
+
+    /// Number of nonces which have been encrypted with
+    #[cfg(feature = "detect")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "detect")))]
+    pub fn nonces_used(&self) -> usize {
+        self.used
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Unwrap the AEAD
+    pub fn into_inner(self) -> A {
+        self.aead
+    }
+
+    /// Record `nonce`, and reject it if it has been used before
+    #[cfg(feature = "detect")]
+    fn record(&self, nonce: &Nonce<A>) -> Result<(), Error> {
+        let fresh = self
+            .used
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(nonce.clone());
+
+        if fresh {
+            Ok(())
+        } else if self.panic {
+            panic!("nonce reused under the same key");
+        } else {
+            Err(Error)
+        }
+    }
+}
+
+impl<A> From<A> for NonceGuard<A>
+where
+    A: AeadInPlace,
+{
+    fn from(aead: A) -> Self {
+        Self {
+            aead,
+            #[cfg(feature = "detect")]
+            used: Arc::default(),
+            #[cfg(feature = "detect")]
+            panic: false,
+        }
+    }
+}
+
+impl<A> NewAead for NonceGuard<A>
+where
+    A: NewAead + AeadInPlace,
+{
+    type KeySize = A::KeySize;
+
+    fn new(key: &GenericArray<u8, A::KeySize>) -> Self {
+        A::new(key).into()
+    }
+}
+
+impl<A> AeadCore for NonceGuard<A>
+where
+    A: AeadInPlace,
+{
+    type NonceSize = A::NonceSize;
+    type TagSize = A::TagSize;
+    type CiphertextOverhead = A::CiphertextOverhead;
+}
+
+impl<A> AeadInPlace for NonceGuard<A>
+where
+    A: AeadInPlace,
+{
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<A>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<A>, Error> {
+        #[cfg(feature = "detect")]
+        self.record(nonce)?;
+
+        self.aead
+            .encrypt_in_place_detached(nonce, associated_data, buffer)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<A>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<A>,
+    ) -> Result<(), Error> {
+        self.aead
+            .decrypt_in_place_detached(nonce, associated_data, buffer, tag)
+    }
+}
+
+impl<A> Clone for NonceGuard<A>
+where
+    A: AeadInPlace + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            aead: self.aead.clone(),
+            #[cfg(feature = "detect")]
+            used: self.used.clone(),
+            #[cfg(feature = "detect")]
+            panic: self.panic,
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! Tests of `NonceGuard`
+
+use chacha20poly1305::ChaCha20Poly1305;
+use nonce_guard::aead::{Aead, NewAead};
+use nonce_guard::NonceGuard;
+
+const KEY: [u8; 32] = [0x42; 32];
+const NONCE: [u8; 12] = *b"unique nonce";
+
+/// Ciphertexts are those of the wrapped AEAD
+#[test]
+fn transparent() {
+    let aead = ChaCha20Poly1305::new(&KEY.into());
+    let expected = aead.encrypt(&NONCE.into(), b"message".as_ref()).unwrap();
+
+    let cipher = NonceGuard::from(aead);
+    let ciphertext = cipher.encrypt(&NONCE.into(), b"message".as_ref()).unwrap();
+    assert_eq!(ciphertext, expected);
+
+    let aead = cipher.into_inner();
+    assert_eq!(
+        aead.decrypt(&NONCE.into(), ciphertext.as_ref()).unwrap(),
+        b"message"
+    );
+}
+
+#[cfg(feature = "detect")]
+mod detect {
+    use super::*;
+    use aes_gcm::Aes128Gcm;
+    use nonce_guard::aead::AeadInPlace;
+
+    #[test]
+    fn reuse() {
+        let cipher = NonceGuard::<ChaCha20Poly1305>::new(&KEY.into());
+        let mut nonce = NONCE;
+
+        cipher.encrypt(&nonce.into(), b"a".as_ref()).unwrap();
+        assert!(cipher.encrypt(&nonce.into(), b"b".as_ref()).is_err());
+
+        // Even with the same message
+        assert!(cipher.encrypt(&nonce.into(), b"a".as_ref()).is_err());
+
+        nonce[11] ^= 1;
+        cipher.encrypt(&nonce.into(), b"b".as_ref()).unwrap();
+        assert_eq!(cipher.nonces_used(), 2);
+    }
+
+    /// A rejected nonce doesn't touch the buffer
+    #[test]
+    fn reuse_in_place() {
+        let cipher = NonceGuard::<Aes128Gcm>::new(&[0x42; 16].into());
+        let nonce = NONCE.into();
+
+        let mut buffer = b"message".to_vec();
+        cipher
+            .encrypt_in_place_detached(&nonce, b"", &mut buffer)
+            .unwrap();
+
+        let mut buffer = b"message".to_vec();
+        assert!(cipher
+            .encrypt_in_place_detached(&nonce, b"", &mut buffer)
+            .is_err());
+        assert_eq!(&buffer, b"message");
+    }
+
+    /// Decrypting with the same nonce several times is allowed, and doesn't
+    /// count as using it
+    #[test]
+    fn decrypt() {
+        let cipher = NonceGuard::<ChaCha20Poly1305>::new(&KEY.into());
+        let nonce = NONCE.into();
+
+        let ciphertext = ChaCha20Poly1305::new(&KEY.into())
+            .encrypt(&nonce, b"message".as_ref())
+            .unwrap();
+        for _ in 0..3 {
+            cipher.decrypt(&nonce, ciphertext.as_ref()).unwrap();
+        }
+        assert_eq!(cipher.nonces_used(), 0);
+
+        cipher.encrypt(&nonce, b"message".as_ref()).unwrap();
+        assert_eq!(cipher.nonces_used(), 1);
+    }
+
+    /// Clones share the key, so they share the nonces which have been used
+    #[test]
+    fn clones() {
+        let cipher = NonceGuard::<ChaCha20Poly1305>::new(&KEY.into());
+        let clone = cipher.clone();
+
+        cipher.encrypt(&NONCE.into(), b"a".as_ref()).unwrap();
+        assert!(clone.encrypt(&NONCE.into(), b"b".as_ref()).is_err());
+    }
+
+    /// Instances created separately don't share anything, even with the same
+    /// key
+    #[test]
+    fn instances() {
+        let a = NonceGuard::<ChaCha20Poly1305>::new(&KEY.into());
+        let b = NonceGuard::<ChaCha20Poly1305>::new(&KEY.into());
+
+        a.encrypt(&NONCE.into(), b"a".as_ref()).unwrap();
+        b.encrypt(&NONCE.into(), b"b".as_ref()).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "nonce reused")]
+    fn panic_on_reuse() {
+        let cipher = NonceGuard::<ChaCha20Poly1305>::new(&KEY.into()).panic_on_reuse();
+
+        cipher.encrypt(&NONCE.into(), b"a".as_ref()).unwrap();
+        let _ = cipher.encrypt(&NONCE.into(), b"b".as_ref());
+    }
+}
+
+#[cfg(not(feature = "detect"))]
+#[test]
+fn no_detect() {
+    let cipher = NonceGuard::<ChaCha20Poly1305>::new(&KEY.into()).panic_on_reuse();
+
+    cipher.encrypt(&NONCE.into(), b"a".as_ref()).unwrap();
+    cipher.encrypt(&NONCE.into(), b"b".as_ref()).unwrap();
+}
@@ -0,0 +1,87 @@
+//! Portable implementation of NORX64 state rows using four `u64` words.
+
+use core::convert::TryInto;
+
+/// Row of four little-endian 64-bit words of the NORX64 state
+#[derive(Clone, Copy)]
+pub(crate) struct Lane([u64; 4]);
+
+impl Lane {
+    #[inline(always)]
+    pub(crate) fn from_words(words: [u64; 4]) -> Self {
+        Self(words)
+    }
+
+    #[inline(always)]
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Self {
+        let mut words = [0u64; 4];
+        for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(8)) {
+            *word = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Self(words)
+    }
+
+    #[inline(always)]
+    pub(crate) fn write(&self, bytes: &mut [u8]) {
+        for (chunk, word) in bytes.chunks_exact_mut(8).zip(self.0.iter()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn xor(&self, other: &Self) -> Self {
+        let (a, b) = (&self.0, &other.0);
+        Self([a[0] ^ b[0], a[1] ^ b[1], a[2] ^ b[2], a[3] ^ b[3]])
+    }
+
+    /// The nonlinear operation of NORX applied to each word:
+    /// `(a ^ b) ^ ((a & b) << 1)`
+    #[inline(always)]
+    pub(crate) fn h(&self, other: &Self) -> Self {
+        let (a, b) = (&self.0, &other.0);
+        Self([
+            (a[0] ^ b[0]) ^ ((a[0] & b[0]) << 1),
+            (a[1] ^ b[1]) ^ ((a[1] & b[1]) << 1),
+            (a[2] ^ b[2]) ^ ((a[2] & b[2]) << 1),
+            (a[3] ^ b[3]) ^ ((a[3] & b[3]) << 1),
+        ])
+    }
+
+    /// Rotate each 64-bit word right by `n` bits
+    #[inline(always)]
+    pub(crate) fn rotr_words(&self, n: u32) -> Self {
+        let a = &self.0;
+        Self([
+            a[0].rotate_right(n),
+            a[1].rotate_right(n),
+            a[2].rotate_right(n),
+            a[3].rotate_right(n),
+        ])
+    }
+
+    /// Rotate the whole row left by 64 bits
+    #[inline(always)]
+    pub(crate) fn rotl_64(&self) -> Self {
+        let a = &self.0;
+        Self([a[3], a[0], a[1], a[2]])
+    }
+
+    /// Rotate the whole row left by 128 bits
+    #[inline(always)]
+    pub(crate) fn rotl_128(&self) -> Self {
+        let a = &self.0;
+        Self([a[2], a[3], a[0], a[1]])
+    }
+
+    /// Rotate the whole row left by 192 bits
+    #[inline(always)]
+    pub(crate) fn rotl_192(&self) -> Self {
+        let a = &self.0;
+        Self([a[1], a[2], a[3], a[0]])
+    }
+
+    /// Overwrite the row with zeroes
+    pub(crate) fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.0);
+    }
+}
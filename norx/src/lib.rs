@@ -0,0 +1,434 @@
+//! [NORX][1]: an [Authenticated Encryption with Associated Data (AEAD)][2]
+//! cipher from the CAESAR competition, based on a sponge construction with
+//! an ARX-like permutation that replaces modular additions with bitwise
+//! operations.
+//!
+//! This crate implements NORX64-4-1 (NORX v3.0 with 64-bit words, 4 rounds
+//! and no parallelism), the main instance of the CAESAR submission, with a
+//! 256-bit key, 256-bit nonce and 256-bit tag.
+//!
+//! ## Performance Notes
+//!
+//! Each row of four 64-bit words of the state is held in a single AVX2
+//! register when this crate is compiled with the `avx2` target feature
+//! enabled, e.g. with:
+//!
+//! ```text
+//! RUSTFLAGS="-Ctarget-feature=+avx2"
+//! ```
+//!
+//! Otherwise a portable implementation based on 64-bit words is used.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! NORX was a third round CAESAR candidate, but wasn't selected for the final
+//! portfolio, and its authors have since stopped maintaining it. It is
+//! provided for interoperability with existing data and protocols, and should
+//! not be chosen for new designs.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! ```
+//! use norx::{Norx6441, Key, Nonce};
+//! use norx::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = Norx6441::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce for NORX64-4-1 msg!"); // 256-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! Note that if you enable the `heapless` feature of this crate,
+//! you will receive an impl of [`aead::Buffer`] for `heapless::Vec`
+//! (re-exported from the [`aead`] crate as [`aead::heapless::Vec`]),
+//! which can then be passed as the `buffer` parameter to the in-place encrypt
+//! and decrypt methods:
+//!
+//! ```
+//! # #[cfg(feature = "heapless")]
+//! # {
+//! use norx::{Norx6441, Key, Nonce};
+//! use norx::aead::{AeadInPlace, NewAead};
+//! use norx::aead::heapless::Vec;
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = Norx6441::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce for NORX64-4-1 msg!"); // 256-bits; unique per message
+//!
+//! let mut buffer: Vec<u8, 128> = Vec::new(); // Buffer needs 32-bytes overhead for the tag
+//! buffer.extend_from_slice(b"plaintext message");
+//!
+//! // Encrypt `buffer` in-place, replacing the plaintext contents with ciphertext
+//! cipher.encrypt_in_place(nonce, b"", &mut buffer).expect("encryption failure!");
+//!
+//! // `buffer` now contains the message ciphertext
+//! assert_ne!(&buffer, b"plaintext message");
+//!
+//! // Decrypt `buffer` in-place, replacing its ciphertext context with the original plaintext
+//! cipher.decrypt_in_place(nonce, b"", &mut buffer).expect("decryption failure!");
+//! assert_eq!(&buffer, b"plaintext message");
+//! # }
+//! ```
+//!
+//! [1]: https://norx.io/data/norx.pdf
+//! [2]: https://en.wikipedia.org/wiki/Authenticated_encryption
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use aead::{self, consts, AeadCore, AeadInPlace, Error, NewAead};
+
+mod lane;
+
+use aead::{
+    consts::{U0, U32},
+    generic_array::GenericArray,
+};
+use lane::Lane;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// NORX64-4-1 keys (256-bit)
+pub type Key = GenericArray<u8, U32>;
+
+/// NORX64-4-1 nonces (256-bit)
+pub type Nonce = GenericArray<u8, U32>;
+
+/// NORX64-4-1 tags (256-bit)
+pub type Tag = GenericArray<u8, U32>;
+
+/// Number of rounds of the permutation
+const ROUNDS: usize = 4;
+
+/// Word size, in bits
+const W: u64 = 64;
+
+/// Parallelism degree
+const P: u64 = 1;
+
+/// Tag size, in bits
+const T: u64 = 256;
+
+/// Number of bytes absorbed per permutation: the first 12 words of the state
+const RATE: usize = 96;
+
+/// Initialization constants `u_0, ..., u_15`: the permutation `F^2` applied
+/// to the words `0, ..., 15`
+const U: [u64; 16] = [
+    0xe4d3_2477_2b91_df79,
+    0x3aec_9aba_aeb0_2ccb,
+    0x9dfb_a13d_b428_9311,
+    0xef9e_b4bf_5a97_f2c8,
+    0x3f46_6e92_c153_2034,
+    0xe6e9_8662_6cc4_05c1,
+    0xace4_0f3b_5491_84e1,
+    0xd9cf_d357_6261_4477,
+    0xb15e_6417_48de_5e6b,
+    0xaa95_e955_e10f_8410,
+    0x28d1_0344_41a9_dd40,
+    0x7f31_bbf9_64e9_3bf5,
+    0xb5e9_e224_93df_fb96,
+    0xb980_c852_479f_afbd,
+    0xda24_516b_f55e_afd4,
+    0x8602_6ae8_536f_1501,
+];
+
+/// Domain separation constants, XORed into the last word of the state
+const HEADER: u64 = 0x01;
+const PAYLOAD: u64 = 0x02;
+const FINAL: u64 = 0x08;
+
+/// NORX64-4-1: 256-bit key, 256-bit nonce, 256-bit tag.
+///
+/// The associated data is absorbed as the *header* of NORX; the *trailer*
+/// is always empty.
+#[derive(Clone)]
+pub struct Norx6441 {
+    /// Secret key
+    key: Key,
+}
+
+impl NewAead for Norx6441 {
+    type KeySize = U32;
+
+    fn new(key: &Key) -> Self {
+        Self { key: *key }
+    }
+}
+
+impl AeadCore for Norx6441 {
+    type NonceSize = U32;
+    type TagSize = U32;
+    type CiphertextOverhead = U0;
+}
+
+impl AeadInPlace for Norx6441 {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        let mut state = State::new(&self.key, nonce);
+        state.absorb(associated_data, HEADER);
+        state.encrypt(buffer);
+        Ok(state.finalize(&self.key))
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        let mut state = State::new(&self.key, nonce);
+        state.absorb(associated_data, HEADER);
+        state.decrypt(buffer);
+
+        let expected_tag = state.finalize(&self.key);
+
+        if expected_tag.ct_eq(tag).unwrap_u8() == 1 {
+            Ok(())
+        } else {
+            // Don't release unauthenticated plaintext
+            buffer.iter_mut().for_each(|b| *b = 0);
+            Err(Error)
+        }
+    }
+}
+
+impl Drop for Norx6441 {
+    fn drop(&mut self) {
+        self.key.as_mut_slice().zeroize();
+    }
+}
+
+/// NORX64 state: four rows of four 64-bit words, the first three of which
+/// are the rate and the last one the capacity
+struct State {
+    s: [Lane; 4],
+}
+
+impl State {
+    /// Initialize the state with the given key and nonce
+    fn new(key: &Key, nonce: &Nonce) -> Self {
+        let key = Lane::from_bytes(key);
+
+        let mut state = Self {
+            s: [
+                Lane::from_bytes(nonce),
+                key,
+                Lane::from_words([U[8], U[9], U[10], U[11]]),
+                Lane::from_words([U[12] ^ W, U[13] ^ ROUNDS as u64, U[14] ^ P, U[15] ^ T]),
+            ],
+        };
+
+        state.permute();
+        state.s[3] = state.s[3].xor(&key);
+        state
+    }
+
+    /// The permutation `F^L`
+    fn permute(&mut self) {
+        for _ in 0..ROUNDS {
+            self.round();
+        }
+    }
+
+    /// A round `F` of the permutation: the `G` function applied to the
+    /// columns of the state, and then to its diagonals, which become columns
+    /// once the rows are rotated.
+    #[inline(always)]
+    fn round(&mut self) {
+        self.g();
+        self.s[1] = self.s[1].rotl_192();
+        self.s[2] = self.s[2].rotl_128();
+        self.s[3] = self.s[3].rotl_64();
+
+        self.g();
+        self.s[1] = self.s[1].rotl_64();
+        self.s[2] = self.s[2].rotl_128();
+        self.s[3] = self.s[3].rotl_192();
+    }
+
+    /// The `G` function applied to the four columns of the state at once
+    #[inline(always)]
+    fn g(&mut self) {
+        let [a, b, c, d] = &mut self.s;
+
+        *a = a.h(b);
+        *d = d.xor(a).rotr_words(8);
+        *c = c.h(d);
+        *b = b.xor(c).rotr_words(19);
+        *a = a.h(b);
+        *d = d.xor(a).rotr_words(40);
+        *c = c.h(d);
+        *b = b.xor(c).rotr_words(63);
+    }
+
+    /// Inject a domain separation constant into the last word of the state,
+    /// and apply the permutation
+    #[inline(always)]
+    fn inject(&mut self, domain: u64) {
+        self.s[3] = self.s[3].xor(&Lane::from_words([0, 0, 0, domain]));
+        self.permute();
+    }
+
+    /// XOR a block into the rate
+    #[inline(always)]
+    fn xor_rate(&mut self, block: &[u8]) {
+        for (row, chunk) in self.s.iter_mut().zip(block.chunks_exact(32)) {
+            *row = row.xor(&Lane::from_bytes(chunk));
+        }
+    }
+
+    /// Overwrite the rate with a block
+    #[inline(always)]
+    fn set_rate(&mut self, block: &[u8]) {
+        for (row, chunk) in self.s.iter_mut().zip(block.chunks_exact(32)) {
+            *row = Lane::from_bytes(chunk);
+        }
+    }
+
+    /// Write the rate out to a block
+    #[inline(always)]
+    fn read_rate(&self, block: &mut [u8]) {
+        for (row, chunk) in self.s.iter().zip(block.chunks_exact_mut(32)) {
+            row.write(chunk);
+        }
+    }
+
+    /// Absorb data under the given domain separation constant. Every
+    /// non-empty input ends with a padded block, even if it is empty.
+    fn absorb(&mut self, data: &[u8], domain: u64) {
+        if data.is_empty() {
+            return;
+        }
+
+        let mut chunks = data.chunks_exact(RATE);
+        for chunk in &mut chunks {
+            self.inject(domain);
+            self.xor_rate(chunk);
+        }
+
+        let mut block = pad(chunks.remainder());
+        self.inject(domain);
+        self.xor_rate(&block);
+        block.zeroize();
+    }
+
+    /// Encrypt the payload in-place
+    fn encrypt(&mut self, buffer: &mut [u8]) {
+        if buffer.is_empty() {
+            return;
+        }
+
+        let mut chunks = buffer.chunks_exact_mut(RATE);
+        for chunk in &mut chunks {
+            self.inject(PAYLOAD);
+            self.xor_rate(chunk);
+            self.read_rate(chunk);
+        }
+
+        let rem = chunks.into_remainder();
+        let mut block = pad(rem);
+        self.inject(PAYLOAD);
+        self.xor_rate(&block);
+        self.read_rate(&mut block);
+        rem.copy_from_slice(&block[..rem.len()]);
+        block.zeroize();
+    }
+
+    /// Decrypt the payload in-place
+    fn decrypt(&mut self, buffer: &mut [u8]) {
+        if buffer.is_empty() {
+            return;
+        }
+
+        let mut block = [0u8; RATE];
+
+        let mut chunks = buffer.chunks_exact_mut(RATE);
+        for chunk in &mut chunks {
+            self.inject(PAYLOAD);
+            self.read_rate(&mut block);
+            self.set_rate(chunk);
+            for (b, k) in chunk.iter_mut().zip(block.iter()) {
+                *b ^= k;
+            }
+        }
+
+        // The rate becomes `pad(M) ^ S`: the ciphertext, followed by the
+        // keystream XORed with the padding
+        let rem = chunks.into_remainder();
+        self.inject(PAYLOAD);
+        self.read_rate(&mut block);
+        for (b, k) in rem.iter_mut().zip(block.iter_mut()) {
+            core::mem::swap(b, k);
+            *b ^= *k;
+        }
+        block[rem.len()] ^= 0x01;
+        block[RATE - 1] ^= 0x80;
+        self.set_rate(&block);
+        block.zeroize();
+    }
+
+    /// Compute the authentication tag
+    fn finalize(mut self, key: &Key) -> Tag {
+        let key = Lane::from_bytes(key);
+
+        self.inject(FINAL);
+        self.s[3] = self.s[3].xor(&key);
+        self.permute();
+
+        let mut tag = Tag::default();
+        self.s[3].xor(&key).write(&mut tag);
+        tag
+    }
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        for lane in self.s.iter_mut() {
+            lane.zeroize();
+        }
+    }
+}
+
+/// Multi-rate padding of the last block of an input: `M || 0x01 || 0* || 0x80`
+fn pad(data: &[u8]) -> [u8; RATE] {
+    let mut block = [0u8; RATE];
+    block[..data.len()].copy_from_slice(data);
+    block[data.len()] ^= 0x01;
+    block[RATE - 1] ^= 0x80;
+    block
+}
@@ -0,0 +1,104 @@
+//! NORX64-4-1 tests
+
+use norx::aead::{generic_array::GenericArray, Aead, AeadInPlace, NewAead, Payload};
+use norx::Norx6441;
+
+/// Known-answer tests of the NORX reference implementation (`genkat`), for
+/// messages and headers of 0 to 255 bytes: each entry is the ciphertext
+/// followed by the 256-bit tag.
+const KAT: &[u8] = include_bytes!("data/norx6441_kat.bin");
+
+/// Inputs of the known-answer tests
+fn kat_inputs() -> ([u8; 32], [u8; 32], [u8; 256], [u8; 256]) {
+    let mut key = [0u8; 32];
+    let mut nonce = [0u8; 32];
+    let mut header = [0u8; 256];
+    let mut msg = [0u8; 256];
+
+    for (i, b) in key.iter_mut().enumerate() {
+        *b = (i * 191 + 123) as u8;
+    }
+    for (i, b) in nonce.iter_mut().enumerate() {
+        *b = (i * 181 + 123) as u8;
+    }
+    for (i, b) in header.iter_mut().enumerate() {
+        *b = (i * 193 + 123) as u8;
+    }
+    for (i, b) in msg.iter_mut().enumerate() {
+        *b = (i * 197 + 123) as u8;
+    }
+
+    (key, nonce, header, msg)
+}
+
+#[test]
+fn encrypt() {
+    let (key, nonce, header, msg) = kat_inputs();
+    let cipher = Norx6441::new(GenericArray::from_slice(&key));
+    let nonce = GenericArray::from_slice(&nonce);
+
+    let mut kat = KAT;
+    for len in 0..msg.len() {
+        let payload = Payload {
+            msg: &msg[..len],
+            aad: &header[..len],
+        };
+        let ciphertext = cipher.encrypt(nonce, payload).unwrap();
+
+        let (expected, rest) = kat.split_at(len + 32);
+        assert_eq!(expected, ciphertext.as_slice(), "length {}", len);
+        kat = rest;
+    }
+    assert!(kat.is_empty());
+}
+
+#[test]
+fn decrypt() {
+    let (key, nonce, header, msg) = kat_inputs();
+    let cipher = Norx6441::new(GenericArray::from_slice(&key));
+    let nonce = GenericArray::from_slice(&nonce);
+
+    let mut kat = KAT;
+    for len in 0..msg.len() {
+        let (ciphertext, rest) = kat.split_at(len + 32);
+        let payload = Payload {
+            msg: ciphertext,
+            aad: &header[..len],
+        };
+        let plaintext = cipher.decrypt(nonce, payload).unwrap();
+
+        assert_eq!(&msg[..len], plaintext.as_slice(), "length {}", len);
+        kat = rest;
+    }
+}
+
+#[test]
+fn decrypt_modified() {
+    let (key, nonce, header, _) = kat_inputs();
+    let cipher = Norx6441::new(GenericArray::from_slice(&key));
+    let nonce = GenericArray::from_slice(&nonce);
+
+    // Entry for 200-byte inputs: spans two full blocks and a partial one
+    let start: usize = (0..200).map(|len| len + 32).sum();
+    let mut ciphertext = KAT[start..][..232].to_vec();
+    let aad = &header[..200];
+
+    // Tweak the first byte of each block and of the tag
+    for &i in &[0, 96, 192, 200] {
+        ciphertext[i] ^= 1;
+        let payload = Payload {
+            msg: &ciphertext,
+            aad,
+        };
+        assert!(cipher.decrypt(nonce, payload).is_err());
+        ciphertext[i] ^= 1;
+    }
+
+    // The plaintext is zeroed when the tag doesn't match
+    let (buffer, tag) = ciphertext.split_at_mut(200);
+    tag[0] ^= 1;
+    assert!(cipher
+        .decrypt_in_place_detached(nonce, aad, buffer, GenericArray::from_slice(tag))
+        .is_err());
+    assert!(buffer.iter().all(|&b| b == 0));
+}
@@ -0,0 +1,506 @@
+//! OCB3: the Offset Codebook Mode v3 [Authenticated Encryption with Associated
+//! Data (AEAD)][1] cipher, as specified in [RFC 7253][2].
+//!
+//! OCB3 encrypts and authenticates a message with a single block cipher call
+//! per block, which makes it one of the fastest block cipher based AEAD
+//! modes.
+//!
+//! # Usage
+//!
+//! Simple usage (allocating, no associated data):
+//!
+//! ```
+//! use ocb3::{Aes256Ocb3, Key, Nonce}; // Or `Aes128Ocb3`
+//! use ocb3::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = Aes256Ocb3::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce"); // 96-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! Note that if you enable the `heapless` feature of this crate,
+//! you will receive an impl of [`aead::Buffer`] for `heapless::Vec`
+//! (re-exported from the [`aead`] crate as [`aead::heapless::Vec`]),
+//! which can then be passed as the `buffer` parameter to the in-place encrypt
+//! and decrypt methods:
+//!
+//! ```
+//! # #[cfg(feature = "heapless")]
+//! # {
+//! use ocb3::{Aes256Ocb3, Key, Nonce}; // Or `Aes128Ocb3`
+//! use ocb3::aead::{AeadInPlace, NewAead};
+//! use ocb3::aead::heapless::Vec;
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = Aes256Ocb3::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce"); // 96-bits; unique per message
+//!
+//! let mut buffer: Vec<u8, 128> = Vec::new();
+//! buffer.extend_from_slice(b"plaintext message");
+//!
+//! // Encrypt `buffer` in-place, replacing the plaintext contents with ciphertext
+//! cipher.encrypt_in_place(nonce, b"", &mut buffer).expect("encryption failure!");
+//!
+//! // `buffer` now contains the message ciphertext
+//! assert_ne!(&buffer, b"plaintext message");
+//!
+//! // Decrypt `buffer` in-place, replacing its ciphertext context with the original plaintext
+//! cipher.decrypt_in_place(nonce, b"", &mut buffer).expect("decryption failure!");
+//! assert_eq!(&buffer, b"plaintext message");
+//! # }
+//! ```
+//!
+//! ## Short Tags
+//!
+//! RFC 7253 defines OCB3 with 64, 96 and 128-bit tags. The tag length is
+//! encoded into the nonce, so each tag length is a distinct instantiation
+//! rather than a truncation of the 128-bit tag: [`Aes128Ocb3Tag64`],
+//! [`Aes128Ocb3Tag96`], [`Aes256Ocb3Tag64`] and [`Aes256Ocb3Tag96`].
+//!
+//! ```
+//! use ocb3::{Aes128Ocb3Tag96, Key, Nonce};
+//! use ocb3::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = Aes128Ocb3Tag96::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce"); // 96-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! // The tag is only 12 bytes long
+//! assert_eq!(ciphertext.len(), 17 + 12);
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! [1]: https://en.wikipedia.org/wiki/Authenticated_encryption
+//! [2]: https://tools.ietf.org/html/rfc7253
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![deny(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use aead::{self, AeadCore, AeadInPlace, Error, NewAead};
+pub use cipher;
+
+#[cfg(feature = "aes")]
+pub use aes;
+
+use cipher::{
+    consts::{U0, U1, U10, U11, U12, U13, U14, U15, U16, U2, U3, U4, U5, U6, U7, U8, U9},
+    generic_array::{ArrayLength, GenericArray},
+    Block, BlockCipher, BlockCipherKey, BlockDecrypt, BlockEncrypt, NewBlockCipher,
+};
+use core::marker::PhantomData;
+use subtle::ConstantTimeEq;
+
+#[cfg(feature = "aes")]
+use aes::{Aes128, Aes256};
+
+/// Maximum length of associated data
+pub const A_MAX: u64 = 1 << 36;
+
+/// Maximum length of plaintext
+pub const P_MAX: u64 = 1 << 36;
+
+/// Maximum length of ciphertext
+pub const C_MAX: u64 = (1 << 36) + 16;
+
+/// Number of precomputed `L_i` values: enough for the `2^32` blocks of a
+/// message of [`P_MAX`] bytes
+const L_TABLE_SIZE: usize = 33;
+
+/// OCB3 keys
+pub type Key<KeySize> = GenericArray<u8, KeySize>;
+
+/// OCB3 nonces
+pub type Nonce<NonceSize = U12> = GenericArray<u8, NonceSize>;
+
+/// OCB3 tags
+pub type Tag<TagSize = U16> = GenericArray<u8, TagSize>;
+
+mod private {
+    // Sealed traits stop other crates from implementing any traits that use it.
+    pub trait SealedNonce {}
+    pub trait SealedTag {}
+
+    macro_rules! impl_sealed_nonce {
+        ($($size:ident),+) => {
+            $(impl SealedNonce for super::$size {})+
+        };
+    }
+
+    impl_sealed_nonce!(U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U11, U12, U13, U14, U15);
+
+    impl SealedTag for super::U8 {}
+    impl SealedTag for super::U12 {}
+    impl SealedTag for super::U16 {}
+}
+
+/// Nonce sizes supported by OCB3: `U1` to `U15` bytes, as RFC 7253 allows
+/// nonces of up to 120 bits.
+///
+/// This trait is sealed: the supported sizes can't be extended by other
+/// crates.
+pub trait Ocb3NonceSize: ArrayLength<u8> + private::SealedNonce {}
+
+macro_rules! impl_nonce_size {
+    ($($size:ident),+) => {
+        $(impl Ocb3NonceSize for $size {})+
+    };
+}
+
+impl_nonce_size!(U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U11, U12, U13, U14, U15);
+
+/// Tag sizes supported by OCB3: the 64, 96 and 128-bit tags of RFC 7253
+/// (`U8`, `U12` and `U16`).
+///
+/// This trait is sealed: the supported sizes can't be extended by other
+/// crates.
+pub trait Ocb3TagSize: ArrayLength<u8> + private::SealedTag {}
+
+impl Ocb3TagSize for U8 {}
+impl Ocb3TagSize for U12 {}
+impl Ocb3TagSize for U16 {}
+
+/// AES-OCB3 with a 128-bit key, 96-bit nonce and 128-bit tag
+#[cfg(feature = "aes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aes")))]
+pub type Aes128Ocb3 = Ocb3<Aes128>;
+
+/// AES-OCB3 with a 256-bit key, 96-bit nonce and 128-bit tag
+#[cfg(feature = "aes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aes")))]
+pub type Aes256Ocb3 = Ocb3<Aes256>;
+
+/// AES-OCB3 with a 128-bit key, 96-bit nonce and 64-bit tag
+#[cfg(feature = "aes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aes")))]
+pub type Aes128Ocb3Tag64 = Ocb3<Aes128, U12, U8>;
+
+/// AES-OCB3 with a 256-bit key, 96-bit nonce and 64-bit tag
+#[cfg(feature = "aes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aes")))]
+pub type Aes256Ocb3Tag64 = Ocb3<Aes256, U12, U8>;
+
+/// AES-OCB3 with a 128-bit key, 96-bit nonce and 96-bit tag
+#[cfg(feature = "aes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aes")))]
+pub type Aes128Ocb3Tag96 = Ocb3<Aes128, U12, U12>;
+
+/// AES-OCB3 with a 256-bit key, 96-bit nonce and 96-bit tag
+#[cfg(feature = "aes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aes")))]
+pub type Aes256Ocb3Tag96 = Ocb3<Aes256, U12, U12>;
+
+/// OCB3: generic over a block cipher with a 128-bit block size, nonce size
+/// and tag size.
+///
+/// If in doubt, use the built-in [`Aes128Ocb3`] and [`Aes256Ocb3`] type
+/// aliases.
+///
+/// Type parameters:
+/// - `Cipher`: block cipher, with a 128-bit block size.
+/// - `NonceSize`: size of the nonce, `U1` to `U15` bytes (see
+///   [`Ocb3NonceSize`]). Defaults to the 96-bit nonces used by RFC 7253's
+///   test vectors.
+/// - `TagSize`: size of the tag, `U8`, `U12` or `U16` bytes (see
+///   [`Ocb3TagSize`]). Defaults to the full 128-bit tag.
+#[derive(Clone)]
+pub struct Ocb3<Cipher, NonceSize = U12, TagSize = U16>
+where
+    Cipher: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+    Cipher::ParBlocks: ArrayLength<Block<Cipher>>,
+    NonceSize: Ocb3NonceSize,
+    TagSize: Ocb3TagSize,
+{
+    /// Block cipher
+    cipher: Cipher,
+
+    /// `L_* = ENCIPHER(K, zeros(128))`
+    ll_star: u128,
+
+    /// `L_$ = double(L_*)`
+    ll_dollar: u128,
+
+    /// `L_0 = double(L_$)`, `L_i = double(L_{i-1})`
+    ll: [u128; L_TABLE_SIZE],
+
+    /// Length of the nonce
+    nonce_size: PhantomData<NonceSize>,
+
+    /// Length of the tag
+    tag_size: PhantomData<TagSize>,
+}
+
+impl<Cipher, NonceSize, TagSize> NewAead for Ocb3<Cipher, NonceSize, TagSize>
+where
+    Cipher: NewBlockCipher + BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+    Cipher::ParBlocks: ArrayLength<Block<Cipher>>,
+    NonceSize: Ocb3NonceSize,
+    TagSize: Ocb3TagSize,
+{
+    type KeySize = Cipher::KeySize;
+
+    fn new(key: &BlockCipherKey<Cipher>) -> Self {
+        Cipher::new(key).into()
+    }
+}
+
+impl<Cipher, NonceSize, TagSize> From<Cipher> for Ocb3<Cipher, NonceSize, TagSize>
+where
+    Cipher: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+    Cipher::ParBlocks: ArrayLength<Block<Cipher>>,
+    NonceSize: Ocb3NonceSize,
+    TagSize: Ocb3TagSize,
+{
+    fn from(cipher: Cipher) -> Self {
+        let mut block = Block::<Cipher>::default();
+        cipher.encrypt_block(&mut block);
+
+        let ll_star = from_bytes(&block);
+        let ll_dollar = double(ll_star);
+
+        let mut ll = [0u128; L_TABLE_SIZE];
+        let mut l = ll_dollar;
+        for l_i in ll.iter_mut() {
+            l = double(l);
+            *l_i = l;
+        }
+
+        Self {
+            cipher,
+            ll_star,
+            ll_dollar,
+            ll,
+            nonce_size: PhantomData,
+            tag_size: PhantomData,
+        }
+    }
+}
+
+impl<Cipher, NonceSize, TagSize> AeadCore for Ocb3<Cipher, NonceSize, TagSize>
+where
+    Cipher: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+    Cipher::ParBlocks: ArrayLength<Block<Cipher>>,
+    NonceSize: Ocb3NonceSize,
+    TagSize: Ocb3TagSize,
+{
+    type NonceSize = NonceSize;
+    type TagSize = TagSize;
+    type CiphertextOverhead = U0;
+}
+
+impl<Cipher, NonceSize, TagSize> AeadInPlace for Ocb3<Cipher, NonceSize, TagSize>
+where
+    Cipher: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+    Cipher::ParBlocks: ArrayLength<Block<Cipher>>,
+    NonceSize: Ocb3NonceSize,
+    TagSize: Ocb3TagSize,
+{
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<TagSize>, Error> {
+        if buffer.len() as u64 > P_MAX || associated_data.len() as u64 > A_MAX {
+            return Err(Error);
+        }
+
+        let mut offset = self.initial_offset(nonce);
+        let mut checksum = 0u128;
+
+        let mut blocks = buffer.chunks_exact_mut(16);
+        for (i, block) in (&mut blocks).enumerate() {
+            offset ^= self.l(i as u64 + 1);
+            let p = from_bytes(block);
+            checksum ^= p;
+            let c = offset ^ self.encipher(p ^ offset);
+            block.copy_from_slice(&c.to_be_bytes());
+        }
+
+        let rem = blocks.into_remainder();
+        if !rem.is_empty() {
+            offset ^= self.ll_star;
+            let pad = self.encipher(offset).to_be_bytes();
+            checksum ^= pad_block(rem);
+            xor(rem, &pad);
+        }
+
+        Ok(self.tag(checksum, offset, associated_data))
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<TagSize>,
+    ) -> Result<(), Error> {
+        if buffer.len() as u64 > C_MAX || associated_data.len() as u64 > A_MAX {
+            return Err(Error);
+        }
+
+        let mut offset = self.initial_offset(nonce);
+        let mut checksum = 0u128;
+
+        let mut blocks = buffer.chunks_exact_mut(16);
+        for (i, block) in (&mut blocks).enumerate() {
+            offset ^= self.l(i as u64 + 1);
+            let c = from_bytes(block);
+            let p = offset ^ self.decipher(c ^ offset);
+            checksum ^= p;
+            block.copy_from_slice(&p.to_be_bytes());
+        }
+
+        let rem = blocks.into_remainder();
+        if !rem.is_empty() {
+            offset ^= self.ll_star;
+            let pad = self.encipher(offset).to_be_bytes();
+            xor(rem, &pad);
+            checksum ^= pad_block(rem);
+        }
+
+        let expected_tag = self.tag(checksum, offset, associated_data);
+
+        if expected_tag.ct_eq(tag).unwrap_u8() == 1 {
+            Ok(())
+        } else {
+            // Don't release unauthenticated plaintext
+            buffer.iter_mut().for_each(|b| *b = 0);
+            Err(Error)
+        }
+    }
+}
+
+impl<Cipher, NonceSize, TagSize> Ocb3<Cipher, NonceSize, TagSize>
+where
+    Cipher: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+    Cipher::ParBlocks: ArrayLength<Block<Cipher>>,
+    NonceSize: Ocb3NonceSize,
+    TagSize: Ocb3TagSize,
+{
+    /// `Offset_0`, derived from the nonce and the tag length
+    fn initial_offset(&self, nonce: &Nonce<NonceSize>) -> u128 {
+        // Nonce = num2str(TAGLEN mod 128, 7) || zeros(120 - bitlen(N)) || 1 || N
+        let mut block = [0u8; 16];
+        block[0] = ((TagSize::to_usize() * 8 % 128) << 1) as u8;
+        block[15 - nonce.len()] |= 1;
+        block[16 - nonce.len()..].copy_from_slice(nonce);
+        let nonce = u128::from_be_bytes(block);
+
+        let bottom = (nonce & 0x3f) as u32;
+        let ktop = self.encipher(nonce & !0x3f);
+
+        // Stretch = Ktop || (Ktop[1..64] xor Ktop[9..72])
+        let stretch = ((ktop >> 64) ^ (ktop >> 56)) as u64;
+
+        // Offset_0 = Stretch[1+bottom..128+bottom]
+        if bottom == 0 {
+            ktop
+        } else {
+            (ktop << bottom) | u128::from(stretch >> (64 - bottom))
+        }
+    }
+
+    /// `HASH(K, A)`
+    fn hash(&self, associated_data: &[u8]) -> u128 {
+        let mut offset = 0u128;
+        let mut sum = 0u128;
+
+        let mut blocks = associated_data.chunks_exact(16);
+        for (i, block) in (&mut blocks).enumerate() {
+            offset ^= self.l(i as u64 + 1);
+            sum ^= self.encipher(from_bytes(block) ^ offset);
+        }
+
+        let rem = blocks.remainder();
+        if !rem.is_empty() {
+            offset ^= self.ll_star;
+            sum ^= self.encipher(pad_block(rem) ^ offset);
+        }
+
+        sum
+    }
+
+    /// `Tag = ENCIPHER(K, Checksum xor Offset xor L_$) xor HASH(K, A)`,
+    /// truncated to `TAGLEN` bits
+    fn tag(&self, checksum: u128, offset: u128, associated_data: &[u8]) -> Tag<TagSize> {
+        let full_tag =
+            self.encipher(checksum ^ offset ^ self.ll_dollar) ^ self.hash(associated_data);
+        Tag::clone_from_slice(&full_tag.to_be_bytes()[..TagSize::to_usize()])
+    }
+
+    /// `L_{ntz(i)}`
+    fn l(&self, i: u64) -> u128 {
+        self.ll[i.trailing_zeros() as usize]
+    }
+
+    fn encipher(&self, block: u128) -> u128 {
+        let mut block = GenericArray::from(block.to_be_bytes());
+        self.cipher.encrypt_block(&mut block);
+        from_bytes(&block)
+    }
+
+    fn decipher(&self, block: u128) -> u128 {
+        let mut block = GenericArray::from(block.to_be_bytes());
+        self.cipher.decrypt_block(&mut block);
+        from_bytes(&block)
+    }
+}
+
+/// Multiply by `x` in GF(2<sup>128</sup>)
+fn double(x: u128) -> u128 {
+    (x << 1) ^ ((x >> 127) * 0x87)
+}
+
+/// `A_* || 1 || zeros(127 - bitlen(A_*))`
+fn pad_block(data: &[u8]) -> u128 {
+    let mut block = [0u8; 16];
+    block[..data.len()].copy_from_slice(data);
+    block[data.len()] = 0x80;
+    u128::from_be_bytes(block)
+}
+
+fn from_bytes(bytes: &[u8]) -> u128 {
+    let mut block = [0u8; 16];
+    block.copy_from_slice(bytes);
+    u128::from_be_bytes(block)
+}
+
+#[inline]
+fn xor(out: &mut [u8], data: &[u8]) {
+    for (a, b) in out.iter_mut().zip(data) {
+        *a ^= b;
+    }
+}
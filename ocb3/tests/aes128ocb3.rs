@@ -0,0 +1,209 @@
+//! AES-128-OCB3 tests
+
+#[macro_use]
+extern crate hex_literal;
+
+use ocb3::aead::{generic_array::GenericArray, Aead, AeadInPlace, NewAead, Payload};
+use ocb3::Aes128Ocb3;
+
+/// Test vectors
+#[derive(Debug)]
+struct TestVector {
+    nonce: &'static [u8; 12],
+    aad: &'static [u8],
+    plaintext: &'static [u8],
+    ciphertext: &'static [u8],
+    tag: &'static [u8; 16],
+}
+
+const KEY: &[u8; 16] = &hex!("000102030405060708090A0B0C0D0E0F");
+
+/// Sample results with 128-bit tags from RFC 7253 Appendix A
+///
+/// <https://tools.ietf.org/html/rfc7253#appendix-A>
+const TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        nonce: &hex!("BBAA99887766554433221100"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("785407BFFFC8AD9EDCC5520AC9111EE6"),
+    },
+    TestVector {
+        nonce: &hex!("BBAA99887766554433221101"),
+        aad: &hex!("0001020304050607"),
+        plaintext: &hex!("0001020304050607"),
+        ciphertext: &hex!("6820B3657B6F615A"),
+        tag: &hex!("5725BDA0D3B4EB3A257C9AF1F8F03009"),
+    },
+    TestVector {
+        nonce: &hex!("BBAA99887766554433221102"),
+        aad: &hex!("0001020304050607"),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("81017F8203F081277152FADE694A0A00"),
+    },
+    TestVector {
+        nonce: &hex!("BBAA99887766554433221103"),
+        aad: &hex!(""),
+        plaintext: &hex!("0001020304050607"),
+        ciphertext: &hex!("45DD69F8F5AAE724"),
+        tag: &hex!("14054CD1F35D82760B2CD00D2F99BFA9"),
+    },
+    TestVector {
+        nonce: &hex!("BBAA99887766554433221104"),
+        aad: &hex!("000102030405060708090A0B0C0D0E0F"),
+        plaintext: &hex!("000102030405060708090A0B0C0D0E0F"),
+        ciphertext: &hex!("571D535B60B277188BE5147170A9A22C"),
+        tag: &hex!("3AD7A4FF3835B8C5701C1CCEC8FC3358"),
+    },
+    TestVector {
+        nonce: &hex!("BBAA99887766554433221105"),
+        aad: &hex!("000102030405060708090A0B0C0D0E0F"),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("8CF761B6902EF764462AD86498CA6B97"),
+    },
+    TestVector {
+        nonce: &hex!("BBAA99887766554433221106"),
+        aad: &hex!(""),
+        plaintext: &hex!("000102030405060708090A0B0C0D0E0F"),
+        ciphertext: &hex!("5CE88EC2E0692706A915C00AEB8B2396"),
+        tag: &hex!("F40E1C743F52436BDF06D8FA1ECA343D"),
+    },
+    TestVector {
+        nonce: &hex!("BBAA99887766554433221107"),
+        aad: &hex!("000102030405060708090A0B0C0D0E0F1011121314151617"),
+        plaintext: &hex!("000102030405060708090A0B0C0D0E0F1011121314151617"),
+        ciphertext: &hex!("1CA2207308C87C010756104D8840CE1952F09673A448A122"),
+        tag: &hex!("C92C62241051F57356D7F3C90BB0E07F"),
+    },
+    TestVector {
+        nonce: &hex!("BBAA99887766554433221108"),
+        aad: &hex!("000102030405060708090A0B0C0D0E0F1011121314151617"),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("6DC225A071FC1B9F7C69F93B0F1E10DE"),
+    },
+    TestVector {
+        nonce: &hex!("BBAA99887766554433221109"),
+        aad: &hex!(""),
+        plaintext: &hex!("000102030405060708090A0B0C0D0E0F1011121314151617"),
+        ciphertext: &hex!("221BD0DE7FA6FE993ECCD769460A0AF2D6CDED0C395B1C3C"),
+        tag: &hex!("E725F32494B9F914D85C0B1EB38357FF"),
+    },
+    TestVector {
+        nonce: &hex!("BBAA9988776655443322110A"),
+        aad: &hex!("000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F"),
+        plaintext: &hex!("000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F"),
+        ciphertext: &hex!("BD6F6C496201C69296C11EFD138A467ABD3C707924B964DEAFFC40319AF5A485"),
+        tag: &hex!("40FBBA186C5553C68AD9F592A79A4240"),
+    },
+    TestVector {
+        nonce: &hex!("BBAA9988776655443322110B"),
+        aad: &hex!("000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F"),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("FE80690BEE8A485D11F32965BC9D2A32"),
+    },
+    TestVector {
+        nonce: &hex!("BBAA9988776655443322110C"),
+        aad: &hex!(""),
+        plaintext: &hex!("000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F"),
+        ciphertext: &hex!("2942BFC773BDA23CABC6ACFD9BFD5835BD300F0973792EF46040C53F1432BCDF"),
+        tag: &hex!("B5E1DDE3BC18A5F840B52E653444D5DF"),
+    },
+    TestVector {
+        nonce: &hex!("BBAA9988776655443322110D"),
+        aad: &hex!(
+            "000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F2021222324252627"
+        ),
+        plaintext: &hex!(
+            "000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F2021222324252627"
+        ),
+        ciphertext: &hex!(
+            "D5CA91748410C1751FF8A2F618255B68A0A12E093FF454606E59F9C1D0DDC54B65E8628E568BAD7A"
+        ),
+        tag: &hex!("ED07BA06A4A69483A7035490C5769E60"),
+    },
+    TestVector {
+        nonce: &hex!("BBAA9988776655443322110E"),
+        aad: &hex!(
+            "000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F2021222324252627"
+        ),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("C5CD9D1850C141E358649994EE701B68"),
+    },
+    TestVector {
+        nonce: &hex!("BBAA9988776655443322110F"),
+        aad: &hex!(""),
+        plaintext: &hex!(
+            "000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F2021222324252627"
+        ),
+        ciphertext: &hex!(
+            "4412923493C57D5DE0D700F753CCE0D1D2D95060122E9F15A5DDBFC5787E50B5CC55EE507BCB084E"
+        ),
+        tag: &hex!("479AD363AC366B95A98CA5F3000B1479"),
+    },
+];
+
+#[test]
+fn encrypt() {
+    let cipher = Aes128Ocb3::new(GenericArray::from_slice(KEY));
+
+    for vector in TEST_VECTORS {
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let payload = Payload {
+            msg: vector.plaintext,
+            aad: vector.aad,
+        };
+
+        let ciphertext = cipher.encrypt(nonce, payload).unwrap();
+        let (ct, tag) = ciphertext.split_at(ciphertext.len() - 16);
+        assert_eq!(vector.ciphertext, ct);
+        assert_eq!(vector.tag, tag);
+    }
+}
+
+#[test]
+fn decrypt() {
+    let cipher = Aes128Ocb3::new(GenericArray::from_slice(KEY));
+
+    for vector in TEST_VECTORS {
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let mut ciphertext = Vec::from(vector.ciphertext);
+        ciphertext.extend_from_slice(vector.tag);
+
+        let payload = Payload {
+            msg: &ciphertext,
+            aad: vector.aad,
+        };
+
+        let plaintext = cipher.decrypt(nonce, payload).unwrap();
+        assert_eq!(vector.plaintext, plaintext.as_slice());
+    }
+}
+
+#[test]
+fn decrypt_modified() {
+    let cipher = Aes128Ocb3::new(GenericArray::from_slice(KEY));
+    let vector = &TEST_VECTORS[13];
+    let nonce = GenericArray::from_slice(vector.nonce);
+
+    // Tweak the first byte of the ciphertext
+    let mut buffer = Vec::from(vector.ciphertext);
+    buffer[0] ^= 0xaa;
+
+    assert!(cipher
+        .decrypt_in_place_detached(
+            nonce,
+            vector.aad,
+            &mut buffer,
+            GenericArray::from_slice(vector.tag)
+        )
+        .is_err());
+
+    // The unauthenticated plaintext is not released
+    assert!(buffer.iter().all(|&b| b == 0));
+}
@@ -0,0 +1,173 @@
+//! OCB3 with the 64 and 96-bit tags of RFC 7253
+//!
+//! <https://tools.ietf.org/html/rfc7253#appendix-A>
+
+#[macro_use]
+extern crate hex_literal;
+
+use ocb3::aead::{
+    consts::{U12, U8},
+    generic_array::GenericArray,
+    Aead, AeadInPlace, NewAead, Payload,
+};
+use ocb3::aes::Aes192;
+use ocb3::{
+    Aes128Ocb3, Aes128Ocb3Tag64, Aes128Ocb3Tag96, Aes256Ocb3, Aes256Ocb3Tag64, Aes256Ocb3Tag96,
+    Ocb3,
+};
+
+/// The sample result of Appendix A with a 96-bit tag
+mod tag96 {
+    use super::*;
+
+    const KEY: [u8; 16] = hex!("0F0E0D0C0B0A09080706050403020100");
+    const NONCE: [u8; 12] = hex!("BBAA9988776655443322110D");
+    const AAD: [u8; 40] =
+        hex!("000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F2021222324252627");
+    const PLAINTEXT: [u8; 40] = AAD;
+    const CIPHERTEXT: [u8; 52] = hex!(
+        "1792A4E31E0755FB03E31B22116E6C2DDF9EFD6E33D536F1A0124B0A55BAE884
+         ED93481529C76B6AD0C515F4D1CDD4FDAC4F02AA"
+    );
+
+    #[test]
+    fn encrypt() {
+        let cipher = Aes128Ocb3Tag96::new(GenericArray::from_slice(&KEY));
+        let payload = Payload {
+            msg: &PLAINTEXT,
+            aad: &AAD,
+        };
+        let ciphertext = cipher
+            .encrypt(GenericArray::from_slice(&NONCE), payload)
+            .unwrap();
+
+        assert_eq!(&CIPHERTEXT[..], ciphertext.as_slice());
+    }
+
+    #[test]
+    fn decrypt() {
+        let cipher = Aes128Ocb3Tag96::new(GenericArray::from_slice(&KEY));
+        let payload = Payload {
+            msg: &CIPHERTEXT,
+            aad: &AAD,
+        };
+        let plaintext = cipher
+            .decrypt(GenericArray::from_slice(&NONCE), payload)
+            .unwrap();
+
+        assert_eq!(&PLAINTEXT[..], plaintext.as_slice());
+    }
+
+    #[test]
+    fn decrypt_modified_tag() {
+        let cipher = Aes128Ocb3Tag96::new(GenericArray::from_slice(&KEY));
+        let (ciphertext, tag) = CIPHERTEXT.split_at(PLAINTEXT.len());
+
+        let mut tag = GenericArray::clone_from_slice(tag);
+        tag[11] ^= 1;
+
+        let mut buffer = ciphertext.to_vec();
+        assert!(cipher
+            .decrypt_in_place_detached(GenericArray::from_slice(&NONCE), &AAD, &mut buffer, &tag)
+            .is_err());
+    }
+}
+
+/// The iterative test of Appendix A, which covers every TAGLEN the aliases
+/// are instantiated with:
+///
+/// ```text
+/// K = zeros(KEYLEN-8) || num2str(TAGLEN,8)
+/// C = <empty string>
+/// for i = 0 to 127 do
+///   S = zeros(8i)
+///   N = num2str(3i+1,96)
+///   C = C || OCB-ENCRYPT(K,N,S,S)
+///   N = num2str(3i+2,96)
+///   C = C || OCB-ENCRYPT(K,N,<empty string>,S)
+///   N = num2str(3i+3,96)
+///   C = C || OCB-ENCRYPT(K,N,S,<empty string>)
+/// end for
+/// N = num2str(385,96)
+/// Output : OCB-ENCRYPT(K,N,C,<empty string>)
+/// ```
+macro_rules! iterative_tests {
+    ($name:ident, $aead:ty, $key_len:expr, $tag_len:expr, $output:expr) => {
+        #[test]
+        fn $name() {
+            let mut key = [0u8; $key_len];
+            key[$key_len - 1] = $tag_len * 8;
+            let cipher = <$aead>::new(GenericArray::from_slice(&key));
+
+            let encrypt = |n: u32, aad: &[u8], msg: &[u8]| {
+                let mut nonce = [0u8; 12];
+                nonce[8..].copy_from_slice(&n.to_be_bytes());
+                cipher
+                    .encrypt(GenericArray::from_slice(&nonce), Payload { msg, aad })
+                    .unwrap()
+            };
+
+            let mut c = Vec::new();
+            for i in 0..128 {
+                let s = vec![0u8; i as usize];
+                c.extend(encrypt(3 * i + 1, &s, &s));
+                c.extend(encrypt(3 * i + 2, &[], &s));
+                c.extend(encrypt(3 * i + 3, &s, &[]));
+            }
+
+            assert_eq!(&$output[..], encrypt(385, &c, &[]).as_slice());
+        }
+    };
+}
+
+iterative_tests!(
+    aes128_tag128,
+    Aes128Ocb3,
+    16,
+    16,
+    hex!("67E944D23256C5E0B6C61FA22FDF1EA2")
+);
+iterative_tests!(
+    aes192_tag128,
+    Ocb3<Aes192>,
+    24,
+    16,
+    hex!("F673F2C3E7174AAE7BAE986CA9F29E17")
+);
+iterative_tests!(
+    aes256_tag128,
+    Aes256Ocb3,
+    32,
+    16,
+    hex!("D90EB8E9C977C88B79DD793D7FFA161C")
+);
+iterative_tests!(
+    aes128_tag96,
+    Aes128Ocb3Tag96,
+    16,
+    12,
+    hex!("77A3D8E73589158D25D01209")
+);
+iterative_tests!(aes192_tag96, Ocb3<Aes192, U12, U12>, 24, 12, hex!("05D56EAD2752C86BE6932C5E"));
+iterative_tests!(
+    aes256_tag96,
+    Aes256Ocb3Tag96,
+    32,
+    12,
+    hex!("5458359AC23B0CBA9E6330DD")
+);
+iterative_tests!(
+    aes128_tag64,
+    Aes128Ocb3Tag64,
+    16,
+    8,
+    hex!("192C9B7BD90BA06A")
+);
+iterative_tests!(aes192_tag64, Ocb3<Aes192, U12, U8>, 24, 8, hex!("0066BC6E0EF34E24"));
+iterative_tests!(
+    aes256_tag64,
+    Aes256Ocb3Tag64,
+    32,
+    8,
+    hex!("7D4EA5D445501CBE")
+);
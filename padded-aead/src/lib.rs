@@ -0,0 +1,346 @@
+//! Length-hiding padding wrapper for [Authenticated Encryption with
+//! Associated Data (AEAD)][1] ciphers.
+//!
+//! The ciphertexts of an AEAD are as long as its plaintexts, plus a fixed
+//! overhead, which can reveal e.g. which of a few known documents or web
+//! pages was sent. [`Padded`] pads plaintexts before encrypting them, and
+//! strips the padding after decrypting them, so ciphertexts only reveal which
+//! range of lengths the plaintext is in. The ranges are given by a
+//! [`Padding`]:
+//!
+//! - [`Padme`] is the padding of Nikitin et al., [*Reducing Metadata Leakage
+//!   from Encrypted Files and Communication with PURBs*][2], which leaks
+//!   O(log log L) bits of a length L, for an overhead of at most 12%.
+//! - [`Bucket`] pads to a multiple of a fixed size, e.g. `Bucket<U256>`.
+//!
+//! The plaintext `M` is padded with a byte `0x80` followed by zeros, and the
+//! padded length `P` is set by the padding:
+//!
+//! ```text
+//! C || T = AEAD(K, N, A, M || 0x80 || 0^(P - |M| - 1))
+//! ```
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! Padding only reduces what the length of a single message reveals: the
+//! lengths of many messages, or their timing, can still reveal their
+//! contents.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! ```
+//! use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+//! use padded_aead::{Padded, Padme};
+//! use padded_aead::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = Padded::<ChaCha20Poly1305, Padme>::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce"); // 96-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"a 32-byte plaintext message here".repeat(3).as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! // 96 bytes of plaintext, padded to 104, and the 16-byte tag
+//! assert_eq!(ciphertext.len(), 104 + 16);
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(plaintext.len(), 96);
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`Padded::encrypt_in_place`] and [`Padded::decrypt_in_place`] methods
+//! accept any type that impls the [`aead::Buffer`] trait which contains the
+//! plaintext for encryption or ciphertext for decryption.
+//!
+//! [1]: https://en.wikipedia.org/wiki/Authenticated_encryption
+//! [2]: https://petsymposium.org/2019/files/papers/issue4/popets-2019-0056.pdf
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub use aead::{self, AeadCore, AeadInPlace, Error, NewAead};
+
+use aead::{
+    generic_array::{
+        typenum::{NonZero, Unsigned},
+        GenericArray,
+    },
+    Buffer,
+};
+use core::marker::PhantomData;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+#[cfg(feature = "alloc")]
+use {
+    aead::{Aead, AeadMut, Payload},
+    alloc::vec::Vec,
+};
+
+/// First byte of the padding
+const PADDING_MARKER: u8 = 0x80;
+
+/// Padding scheme, which sets the padded length of messages.
+pub trait Padding {
+    /// Padded length of a message of `len` bytes, which must be greater than
+    /// `len`, or `None` if it would overflow.
+    fn padded_len(len: usize) -> Option<usize>;
+}
+
+/// Padmé padding, which pads a length `L` to a length with at most
+/// `floor(log2(log2(L))) + 1` significant bits.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Padme;
+
+impl Padding for Padme {
+    fn padded_len(len: usize) -> Option<usize> {
+        // The marker byte is always added
+        let len = len.checked_add(1)?;
+        if len < 2 {
+            return Some(len);
+        }
+
+        let e = (!0usize).count_ones() - 1 - len.leading_zeros();
+        let s = 32 - e.leading_zeros();
+        let mask = (1 << (e - s)) - 1;
+        Some(len.checked_add(mask)? & !mask)
+    }
+}
+
+/// Padding to a multiple of `N` bytes.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Bucket<N: Unsigned + NonZero> {
+    size: PhantomData<N>,
+}
+
+impl<N: Unsigned + NonZero> Padding for Bucket<N> {
+    fn padded_len(len: usize) -> Option<usize> {
+        let buckets = len / N::USIZE + 1;
+        buckets.checked_mul(N::USIZE)
+    }
+}
+
+/// Wrapper around an AEAD which pads plaintexts with a [`Padding`] `P`.
+///
+/// See the [toplevel documentation](index.html) for details of the
+/// construction and a usage example.
+///
+/// The key, nonce and tag sizes are those of the wrapped AEAD `A`.
+///
+/// [`AeadInPlace`] is not implemented: its detached methods encrypt and
+/// decrypt a buffer of a fixed length, which has no room for the padding.
+pub struct Padded<A, P>
+where
+    A: AeadInPlace,
+    P: Padding,
+{
+    /// Wrapped AEAD
+    aead: A,
+
+    /// Padding scheme
+    padding: PhantomData<P>,
+}
+
+impl<A, P> From<A> for Padded<A, P>
+where
+    A: AeadInPlace,
+    P: Padding,
+{
+    fn from(aead: A) -> Self {
+        Self {
+            aead,
+            padding: PhantomData,
+        }
+    }
+}
+
+impl<A, P> NewAead for Padded<A, P>
+where
+    A: NewAead + AeadInPlace,
+    P: Padding,
+{
+    type KeySize = A::KeySize;
+
+    fn new(key: &GenericArray<u8, A::KeySize>) -> Self {
+        A::new(key).into()
+    }
+}
+
+impl<A, P> AeadCore for Padded<A, P>
+where
+    A: AeadInPlace,
+    P: Padding,
+{
+    type NonceSize = A::NonceSize;
+    type TagSize = A::TagSize;
+    type CiphertextOverhead = A::CiphertextOverhead;
+}
+
+impl<A, P> Padded<A, P>
+where
+    A: AeadInPlace,
+    P: Padding,
+{
+    /// Encrypt the given buffer containing a plaintext message in-place,
+    /// padding it and appending the tag.
+    pub fn encrypt_in_place(
+        &self,
+        nonce: &GenericArray<u8, A::NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        let msg_len = buffer.len();
+        let padded_len = P::padded_len(msg_len).ok_or(Error)?;
+        debug_assert!(padded_len > msg_len);
+
+        if let Err(e) = pad(buffer, padded_len) {
+            buffer.truncate(msg_len);
+            return Err(e);
+        }
+
+        self.aead.encrypt_in_place(nonce, associated_data, buffer)
+    }
+
+    /// Decrypt the given buffer containing a ciphertext in-place, returning
+    /// an error if its tag or padding is invalid.
+    ///
+    /// On success, the buffer only contains the plaintext.
+    pub fn decrypt_in_place(
+        &self,
+        nonce: &GenericArray<u8, A::NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        self.aead.decrypt_in_place(nonce, associated_data, buffer)?;
+
+        let (msg_len, valid) = unpadded_len(buffer.as_ref());
+        if valid.unwrap_u8() == 0 {
+            buffer.as_mut().iter_mut().for_each(|b| *b = 0);
+            return Err(Error);
+        }
+
+        buffer.truncate(msg_len);
+        Ok(())
+    }
+
+    /// Unwrap the AEAD
+    pub fn into_inner(self) -> A {
+        self.aead
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<A, P> Aead for Padded<A, P>
+where
+    A: AeadInPlace,
+    P: Padding,
+{
+    fn encrypt<'msg, 'aad>(
+        &self,
+        nonce: &GenericArray<u8, A::NonceSize>,
+        plaintext: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<Vec<u8>, Error> {
+        let payload = plaintext.into();
+        let padded_len = P::padded_len(payload.msg.len()).ok_or(Error)?;
+        let mut buffer = Vec::with_capacity(padded_len + A::TagSize::USIZE);
+        buffer.extend_from_slice(payload.msg);
+        self.encrypt_in_place(nonce, payload.aad, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    fn decrypt<'msg, 'aad>(
+        &self,
+        nonce: &GenericArray<u8, A::NonceSize>,
+        ciphertext: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<Vec<u8>, Error> {
+        let payload = ciphertext.into();
+        let mut buffer = Vec::from(payload.msg);
+        self.decrypt_in_place(nonce, payload.aad, &mut buffer)?;
+        Ok(buffer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<A, P> AeadMut for Padded<A, P>
+where
+    A: AeadInPlace,
+    P: Padding,
+{
+    fn encrypt<'msg, 'aad>(
+        &mut self,
+        nonce: &GenericArray<u8, A::NonceSize>,
+        plaintext: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<Vec<u8>, Error> {
+        Aead::encrypt(self, nonce, plaintext)
+    }
+
+    fn decrypt<'msg, 'aad>(
+        &mut self,
+        nonce: &GenericArray<u8, A::NonceSize>,
+        ciphertext: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<Vec<u8>, Error> {
+        Aead::decrypt(self, nonce, ciphertext)
+    }
+}
+
+impl<A, P> Clone for Padded<A, P>
+where
+    A: AeadInPlace + Clone,
+    P: Padding,
+{
+    fn clone(&self) -> Self {
+        self.aead.clone().into()
+    }
+}
+
+/// Append the marker byte and zeros to `buffer`, up to `padded_len` bytes
+fn pad(buffer: &mut dyn Buffer, padded_len: usize) -> Result<(), Error> {
+    const ZEROS: [u8; 64] = [0; 64];
+
+    buffer.extend_from_slice(&[PADDING_MARKER])?;
+    while buffer.len() < padded_len {
+        let n = (padded_len - buffer.len()).min(ZEROS.len());
+        buffer.extend_from_slice(&ZEROS[..n])?;
+    }
+    Ok(())
+}
+
+/// Find the length of the message in `padded`, in constant time, and whether
+/// its padding is valid
+fn unpadded_len(padded: &[u8]) -> (usize, Choice) {
+    let mut len = 0u64;
+    let mut found = Choice::from(0);
+    let mut invalid = Choice::from(0);
+
+    // The padding is the last marker byte, followed by zeros only
+    for (i, b) in padded.iter().enumerate().rev() {
+        let is_marker = b.ct_eq(&PADDING_MARKER);
+        invalid |= !found & !is_marker & !b.ct_eq(&0);
+        len.conditional_assign(&(i as u64), !found & is_marker);
+        found |= is_marker;
+    }
+
+    (len as usize, found & !invalid)
+}
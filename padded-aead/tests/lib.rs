@@ -0,0 +1,126 @@
+//! Tests of `Padded`
+
+use aes_gcm::Aes128Gcm;
+use chacha20poly1305::ChaCha20Poly1305;
+use padded_aead::aead::{consts::U16, Aead, AeadMut, NewAead};
+use padded_aead::{Bucket, Padded, Padding, Padme};
+
+const KEY: [u8; 32] = [0x42; 32];
+const NONCE: [u8; 12] = *b"unique nonce";
+
+/// Message lengths, and their padded lengths computed with the Padmé
+/// algorithm of the PURBs paper
+#[test]
+fn padme_lengths() {
+    for &(len, padded_len) in &[
+        (0, 1),
+        (1, 2),
+        (2, 3),
+        (3, 4),
+        (7, 8),
+        (8, 10),
+        (9, 10),
+        (15, 16),
+        (16, 18),
+        (17, 18),
+        (100, 104),
+        (103, 104),
+        (104, 112),
+        (1000, 1024),
+        (1023, 1024),
+        (1024, 1088),
+        (65536, 67584),
+        (1000000, 1015808),
+    ] {
+        assert_eq!(Padme::padded_len(len), Some(padded_len), "{}", len);
+    }
+
+    assert_eq!(Padme::padded_len(usize::MAX), None);
+}
+
+#[test]
+fn bucket_lengths() {
+    for &(len, padded_len) in &[(0, 16), (1, 16), (15, 16), (16, 32), (31, 32), (32, 48)] {
+        assert_eq!(Bucket::<U16>::padded_len(len), Some(padded_len), "{}", len);
+    }
+
+    assert_eq!(Bucket::<U16>::padded_len(usize::MAX), None);
+}
+
+/// Ciphertexts are those of the wrapped AEAD for the padded messages
+#[test]
+fn padded_ciphertext() {
+    let cipher = Padded::<ChaCha20Poly1305, Padme>::new(&KEY.into());
+    let ciphertext = cipher
+        .encrypt(&NONCE.into(), b"attack at dawn".as_ref())
+        .unwrap();
+    assert_eq!(ciphertext.len(), 16 + 16);
+
+    let padded = ChaCha20Poly1305::new(&KEY.into())
+        .decrypt(&NONCE.into(), ciphertext.as_ref())
+        .unwrap();
+    assert_eq!(&padded, b"attack at dawn\x80\x00");
+}
+
+#[test]
+fn roundtrip() {
+    let mut cipher = Padded::<Aes128Gcm, Bucket<U16>>::new(&[0x42; 16].into());
+
+    for len in 0..100 {
+        let msg = vec![0x80; len];
+        let ciphertext = AeadMut::encrypt(&mut cipher, &NONCE.into(), msg.as_ref()).unwrap();
+        assert_eq!(ciphertext.len(), (len / 16 + 1) * 16 + 16);
+
+        let plaintext = AeadMut::decrypt(&mut cipher, &NONCE.into(), ciphertext.as_ref()).unwrap();
+        assert_eq!(plaintext, msg);
+    }
+}
+
+/// Messages of lengths within the same range have ciphertexts of the same
+/// length
+#[test]
+fn hides_length() {
+    let cipher = Padded::<ChaCha20Poly1305, Padme>::new(&KEY.into());
+
+    for len in 100..104 {
+        let ciphertext = cipher.encrypt(&NONCE.into(), &[0; 200][..len]).unwrap();
+        assert_eq!(ciphertext.len(), 104 + 16);
+    }
+}
+
+/// The padding is authenticated, but must be checked after decryption
+#[test]
+fn invalid_padding() {
+    let aead = ChaCha20Poly1305::new(&KEY.into());
+    let cipher = Padded::<_, Padme>::from(aead.clone());
+
+    for padded in &[
+        &b""[..],
+        b"\x00",
+        b"\x00\x00\x00\x00",
+        b"message",
+        b"message\x80\x01",
+        b"message\x80\x00\x00\x7f",
+    ] {
+        let ciphertext = aead.encrypt(&NONCE.into(), *padded).unwrap();
+        assert!(cipher.decrypt(&NONCE.into(), ciphertext.as_ref()).is_err());
+    }
+
+    // Any amount of zeros is allowed after the marker
+    let ciphertext = aead
+        .encrypt(&NONCE.into(), b"message\x80\x00\x00\x00\x00\x00".as_ref())
+        .unwrap();
+    assert_eq!(
+        cipher.decrypt(&NONCE.into(), ciphertext.as_ref()).unwrap(),
+        b"message"
+    );
+}
+
+#[test]
+fn decrypt_modified() {
+    let cipher = Padded::<ChaCha20Poly1305, Padme>::new(&KEY.into());
+    let mut ciphertext = cipher.encrypt(&NONCE.into(), b"message".as_ref()).unwrap();
+
+    ciphertext[0] ^= 1;
+    assert!(cipher.decrypt(&NONCE.into(), ciphertext.as_ref()).is_err());
+}
@@ -0,0 +1,172 @@
+//! [PHOTON-Beetle][1]: a lightweight [Authenticated Encryption with
+//! Associated Data (AEAD)][2] cipher built on the PHOTON-256 permutation.
+//!
+//! PHOTON-Beetle was a finalist of the NIST lightweight cryptography process.
+//! It is a sponge-based mode with a 256-bit state initialized with the nonce
+//! and key, where the ciphertext is computed from a shuffle of the rate rather
+//! than the rate itself. This crate implements PHOTON-Beetle-AEAD\[128\],
+//! which absorbs 128 bits per permutation call.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! ```
+//! use photon_beetle::{PhotonBeetle128, Key, Nonce};
+//! use photon_beetle::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = PhotonBeetle128::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"my unique nonce!"); // 128-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! Note that if you enable the `heapless` feature of this crate,
+//! you will receive an impl of [`aead::Buffer`] for `heapless::Vec`
+//! (re-exported from the [`aead`] crate as [`aead::heapless::Vec`]),
+//! which can then be passed as the `buffer` parameter to the in-place encrypt
+//! and decrypt methods:
+//!
+//! ```
+//! # #[cfg(feature = "heapless")]
+//! # {
+//! use photon_beetle::{PhotonBeetle128, Key, Nonce};
+//! use photon_beetle::aead::{AeadInPlace, NewAead};
+//! use photon_beetle::aead::heapless::Vec;
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = PhotonBeetle128::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"my unique nonce!"); // 128-bits; unique per message
+//!
+//! let mut buffer: Vec<u8, 128> = Vec::new(); // Buffer needs 16-bytes overhead for the tag
+//! buffer.extend_from_slice(b"plaintext message");
+//!
+//! // Encrypt `buffer` in-place, replacing the plaintext contents with ciphertext
+//! cipher.encrypt_in_place(nonce, b"", &mut buffer).expect("encryption failure!");
+//!
+//! // `buffer` now contains the message ciphertext
+//! assert_ne!(&buffer, b"plaintext message");
+//!
+//! // Decrypt `buffer` in-place, replacing its ciphertext context with the original plaintext
+//! cipher.decrypt_in_place(nonce, b"", &mut buffer).expect("decryption failure!");
+//! assert_eq!(&buffer, b"plaintext message");
+//! # }
+//! ```
+//!
+//! [1]: https://csrc.nist.gov/projects/lightweight-cryptography/finalists
+//! [2]: https://en.wikipedia.org/wiki/Authenticated_encryption
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![deny(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use aead::{self, consts, AeadCore, AeadInPlace, Error, NewAead};
+
+mod photon;
+mod state;
+
+use aead::{
+    consts::{U0, U16},
+    generic_array::GenericArray,
+};
+use state::State;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// PHOTON-Beetle keys (128-bit)
+pub type Key = GenericArray<u8, U16>;
+
+/// PHOTON-Beetle nonces (128-bit)
+pub type Nonce = GenericArray<u8, U16>;
+
+/// PHOTON-Beetle tags (128-bit)
+pub type Tag = GenericArray<u8, U16>;
+
+/// PHOTON-Beetle-AEAD\[128\]: 128-bit key, 128-bit nonce, 128-bit tag, 128-bit
+/// rate.
+#[derive(Clone)]
+pub struct PhotonBeetle128 {
+    key: [u8; 16],
+}
+
+impl NewAead for PhotonBeetle128 {
+    type KeySize = U16;
+
+    fn new(key: &Key) -> Self {
+        Self { key: (*key).into() }
+    }
+}
+
+impl AeadCore for PhotonBeetle128 {
+    type NonceSize = U16;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl AeadInPlace for PhotonBeetle128 {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        let mut state = State::new(&self.key, nonce, associated_data, buffer.len());
+        state.encrypt(buffer);
+        Ok(state.finalize())
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        let mut state = State::new(&self.key, nonce, associated_data, buffer.len());
+        state.decrypt(buffer);
+        let expected_tag = state.finalize();
+
+        if expected_tag.ct_eq(tag).unwrap_u8() == 1 {
+            Ok(())
+        } else {
+            // Don't release unauthenticated plaintext
+            buffer.iter_mut().for_each(|b| *b = 0);
+            Err(Error)
+        }
+    }
+}
+
+impl Drop for PhotonBeetle128 {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
@@ -0,0 +1,105 @@
+//! PHOTON-256 permutation
+//!
+//! The 8x8 matrix of 4-bit cells is kept as one 32-bit word per row, cell `j`
+//! of a row being its `j`-th nibble. The S-box is bitsliced over the nibbles
+//! and the field multiplications of the column mixing are done on all the
+//! cells of a row at once, so there are no secret-dependent table lookups.
+
+use core::convert::TryInto;
+
+/// Number of rounds
+const ROUNDS: usize = 12;
+
+/// Round constants
+const RC: [u32; ROUNDS] = [1, 3, 7, 14, 13, 11, 6, 12, 9, 2, 5, 10];
+
+/// Internal constants of the rows
+const IC: [u32; 8] = [0, 1, 3, 7, 15, 14, 12, 8];
+
+/// Serial column mixing matrix raised to the 8th power
+const MIX_COLUMNS: [[u8; 8]; 8] = [
+    [2, 4, 2, 11, 2, 8, 5, 6],
+    [12, 9, 8, 13, 7, 7, 5, 2],
+    [4, 4, 13, 13, 9, 4, 13, 9],
+    [1, 6, 5, 1, 12, 13, 15, 14],
+    [15, 12, 9, 13, 14, 5, 14, 13],
+    [9, 14, 5, 15, 4, 12, 9, 6],
+    [12, 2, 2, 10, 3, 1, 1, 14],
+    [15, 1, 13, 10, 5, 10, 2, 3],
+];
+
+/// Lowest bit of every nibble
+const LSB: u32 = 0x1111_1111;
+
+/// Apply PHOTON-256 to the state. Byte `i` holds cells `2 * i` (low nibble)
+/// and `2 * i + 1` (high nibble), cells being numbered row by row.
+pub(crate) fn photon256(state: &mut [u8; 32]) {
+    let mut rows = [0u32; 8];
+    for (row, bytes) in rows.iter_mut().zip(state.chunks_exact(4)) {
+        *row = u32::from_le_bytes(bytes.try_into().unwrap());
+    }
+
+    for &rc in RC.iter() {
+        for (i, row) in rows.iter_mut().enumerate() {
+            // AddConstant on the first column, SubCells and ShiftRows
+            *row = sub_cells(*row ^ rc ^ IC[i]).rotate_right(4 * i as u32);
+        }
+
+        mix_columns(&mut rows);
+    }
+
+    for (bytes, row) in state.chunks_exact_mut(4).zip(rows.iter()) {
+        bytes.copy_from_slice(&row.to_le_bytes());
+    }
+}
+
+/// The PRESENT S-box applied to the eight cells of a row
+#[inline(always)]
+fn sub_cells(x: u32) -> u32 {
+    let x0 = x & LSB;
+    let x1 = x >> 1 & LSB;
+    let x2 = x >> 2 & LSB;
+    let x3 = x >> 3 & LSB;
+
+    let x12 = x1 & x2;
+    let x013 = x0 & x1 & x3;
+    let x023 = x0 & x2 & x3;
+
+    let y0 = x0 ^ x2 ^ x3 ^ x12;
+    let y1 = x1 ^ x3 ^ (x0 & x12) ^ (x1 & x3) ^ (x2 & x3) ^ x013 ^ x023;
+    let y2 = !(x2 ^ x3 ^ (x0 & x1) ^ (x0 & x3) ^ (x1 & x3) ^ x013 ^ x023) & LSB;
+    let y3 = !(x0 ^ x1 ^ x3 ^ x12 ^ (x0 & x12) ^ x013 ^ x023) & LSB;
+
+    y0 | y1 << 1 | y2 << 2 | y3 << 3
+}
+
+/// Multiply the eight cells of a row by `x` in GF(2^4) = GF(2)\[x\] / (x^4 + x + 1)
+#[inline(always)]
+fn double(x: u32) -> u32 {
+    ((x & !(LSB << 3)) << 1) ^ ((x >> 3 & LSB) * 0x3)
+}
+
+/// MixColumnSerial, multiplying every column by [`MIX_COLUMNS`]
+#[inline(always)]
+fn mix_columns(rows: &mut [u32; 8]) {
+    // Each row multiplied by 1, 2, 4 and 8
+    let mut multiples = [[0u32; 4]; 8];
+    for (m, &row) in multiples.iter_mut().zip(rows.iter()) {
+        m[0] = row;
+        m[1] = double(m[0]);
+        m[2] = double(m[1]);
+        m[3] = double(m[2]);
+    }
+
+    for (row, coefficients) in rows.iter_mut().zip(MIX_COLUMNS.iter()) {
+        *row = 0;
+
+        for (m, &c) in multiples.iter().zip(coefficients.iter()) {
+            for (bit, &multiple) in m.iter().enumerate() {
+                if c >> bit & 1 == 1 {
+                    *row ^= multiple;
+                }
+            }
+        }
+    }
+}
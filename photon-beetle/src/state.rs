@@ -0,0 +1,146 @@
+//! PHOTON-Beetle mode of operation
+
+use crate::{photon::photon256, Nonce, Tag};
+use zeroize::Zeroize;
+
+/// Rate of the sponge in bytes
+const RATE: usize = 16;
+
+/// Sponge state processing one message
+pub(crate) struct State {
+    s: [u8; 32],
+
+    /// Domain separation constant added after the message
+    message_constant: u8,
+}
+
+impl State {
+    /// Initialize the state with the nonce and key, and absorb the associated
+    /// data of a message of `message_len` bytes
+    pub(crate) fn new(
+        key: &[u8; 16],
+        nonce: &Nonce,
+        associated_data: &[u8],
+        message_len: usize,
+    ) -> Self {
+        let mut s = [0u8; 32];
+        s[..RATE].copy_from_slice(nonce);
+        s[RATE..].copy_from_slice(key);
+
+        let mut state = Self {
+            s,
+            message_constant: select(
+                !associated_data.is_empty(),
+                message_len % RATE == 0,
+                [1, 2, 5, 6],
+            ),
+        };
+
+        if !associated_data.is_empty() {
+            let constant = select(
+                message_len != 0,
+                associated_data.len() % RATE == 0,
+                [1, 2, 3, 4],
+            );
+
+            for chunk in associated_data.chunks(RATE) {
+                photon256(&mut state.s);
+                xor(&mut state.s, chunk);
+                state.pad(chunk.len());
+            }
+            state.add_constant(constant);
+        } else if message_len == 0 {
+            state.add_constant(1);
+        }
+
+        state
+    }
+
+    /// Encrypt `buffer` in-place
+    pub(crate) fn encrypt(&mut self, buffer: &mut [u8]) {
+        for chunk in buffer.chunks_mut(RATE) {
+            photon256(&mut self.s);
+            let keystream = self.shuffle();
+            xor(&mut self.s, chunk);
+            xor(chunk, &keystream);
+            self.pad(chunk.len());
+        }
+
+        if !buffer.is_empty() {
+            self.add_constant(self.message_constant);
+        }
+    }
+
+    /// Decrypt `buffer` in-place
+    pub(crate) fn decrypt(&mut self, buffer: &mut [u8]) {
+        for chunk in buffer.chunks_mut(RATE) {
+            photon256(&mut self.s);
+            xor(chunk, &self.shuffle());
+            xor(&mut self.s, chunk);
+            self.pad(chunk.len());
+        }
+
+        if !buffer.is_empty() {
+            self.add_constant(self.message_constant);
+        }
+    }
+
+    /// Squeeze the tag
+    pub(crate) fn finalize(mut self) -> Tag {
+        photon256(&mut self.s);
+        Tag::clone_from_slice(&self.s[..RATE])
+    }
+
+    /// Output of the shuffle `S1 || S2 ↦ S2 || (S1 ⋙ 1)` of the rate, where
+    /// `S1` is read as a little-endian integer
+    fn shuffle(&self) -> [u8; RATE] {
+        let mut out = [0u8; RATE];
+        out[..RATE / 2].copy_from_slice(&self.s[RATE / 2..RATE]);
+
+        for i in 0..RATE / 2 {
+            out[RATE / 2 + i] = self.s[i] >> 1 | self.s[(i + 1) % (RATE / 2)] << 7;
+        }
+
+        out
+    }
+
+    /// Add the padding of a block of `len` bytes if it is partial
+    #[inline(always)]
+    fn pad(&mut self, len: usize) {
+        if len < RATE {
+            self.s[len] ^= 0x01;
+        }
+    }
+
+    /// Add a domain separation constant to the three last bits of the state
+    #[inline(always)]
+    fn add_constant(&mut self, constant: u8) {
+        self.s[31] ^= constant << 5;
+    }
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        self.s.zeroize();
+    }
+}
+
+/// Select a domain separation constant from whether the other input is
+/// non-empty and whether this one ends on a block boundary
+#[inline(always)]
+fn select(other_non_empty: bool, full_blocks: bool, constants: [u8; 4]) -> u8 {
+    match (other_non_empty, full_blocks) {
+        (true, true) => constants[0],
+        (true, false) => constants[1],
+        (false, true) => constants[2],
+        (false, false) => constants[3],
+    }
+}
+
+/// XOR `src` into `dst`, up to the shorter of the two lengths
+#[inline(always)]
+fn xor(dst: &mut [u8], src: &[u8]) {
+    for (a, b) in dst.iter_mut().zip(src) {
+        *a ^= b;
+    }
+}
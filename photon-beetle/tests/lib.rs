@@ -0,0 +1,148 @@
+//! PHOTON-Beetle tests
+
+#[macro_use]
+extern crate hex_literal;
+
+use photon_beetle::aead::{generic_array::GenericArray, Aead, NewAead, Payload};
+use photon_beetle::PhotonBeetle128;
+
+// Known-answer tests in the format of the NIST LWC submissions
+aead::new_test!(photon_beetle128, "photon_beetle128", PhotonBeetle128);
+
+/// Test vectors
+#[derive(Debug)]
+pub struct TestVector {
+    pub key: &'static [u8; 16],
+    pub nonce: &'static [u8; 16],
+    pub aad: &'static [u8],
+    pub plaintext: &'static [u8],
+    pub ciphertext: &'static [u8],
+    pub tag: &'static [u8; 16],
+}
+
+/// PHOTON-Beetle-AEAD[128] test vectors, selected from the known-answer tests
+/// to cover empty, partial and multi-block inputs
+const TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("df4e0bac1162408098fa5cf084d8f464"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!("00"),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("e840449949081c5378e01eba6046dbe8"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!("00"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f"),
+        ciphertext: &hex!("856bb76c8303baed048857dc8f4fa64f"),
+        tag: &hex!("fa1310857e364dfd2ebcee5961bd19d1"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!("000102030405060708090a0b0c0d0e"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f10111213141516"),
+        ciphertext: &hex!("4b5b4abdfb2b7a23357aed8d47f5831c3b5c903f4f581f"),
+        tag: &hex!("a0e2ebaae77d4d751acc25908f920c10"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        ciphertext: &hex!("29bbcd6b33407b0379eb0a1f75f2280ed67ed15bfbb2454c7c1b7388bebfaa90"),
+        tag: &hex!("55c2074d2bc87e43db483b0081429d26"),
+    },
+];
+
+#[test]
+fn encrypt() {
+    for vector in TEST_VECTORS {
+        let key = GenericArray::from_slice(vector.key);
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let payload = Payload {
+            msg: vector.plaintext,
+            aad: vector.aad,
+        };
+
+        let cipher = PhotonBeetle128::new(key);
+        let ciphertext = cipher.encrypt(nonce, payload).unwrap();
+        let (ct, tag) = ciphertext.split_at(vector.plaintext.len());
+        assert_eq!(vector.ciphertext, ct);
+        assert_eq!(vector.tag, tag);
+    }
+}
+
+#[test]
+fn decrypt() {
+    for vector in TEST_VECTORS {
+        let key = GenericArray::from_slice(vector.key);
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let mut ciphertext = Vec::from(vector.ciphertext);
+        ciphertext.extend_from_slice(vector.tag);
+
+        let payload = Payload {
+            msg: &ciphertext,
+            aad: vector.aad,
+        };
+
+        let cipher = PhotonBeetle128::new(key);
+        let plaintext = cipher.decrypt(nonce, payload).unwrap();
+
+        assert_eq!(vector.plaintext, plaintext.as_slice());
+    }
+}
+
+#[test]
+fn decrypt_modified() {
+    let vector = &TEST_VECTORS[4];
+    let key = GenericArray::from_slice(vector.key);
+    let nonce = GenericArray::from_slice(vector.nonce);
+
+    let mut ciphertext = Vec::from(vector.ciphertext);
+    ciphertext.extend_from_slice(vector.tag);
+
+    // Tweak the first byte
+    ciphertext[0] ^= 0xaa;
+
+    let payload = Payload {
+        msg: &ciphertext,
+        aad: vector.aad,
+    };
+
+    let cipher = PhotonBeetle128::new(key);
+    assert!(cipher.decrypt(nonce, payload).is_err());
+}
+
+#[test]
+fn multi_block() {
+    let key = GenericArray::from_slice(TEST_VECTORS[0].key);
+    let nonce = GenericArray::from_slice(TEST_VECTORS[0].nonce);
+    let data = (0..100).map(|i| i as u8).collect::<Vec<_>>();
+    let payload = Payload {
+        msg: &data,
+        aad: &data,
+    };
+
+    let cipher = PhotonBeetle128::new(key);
+    let ciphertext = cipher.encrypt(nonce, payload).unwrap();
+    let (ct, tag) = ciphertext.split_at(data.len());
+    assert_eq!(ct, &hex!("45ece827e8d36afcfafb185c40113c3b815cb79d5f11200bbede299429c3f9b07aa8bd15ea4b92278a9d26cd2f9a73990c8e470481dd19eb887d4fcec6d2e76d35ea1451e84510b939e4ca82954e1ec74cb7faebb657de0712213427ff5c2ae706bb534b")[..]);
+    assert_eq!(tag, hex!("a4ea83d0a09c112c9e76b285492a5942"));
+
+    let payload = Payload {
+        msg: &ciphertext,
+        aad: &data,
+    };
+    assert_eq!(cipher.decrypt(nonce, payload).unwrap(), data);
+}
@@ -0,0 +1,386 @@
+//! [Rocca-S][1]: an [Authenticated Encryption with Associated Data (AEAD)][2]
+//! cipher built on the AES round function, as specified in
+//! [draft-nakano-rocca-s][1].
+//!
+//! Rocca-S takes a 256-bit key and a 128-bit nonce, and produces a 256-bit
+//! authentication tag.
+//!
+//! ## Performance Notes
+//!
+//! The AES round function is provided by the [`aes`] crate's `hazmat` API,
+//! which uses AES-NI on x86/x86_64 when it is detected at runtime and falls
+//! back to a portable bitsliced implementation otherwise.
+//!
+//! On aarch64 the ARMv8 Cryptography Extensions can be used by enabling the
+//! nightly-only `armv8` feature of this crate.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! Rocca-S is not nonce misuse-resistant: reusing a nonce under the same key
+//! allows an attacker to recover the internal state, which breaks both the
+//! confidentiality and the authenticity of any message encrypted with it.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! ```
+//! use rocca_s::{RoccaS, Key, Nonce};
+//! use rocca_s::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = RoccaS::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce 128"); // 128-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! Note that if you enable the `heapless` feature of this crate,
+//! you will receive an impl of [`aead::Buffer`] for `heapless::Vec`
+//! (re-exported from the [`aead`] crate as [`aead::heapless::Vec`]),
+//! which can then be passed as the `buffer` parameter to the in-place encrypt
+//! and decrypt methods:
+//!
+//! ```
+//! # #[cfg(feature = "heapless")]
+//! # {
+//! use rocca_s::{RoccaS, Key, Nonce};
+//! use rocca_s::aead::{AeadInPlace, NewAead};
+//! use rocca_s::aead::heapless::Vec;
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = RoccaS::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce 128"); // 128-bits; unique per message
+//!
+//! let mut buffer: Vec<u8, 128> = Vec::new(); // Buffer needs 32-bytes overhead for the tag
+//! buffer.extend_from_slice(b"plaintext message");
+//!
+//! // Encrypt `buffer` in-place, replacing the plaintext contents with ciphertext
+//! cipher.encrypt_in_place(nonce, b"", &mut buffer).expect("encryption failure!");
+//!
+//! // `buffer` now contains the message ciphertext
+//! assert_ne!(&buffer, b"plaintext message");
+//!
+//! // Decrypt `buffer` in-place, replacing its ciphertext context with the original plaintext
+//! cipher.decrypt_in_place(nonce, b"", &mut buffer).expect("decryption failure!");
+//! assert_eq!(&buffer, b"plaintext message");
+//! # }
+//! ```
+//!
+//! [1]: https://datatracker.ietf.org/doc/draft-nakano-rocca-s/
+//! [2]: https://en.wikipedia.org/wiki/Authenticated_encryption
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![deny(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use aead::{self, consts, AeadCore, AeadInPlace, Error, NewAead};
+
+use aead::{
+    consts::{U0, U16, U32},
+    generic_array::GenericArray,
+};
+use aes::{
+    hazmat::{cipher_round, cipher_round_par},
+    ParBlocks,
+};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// Rocca-S keys (256-bit)
+pub type Key = GenericArray<u8, U32>;
+
+/// Rocca-S nonces (128-bit)
+pub type Nonce = GenericArray<u8, U16>;
+
+/// Rocca-S tags (256-bit)
+pub type Tag = GenericArray<u8, U32>;
+
+/// AES block
+type Block = aes::Block;
+
+/// Constant `Z0`
+const Z0: [u8; 16] = [
+    0xcd, 0x65, 0xef, 0x23, 0x91, 0x44, 0x37, 0x71, 0x22, 0xae, 0x28, 0xd7, 0x98, 0x2f, 0x8a, 0x42,
+];
+
+/// Constant `Z1`
+const Z1: [u8; 16] = [
+    0xbc, 0xdb, 0x89, 0x81, 0xa5, 0xdb, 0xb5, 0xe9, 0x2f, 0x3b, 0x4d, 0xec, 0xcf, 0xfb, 0xc0, 0xb5,
+];
+
+/// Number of rounds of the initialization and finalization
+const ROUNDS: usize = 16;
+
+/// Number of bytes absorbed by a single state update
+const RATE: usize = 32;
+
+/// Rocca-S: 256-bit key, 128-bit nonce, 256-bit tag.
+#[derive(Clone)]
+pub struct RoccaS {
+    /// Secret key
+    key: Key,
+}
+
+impl NewAead for RoccaS {
+    type KeySize = U32;
+
+    fn new(key: &Key) -> Self {
+        Self { key: *key }
+    }
+}
+
+impl AeadCore for RoccaS {
+    type NonceSize = U16;
+    type TagSize = U32;
+    type CiphertextOverhead = U0;
+}
+
+impl AeadInPlace for RoccaS {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        let mut state = State::new(&self.key, nonce);
+        state.absorb_associated_data(associated_data);
+
+        let mut chunks = buffer.chunks_exact_mut(RATE);
+        for chunk in &mut chunks {
+            state.enc(chunk);
+        }
+
+        let rem = chunks.into_remainder();
+        if !rem.is_empty() {
+            let mut block = [0u8; RATE];
+            block[..rem.len()].copy_from_slice(rem);
+            state.enc(&mut block);
+            rem.copy_from_slice(&block[..rem.len()]);
+        }
+
+        Ok(state.finalize(associated_data.len(), buffer.len()))
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        let mut state = State::new(&self.key, nonce);
+        state.absorb_associated_data(associated_data);
+
+        let mut chunks = buffer.chunks_exact_mut(RATE);
+        for chunk in &mut chunks {
+            state.dec(chunk);
+        }
+
+        let rem = chunks.into_remainder();
+        if !rem.is_empty() {
+            state.dec_partial(rem);
+        }
+
+        let expected_tag = state.finalize(associated_data.len(), buffer.len());
+
+        if expected_tag.ct_eq(tag).unwrap_u8() == 1 {
+            Ok(())
+        } else {
+            // Don't release unauthenticated plaintext
+            buffer.iter_mut().for_each(|b| *b = 0);
+            Err(Error)
+        }
+    }
+}
+
+impl Drop for RoccaS {
+    fn drop(&mut self) {
+        self.key.as_mut_slice().zeroize();
+    }
+}
+
+/// Rocca-S state: seven AES blocks
+struct State {
+    s: [Block; 7],
+}
+
+impl State {
+    /// Initialize the state with the given key and nonce
+    fn new(key: &Key, nonce: &Nonce) -> Self {
+        let z0 = Block::from(Z0);
+        let z1 = Block::from(Z1);
+        let k0 = Block::from_slice(&key[..16]);
+        let k1 = Block::from_slice(&key[16..]);
+
+        let mut state = Self {
+            s: [*k1, *nonce, z0, *k0, z1, xor(nonce, k1), Block::default()],
+        };
+
+        for _ in 0..ROUNDS {
+            state.update(&z0, &z1);
+        }
+
+        for (i, block) in state.s.iter_mut().enumerate() {
+            let k = if i == 2 || i >= 5 { k1 } else { k0 };
+            xor_in_place(block, k);
+        }
+
+        state
+    }
+
+    /// Update the state, absorbing the two message blocks `x0` and `x1`.
+    ///
+    /// The six AES rounds are independent and are computed with a single
+    /// parallel invocation of the round function, leaving two lanes unused.
+    #[inline(always)]
+    fn update(&mut self, x0: &Block, x1: &Block) {
+        let s = &self.s;
+        let zero = Block::default();
+
+        let mut blocks: ParBlocks = [s[0], s[1], s[2], s[3], s[4], s[5], zero, zero].into();
+        let round_keys: ParBlocks = [*x0, s[0], s[6], *x1, s[3], s[4], zero, zero].into();
+        cipher_round_par(&mut blocks, &round_keys);
+
+        self.s = [
+            xor(&s[6], &s[1]),
+            blocks[0],
+            blocks[1],
+            blocks[2],
+            blocks[3],
+            blocks[4],
+            blocks[5],
+        ];
+    }
+
+    /// Absorb the associated data, zero-padding the last block
+    fn absorb_associated_data(&mut self, associated_data: &[u8]) {
+        let mut chunks = associated_data.chunks_exact(RATE);
+        for chunk in &mut chunks {
+            self.absorb(chunk);
+        }
+
+        let rem = chunks.remainder();
+        if !rem.is_empty() {
+            let mut block = [0u8; RATE];
+            block[..rem.len()].copy_from_slice(rem);
+            self.absorb(&block);
+        }
+    }
+
+    /// Absorb a 256-bit block
+    #[inline(always)]
+    fn absorb(&mut self, ai: &[u8]) {
+        let (x0, x1) = ai.split_at(16);
+        self.update(Block::from_slice(x0), Block::from_slice(x1));
+    }
+
+    /// XOR the keystream for the next 256-bit block into `buf`
+    #[inline(always)]
+    fn apply_keystream(&self, buf: &mut [u8]) {
+        let s = &self.s;
+
+        let mut z0 = xor(&s[3], &s[5]);
+        cipher_round(&mut z0, &s[0]);
+        let mut z1 = xor(&s[4], &s[6]);
+        cipher_round(&mut z1, &s[2]);
+
+        xor_in_place(&mut buf[..16], &z0);
+        xor_in_place(&mut buf[16..], &z1);
+    }
+
+    /// Encrypt a 256-bit block in-place
+    fn enc(&mut self, xi: &mut [u8]) {
+        let mut msg = [0u8; RATE];
+        msg.copy_from_slice(xi);
+        self.apply_keystream(xi);
+        self.absorb(&msg);
+        msg.zeroize();
+    }
+
+    /// Decrypt a 256-bit block in-place
+    fn dec(&mut self, ci: &mut [u8]) {
+        self.apply_keystream(ci);
+        self.absorb(ci);
+    }
+
+    /// Decrypt the trailing partial block in-place
+    fn dec_partial(&mut self, cn: &mut [u8]) {
+        let mut block = [0u8; RATE];
+        block[..cn.len()].copy_from_slice(cn);
+        self.apply_keystream(&mut block);
+        cn.copy_from_slice(&block[..cn.len()]);
+
+        // Only the plaintext bytes are absorbed, zero-padded to 256 bits
+        block[cn.len()..].iter_mut().for_each(|b| *b = 0);
+        self.absorb(&block);
+        block.zeroize();
+    }
+
+    /// Compute the authentication tag
+    fn finalize(mut self, ad_len: usize, msg_len: usize) -> Tag {
+        let ad_len_bits = Block::from((ad_len as u128 * 8).to_le_bytes());
+        let msg_len_bits = Block::from((msg_len as u128 * 8).to_le_bytes());
+
+        for _ in 0..ROUNDS {
+            self.update(&ad_len_bits, &msg_len_bits);
+        }
+
+        let s = &self.s;
+        let t0 = xor(&xor(&s[0], &s[1]), &xor(&s[2], &s[3]));
+        let t1 = xor(&xor(&s[4], &s[5]), &s[6]);
+
+        let mut tag = Tag::default();
+        tag[..16].copy_from_slice(&t0);
+        tag[16..].copy_from_slice(&t1);
+        tag
+    }
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        for block in self.s.iter_mut() {
+            block.as_mut_slice().zeroize();
+        }
+    }
+}
+
+#[inline(always)]
+fn xor(a: &Block, b: &Block) -> Block {
+    let mut out = *a;
+    xor_in_place(&mut out, b);
+    out
+}
+
+#[inline(always)]
+fn xor_in_place(a: &mut [u8], b: &[u8]) {
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        *x ^= y;
+    }
+}
@@ -0,0 +1,133 @@
+//! Rocca-S tests
+
+#[macro_use]
+extern crate hex_literal;
+
+use rocca_s::aead::{generic_array::GenericArray, Aead, NewAead, Payload};
+use rocca_s::RoccaS;
+
+/// Test vectors
+#[derive(Debug)]
+pub struct TestVector {
+    pub key: &'static [u8; 32],
+    pub nonce: &'static [u8; 16],
+    pub aad: &'static [u8],
+    pub plaintext: &'static [u8],
+    pub ciphertext: &'static [u8],
+    pub tag: &'static [u8; 32],
+}
+
+/// Rocca-S test vectors
+///
+/// The first three are from draft-nakano-rocca-s, the remaining ones cover
+/// partial blocks and were cross-checked against an independent
+/// implementation.
+const TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        key: &hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+        nonce: &hex!("00000000000000000000000000000000"),
+        aad: &hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+        plaintext: &hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+        ciphertext: &hex!("9ac3326495a8d414fe407f47b54410502481cf79cab8c0a669323e07711e46170de5b2fbba0fae8de7c1fccaeefc362624fcfdc15f8bb3e64457e8b7e37557bb"),
+        tag: &hex!("8df934d1483710c9410f6a089c4ced9791901b7e2e661206202db2cc7a24a386"),
+    },
+    TestVector {
+        key: &hex!("0101010101010101010101010101010101010101010101010101010101010101"),
+        nonce: &hex!("01010101010101010101010101010101"),
+        aad: &hex!("0101010101010101010101010101010101010101010101010101010101010101"),
+        plaintext: &hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+        ciphertext: &hex!("559ecb253bcfe26b483bf00e9c748345978ff921036a6c1fdcb712172836504fbc64d430a73fc67acd3c3b9c1976d80790f48357e7fe0c0682624569d3a658fb"),
+        tag: &hex!("c1fdf39762eca77da8b0f1dae5fff75a92fb0adfa7940a28c8cadbbbe8e4ca8d"),
+    },
+    TestVector {
+        key: &hex!("0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef"),
+        nonce: &hex!("11111111111111111111111111111111"),
+        aad: &hex!("0101010101010101010101010101010101010101010101010101010101010101"),
+        plaintext: &hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+        ciphertext: &hex!("f62f1e23c85bf60b1344efabb52db6d1e3b7d3ee2d600e017894331ca028635f08d9c979aa85bf209270565ac8baa444ea754a02040752bd7a34ac0983b2fb6d"),
+        tag: &hex!("ccfc414f05b4ba38f913225bfcc39dcd96d1fcf6194e8e5b0a1ed1b0482f9b56"),
+    },
+    TestVector {
+        key: &hex!("1111111111111111111111111111111122222222222222222222222222222222"),
+        nonce: &hex!("44444444444444444444444444444444"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f1011"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"),
+        ciphertext: &hex!("e28d9f86288f77115d4ef620e7cedeced6cec724ce38a9061f813c982371f54c8e079f8298515afeb07a9515d2c5e2aa0687a8261f4100a33a24e7f78e8e5a2e"),
+        tag: &hex!("95f4fc1f039b6a71bb0e81828b0e090b808f5f69d188dcfc3eb3f998b88876cf"),
+    },
+    TestVector {
+        key: &hex!("1111111111111111111111111111111122222222222222222222222222222222"),
+        nonce: &hex!("44444444444444444444444444444444"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("5b97a8bfc990e97d6032745e3152ecadb08516ac5ff62e7b28a18b8fbb13a9d9"),
+    },
+    TestVector {
+        key: &hex!("1111111111111111111111111111111122222222222222222222222222222222"),
+        nonce: &hex!("44444444444444444444444444444444"),
+        aad: &hex!("00010203040506"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728"),
+        ciphertext: &hex!("e28d9f86288f77115d4ef620e7cedece470b0270ce38a9061f813c981ba814ada8f2f0cb4155c96eb1"),
+        tag: &hex!("69de0cffc26ac090d723fca3f2b91c516756f19044e6a785b2a75e824d54818d"),
+    },
+];
+
+#[test]
+fn encrypt() {
+    for vector in TEST_VECTORS {
+        let key = GenericArray::from_slice(vector.key);
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let payload = Payload {
+            msg: vector.plaintext,
+            aad: vector.aad,
+        };
+
+        let cipher = RoccaS::new(key);
+        let ciphertext = cipher.encrypt(nonce, payload).unwrap();
+        let (ct, tag) = ciphertext.split_at(vector.plaintext.len());
+        assert_eq!(vector.ciphertext, ct);
+        assert_eq!(vector.tag, tag);
+    }
+}
+
+#[test]
+fn decrypt() {
+    for vector in TEST_VECTORS {
+        let key = GenericArray::from_slice(vector.key);
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let mut ciphertext = Vec::from(vector.ciphertext);
+        ciphertext.extend_from_slice(vector.tag);
+
+        let payload = Payload {
+            msg: &ciphertext,
+            aad: vector.aad,
+        };
+
+        let cipher = RoccaS::new(key);
+        let plaintext = cipher.decrypt(nonce, payload).unwrap();
+
+        assert_eq!(vector.plaintext, plaintext.as_slice());
+    }
+}
+
+#[test]
+fn decrypt_modified() {
+    let vector = &TEST_VECTORS[0];
+    let key = GenericArray::from_slice(vector.key);
+    let nonce = GenericArray::from_slice(vector.nonce);
+
+    let mut ciphertext = Vec::from(vector.ciphertext);
+    ciphertext.extend_from_slice(vector.tag);
+
+    // Tweak the first byte
+    ciphertext[0] ^= 0xaa;
+
+    let payload = Payload {
+        msg: &ciphertext,
+        aad: vector.aad,
+    };
+
+    let cipher = RoccaS::new(key);
+    assert!(cipher.decrypt(nonce, payload).is_err());
+}
@@ -0,0 +1,332 @@
+//! [Romulus][1]: a lightweight [Authenticated Encryption with Associated
+//! Data (AEAD)][2] cipher built on the SKINNY-128-384+ tweakable block cipher.
+//!
+//! Romulus was a finalist of the NIST lightweight cryptography process. This
+//! crate implements Romulus-N, its primary nonce-based member, which has
+//! beyond-birthday-bound security thanks to the tweakable block cipher: a
+//! block counter, the nonce and the key all go into the 384-bit tweakey, and
+//! every other block of associated data is absorbed through the tweak.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! ```
+//! use romulus_aead::{RomulusN, Key, Nonce};
+//! use romulus_aead::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = RomulusN::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"my unique nonce!"); // 128-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! Note that if you enable the `heapless` feature of this crate,
+//! you will receive an impl of [`aead::Buffer`] for `heapless::Vec`
+//! (re-exported from the [`aead`] crate as [`aead::heapless::Vec`]),
+//! which can then be passed as the `buffer` parameter to the in-place encrypt
+//! and decrypt methods:
+//!
+//! ```
+//! # #[cfg(feature = "heapless")]
+//! # {
+//! use romulus_aead::{RomulusN, Key, Nonce};
+//! use romulus_aead::aead::{AeadInPlace, NewAead};
+//! use romulus_aead::aead::heapless::Vec;
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = RomulusN::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"my unique nonce!"); // 128-bits; unique per message
+//!
+//! let mut buffer: Vec<u8, 128> = Vec::new(); // Buffer needs 16-bytes overhead for the tag
+//! buffer.extend_from_slice(b"plaintext message");
+//!
+//! // Encrypt `buffer` in-place, replacing the plaintext contents with ciphertext
+//! cipher.encrypt_in_place(nonce, b"", &mut buffer).expect("encryption failure!");
+//!
+//! // `buffer` now contains the message ciphertext
+//! assert_ne!(&buffer, b"plaintext message");
+//!
+//! // Decrypt `buffer` in-place, replacing its ciphertext context with the original plaintext
+//! cipher.decrypt_in_place(nonce, b"", &mut buffer).expect("decryption failure!");
+//! assert_eq!(&buffer, b"plaintext message");
+//! # }
+//! ```
+//!
+//! [1]: https://csrc.nist.gov/projects/lightweight-cryptography/finalists
+//! [2]: https://en.wikipedia.org/wiki/Authenticated_encryption
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![deny(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use aead::{self, consts, AeadCore, AeadInPlace, Error, NewAead};
+
+mod skinny;
+
+use aead::{
+    consts::{U0, U16},
+    generic_array::GenericArray,
+};
+use skinny::Skinny128_384Plus;
+use subtle::ConstantTimeEq;
+
+/// Romulus keys (128-bit)
+pub type Key = GenericArray<u8, U16>;
+
+/// Romulus nonces (128-bit)
+pub type Nonce = GenericArray<u8, U16>;
+
+/// Romulus tags (128-bit)
+pub type Tag = GenericArray<u8, U16>;
+
+/// Size of a block in bytes
+const BLOCK_SIZE: usize = 16;
+
+/// Domain separation of the tweakey for the double blocks of associated data
+const DOMAIN_AD: u8 = 8;
+
+/// Domain separation after the associated data, ending with a full block
+const DOMAIN_AD_FULL: u8 = 24;
+
+/// Domain separation after the associated data, ending with a partial block
+const DOMAIN_AD_PARTIAL: u8 = 26;
+
+/// Domain separation of the message blocks except the last one
+const DOMAIN_MESSAGE: u8 = 4;
+
+/// Domain separation of the last message block if it is full
+const DOMAIN_MESSAGE_FULL: u8 = 20;
+
+/// Domain separation of the last message block if it is partial or empty
+const DOMAIN_MESSAGE_PARTIAL: u8 = 21;
+
+/// Romulus-N: 128-bit key, 128-bit nonce, 128-bit tag.
+#[derive(Clone)]
+pub struct RomulusN {
+    cipher: Skinny128_384Plus,
+}
+
+impl NewAead for RomulusN {
+    type KeySize = U16;
+
+    fn new(key: &Key) -> Self {
+        Self {
+            cipher: Skinny128_384Plus::new(&(*key).into()),
+        }
+    }
+}
+
+impl AeadCore for RomulusN {
+    type NonceSize = U16;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl AeadInPlace for RomulusN {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        let nonce = (*nonce).into();
+        let mut state = self.absorb(&nonce, associated_data);
+        let mut counter = Counter::new();
+
+        if buffer.is_empty() {
+            counter.step();
+            self.encrypt_block(&mut state, &counter, DOMAIN_MESSAGE_PARTIAL, nonce);
+        }
+
+        let len = buffer.len();
+        for (i, chunk) in buffer.chunks_mut(BLOCK_SIZE).enumerate() {
+            let m = pad(chunk);
+            xor(chunk, &g(&state));
+            xor(&mut state, &m);
+
+            counter.step();
+            let domain = message_domain(i, chunk.len(), len);
+            self.encrypt_block(&mut state, &counter, domain, nonce);
+        }
+
+        Ok(g(&state).into())
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        let nonce = (*nonce).into();
+        let mut state = self.absorb(&nonce, associated_data);
+        let mut counter = Counter::new();
+
+        if buffer.is_empty() {
+            counter.step();
+            self.encrypt_block(&mut state, &counter, DOMAIN_MESSAGE_PARTIAL, nonce);
+        }
+
+        let len = buffer.len();
+        for (i, chunk) in buffer.chunks_mut(BLOCK_SIZE).enumerate() {
+            xor(chunk, &g(&state));
+            xor(&mut state, &pad(chunk));
+
+            counter.step();
+            let domain = message_domain(i, chunk.len(), len);
+            self.encrypt_block(&mut state, &counter, domain, nonce);
+        }
+
+        let expected_tag = g(&state);
+
+        if expected_tag.ct_eq(tag).unwrap_u8() == 1 {
+            Ok(())
+        } else {
+            // Don't release unauthenticated plaintext
+            buffer.iter_mut().for_each(|b| *b = 0);
+            Err(Error)
+        }
+    }
+}
+
+impl RomulusN {
+    /// Absorb the associated data, the odd blocks into the state and the even
+    /// ones through the tweakey, and then the nonce
+    fn absorb(&self, nonce: &[u8; BLOCK_SIZE], associated_data: &[u8]) -> [u8; BLOCK_SIZE] {
+        let mut state = [0u8; BLOCK_SIZE];
+        let mut counter = Counter::new();
+
+        // Empty associated data is a single empty block
+        if associated_data.is_empty() {
+            counter.step();
+        }
+
+        for pair in associated_data.chunks(2 * BLOCK_SIZE) {
+            let (a, t) = pair.split_at(pair.len().min(BLOCK_SIZE));
+            xor(&mut state, &pad(a));
+            counter.step();
+
+            if !t.is_empty() {
+                self.encrypt_block(&mut state, &counter, DOMAIN_AD, pad(t));
+                counter.step();
+            }
+        }
+
+        let domain = if !associated_data.is_empty() && associated_data.len() % BLOCK_SIZE == 0 {
+            DOMAIN_AD_FULL
+        } else {
+            DOMAIN_AD_PARTIAL
+        };
+        self.encrypt_block(&mut state, &counter, domain, *nonce);
+
+        state
+    }
+
+    /// Encrypt the state with the tweakey `(counter, domain, tweak, key)`
+    #[inline(always)]
+    fn encrypt_block(
+        &self,
+        state: &mut [u8; BLOCK_SIZE],
+        counter: &Counter,
+        domain: u8,
+        tweak: [u8; BLOCK_SIZE],
+    ) {
+        let mut tk1 = [0u8; BLOCK_SIZE];
+        tk1[..7].copy_from_slice(&counter.0.to_le_bytes()[..7]);
+        tk1[7] = domain;
+        self.cipher.encrypt(state, tk1, tweak);
+    }
+}
+
+/// Block counter, a 56-bit LFSR
+struct Counter(u64);
+
+impl Counter {
+    /// Counter with its initial value, one
+    fn new() -> Self {
+        Counter(1)
+    }
+
+    /// Multiply by `x` in GF(2^56) = GF(2)\[x\] / (x^56 + x^7 + x^4 + x^2 + 1)
+    #[inline(always)]
+    fn step(&mut self) {
+        let v = self.0;
+        self.0 = ((v << 1) & 0x00ff_ffff_ffff_ffff) ^ ((v >> 55) * 0x95);
+    }
+}
+
+/// Domain separation of message block `i` of `len` bytes, in a message of
+/// `total` bytes
+#[inline(always)]
+fn message_domain(i: usize, len: usize, total: usize) -> u8 {
+    if (i + 1) * BLOCK_SIZE < total {
+        DOMAIN_MESSAGE
+    } else if len == BLOCK_SIZE {
+        DOMAIN_MESSAGE_FULL
+    } else {
+        DOMAIN_MESSAGE_PARTIAL
+    }
+}
+
+/// The function `G` of the state update applied to every byte:
+/// `(x7, ..., x0) ↦ (x0 ⊕ x7, x7, ..., x1)`
+#[inline(always)]
+fn g(state: &[u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE] {
+    let mut out = [0u8; BLOCK_SIZE];
+    for (o, &x) in out.iter_mut().zip(state.iter()) {
+        *o = (x >> 1) ^ (x & 0x80) ^ (x << 7);
+    }
+    out
+}
+
+/// Pad a partial block with zeroes and its length in the last byte
+#[inline(always)]
+fn pad(bytes: &[u8]) -> [u8; BLOCK_SIZE] {
+    let mut block = [0u8; BLOCK_SIZE];
+    block[..bytes.len()].copy_from_slice(bytes);
+
+    if bytes.len() < BLOCK_SIZE {
+        block[BLOCK_SIZE - 1] = bytes.len() as u8;
+    }
+
+    block
+}
+
+/// XOR `src` into `dst`, up to the shorter of the two lengths
+#[inline(always)]
+fn xor(dst: &mut [u8], src: &[u8]) {
+    for (a, b) in dst.iter_mut().zip(src) {
+        *a ^= b;
+    }
+}
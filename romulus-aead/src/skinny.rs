@@ -0,0 +1,151 @@
+//! SKINNY-128-384+ tweakable block cipher
+//!
+//! The state is kept as four 32-bit words, one per row, with cell `j` of a
+//! row in its `j`-th byte. The S-box is computed with bitwise operations on
+//! all four cells of a row at once, so there are no secret-dependent table
+//! lookups.
+
+use core::convert::TryInto;
+use zeroize::Zeroize;
+
+/// Number of rounds of SKINNY-128-384+
+const ROUNDS: usize = 40;
+
+/// Permutation of the tweakey cells applied between rounds
+const PT: [usize; 16] = [9, 15, 8, 13, 10, 14, 12, 11, 0, 1, 2, 3, 4, 5, 6, 7];
+
+/// SKINNY-128-384+ with the round keys of the third tweakey word, which holds
+/// the key, precomputed
+#[derive(Clone)]
+pub(crate) struct Skinny128_384Plus {
+    /// Contributions of `TK3` to the two first rows in each round
+    round_keys: [[u32; 2]; ROUNDS],
+}
+
+impl Skinny128_384Plus {
+    /// Expand the schedule of the key `TK3`
+    pub(crate) fn new(key: &[u8; 16]) -> Self {
+        let mut tk3 = *key;
+        let mut round_keys = [[0u32; 2]; ROUNDS];
+
+        for round_key in round_keys.iter_mut() {
+            *round_key = half_rows(&tk3);
+            permute_tweakey(&mut tk3);
+
+            let [r0, r1] = half_rows(&tk3);
+            tk3[..4].copy_from_slice(&lfsr3(r0).to_le_bytes());
+            tk3[4..8].copy_from_slice(&lfsr3(r1).to_le_bytes());
+        }
+
+        tk3.zeroize();
+        Self { round_keys }
+    }
+
+    /// Encrypt `block` with the tweakey words `TK1` and `TK2`
+    pub(crate) fn encrypt(&self, block: &mut [u8; 16], mut tk1: [u8; 16], mut tk2: [u8; 16]) {
+        let mut rows = [0u32; 4];
+        for (row, bytes) in rows.iter_mut().zip(block.chunks_exact(4)) {
+            *row = u32::from_le_bytes(bytes.try_into().unwrap());
+        }
+
+        let mut rc = 0u32;
+        for &[k0, k1] in self.round_keys.iter() {
+            for row in rows.iter_mut() {
+                *row = sbox(*row);
+            }
+
+            // AddConstants
+            rc = (rc << 1 & 0x3f) | ((rc >> 5 ^ rc >> 4 ^ 1) & 1);
+            rows[0] ^= rc & 0xf;
+            rows[1] ^= rc >> 4;
+            rows[2] ^= 0x2;
+
+            // AddRoundTweakey
+            let [t0, t1] = half_rows(&tk1);
+            let [u0, u1] = half_rows(&tk2);
+            rows[0] ^= t0 ^ u0 ^ k0;
+            rows[1] ^= t1 ^ u1 ^ k1;
+
+            permute_tweakey(&mut tk1);
+            permute_tweakey(&mut tk2);
+            let [u0, u1] = half_rows(&tk2);
+            tk2[..4].copy_from_slice(&lfsr2(u0).to_le_bytes());
+            tk2[4..8].copy_from_slice(&lfsr2(u1).to_le_bytes());
+
+            // ShiftRows
+            rows[1] = rows[1].rotate_left(8);
+            rows[2] = rows[2].rotate_left(16);
+            rows[3] = rows[3].rotate_left(24);
+
+            // MixColumns
+            rows[1] ^= rows[2];
+            rows[2] ^= rows[0];
+            rows[3] ^= rows[2];
+            rows.rotate_right(1);
+        }
+
+        for (bytes, row) in block.chunks_exact_mut(4).zip(rows.iter()) {
+            bytes.copy_from_slice(&row.to_le_bytes());
+        }
+    }
+}
+
+impl Drop for Skinny128_384Plus {
+    fn drop(&mut self) {
+        for round_key in self.round_keys.iter_mut() {
+            round_key.zeroize();
+        }
+    }
+}
+
+/// The two first rows of a tweakey word, which are added to the state
+#[inline(always)]
+fn half_rows(tk: &[u8; 16]) -> [u32; 2] {
+    [
+        u32::from_le_bytes(tk[..4].try_into().unwrap()),
+        u32::from_le_bytes(tk[4..8].try_into().unwrap()),
+    ]
+}
+
+/// Apply the cell permutation `PT` to a tweakey word
+#[inline(always)]
+fn permute_tweakey(tk: &mut [u8; 16]) {
+    let old = *tk;
+    for (cell, &i) in tk.iter_mut().zip(PT.iter()) {
+        *cell = old[i];
+    }
+}
+
+/// The LFSR of `TK2` on each byte: `(x7, ..., x0) ↦ (x6, ..., x0, x7 ⊕ x5)`
+#[inline(always)]
+fn lfsr2(x: u32) -> u32 {
+    (x << 1 & 0xfefe_fefe) | ((x >> 7 ^ x >> 5) & 0x0101_0101)
+}
+
+/// The LFSR of `TK3` on each byte: `(x7, ..., x0) ↦ (x0 ⊕ x6, x7, ..., x1)`
+#[inline(always)]
+fn lfsr3(x: u32) -> u32 {
+    (x >> 1 & 0x7f7f_7f7f) | ((x << 7 ^ x << 1) & 0x8080_8080)
+}
+
+/// The 8-bit S-box applied to the four bytes of `x`
+#[inline(always)]
+fn sbox(mut x: u32) -> u32 {
+    x = !x;
+    x ^= (x >> 2) & (x >> 3) & 0x1111_1111;
+    let y = (x << 5) & (x << 1) & 0x2020_2020;
+    x ^= ((x << 5) & (x << 4) & 0x4040_4040) ^ y;
+    let y = (x << 2) & (x << 1) & 0x8080_8080;
+    x ^= ((x >> 2) & (x << 1) & 0x0202_0202) ^ y;
+    let y = (x >> 5) & (x << 1) & 0x0404_0404;
+    x ^= ((x >> 1) & (x >> 2) & 0x0808_0808) ^ y;
+    x = !x;
+
+    // Final bit permutation of each byte
+    ((x & 0x0808_0808) << 1)
+        | ((x & 0x3232_3232) << 2)
+        | ((x & 0x0101_0101) << 5)
+        | ((x & 0x8080_8080) >> 6)
+        | ((x & 0x4040_4040) >> 4)
+        | ((x & 0x0404_0404) >> 2)
+}
@@ -0,0 +1,148 @@
+//! Romulus tests
+
+#[macro_use]
+extern crate hex_literal;
+
+use romulus_aead::aead::{generic_array::GenericArray, Aead, NewAead, Payload};
+use romulus_aead::RomulusN;
+
+// Known-answer tests in the format of the NIST LWC submissions
+aead::new_test!(romulus_n, "romulus_n", RomulusN);
+
+/// Test vectors
+#[derive(Debug)]
+pub struct TestVector {
+    pub key: &'static [u8; 16],
+    pub nonce: &'static [u8; 16],
+    pub aad: &'static [u8],
+    pub plaintext: &'static [u8],
+    pub ciphertext: &'static [u8],
+    pub tag: &'static [u8; 16],
+}
+
+/// Romulus-N test vectors, selected from the known-answer tests to cover empty,
+/// partial and multi-block inputs
+const TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("4f42aed219ecc79f4daf3e3bad52aee7"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!("00"),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("ab8fe298cf6a3261f1f6c89b2b5e3367"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!("00"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f"),
+        ciphertext: &hex!("998d7701c14bb41dba91a240da82606b"),
+        tag: &hex!("c97ba43a842706ea35ed04ee12bd6038"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!("000102030405060708090a0b0c0d0e"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f10111213141516"),
+        ciphertext: &hex!("39d9906849736afee9b9ec5851cbbfbf1305686b8bfed9"),
+        tag: &hex!("95f72d69f3cf9b8f0c236b4e0cd91317"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        ciphertext: &hex!("1a9b58442bbd18f7f5ea1b1d243be2277d08abab0a47ac4ab11386bbdcada04a"),
+        tag: &hex!("47b9e1731e9679190165412401bd62ab"),
+    },
+];
+
+#[test]
+fn encrypt() {
+    for vector in TEST_VECTORS {
+        let key = GenericArray::from_slice(vector.key);
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let payload = Payload {
+            msg: vector.plaintext,
+            aad: vector.aad,
+        };
+
+        let cipher = RomulusN::new(key);
+        let ciphertext = cipher.encrypt(nonce, payload).unwrap();
+        let (ct, tag) = ciphertext.split_at(vector.plaintext.len());
+        assert_eq!(vector.ciphertext, ct);
+        assert_eq!(vector.tag, tag);
+    }
+}
+
+#[test]
+fn decrypt() {
+    for vector in TEST_VECTORS {
+        let key = GenericArray::from_slice(vector.key);
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let mut ciphertext = Vec::from(vector.ciphertext);
+        ciphertext.extend_from_slice(vector.tag);
+
+        let payload = Payload {
+            msg: &ciphertext,
+            aad: vector.aad,
+        };
+
+        let cipher = RomulusN::new(key);
+        let plaintext = cipher.decrypt(nonce, payload).unwrap();
+
+        assert_eq!(vector.plaintext, plaintext.as_slice());
+    }
+}
+
+#[test]
+fn decrypt_modified() {
+    let vector = &TEST_VECTORS[4];
+    let key = GenericArray::from_slice(vector.key);
+    let nonce = GenericArray::from_slice(vector.nonce);
+
+    let mut ciphertext = Vec::from(vector.ciphertext);
+    ciphertext.extend_from_slice(vector.tag);
+
+    // Tweak the first byte
+    ciphertext[0] ^= 0xaa;
+
+    let payload = Payload {
+        msg: &ciphertext,
+        aad: vector.aad,
+    };
+
+    let cipher = RomulusN::new(key);
+    assert!(cipher.decrypt(nonce, payload).is_err());
+}
+
+#[test]
+fn multi_block() {
+    let key = GenericArray::from_slice(TEST_VECTORS[0].key);
+    let nonce = GenericArray::from_slice(TEST_VECTORS[0].nonce);
+    let data = (0..100).map(|i| i as u8).collect::<Vec<_>>();
+    let payload = Payload {
+        msg: &data,
+        aad: &data,
+    };
+
+    let cipher = RomulusN::new(key);
+    let ciphertext = cipher.encrypt(nonce, payload).unwrap();
+    let (ct, tag) = ciphertext.split_at(data.len());
+    assert_eq!(ct, &hex!("6e0479eaad0bdacf80512bdf08a86e24f87a6f8c5768d2b89ccb463da56c1ebf078bb27c33a3562958408795b27e165fdc3cc67d96002ce1cc9cf25430752c6248750a33e642a18a950d2e8aa78604213e0bdb670e50c3848daf45996b0ef99285120247")[..]);
+    assert_eq!(tag, hex!("05a818c1cc0fe5a65b06297e90efe030"));
+
+    let payload = Payload {
+        msg: &ciphertext,
+        aad: &data,
+    };
+    assert_eq!(cipher.decrypt(nonce, payload).unwrap(), data);
+}
@@ -0,0 +1,358 @@
+//! [Schwaemm256-128][1]: a lightweight [Authenticated Encryption with
+//! Associated Data (AEAD)][2] cipher from the SPARKLE family.
+//!
+//! SPARKLE was a finalist of the NIST lightweight cryptography process.
+//! Schwaemm256-128 is its primary AEAD member: a duplex sponge with a 256-bit
+//! rate on the 384-bit SPARKLE384 permutation, which is built from the Alzette
+//! ARX-box and only needs 32-bit additions, XORs and rotations.
+//!
+//! On 32-bit ARM targets a variant of the permutation tuned for ARMv7-M is
+//! used, while other targets use a portable implementation.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! ```
+//! use schwaemm::{Schwaemm256_128, Key, Nonce};
+//! use schwaemm::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = Schwaemm256_128::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce, thirty-two bytes!!"); // 256-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! Note that if you enable the `heapless` feature of this crate,
+//! you will receive an impl of [`aead::Buffer`] for `heapless::Vec`
+//! (re-exported from the [`aead`] crate as [`aead::heapless::Vec`]),
+//! which can then be passed as the `buffer` parameter to the in-place encrypt
+//! and decrypt methods:
+//!
+//! ```
+//! # #[cfg(feature = "heapless")]
+//! # {
+//! use schwaemm::{Schwaemm256_128, Key, Nonce};
+//! use schwaemm::aead::{AeadInPlace, NewAead};
+//! use schwaemm::aead::heapless::Vec;
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = Schwaemm256_128::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce, thirty-two bytes!!"); // 256-bits; unique per message
+//!
+//! let mut buffer: Vec<u8, 128> = Vec::new(); // Buffer needs 16-bytes overhead for the tag
+//! buffer.extend_from_slice(b"plaintext message");
+//!
+//! // Encrypt `buffer` in-place, replacing the plaintext contents with ciphertext
+//! cipher.encrypt_in_place(nonce, b"", &mut buffer).expect("encryption failure!");
+//!
+//! // `buffer` now contains the message ciphertext
+//! assert_ne!(&buffer, b"plaintext message");
+//!
+//! // Decrypt `buffer` in-place, replacing its ciphertext context with the original plaintext
+//! cipher.decrypt_in_place(nonce, b"", &mut buffer).expect("decryption failure!");
+//! assert_eq!(&buffer, b"plaintext message");
+//! # }
+//! ```
+//!
+//! [1]: https://sparkle-lwc.github.io/
+//! [2]: https://en.wikipedia.org/wiki/Authenticated_encryption
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![deny(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use aead::{self, consts, AeadCore, AeadInPlace, Error, NewAead};
+
+mod sparkle;
+
+use aead::{
+    consts::{U0, U16, U32},
+    generic_array::GenericArray,
+};
+use core::convert::TryInto;
+use sparkle::sparkle384;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// Schwaemm256-128 keys (128-bit)
+pub type Key = GenericArray<u8, U16>;
+
+/// Schwaemm256-128 nonces (256-bit)
+pub type Nonce = GenericArray<u8, U32>;
+
+/// Schwaemm256-128 tags (128-bit)
+pub type Tag = GenericArray<u8, U16>;
+
+/// Size of the rate in bytes
+const RATE: usize = 32;
+
+/// Size of the rate in words
+const RATE_WORDS: usize = RATE / 4;
+
+/// Number of steps between blocks
+const STEPS_SLIM: usize = 7;
+
+/// Number of steps after initialization and the last block
+const STEPS_BIG: usize = 11;
+
+/// Domain separation constant of a padded last block of associated data
+const CONST_A0: u32 = 0x0400_0000;
+
+/// Domain separation constant of a full last block of associated data
+const CONST_A1: u32 = 0x0500_0000;
+
+/// Domain separation constant of a padded last block of the message
+const CONST_M2: u32 = 0x0600_0000;
+
+/// Domain separation constant of a full last block of the message
+const CONST_M3: u32 = 0x0700_0000;
+
+/// Schwaemm256-128: 128-bit key, 256-bit nonce, 128-bit tag.
+#[derive(Clone)]
+pub struct Schwaemm256_128 {
+    /// Secret key
+    key: Key,
+}
+
+impl NewAead for Schwaemm256_128 {
+    type KeySize = U16;
+
+    fn new(key: &Key) -> Self {
+        Self { key: *key }
+    }
+}
+
+impl AeadCore for Schwaemm256_128 {
+    type NonceSize = U32;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl AeadInPlace for Schwaemm256_128 {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        let mut state = State::new(&self.key, nonce);
+        state.absorb(associated_data);
+        state.encrypt(buffer);
+        Ok(state.finalize(&self.key))
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        let mut state = State::new(&self.key, nonce);
+        state.absorb(associated_data);
+        state.decrypt(buffer);
+        let expected_tag = state.finalize(&self.key);
+
+        if expected_tag.ct_eq(tag).unwrap_u8() == 1 {
+            Ok(())
+        } else {
+            // Don't release unauthenticated plaintext
+            buffer.iter_mut().for_each(|b| *b = 0);
+            Err(Error)
+        }
+    }
+}
+
+impl Drop for Schwaemm256_128 {
+    fn drop(&mut self) {
+        self.key.as_mut_slice().zeroize();
+    }
+}
+
+/// SPARKLE384 state as the words `x0, y0, x1, y1, ...`, the first
+/// `RATE_WORDS` of which are the rate
+struct State([u32; 12]);
+
+impl State {
+    /// Load the nonce into the rate and the key into the capacity
+    fn new(key: &Key, nonce: &Nonce) -> Self {
+        let mut state = Self([0; 12]);
+        for (word, bytes) in state
+            .0
+            .iter_mut()
+            .zip(nonce.chunks_exact(4).chain(key.chunks_exact(4)))
+        {
+            *word = load(bytes);
+        }
+
+        sparkle384(&mut state.0, STEPS_BIG);
+        state
+    }
+
+    /// Absorb the associated data, if any
+    fn absorb(&mut self, associated_data: &[u8]) {
+        let blocks = block_count(associated_data.len());
+
+        for (i, chunk) in associated_data.chunks(RATE).enumerate() {
+            let last = i + 1 == blocks;
+            if last {
+                self.add_constant(chunk.len(), CONST_A0, CONST_A1);
+            }
+
+            self.rho_whitening(&pad(chunk));
+            self.permute(last);
+        }
+    }
+
+    /// Encrypt the message, if any, in-place
+    fn encrypt(&mut self, buffer: &mut [u8]) {
+        let blocks = block_count(buffer.len());
+
+        for (i, chunk) in buffer.chunks_mut(RATE).enumerate() {
+            let last = i + 1 == blocks;
+            if last {
+                self.add_constant(chunk.len(), CONST_M2, CONST_M3);
+            }
+
+            let block = pad(chunk);
+            self.apply_rate(chunk);
+            self.rho_whitening(&block);
+            self.permute(last);
+        }
+    }
+
+    /// Decrypt the message, if any, in-place
+    fn decrypt(&mut self, buffer: &mut [u8]) {
+        let blocks = block_count(buffer.len());
+
+        for (i, chunk) in buffer.chunks_mut(RATE).enumerate() {
+            let last = i + 1 == blocks;
+            if last {
+                self.add_constant(chunk.len(), CONST_M2, CONST_M3);
+            }
+
+            // Absorbing the plaintext leaves the same state as encryption
+            self.apply_rate(chunk);
+            self.rho_whitening(&pad(chunk));
+            self.permute(last);
+        }
+    }
+
+    /// Add the key to the capacity, which then is the tag
+    fn finalize(mut self, key: &Key) -> Tag {
+        let mut tag = Tag::default();
+
+        for ((word, k), t) in self.0[RATE_WORDS..]
+            .iter_mut()
+            .zip(key.chunks_exact(4))
+            .zip(tag.chunks_exact_mut(4))
+        {
+            *word ^= load(k);
+            t.copy_from_slice(&word.to_le_bytes());
+        }
+
+        tag
+    }
+
+    /// Add the domain separation constant of a last block of `len` bytes
+    #[inline(always)]
+    fn add_constant(&mut self, len: usize, padded: u32, full: u32) {
+        self.0[11] ^= if len < RATE { padded } else { full };
+    }
+
+    /// XOR the rate onto `data`
+    #[inline(always)]
+    fn apply_rate(&self, data: &mut [u8]) {
+        for (i, b) in data.iter_mut().enumerate() {
+            *b ^= (self.0[i / 4] >> (8 * (i % 4))) as u8;
+        }
+    }
+
+    /// The Feistel swap `ρ1` of the rate absorbing `block`, followed by the
+    /// rate whitening with the capacity
+    #[inline(always)]
+    fn rho_whitening(&mut self, block: &[u32; RATE_WORDS]) {
+        let s = &mut self.0;
+        let half = RATE_WORDS / 2;
+
+        for i in 0..half {
+            let j = i + half;
+            let t = s[i];
+            s[i] = s[j] ^ block[i];
+            s[j] ^= t ^ block[j];
+        }
+
+        for i in 0..RATE_WORDS {
+            s[i] ^= s[RATE_WORDS + i % 4];
+        }
+    }
+
+    #[inline(always)]
+    fn permute(&mut self, last: bool) {
+        sparkle384(&mut self.0, if last { STEPS_BIG } else { STEPS_SLIM });
+    }
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// Number of blocks of `len` bytes of input
+#[inline(always)]
+fn block_count(len: usize) -> usize {
+    (len + RATE - 1) / RATE
+}
+
+/// Load a block of up to `RATE` bytes, padded with `0x80` if shorter
+#[inline(always)]
+fn pad(bytes: &[u8]) -> [u32; RATE_WORDS] {
+    let mut buf = [0u8; RATE];
+    buf[..bytes.len()].copy_from_slice(bytes);
+    if bytes.len() < RATE {
+        buf[bytes.len()] = 0x80;
+    }
+
+    let mut block = [0u32; RATE_WORDS];
+    for (word, bytes) in block.iter_mut().zip(buf.chunks_exact(4)) {
+        *word = load(bytes);
+    }
+    block
+}
+
+/// Load a little-endian 32-bit word
+#[inline(always)]
+fn load(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes(bytes.try_into().unwrap())
+}
@@ -0,0 +1,77 @@
+//! SPARKLE384 permutation
+
+// Both implementations are built for tests so that the one for ARM is also
+// checked on the host
+#[cfg(any(target_arch = "arm", test))]
+mod armv7m;
+
+#[cfg(any(not(target_arch = "arm"), test))]
+mod soft;
+
+#[cfg(target_arch = "arm")]
+pub(crate) use armv7m::sparkle384;
+
+#[cfg(not(target_arch = "arm"))]
+pub(crate) use soft::sparkle384;
+
+/// Round constants, also used as the step constants
+const RCON: [u32; 8] = [
+    0xb7e1_5162,
+    0xbf71_5880,
+    0x38b4_da56,
+    0x324e_7738,
+    0xbb11_85eb,
+    0x4f7c_7b57,
+    0xcfbf_a1c8,
+    0xc2b3_293d,
+];
+
+/// The Alzette ARX-box on the branch `(x, y)` with round constant `c`
+#[inline(always)]
+fn alzette(mut x: u32, mut y: u32, c: u32) -> (u32, u32) {
+    x = x.wrapping_add(y.rotate_right(31));
+    y ^= x.rotate_right(24);
+    x ^= c;
+
+    x = x.wrapping_add(y.rotate_right(17));
+    y ^= x.rotate_right(17);
+    x ^= c;
+
+    x = x.wrapping_add(y);
+    y ^= x.rotate_right(31);
+    x ^= c;
+
+    x = x.wrapping_add(y.rotate_right(24));
+    y ^= x.rotate_right(16);
+    x ^= c;
+
+    (x, y)
+}
+
+/// The function `ℓ` of the linear layer
+#[inline(always)]
+fn ell(x: u32) -> u32 {
+    (x ^ (x << 16)).rotate_right(16)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn armv7m_matches_soft() {
+        let mut state = [0u32; 12];
+        for (i, word) in state.iter_mut().enumerate() {
+            *word = (i as u32).wrapping_mul(0x9e37_79b9);
+        }
+
+        for &steps in &[7, 11] {
+            let mut expected = state;
+            super::soft::sparkle384(&mut expected, steps);
+
+            let mut actual = state;
+            super::armv7m::sparkle384(&mut actual, steps);
+
+            assert_eq!(expected, actual);
+            state = expected;
+        }
+    }
+}
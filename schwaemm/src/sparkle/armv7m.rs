@@ -0,0 +1,45 @@
+//! SPARKLE384 for 32-bit ARM, tuned for ARMv7-M
+//!
+//! The twelve state words are kept in locals rather than indexed from memory,
+//! which lets the compiler keep most of them in registers, and the branch
+//! rotation of the linear layer is a renaming instead of a copy. On ARMv7-M
+//! the rotations inside Alzette fold into the shifted second operand of `add`
+//! and `eor`, so each of its operations is a single instruction.
+
+use super::{alzette, ell, RCON};
+
+/// Apply `steps` steps of SPARKLE384 to the state `x0, y0, x1, y1, ...`
+pub(crate) fn sparkle384(state: &mut [u32; 12], steps: usize) {
+    let [mut x0, mut y0, mut x1, mut y1, mut x2, mut y2, mut x3, mut y3, mut x4, mut y4, mut x5, mut y5] =
+        *state;
+
+    for step in 0..steps {
+        y0 ^= RCON[step % RCON.len()];
+        y1 ^= step as u32;
+
+        let (a0, b0) = alzette(x0, y0, RCON[0]);
+        let (a1, b1) = alzette(x1, y1, RCON[1]);
+        let (a2, b2) = alzette(x2, y2, RCON[2]);
+        let (a3, b3) = alzette(x3, y3, RCON[3]);
+        let (a4, b4) = alzette(x4, y4, RCON[4]);
+        let (a5, b5) = alzette(x5, y5, RCON[5]);
+
+        let tx = ell(a0 ^ a1 ^ a2);
+        let ty = ell(b0 ^ b1 ^ b2);
+
+        x0 = a4 ^ a1 ^ ty;
+        y0 = b4 ^ b1 ^ tx;
+        x1 = a5 ^ a2 ^ ty;
+        y1 = b5 ^ b2 ^ tx;
+        x2 = a3 ^ a0 ^ ty;
+        y2 = b3 ^ b0 ^ tx;
+        x3 = a0;
+        y3 = b0;
+        x4 = a1;
+        y4 = b1;
+        x5 = a2;
+        y5 = b2;
+    }
+
+    *state = [x0, y0, x1, y1, x2, y2, x3, y3, x4, y4, x5, y5];
+}
@@ -0,0 +1,51 @@
+//! Portable implementation of the SPARKLE384 permutation
+
+use super::{alzette, ell, RCON};
+
+/// Number of 64-bit branches of SPARKLE384
+const BRANCHES: usize = 6;
+
+/// Apply `steps` steps of SPARKLE384 to the state `x0, y0, x1, y1, ...`
+pub(crate) fn sparkle384(state: &mut [u32; 12], steps: usize) {
+    for step in 0..steps {
+        state[1] ^= RCON[step % RCON.len()];
+        state[3] ^= step as u32;
+
+        for (branch, &c) in state.chunks_exact_mut(2).zip(RCON.iter()) {
+            let (x, y) = alzette(branch[0], branch[1], c);
+            branch[0] = x;
+            branch[1] = y;
+        }
+
+        linear_layer(state);
+    }
+}
+
+/// Feistel-like linear layer: the left branches are mixed into the right
+/// ones, then the branches are rotated so the mixed ones come first
+#[inline(always)]
+fn linear_layer(state: &mut [u32; 12]) {
+    // Number of branches and words in each half of the state
+    const HALF: usize = BRANCHES / 2;
+    const WORDS: usize = BRANCHES;
+
+    let mut left = [0u32; WORDS];
+    left.copy_from_slice(&state[..WORDS]);
+
+    let mut tx = 0;
+    let mut ty = 0;
+    for branch in left.chunks_exact(2) {
+        tx ^= branch[0];
+        ty ^= branch[1];
+    }
+    let tx = ell(tx);
+    let ty = ell(ty);
+
+    for i in 0..HALF {
+        let j = (i + HALF - 1) % HALF;
+        state[2 * j] = state[WORDS + 2 * i] ^ left[2 * i] ^ ty;
+        state[2 * j + 1] = state[WORDS + 2 * i + 1] ^ left[2 * i + 1] ^ tx;
+    }
+
+    state[WORDS..].copy_from_slice(&left);
+}
@@ -0,0 +1,157 @@
+//! Schwaemm tests
+
+#[macro_use]
+extern crate hex_literal;
+
+use schwaemm::aead::{generic_array::GenericArray, Aead, NewAead, Payload};
+use schwaemm::Schwaemm256_128;
+
+// Known-answer tests in the format of the NIST LWC submissions
+aead::new_test!(schwaemm256_128, "schwaemm256_128", Schwaemm256_128);
+
+/// Test vectors
+#[derive(Debug)]
+pub struct TestVector {
+    pub key: &'static [u8; 16],
+    pub nonce: &'static [u8; 32],
+    pub aad: &'static [u8],
+    pub plaintext: &'static [u8],
+    pub ciphertext: &'static [u8],
+    pub tag: &'static [u8; 16],
+}
+
+/// Schwaemm256-128 test vectors, selected from the known-answer tests to cover
+/// empty, partial, full and multi-block inputs
+const TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("9e3f9f2e8e26e7d00a9eb92730717a51"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("23d8a933c4955c665f6143267be8e714"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        aad: &hex!(""),
+        plaintext: &hex!("00"),
+        ciphertext: &hex!("9b"),
+        tag: &hex!("6f7db3323c0b372a4584082e5ab4265c"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        aad: &hex!("00"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f"),
+        ciphertext: &hex!("2eec3bf301238949cc25dd77cd3f6feb"),
+        tag: &hex!("a771fabfbe84ac1cb5638338de268e9e"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        aad: &hex!("000102030405060708090a0b0c0d0e"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e"),
+        ciphertext: &hex!("4c86a27b1be261ef6a715086c547f4289d0abd930d10ca0132336147dd159a"),
+        tag: &hex!("04df6e5750db14d27fed44d12600d2f2"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        ciphertext: &hex!("8494eb28d98e391b6914564625b243f63da336497427884d4275a6aa088b8bee"),
+        tag: &hex!("f1cfb0892801fdd208a134182e5d50ce"),
+    },
+];
+
+#[test]
+fn encrypt() {
+    for vector in TEST_VECTORS {
+        let key = GenericArray::from_slice(vector.key);
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let payload = Payload {
+            msg: vector.plaintext,
+            aad: vector.aad,
+        };
+
+        let cipher = Schwaemm256_128::new(key);
+        let ciphertext = cipher.encrypt(nonce, payload).unwrap();
+        let (ct, tag) = ciphertext.split_at(vector.plaintext.len());
+        assert_eq!(vector.ciphertext, ct);
+        assert_eq!(vector.tag, tag);
+    }
+}
+
+#[test]
+fn decrypt() {
+    for vector in TEST_VECTORS {
+        let key = GenericArray::from_slice(vector.key);
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let mut ciphertext = Vec::from(vector.ciphertext);
+        ciphertext.extend_from_slice(vector.tag);
+
+        let payload = Payload {
+            msg: &ciphertext,
+            aad: vector.aad,
+        };
+
+        let cipher = Schwaemm256_128::new(key);
+        let plaintext = cipher.decrypt(nonce, payload).unwrap();
+
+        assert_eq!(vector.plaintext, plaintext.as_slice());
+    }
+}
+
+#[test]
+fn decrypt_modified() {
+    let vector = &TEST_VECTORS[5];
+    let key = GenericArray::from_slice(vector.key);
+    let nonce = GenericArray::from_slice(vector.nonce);
+
+    let mut ciphertext = Vec::from(vector.ciphertext);
+    ciphertext.extend_from_slice(vector.tag);
+
+    // Tweak the first byte
+    ciphertext[0] ^= 0xaa;
+
+    let payload = Payload {
+        msg: &ciphertext,
+        aad: vector.aad,
+    };
+
+    let cipher = Schwaemm256_128::new(key);
+    assert!(cipher.decrypt(nonce, payload).is_err());
+}
+
+/// The known-answer tests never have more than one block of input
+#[test]
+fn multi_block() {
+    let key = GenericArray::from_slice(TEST_VECTORS[0].key);
+    let nonce = GenericArray::from_slice(TEST_VECTORS[0].nonce);
+    let data = (0..100).map(|i| i as u8).collect::<Vec<_>>();
+    let payload = Payload {
+        msg: &data,
+        aad: &data,
+    };
+
+    let cipher = Schwaemm256_128::new(key);
+    let ciphertext = cipher.encrypt(nonce, payload).unwrap();
+    let (ct, tag) = ciphertext.split_at(data.len());
+    assert_eq!(ct, &hex!("1dae87fd52ab4e746ddabcb8b521517287ad7f74cf04892c11da2719a49061a5b7c82e68296d85e891a589832de13c768629569f1d1347c278fe82b29b560723ae41a53f5c978734b6452f169044dd9b3267ab6c9399d2bd19b874764052e048b910dc55")[..]);
+    assert_eq!(tag, hex!("7f3126b8e2b1742255f2839c00019ac7"));
+
+    let payload = Payload {
+        msg: &ciphertext,
+        aad: &data,
+    };
+    assert_eq!(cipher.decrypt(nonce, payload).unwrap(), data);
+}
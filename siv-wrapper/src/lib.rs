@@ -0,0 +1,263 @@
+//! Generic synthetic IV (SIV) wrapper for [Authenticated Encryption with
+//! Associated Data (AEAD)][1] ciphers, which makes any AEAD resistant to
+//! nonce misuse.
+//!
+//! AES-GCM, ChaCha20Poly1305 and most other AEADs lose their confidentiality
+//! and authenticity when a nonce is repeated under a key, which is hard to
+//! rule out across replicated services or restored snapshots.
+//!
+//! [`SivWrapper`] applies the SIV construction of Rogaway and Shrimpton,
+//! [*Deterministic Authenticated-Encryption*][2], with HMAC-SHA-256 keyed with
+//! the key `K` as the PRF. It derives the key `L` of the wrapped AEAD once,
+//! and the nonce of each message from the nonce `N`, associated data `A` and
+//! message `M`:
+//!
+//! ```text
+//! L = HMAC-SHA-256(K, 0x01) || HMAC-SHA-256(K, 0x02) || ...
+//! V = HMAC-SHA-256(K, 0x00 || N || A || M || len64(A) || len64(M))
+//! C || T = AEAD(L, V, A, M)
+//! ```
+//!
+//! where `L` is truncated to the key size of the wrapped AEAD, `V` to its
+//! nonce size, and `len64` is a 64-bit big endian byte count. The ciphertext
+//! is `C || T || V`: the tag of [`SivWrapper`] is the tag of the wrapped AEAD
+//! followed by the synthetic IV. On decryption, `V` is recomputed from the
+//! decrypted message and compared in constant time.
+//!
+//! Repeating a nonce only reveals whether the same message was encrypted
+//! with the same associated data. Messages are still authenticated by the
+//! wrapped AEAD, with `V` as its nonce.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! The synthetic IV is the size of the nonce of the wrapped AEAD. With a
+//! 96-bit nonce, e.g. AES-GCM or ChaCha20Poly1305, two different messages get
+//! the same synthetic IV with probability 2<sup>-96</sup>, and encrypting
+//! about 2<sup>48</sup> messages under a key makes a collision likely. Wrap
+//! XChaCha20Poly1305 for a 192-bit synthetic IV instead.
+//!
+//! Encryption reads the message twice, once to compute `V` and once to
+//! encrypt it, and the construction is specific to this crate: its
+//! ciphertexts can only be decrypted by implementations of it.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! Simple usage (allocating, no associated data):
+//!
+//! ```
+//! use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+//! use siv_wrapper::SivWrapper;
+//! use siv_wrapper::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = SivWrapper::<ChaCha20Poly1305>::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce"); // 96-bits; may be repeated
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! // The message, then the 16-byte Poly1305 tag and the 12-byte synthetic IV
+//! assert_eq!(ciphertext.len(), 17 + 16 + 12);
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! [1]: https://en.wikipedia.org/wiki/Authenticated_encryption
+//! [2]: https://web.cs.ucdavis.edu/~rogaway/papers/siv.pdf
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+pub use aead::{self, AeadCore, AeadInPlace, Error, NewAead};
+
+use aead::{
+    consts::{True, U32},
+    generic_array::{
+        typenum::{IsLessOrEqual, Sum, Unsigned},
+        ArrayLength, GenericArray,
+    },
+};
+use core::ops::Add;
+use hmac::{Hmac, Mac, NewMac};
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// [`SivWrapper`] tags: the tag of the wrapped AEAD followed by the
+/// synthetic IV
+pub type Tag<TagSize, NonceSize> = GenericArray<u8, Sum<TagSize, NonceSize>>;
+
+/// Nonce-misuse resistant wrapper around an AEAD.
+///
+/// See the [toplevel documentation](index.html) for details of the
+/// construction and a usage example.
+///
+/// The key and nonce sizes are those of the wrapped AEAD `A`, and its tag is
+/// longer by the nonce size of `A`, which must be at most 256 bits.
+pub struct SivWrapper<A>
+where
+    A: NewAead + AeadInPlace,
+{
+    /// HMAC-SHA-256, keyed with the key
+    mac: Hmac<Sha256>,
+
+    /// Wrapped AEAD, keyed with the derived key
+    aead: A,
+}
+
+impl<A> NewAead for SivWrapper<A>
+where
+    A: NewAead + AeadInPlace,
+{
+    type KeySize = A::KeySize;
+
+    fn new(key: &GenericArray<u8, A::KeySize>) -> Self {
+        let mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+
+        // The counter can't wrap around: AEAD keys are far shorter than 255
+        // HMAC-SHA-256 outputs
+        let mut aead_key = GenericArray::<u8, A::KeySize>::default();
+        for (i, chunk) in aead_key.chunks_mut(32).enumerate() {
+            let mut block = mac.clone();
+            block.update(&[i as u8 + 1]);
+            let mut block = block.finalize().into_bytes();
+            chunk.copy_from_slice(&block[..chunk.len()]);
+            block.as_mut_slice().zeroize();
+        }
+
+        let aead = A::new(&aead_key);
+        aead_key.as_mut_slice().zeroize();
+        Self { mac, aead }
+    }
+}
+
+impl<A> AeadCore for SivWrapper<A>
+where
+    A: NewAead + AeadInPlace,
+    A::NonceSize: IsLessOrEqual<U32, Output = True>,
+    A::TagSize: Add<A::NonceSize>,
+    Sum<A::TagSize, A::NonceSize>: ArrayLength<u8>,
+{
+    type NonceSize = A::NonceSize;
+    type TagSize = Sum<A::TagSize, A::NonceSize>;
+    type CiphertextOverhead = A::CiphertextOverhead;
+}
+
+impl<A> AeadInPlace for SivWrapper<A>
+where
+    A: NewAead + AeadInPlace,
+    A::NonceSize: IsLessOrEqual<U32, Output = True>,
+    A::TagSize: Add<A::NonceSize>,
+    Sum<A::TagSize, A::NonceSize>: ArrayLength<u8>,
+{
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &GenericArray<u8, A::NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<A::TagSize, A::NonceSize>, Error> {
+        let siv = self.siv(nonce, associated_data, buffer);
+        let aead_tag = self
+            .aead
+            .encrypt_in_place_detached(&siv, associated_data, buffer)?;
+
+        let mut tag = Tag::<A::TagSize, A::NonceSize>::default();
+        let (tag_aead, tag_siv) = tag.split_at_mut(A::TagSize::to_usize());
+        tag_aead.copy_from_slice(&aead_tag);
+        tag_siv.copy_from_slice(&siv);
+        Ok(tag)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &GenericArray<u8, A::NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<A::TagSize, A::NonceSize>,
+    ) -> Result<(), Error> {
+        let (tag_aead, tag_siv) = tag.split_at(A::TagSize::to_usize());
+        let siv = GenericArray::from_slice(tag_siv);
+
+        self.aead.decrypt_in_place_detached(
+            siv,
+            associated_data,
+            buffer,
+            GenericArray::from_slice(tag_aead),
+        )?;
+
+        // The message is authentic, but must also have been encrypted with
+        // this nonce
+        if self
+            .siv(nonce, associated_data, buffer)
+            .ct_eq(siv)
+            .unwrap_u8()
+            == 1
+        {
+            Ok(())
+        } else {
+            buffer.iter_mut().for_each(|b| *b = 0);
+            Err(Error)
+        }
+    }
+}
+
+impl<A> SivWrapper<A>
+where
+    A: NewAead + AeadInPlace,
+    A::NonceSize: IsLessOrEqual<U32, Output = True>,
+{
+    /// Compute the synthetic IV of a message
+    fn siv(
+        &self,
+        nonce: &GenericArray<u8, A::NonceSize>,
+        associated_data: &[u8],
+        msg: &[u8],
+    ) -> GenericArray<u8, A::NonceSize> {
+        let mut mac = self.mac.clone();
+        mac.update(&[0]);
+        mac.update(nonce);
+        mac.update(associated_data);
+        mac.update(msg);
+        mac.update(&(associated_data.len() as u64).to_be_bytes());
+        mac.update(&(msg.len() as u64).to_be_bytes());
+
+        let v = mac.finalize().into_bytes();
+        GenericArray::clone_from_slice(&v[..A::NonceSize::to_usize()])
+    }
+}
+
+impl<A> Clone for SivWrapper<A>
+where
+    A: NewAead + AeadInPlace + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            mac: self.mac.clone(),
+            aead: self.aead.clone(),
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! Tests of `SivWrapper`
+//!
+//! The expected ciphertexts were computed independently, with Python's
+//! `hmac` module and the AES-GCM and ChaCha20Poly1305 implementations of
+//! `cryptography`.
+
+#[macro_use]
+extern crate hex_literal;
+
+use aes_gcm::{Aes128Gcm, Aes256Gcm};
+use chacha20poly1305::ChaCha20Poly1305;
+use siv_wrapper::aead::{generic_array::GenericArray, Aead, AeadInPlace, NewAead, Payload};
+use siv_wrapper::SivWrapper;
+
+/// Nonce of all the test cases
+const NONCE: [u8; 12] = hex!("000102030405060708090a0b");
+
+/// Associated data of the second test case of each AEAD
+const AAD: &[u8] = b"associated data";
+
+/// Plaintext of the second test case of each AEAD
+const PLAINTEXT: &[u8] = b"In the beginning the Universe was created.";
+
+macro_rules! tests {
+    ($name:ident, $aead:ty, $key:expr, $empty:expr, $ciphertext:expr) => {
+        mod $name {
+            use super::*;
+
+            const KEY: &[u8] = &$key;
+
+            /// Ciphertext of the empty message with no associated data
+            const EMPTY: &[u8] = &$empty;
+
+            /// Ciphertext of `PLAINTEXT` with `AAD`
+            const CIPHERTEXT: &[u8] = &$ciphertext;
+
+            fn cipher() -> SivWrapper<$aead> {
+                SivWrapper::new(GenericArray::from_slice(KEY))
+            }
+
+            #[test]
+            fn encrypt() {
+                let nonce = NONCE.into();
+                assert_eq!(cipher().encrypt(&nonce, b"".as_ref()).unwrap(), EMPTY);
+
+                let payload = Payload {
+                    msg: PLAINTEXT,
+                    aad: AAD,
+                };
+                assert_eq!(cipher().encrypt(&nonce, payload).unwrap(), CIPHERTEXT);
+            }
+
+            #[test]
+            fn decrypt() {
+                let nonce = NONCE.into();
+                assert!(cipher().decrypt(&nonce, EMPTY).unwrap().is_empty());
+
+                let payload = Payload {
+                    msg: CIPHERTEXT,
+                    aad: AAD,
+                };
+                assert_eq!(cipher().decrypt(&nonce, payload).unwrap(), PLAINTEXT);
+            }
+
+            #[test]
+            fn decrypt_modified() {
+                let mut ciphertext = CIPHERTEXT.to_vec();
+
+                // Tweak the first byte of the ciphertext, the first byte of the
+                // wrapped AEAD's tag and the last byte of the synthetic IV
+                let tag_pos = PLAINTEXT.len();
+                for &i in &[0, tag_pos, ciphertext.len() - 1] {
+                    ciphertext[i] ^= 1;
+                    let payload = Payload {
+                        msg: &ciphertext,
+                        aad: AAD,
+                    };
+                    assert!(cipher().decrypt(&NONCE.into(), payload).is_err());
+                    ciphertext[i] ^= 1;
+                }
+            }
+
+            /// The nonce isn't an input of the wrapped AEAD, so it is only
+            /// authenticated by the synthetic IV
+            #[test]
+            fn decrypt_wrong_nonce() {
+                let mut nonce = NONCE;
+                nonce[0] ^= 1;
+
+                let payload = Payload {
+                    msg: CIPHERTEXT,
+                    aad: AAD,
+                };
+                assert!(cipher().decrypt(&nonce.into(), payload).is_err());
+            }
+
+            #[test]
+            fn detached() {
+                let nonce = NONCE.into();
+                let mut buffer = PLAINTEXT.to_vec();
+                let tag = cipher()
+                    .encrypt_in_place_detached(&nonce, AAD, &mut buffer)
+                    .unwrap();
+                assert_eq!(&buffer, &CIPHERTEXT[..PLAINTEXT.len()]);
+                assert_eq!(tag.as_slice(), &CIPHERTEXT[PLAINTEXT.len()..]);
+
+                cipher()
+                    .decrypt_in_place_detached(&nonce, AAD, &mut buffer, &tag)
+                    .unwrap();
+                assert_eq!(&buffer, PLAINTEXT);
+            }
+        }
+    };
+}
+
+tests!(
+    chacha20poly1305_ietf,
+    ChaCha20Poly1305,
+    hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+    hex!(
+        "967395cc8dd5deb2f35af096b80be609
+         5ae3d99f6bf3485cb3058f52"
+    ),
+    hex!(
+        "a051400b9d795ed995c94572c5cb62d05dc503b2512a112389ce4b8194292487
+         782fd8128f6ff5755e5e
+         a03a5383e308c000f247e85370f1510a
+         be22601d2bc3bcbc4c790024"
+    )
+);
+
+tests!(
+    aes128gcm,
+    Aes128Gcm,
+    hex!("000102030405060708090a0b0c0d0e0f"),
+    hex!(
+        "20491f7b4a7a70e5b37247f519fd569d
+         749078a5b9ff9666a1a47079"
+    ),
+    hex!(
+        "46ce06f1ad5b1141ed93010b390c636d836431644d3f99ae8da64ba7c957beea
+         a6c0215fcb62a08ac90f
+         2bb5e1e20b553bed48ce30d72df2dd09
+         1e397c828b8521d27a4bdcbd"
+    )
+);
+
+tests!(
+    aes256gcm,
+    Aes256Gcm,
+    hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+    hex!(
+        "bbd1aa552e6e9a0dc7268b4ead78fd8e
+         5ae3d99f6bf3485cb3058f52"
+    ),
+    hex!(
+        "ff385b8180dbedf8b5bf2c9cd1517cc8b4a0589613a219536cc7f76bbb98f077
+         22f2abd6e52b5007e649
+         4e7eb89403f3abf4480e020bd55b9f9d
+         be22601d2bc3bcbc4c790024"
+    )
+);
+
+/// Repeating a nonce only reveals repeated messages: different messages
+/// still get different synthetic IVs, and so different keystreams.
+#[test]
+fn repeated_nonce() {
+    let cipher = SivWrapper::<ChaCha20Poly1305>::new(&[0x42; 32].into());
+    let nonce = NONCE.into();
+
+    let a = cipher.encrypt(&nonce, b"attack at dawn".as_ref()).unwrap();
+    let b = cipher.encrypt(&nonce, b"attack at dusk".as_ref()).unwrap();
+    assert_ne!(a[14 + 16..], b[14 + 16..]);
+    assert_ne!(a[..10], b[..10]);
+
+    // Deterministic for the same inputs
+    let c = cipher.encrypt(&nonce, b"attack at dawn".as_ref()).unwrap();
+    assert_eq!(a, c);
+}
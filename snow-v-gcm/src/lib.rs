@@ -0,0 +1,235 @@
+//! [SNOW-V-GCM][1]: an [Authenticated Encryption with Associated Data (AEAD)][2]
+//! cipher combining the SNOW-V stream cipher with GHASH, following the
+//! structure of AES-GCM.
+//!
+//! SNOW-V is a 256-bit key stream cipher designed by Ekdahl, Johansson,
+//! Maximov and Yang for the 256-bit security level targeted by 5G, and is the
+//! basis of the SNOW 5G cipher suites considered by 3GPP. In SNOW-V-GCM, it is
+//! initialized in its AEAD mode with the key and 128-bit nonce: the first
+//! keystream block is the GHASH key `H`, the second one masks the tag, and
+//! the following ones encrypt the message.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! Simple usage (allocating, no associated data):
+//!
+//! ```
+//! use snow_v_gcm::{SnowVGcm, Key, Nonce};
+//! use snow_v_gcm::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = SnowVGcm::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"my unique nonce!"); // 128-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! Note that if you enable the `heapless` feature of this crate,
+//! you will receive an impl of [`aead::Buffer`] for `heapless::Vec`
+//! (re-exported from the [`aead`] crate as [`aead::heapless::Vec`]),
+//! which can then be passed as the `buffer` parameter to the in-place encrypt
+//! and decrypt methods:
+//!
+//! ```
+//! # #[cfg(feature = "heapless")]
+//! # {
+//! use snow_v_gcm::{SnowVGcm, Key, Nonce};
+//! use snow_v_gcm::aead::{AeadInPlace, NewAead};
+//! use snow_v_gcm::aead::heapless::Vec;
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = SnowVGcm::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"my unique nonce!"); // 128-bits; unique per message
+//!
+//! let mut buffer: Vec<u8, 128> = Vec::new(); // Buffer needs 16-bytes overhead for tag
+//! buffer.extend_from_slice(b"plaintext message");
+//!
+//! // Encrypt `buffer` in-place, replacing the plaintext contents with ciphertext
+//! cipher.encrypt_in_place(nonce, b"", &mut buffer).expect("encryption failure!");
+//!
+//! // `buffer` now contains the message ciphertext
+//! assert_ne!(&buffer, b"plaintext message");
+//!
+//! // Decrypt `buffer` in-place, replacing its ciphertext context with the original plaintext
+//! cipher.decrypt_in_place(nonce, b"", &mut buffer).expect("decryption failure!");
+//! assert_eq!(&buffer, b"plaintext message");
+//! # }
+//! ```
+//!
+//! [1]: https://tosc.iacr.org/index.php/ToSC/article/view/8356
+//! [2]: https://en.wikipedia.org/wiki/Authenticated_encryption
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![deny(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+mod snow_v;
+
+pub use aead::{self, consts, AeadCore, AeadInPlace, Error, NewAead};
+
+use crate::snow_v::SnowV;
+use aead::{
+    consts::{U0, U16, U32},
+    generic_array::GenericArray,
+};
+use ghash::{
+    universal_hash::{NewUniversalHash, UniversalHash},
+    GHash,
+};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// Maximum length of associated data: the GHASH length block encodes it in
+/// bits on 64 bits.
+pub const A_MAX: u64 = 1 << 61;
+
+/// Maximum length of plaintext
+pub const P_MAX: u64 = 1 << 61;
+
+/// Maximum length of ciphertext
+pub const C_MAX: u64 = (1 << 61) + 16;
+
+/// SNOW-V-GCM keys
+pub type Key = GenericArray<u8, U32>;
+
+/// SNOW-V-GCM nonces
+pub type Nonce = GenericArray<u8, U16>;
+
+/// SNOW-V-GCM tags
+pub type Tag = GenericArray<u8, U16>;
+
+/// SNOW-V-GCM: SNOW-V in AEAD mode, with GHASH authentication.
+#[derive(Clone)]
+pub struct SnowVGcm {
+    /// Secret key
+    key: [u8; 32],
+}
+
+impl NewAead for SnowVGcm {
+    type KeySize = U32;
+
+    fn new(key: &Key) -> Self {
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(key);
+        Self { key: bytes }
+    }
+}
+
+impl AeadCore for SnowVGcm {
+    type NonceSize = U16;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl AeadInPlace for SnowVGcm {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        if buffer.len() as u64 > P_MAX || associated_data.len() as u64 > A_MAX {
+            return Err(Error);
+        }
+
+        let (mut snow_v, ghash, mask) = self.init(nonce);
+        snow_v.apply_keystream(buffer);
+
+        Ok(compute_tag(ghash, &mask, associated_data, buffer))
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        if buffer.len() as u64 > C_MAX || associated_data.len() as u64 > A_MAX {
+            return Err(Error);
+        }
+
+        let (mut snow_v, ghash, mask) = self.init(nonce);
+        let expected_tag = compute_tag(ghash, &mask, associated_data, buffer);
+
+        if expected_tag.ct_eq(tag).into() {
+            snow_v.apply_keystream(buffer);
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+}
+
+impl SnowVGcm {
+    /// Initialize SNOW-V with `nonce`, returning it along with GHASH keyed by
+    /// its first keystream block, and the tag mask from the second one.
+    fn init(&self, nonce: &Nonce) -> (SnowV, GHash, [u8; 16]) {
+        let mut iv = [0u8; 16];
+        iv.copy_from_slice(nonce);
+        let mut snow_v = SnowV::new(&self.key, &iv);
+
+        let mut h = snow_v.keystream_block();
+        let ghash = GHash::new(ghash::Key::from_slice(&h));
+        h.zeroize();
+
+        let mask = snow_v.keystream_block();
+        (snow_v, ghash, mask)
+    }
+}
+
+impl Drop for SnowVGcm {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+/// `GHASH_H(A, C) ^ mask`
+fn compute_tag(mut ghash: GHash, mask: &[u8; 16], associated_data: &[u8], buffer: &[u8]) -> Tag {
+    ghash.update_padded(associated_data);
+    ghash.update_padded(buffer);
+
+    let associated_data_bits = (associated_data.len() as u64) * 8;
+    let buffer_bits = (buffer.len() as u64) * 8;
+
+    let mut block = ghash::Block::default();
+    block[..8].copy_from_slice(&associated_data_bits.to_be_bytes());
+    block[8..].copy_from_slice(&buffer_bits.to_be_bytes());
+    ghash.update(&block);
+
+    let mut tag = ghash.finalize().into_bytes();
+    for (a, b) in tag.iter_mut().zip(mask.iter()) {
+        *a ^= b;
+    }
+    tag
+}
@@ -0,0 +1,232 @@
+//! The SNOW-V stream cipher, initialized in AEAD mode.
+//!
+//! The two LFSRs are made of sixteen 16-bit cells each, `a0..a15` and
+//! `b0..b15`, which are updated eight steps at a time, and the FSM of three
+//! 128-bit registers updated with AES encryption rounds.
+
+use aes::{hazmat::cipher_round, Block};
+use zeroize::Zeroize;
+
+/// SNOW-V keystream blocks (128-bit)
+pub type KeystreamBlock = [u8; 16];
+
+/// Initial value of `(b7, ..., b0)` in AEAD mode: "AlexEkd JingThom"
+const AEAD_MODE: [u16; 8] = [
+    0x6c41, 0x7865, 0x6b45, 0x2064, 0x694a, 0x676e, 0x6854, 0x6d6f,
+];
+
+/// Byte permutation `σ` applied to `R1`
+const SIGMA: [usize; 16] = [0, 4, 8, 12, 1, 5, 9, 13, 2, 6, 10, 14, 3, 7, 11, 15];
+
+/// SNOW-V state
+pub(crate) struct SnowV {
+    /// LFSR-A: `a0..a15`
+    a: [u16; 16],
+
+    /// LFSR-B: `b0..b15`
+    b: [u16; 16],
+
+    /// FSM registers, as four 32-bit words each
+    r1: [u32; 4],
+    r2: [u32; 4],
+    r3: [u32; 4],
+}
+
+impl SnowV {
+    /// Initialize SNOW-V in AEAD mode with a 256-bit key and 128-bit IV.
+    pub(crate) fn new(key: &[u8; 32], iv: &[u8; 16]) -> Self {
+        let mut a = [0u16; 16];
+        let mut b = [0u16; 16];
+
+        load_u16s(&mut a[..8], iv);
+        load_u16s(&mut a[8..], &key[..16]);
+        b[..8].copy_from_slice(&AEAD_MODE);
+        load_u16s(&mut b[8..], &key[16..]);
+
+        let mut state = Self {
+            a,
+            b,
+            r1: [0; 4],
+            r2: [0; 4],
+            r3: [0; 4],
+        };
+
+        let mut k0 = [0u32; 4];
+        let mut k1 = [0u32; 4];
+        load_u32s(&mut k0, &key[..16]);
+        load_u32s(&mut k1, &key[16..]);
+
+        for t in 0..16 {
+            let z = state.keystream_block();
+            for (a, z) in state.a[8..].iter_mut().zip(z.chunks_exact(2)) {
+                *a ^= u16::from_le_bytes([z[0], z[1]]);
+            }
+
+            if t == 14 {
+                xor_words(&mut state.r1, &k0);
+            } else if t == 15 {
+                xor_words(&mut state.r1, &k1);
+            }
+        }
+
+        k0.zeroize();
+        k1.zeroize();
+        state
+    }
+
+    /// Generate the next keystream block.
+    pub(crate) fn keystream_block(&mut self) -> KeystreamBlock {
+        // z = (R1 +32 T1) ^ R2, with T1 = (b15, ..., b8)
+        let t1 = self.t1();
+        let mut z = [0u8; 16];
+        for (i, z) in z.chunks_exact_mut(4).enumerate() {
+            let word = self.r1[i].wrapping_add(t1[i]) ^ self.r2[i];
+            z.copy_from_slice(&word.to_le_bytes());
+        }
+
+        self.update_fsm();
+        self.update_lfsr();
+        z
+    }
+
+    /// XOR the keystream into `buffer`.
+    pub(crate) fn apply_keystream(&mut self, buffer: &mut [u8]) {
+        for chunk in buffer.chunks_mut(16) {
+            let z = self.keystream_block();
+            for (a, b) in chunk.iter_mut().zip(z.iter()) {
+                *a ^= b;
+            }
+        }
+    }
+
+    /// `T1 = (b15, ..., b8)`
+    fn t1(&self) -> [u32; 4] {
+        words(&self.b[8..])
+    }
+
+    /// `T2 = (a7, ..., a0)`
+    fn t2(&self) -> [u32; 4] {
+        words(&self.a[..8])
+    }
+
+    /// `R1 ← σ(R2 +32 (R3 ^ T2))`, `R3 ← AES^R(R2)`, `R2 ← AES^R(R1)`
+    fn update_fsm(&mut self) {
+        let t2 = self.t2();
+        let mut tmp = [0u32; 4];
+        for (i, tmp) in tmp.iter_mut().enumerate() {
+            *tmp = self.r2[i].wrapping_add(self.r3[i] ^ t2[i]);
+        }
+
+        self.r3 = aes_round(&self.r2);
+        self.r2 = aes_round(&self.r1);
+        self.r1 = sigma(&tmp);
+    }
+
+    /// Eight steps of both LFSRs:
+    ///
+    /// ```text
+    /// a16 = b0 + α·a0 + a1 + α⁻¹·a8 mod gA(α)
+    /// b16 = a0 + β·b0 + b3 + β⁻¹·b8 mod gB(β)
+    /// ```
+    fn update_lfsr(&mut self) {
+        let mut a = [0u16; 8];
+        let mut b = [0u16; 8];
+
+        for i in 0..8 {
+            a[i] = self.b[i]
+                ^ mul_x(self.a[i], 0x990f)
+                ^ self.a[i + 1]
+                ^ mul_x_inv(self.a[i + 8], 0xcc87);
+            b[i] = self.a[i]
+                ^ mul_x(self.b[i], 0xc963)
+                ^ self.b[i + 3]
+                ^ mul_x_inv(self.b[i + 8], 0xe4b1);
+        }
+
+        self.a.copy_within(8.., 0);
+        self.a[8..].copy_from_slice(&a);
+        self.b.copy_within(8.., 0);
+        self.b[8..].copy_from_slice(&b);
+    }
+}
+
+impl Drop for SnowV {
+    fn drop(&mut self) {
+        self.a.zeroize();
+        self.b.zeroize();
+        self.r1.zeroize();
+        self.r2.zeroize();
+        self.r3.zeroize();
+    }
+}
+
+/// Multiply by `α` (or `β`) in GF(2<sup>16</sup>), reducing by `poly`
+fn mul_x(v: u16, poly: u16) -> u16 {
+    (v << 1) ^ (0u16.wrapping_sub(v >> 15) & poly)
+}
+
+/// Multiply by `α⁻¹` (or `β⁻¹`) in GF(2<sup>16</sup>)
+fn mul_x_inv(v: u16, poly: u16) -> u16 {
+    (v >> 1) ^ (0u16.wrapping_sub(v & 1) & poly)
+}
+
+/// One AES encryption round with an all-zero round key
+fn aes_round(r: &[u32; 4]) -> [u32; 4] {
+    let mut block = Block::default();
+    for (chunk, word) in block.chunks_exact_mut(4).zip(r.iter()) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+
+    cipher_round(&mut block, &Block::default());
+
+    let mut out = [0u32; 4];
+    load_u32s(&mut out, &block);
+    block.as_mut_slice().zeroize();
+    out
+}
+
+/// Byte permutation `σ`
+fn sigma(r: &[u32; 4]) -> [u32; 4] {
+    let mut bytes = [0u8; 16];
+    for (chunk, word) in bytes.chunks_exact_mut(4).zip(r.iter()) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+
+    let mut permuted = [0u8; 16];
+    for (b, &i) in permuted.iter_mut().zip(SIGMA.iter()) {
+        *b = bytes[i];
+    }
+
+    let mut out = [0u32; 4];
+    load_u32s(&mut out, &permuted);
+    bytes.zeroize();
+    permuted.zeroize();
+    out
+}
+
+/// Pack eight 16-bit cells into four 32-bit words.
+fn words(cells: &[u16]) -> [u32; 4] {
+    let mut out = [0u32; 4];
+    for (word, pair) in out.iter_mut().zip(cells.chunks_exact(2)) {
+        *word = u32::from(pair[0]) | (u32::from(pair[1]) << 16);
+    }
+    out
+}
+
+fn load_u16s(out: &mut [u16], bytes: &[u8]) {
+    for (v, chunk) in out.iter_mut().zip(bytes.chunks_exact(2)) {
+        *v = u16::from_le_bytes([chunk[0], chunk[1]]);
+    }
+}
+
+fn load_u32s(out: &mut [u32], bytes: &[u8]) {
+    for (v, chunk) in out.iter_mut().zip(bytes.chunks_exact(4)) {
+        *v = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+}
+
+fn xor_words(out: &mut [u32; 4], words: &[u32; 4]) {
+    for (a, b) in out.iter_mut().zip(words.iter()) {
+        *a ^= b;
+    }
+}
@@ -0,0 +1,121 @@
+//! SNOW-V-GCM tests
+
+#[macro_use]
+extern crate hex_literal;
+
+use snow_v_gcm::aead::{Aead, AeadInPlace, NewAead, Payload};
+use snow_v_gcm::{Key, Nonce, SnowVGcm};
+
+/// SNOW-V-GCM test vector
+struct TestVector {
+    key: &'static [u8; 32],
+    nonce: &'static [u8; 16],
+    aad: &'static [u8],
+    plaintext: &'static [u8],
+    ciphertext: &'static [u8],
+}
+
+/// Test vectors from Appendix A of the SNOW-V paper
+///
+/// <https://tosc.iacr.org/index.php/ToSC/article/view/8356>
+const TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        key: &hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+        nonce: &hex!("00000000000000000000000000000000"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!("029a624cdaa4d46cb9a0ef4046956c9f"),
+    },
+    TestVector {
+        key: &hex!("505152535455565758595a5b5c5d5e5f0a1a2a3a4a5a6a7a8a9aaabacadaeafa"),
+        nonce: &hex!("0123456789abcdeffedcba9876543210"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!("fc7cac574c49feae6150315b9685424c"),
+    },
+    TestVector {
+        key: &hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+        nonce: &hex!("00000000000000000000000000000000"),
+        aad: &hex!("30313233343536373839616263646566"),
+        plaintext: &hex!(""),
+        ciphertext: &hex!("5a5aa5fbd635ef1ae129614203e10384"),
+    },
+    TestVector {
+        key: &hex!("505152535455565758595a5b5c5d5e5f0a1a2a3a4a5a6a7a8a9aaabacadaeafa"),
+        nonce: &hex!("0123456789abcdeffedcba9876543210"),
+        aad: &hex!("30313233343536373839616263646566"),
+        plaintext: &hex!(""),
+        ciphertext: &hex!("250ec8d77a022c087adf08b65adcbb1a"),
+    },
+    TestVector {
+        key: &hex!("505152535455565758595a5b5c5d5e5f0a1a2a3a4a5a6a7a8a9aaabacadaeafa"),
+        nonce: &hex!("0123456789abcdeffedcba9876543210"),
+        aad: &hex!(""),
+        plaintext: &hex!("30313233343536373839"),
+        ciphertext: &hex!("dd7e01b2b424a2ef8250ddfe4e31e7bfe6902331ec5ce319d90d"),
+    },
+    TestVector {
+        key: &hex!("505152535455565758595a5b5c5d5e5f0a1a2a3a4a5a6a7a8a9aaabacadaeafa"),
+        nonce: &hex!("0123456789abcdeffedcba9876543210"),
+        aad: &hex!("41414420746573742076616c756521"),
+        plaintext: &hex!("3031323334353637383961626364656620536e6f77562d41454144206d6f646521"),
+        ciphertext: &hex!("dd7e01b2b424a2ef82502707e87a32c152b0d01818fd7f12243eb5a15659e91b4c907ea6a5b73a51de747c3e9ad9ee029b"),
+    },
+];
+
+#[test]
+fn encrypt() {
+    for vector in TEST_VECTORS {
+        let cipher = SnowVGcm::new(Key::from_slice(vector.key));
+        let payload = Payload {
+            msg: vector.plaintext,
+            aad: vector.aad,
+        };
+
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(vector.nonce), payload)
+            .unwrap();
+        assert_eq!(vector.ciphertext, ciphertext.as_slice());
+    }
+}
+
+#[test]
+fn decrypt() {
+    for vector in TEST_VECTORS {
+        let cipher = SnowVGcm::new(Key::from_slice(vector.key));
+        let payload = Payload {
+            msg: vector.ciphertext,
+            aad: vector.aad,
+        };
+
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(vector.nonce), payload)
+            .unwrap();
+        assert_eq!(vector.plaintext, plaintext.as_slice());
+    }
+}
+
+#[test]
+fn decrypt_modified() {
+    let vector = &TEST_VECTORS[5];
+    let cipher = SnowVGcm::new(Key::from_slice(vector.key));
+    let nonce = Nonce::from_slice(vector.nonce);
+    let mut ciphertext = vector.ciphertext.to_vec();
+
+    // Tweak the first byte of the ciphertext and the last byte of the tag
+    for &i in &[0, ciphertext.len() - 1] {
+        ciphertext[i] ^= 1;
+        let payload = Payload {
+            msg: &ciphertext,
+            aad: vector.aad,
+        };
+        assert!(cipher.decrypt(nonce, payload).is_err());
+        ciphertext[i] ^= 1;
+    }
+
+    // The buffer is left untouched when decryption fails
+    let (msg, tag) = ciphertext.split_at_mut(vector.plaintext.len());
+    let result = cipher.decrypt_in_place_detached(nonce, b"", msg, (&*tag).into());
+    assert!(result.is_err());
+    assert_eq!(msg, &vector.ciphertext[..vector.plaintext.len()]);
+}
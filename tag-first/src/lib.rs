@@ -0,0 +1,243 @@
+//! Wrapper for [Authenticated Encryption with Associated Data (AEAD)][1]
+//! ciphers which puts the tag before the ciphertext instead of after it.
+//!
+//! Most AEADs append the tag to the ciphertext, but several formats put it
+//! first: e.g. the "combined mode" of libsodium's `crypto_secretbox_easy` and
+//! `crypto_box_easy`, and various legacy protocols. [`TagFirst`] wraps any
+//! AEAD so the ciphertexts of [`AeadInPlace::encrypt_in_place`] are laid out
+//! as `tag || ciphertext`, using the detached methods of the wrapped AEAD.
+//! [`TagFirst::encrypt_in_place_with_nonce`] also puts the nonce between the
+//! tag and ciphertext, as `tag || nonce || ciphertext`.
+//!
+//! The detached methods are those of the wrapped AEAD, so ciphertexts and
+//! tags are the same as its own: only their position in the buffer changes.
+//!
+//! # Usage
+//!
+//! ```
+//! use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+//! use tag_first::TagFirst;
+//! use tag_first::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = TagFirst::<ChaCha20Poly1305>::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce"); // 96-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! // The tag comes first
+//! let appended = ChaCha20Poly1305::new(key)
+//!     .encrypt(nonce, b"plaintext message".as_ref())
+//!     .unwrap();
+//! assert_eq!(&ciphertext[..16], &appended[17..]);
+//! assert_eq!(&ciphertext[16..], &appended[..17]);
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption, as do
+//! [`TagFirst::encrypt_in_place_with_nonce`] and
+//! [`TagFirst::decrypt_in_place_with_nonce`].
+//!
+//! [1]: https://en.wikipedia.org/wiki/Authenticated_encryption
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+pub use aead::{self, AeadCore, AeadInPlace, Error, NewAead};
+
+use aead::{
+    generic_array::{typenum::Unsigned, GenericArray},
+    Buffer, Key, Nonce, Tag,
+};
+
+/// AEAD wrapper which puts the tag of `A` before the ciphertext.
+///
+/// See the [toplevel documentation](index.html) for a usage example.
+#[derive(Clone, Debug)]
+pub struct TagFirst<A> {
+    /// Wrapped AEAD
+    aead: A,
+}
+
+impl<A> TagFirst<A> {
+    /// Get the wrapped AEAD
+    pub fn into_inner(self) -> A {
+        self.aead
+    }
+}
+
+impl<A> From<A> for TagFirst<A> {
+    fn from(aead: A) -> Self {
+        Self { aead }
+    }
+}
+
+impl<A: NewAead> NewAead for TagFirst<A> {
+    type KeySize = A::KeySize;
+
+    fn new(key: &Key<Self>) -> Self {
+        Self { aead: A::new(key) }
+    }
+}
+
+impl<A: AeadCore> AeadCore for TagFirst<A> {
+    type NonceSize = A::NonceSize;
+    type TagSize = A::TagSize;
+    type CiphertextOverhead = A::CiphertextOverhead;
+}
+
+impl<A: AeadInPlace> AeadInPlace for TagFirst<A> {
+    fn encrypt_in_place(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        self.encrypt_in_place_at(nonce, associated_data, buffer, 0)
+    }
+
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self>, Error> {
+        self.aead
+            .encrypt_in_place_detached(nonce, associated_data, buffer)
+    }
+
+    fn decrypt_in_place(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        self.decrypt_in_place_at(nonce, associated_data, buffer, 0)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self>,
+    ) -> Result<(), Error> {
+        self.aead
+            .decrypt_in_place_detached(nonce, associated_data, buffer, tag)
+    }
+}
+
+impl<A: AeadInPlace> TagFirst<A> {
+    /// Encrypt the given buffer containing a plaintext message in-place, and
+    /// lay it out as `tag || nonce || ciphertext`.
+    pub fn encrypt_in_place_with_nonce(
+        &self,
+        nonce: &Nonce<A>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        let nonce_size = A::NonceSize::to_usize();
+        self.encrypt_in_place_at(nonce, associated_data, buffer, nonce_size)?;
+
+        let tag_size = A::TagSize::to_usize();
+        buffer.as_mut()[tag_size..][..nonce_size].copy_from_slice(nonce);
+        Ok(())
+    }
+
+    /// Decrypt a `tag || nonce || ciphertext` buffer in-place, returning an
+    /// error in the event the tag does not match the nonce and ciphertext.
+    ///
+    /// On success, the buffer only contains the plaintext.
+    pub fn decrypt_in_place_with_nonce(
+        &self,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        let tag_size = A::TagSize::to_usize();
+        let nonce_size = A::NonceSize::to_usize();
+
+        if buffer.len() < tag_size + nonce_size {
+            return Err(Error);
+        }
+
+        let nonce = Nonce::<A>::clone_from_slice(&buffer.as_ref()[tag_size..][..nonce_size]);
+        self.decrypt_in_place_at(&nonce, associated_data, buffer, nonce_size)
+    }
+
+    /// Encrypt the plaintext of `buffer` in-place to `tag || gap ||
+    /// ciphertext`, where `gap` is `gap_size` bytes long, at most the nonce
+    /// size
+    fn encrypt_in_place_at(
+        &self,
+        nonce: &Nonce<A>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+        gap_size: usize,
+    ) -> Result<(), Error> {
+        let pt_len = buffer.len();
+        let offset = A::TagSize::to_usize() + gap_size;
+
+        // Make room in the buffer for the tag and gap. They need to be
+        // prepended.
+        buffer.extend_from_slice(&Tag::<A>::default())?;
+        buffer.extend_from_slice(&Nonce::<A>::default()[..gap_size])?;
+
+        buffer.as_mut().copy_within(..pt_len, offset);
+
+        let tag = self.aead.encrypt_in_place_detached(
+            nonce,
+            associated_data,
+            &mut buffer.as_mut()[offset..],
+        )?;
+        buffer.as_mut()[..tag.len()].copy_from_slice(&tag);
+        Ok(())
+    }
+
+    /// Decrypt a `tag || gap || ciphertext` buffer in-place, where `gap` is
+    /// `gap_size` bytes long
+    fn decrypt_in_place_at(
+        &self,
+        nonce: &Nonce<A>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+        gap_size: usize,
+    ) -> Result<(), Error> {
+        let tag_size = A::TagSize::to_usize();
+        let offset = tag_size + gap_size;
+
+        if buffer.len() < offset {
+            return Err(Error);
+        }
+
+        let tag = GenericArray::clone_from_slice(&buffer.as_ref()[..tag_size]);
+        self.aead.decrypt_in_place_detached(
+            nonce,
+            associated_data,
+            &mut buffer.as_mut()[offset..],
+            &tag,
+        )?;
+
+        let ct_len = buffer.len() - offset;
+        buffer.as_mut().copy_within(offset.., 0);
+        buffer.truncate(ct_len);
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+//! `TagFirst` tests
+//!
+//! The expected ciphertexts were computed independently with libsodium:
+//! `crypto_aead_chacha20poly1305_ietf_encrypt_detached`, with the tag moved to
+//! the front, and `crypto_secretbox_easy`, whose combined mode is tag-first.
+
+#[macro_use]
+extern crate hex_literal;
+
+use chacha20poly1305::ChaCha20Poly1305;
+use tag_first::aead::{generic_array::GenericArray, Aead, AeadInPlace, NewAead, Payload};
+use tag_first::TagFirst;
+use xsalsa20poly1305::XSalsa20Poly1305;
+
+/// ChaCha20Poly1305 test vector from RFC 8439 Section 2.8.2
+mod rfc8439 {
+    use super::*;
+
+    const KEY: [u8; 32] = hex!("808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f");
+
+    const NONCE: [u8; 12] = hex!("070000004041424344454647");
+
+    const AAD: &[u8] = &hex!("50515253c0c1c2c3c4c5c6c7");
+
+    const PLAINTEXT: &[u8] = b"Ladies and Gentlemen of the class of '99: \
+        If I could offer you only one tip for the future, sunscreen would be it.";
+
+    const TAG: [u8; 16] = hex!("1ae10b594f09e26a7e902ecbd0600691");
+
+    const CIPHERTEXT: &[u8] = &hex!(
+        "d31a8d34648e60db7b86afbc53ef7ec2a4aded51296e08fea9e2b5a736ee62d6
+         3dbea45e8ca9671282fafb69da92728b1a71de0a9e060b2905d6a5b67ecd3b36
+         92ddbd7f2d778b8c9803aee328091b58fab324e4fad675945585808b4831d7bc
+         3ff4def08e4b7a9de576d26586cec64b6116"
+    );
+
+    fn cipher() -> TagFirst<ChaCha20Poly1305> {
+        TagFirst::new(GenericArray::from_slice(&KEY))
+    }
+
+    fn tag_first() -> Vec<u8> {
+        let mut expected = TAG.to_vec();
+        expected.extend_from_slice(CIPHERTEXT);
+        expected
+    }
+
+    #[test]
+    fn encrypt() {
+        let payload = Payload {
+            msg: PLAINTEXT,
+            aad: AAD,
+        };
+        let ciphertext = cipher().encrypt(&NONCE.into(), payload).unwrap();
+        assert_eq!(ciphertext, tag_first());
+    }
+
+    #[test]
+    fn decrypt() {
+        let ciphertext = tag_first();
+        let payload = Payload {
+            msg: &ciphertext,
+            aad: AAD,
+        };
+        let plaintext = cipher().decrypt(&NONCE.into(), payload).unwrap();
+        assert_eq!(plaintext, PLAINTEXT);
+    }
+
+    #[test]
+    fn decrypt_modified() {
+        let mut ciphertext = tag_first();
+
+        // Tweak the first byte of the tag and of the ciphertext
+        for &i in &[0, TAG.len()] {
+            ciphertext[i] ^= 1;
+            let payload = Payload {
+                msg: &ciphertext,
+                aad: AAD,
+            };
+            assert!(cipher().decrypt(&NONCE.into(), payload).is_err());
+            ciphertext[i] ^= 1;
+        }
+
+        // Too short to hold a tag
+        let payload = Payload {
+            msg: &ciphertext[..TAG.len() - 1],
+            aad: AAD,
+        };
+        assert!(cipher().decrypt(&NONCE.into(), payload).is_err());
+    }
+
+    #[test]
+    fn detached() {
+        let mut buffer = PLAINTEXT.to_vec();
+        let tag = cipher()
+            .encrypt_in_place_detached(&NONCE.into(), AAD, &mut buffer)
+            .unwrap();
+        assert_eq!(buffer, CIPHERTEXT);
+        assert_eq!(tag.as_slice(), &TAG);
+
+        cipher()
+            .decrypt_in_place_detached(&NONCE.into(), AAD, &mut buffer, &tag)
+            .unwrap();
+        assert_eq!(buffer, PLAINTEXT);
+    }
+
+    #[test]
+    fn with_nonce() {
+        let mut expected = TAG.to_vec();
+        expected.extend_from_slice(&NONCE);
+        expected.extend_from_slice(CIPHERTEXT);
+
+        let mut buffer = PLAINTEXT.to_vec();
+        cipher()
+            .encrypt_in_place_with_nonce(&NONCE.into(), AAD, &mut buffer)
+            .unwrap();
+        assert_eq!(buffer, expected);
+
+        cipher()
+            .decrypt_in_place_with_nonce(AAD, &mut buffer)
+            .unwrap();
+        assert_eq!(buffer, PLAINTEXT);
+
+        // Tweak the first byte of the nonce
+        let mut modified = expected.clone();
+        modified[TAG.len()] ^= 1;
+        assert!(cipher()
+            .decrypt_in_place_with_nonce(AAD, &mut modified)
+            .is_err());
+
+        // Too short to hold a tag and nonce
+        let mut truncated = expected[..TAG.len() + NONCE.len() - 1].to_vec();
+        assert!(cipher()
+            .decrypt_in_place_with_nonce(AAD, &mut truncated)
+            .is_err());
+    }
+}
+
+/// libsodium's `crypto_secretbox_easy`
+mod secretbox {
+    use super::*;
+
+    const KEY: [u8; 32] = hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+
+    const NONCE: [u8; 24] = hex!("000102030405060708090a0b0c0d0e0f1011121314151617");
+
+    const PLAINTEXT: &[u8] = b"In the beginning the Universe was created.";
+
+    const BOX: &[u8] = &hex!(
+        "39e2398204d67eecf680b2ba66a8e332
+         1791183bafaf8272de5be65006e624f072d12ebaac70518661c715e730e7d680
+         f0fb96a720d18167dda3"
+    );
+
+    #[test]
+    fn encrypt() {
+        let cipher = TagFirst::<XSalsa20Poly1305>::new(GenericArray::from_slice(&KEY));
+        let ciphertext = cipher.encrypt(&NONCE.into(), PLAINTEXT).unwrap();
+        assert_eq!(ciphertext, BOX);
+    }
+
+    #[test]
+    fn decrypt() {
+        let cipher = TagFirst::<XSalsa20Poly1305>::new(GenericArray::from_slice(&KEY));
+        let plaintext = cipher.decrypt(&NONCE.into(), BOX).unwrap();
+        assert_eq!(plaintext, PLAINTEXT);
+    }
+}
@@ -0,0 +1,313 @@
+//! [Google Tink][1] output prefix wrapper for [Authenticated Encryption with
+//! Associated Data (AEAD)][2] ciphers, whose ciphertexts can be exchanged with
+//! Tink keysets.
+//!
+//! Tink identifies the key of a ciphertext by an [output prefix][3], and
+//! carries the nonce in the ciphertext. A [`TinkPrefix`] wrapper lays
+//! ciphertexts out the same way:
+//!
+//! ```text
+//! output prefix || nonce || ciphertext || tag
+//! ```
+//!
+//! where the [`OutputPrefix`] is set by the output prefix type of the key in
+//! the Tink keyset:
+//!
+//! - `TINK`: [`OutputPrefix::Tink`], i.e. `0x01` followed by the big-endian
+//!   key ID
+//! - `LEGACY` or `CRUNCHY`: [`OutputPrefix::Legacy`], i.e. `0x00` followed by
+//!   the big-endian key ID
+//! - `RAW`: [`OutputPrefix::Raw`], i.e. no prefix
+//!
+//! The associated data is passed to the wrapped AEAD unchanged. This is the
+//! layout of the Tink key types of the AEADs of this workspace:
+//!
+//! - `AesGcmKey`: `Aes128Gcm` and `Aes256Gcm`
+//! - `AesGcmSivKey`: `Aes128GcmSiv` and `Aes256GcmSiv`
+//! - `ChaCha20Poly1305Key`: `ChaCha20Poly1305`
+//! - `XChaCha20Poly1305Key`: `XChaCha20Poly1305`
+//!
+//! Tink generates a random nonce for each message: to interoperate with
+//! Tink applications encrypting with the same key, do the same.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed.
+//!
+//! The output prefix isn't authenticated, and it is not secret: it reveals
+//! which key encrypted each message.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! ```
+//! use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+//! use chacha20poly1305::aead::NewAead;
+//! use tink_prefix::{OutputPrefix, TinkPrefix};
+//!
+//! // The key with ID 0x12345678 of a Tink keyset
+//! let key = Key::from_slice(b"an example very very secret key.");
+//! let cipher = TinkPrefix::new(ChaCha20Poly1305::new(key), OutputPrefix::Tink(0x12345678));
+//!
+//! let nonce = Nonce::from_slice(b"random nonce"); // 96-bits; random as in Tink
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&ciphertext[..5], &[0x01, 0x12, 0x34, 0x56, 0x78]);
+//! assert_eq!(OutputPrefix::from_ciphertext(&ciphertext), Some(OutputPrefix::Tink(0x12345678)));
+//!
+//! // Nonces are read from the ciphertext
+//! let plaintext = cipher.decrypt(ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`TinkPrefix::encrypt_in_place`] and [`TinkPrefix::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! [1]: https://developers.google.com/tink
+//! [2]: https://en.wikipedia.org/wiki/Authenticated_encryption
+//! [3]: https://developers.google.com/tink/wire-format
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub use aead::{self, AeadCore, AeadInPlace, Error};
+
+use aead::{generic_array::typenum::Unsigned, Buffer, Nonce, Tag};
+
+#[cfg(feature = "alloc")]
+use {aead::Payload, alloc::vec::Vec};
+
+/// Size of the [`OutputPrefix::Tink`] and [`OutputPrefix::Legacy`] prefixes
+pub const PREFIX_SIZE: usize = 5;
+
+/// First byte of [`OutputPrefix::Tink`] prefixes
+const TINK_START_BYTE: u8 = 0x01;
+
+/// First byte of [`OutputPrefix::Legacy`] prefixes
+const LEGACY_START_BYTE: u8 = 0x00;
+
+/// Output prefix of the ciphertexts of a key in a Tink keyset, given by its
+/// output prefix type and key ID.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum OutputPrefix {
+    /// `TINK` output prefix type: `0x01` followed by the big-endian key ID
+    Tink(u32),
+
+    /// `LEGACY` or `CRUNCHY` output prefix type: `0x00` followed by the
+    /// big-endian key ID
+    Legacy(u32),
+
+    /// `RAW` output prefix type: no prefix
+    Raw,
+}
+
+impl OutputPrefix {
+    /// Read the prefix of a `TINK` or `LEGACY` ciphertext, e.g. to find its
+    /// key in a keyset.
+    ///
+    /// Returns `None` if the ciphertext doesn't start with either prefix, in
+    /// which case it may be a `RAW` ciphertext. Raw ciphertexts may also
+    /// happen to start with a valid prefix, so Tink tries to decrypt them
+    /// with the `RAW` keys of the keyset if no key matches their prefix.
+    pub fn from_ciphertext(ciphertext: &[u8]) -> Option<Self> {
+        if ciphertext.len() < PREFIX_SIZE {
+            return None;
+        }
+
+        let mut key_id = [0u8; 4];
+        key_id.copy_from_slice(&ciphertext[1..PREFIX_SIZE]);
+        let key_id = u32::from_be_bytes(key_id);
+
+        match ciphertext[0] {
+            TINK_START_BYTE => Some(OutputPrefix::Tink(key_id)),
+            LEGACY_START_BYTE => Some(OutputPrefix::Legacy(key_id)),
+            _ => None,
+        }
+    }
+
+    /// Key ID of the prefix, unless the key is `RAW`
+    pub fn key_id(&self) -> Option<u32> {
+        match self {
+            OutputPrefix::Tink(key_id) | OutputPrefix::Legacy(key_id) => Some(*key_id),
+            OutputPrefix::Raw => None,
+        }
+    }
+
+    /// Encode the prefix, returning the buffer and the length of its start
+    /// which holds the prefix
+    fn to_bytes(self) -> ([u8; PREFIX_SIZE], usize) {
+        let (start, key_id) = match self {
+            OutputPrefix::Tink(key_id) => (TINK_START_BYTE, key_id),
+            OutputPrefix::Legacy(key_id) => (LEGACY_START_BYTE, key_id),
+            OutputPrefix::Raw => return ([0; PREFIX_SIZE], 0),
+        };
+
+        let mut bytes = [start; PREFIX_SIZE];
+        bytes[1..].copy_from_slice(&key_id.to_be_bytes());
+        (bytes, PREFIX_SIZE)
+    }
+}
+
+/// Wrapper around an AEAD which lays out its ciphertexts like Tink, with an
+/// [`OutputPrefix`] and the nonce.
+///
+/// See the [toplevel documentation](index.html) for details of the layout and
+/// a usage example.
+///
+/// The nonce isn't an input of decryption, so [`AeadInPlace`] is not
+/// implemented.
+#[derive(Clone)]
+pub struct TinkPrefix<A>
+where
+    A: AeadInPlace,
+{
+    /// Wrapped AEAD
+    aead: A,
+
+    /// Output prefix of the key
+    prefix: OutputPrefix,
+}
+
+impl<A> TinkPrefix<A>
+where
+    A: AeadInPlace,
+{
+    /// Wrap an AEAD keyed with a key of a Tink keyset, which has the given
+    /// output prefix.
+    pub fn new(aead: A, prefix: OutputPrefix) -> Self {
+        Self { aead, prefix }
+    }
+
+    /// Output prefix of the key
+    pub fn prefix(&self) -> OutputPrefix {
+        self.prefix
+    }
+
+    /// Unwrap the AEAD
+    pub fn into_inner(self) -> A {
+        self.aead
+    }
+
+    /// Encrypt the given buffer containing a plaintext message in-place, and
+    /// lay it out as `output prefix || nonce || ciphertext || tag`.
+    pub fn encrypt_in_place(
+        &self,
+        nonce: &Nonce<A>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        let msg_len = buffer.len();
+        let (prefix, prefix_len) = self.prefix.to_bytes();
+        let header_len = prefix_len + A::NonceSize::to_usize();
+
+        // Make room in the buffer for the prefix and nonce, which need to be
+        // prepended, and the tag
+        let extended = buffer
+            .extend_from_slice(&prefix[..prefix_len])
+            .and_then(|()| buffer.extend_from_slice(nonce))
+            .and_then(|()| buffer.extend_from_slice(&Tag::<A>::default()));
+        if let Err(e) = extended {
+            buffer.truncate(msg_len);
+            return Err(e);
+        }
+
+        let buffer = buffer.as_mut();
+        buffer.copy_within(..msg_len, header_len);
+        buffer[..prefix_len].copy_from_slice(&prefix[..prefix_len]);
+        buffer[prefix_len..header_len].copy_from_slice(nonce);
+
+        let (msg, tag_out) = buffer[header_len..].split_at_mut(msg_len);
+        let tag = self
+            .aead
+            .encrypt_in_place_detached(nonce, associated_data, msg)?;
+        tag_out.copy_from_slice(&tag);
+        Ok(())
+    }
+
+    /// Decrypt an `output prefix || nonce || ciphertext || tag` buffer
+    /// in-place, returning an error if the prefix isn't that of the key, or
+    /// the tag does not match the nonce and ciphertext.
+    ///
+    /// On success, the buffer only contains the plaintext.
+    pub fn decrypt_in_place(
+        &self,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        let (prefix, prefix_len) = self.prefix.to_bytes();
+        let header_len = prefix_len + A::NonceSize::to_usize();
+        let tag_size = A::TagSize::to_usize();
+
+        if buffer.len() < header_len + tag_size
+            || buffer.as_ref()[..prefix_len] != prefix[..prefix_len]
+        {
+            return Err(Error);
+        }
+
+        let tag_pos = buffer.len() - tag_size;
+        let tag = Tag::<A>::clone_from_slice(&buffer.as_ref()[tag_pos..]);
+        let nonce = Nonce::<A>::clone_from_slice(&buffer.as_ref()[prefix_len..header_len]);
+
+        self.aead.decrypt_in_place_detached(
+            &nonce,
+            associated_data,
+            &mut buffer.as_mut()[header_len..tag_pos],
+            &tag,
+        )?;
+
+        buffer.as_mut().copy_within(header_len..tag_pos, 0);
+        buffer.truncate(tag_pos - header_len);
+        Ok(())
+    }
+
+    /// Encrypt the given plaintext payload, and return the resulting
+    /// `output prefix || nonce || ciphertext || tag` as a vector of bytes.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn encrypt<'msg, 'aad>(
+        &self,
+        nonce: &Nonce<A>,
+        plaintext: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<Vec<u8>, Error> {
+        let payload = plaintext.into();
+        let mut buffer = Vec::with_capacity(
+            PREFIX_SIZE + A::NonceSize::to_usize() + payload.msg.len() + A::TagSize::to_usize(),
+        );
+        buffer.extend_from_slice(payload.msg);
+        self.encrypt_in_place(nonce, payload.aad, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Decrypt the given `output prefix || nonce || ciphertext || tag`
+    /// payload, and return the resulting plaintext as a vector of bytes.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn decrypt<'msg, 'aad>(
+        &self,
+        ciphertext: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<Vec<u8>, Error> {
+        let payload = ciphertext.into();
+        let mut buffer = Vec::from(payload.msg);
+        self.decrypt_in_place(payload.aad, &mut buffer)?;
+        Ok(buffer)
+    }
+}
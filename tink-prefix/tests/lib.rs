@@ -0,0 +1,264 @@
+//! Tests of `TinkPrefix`, with ciphertexts of keys of Tink keysets
+
+#[macro_use]
+extern crate hex_literal;
+
+use aes_gcm::{Aes128Gcm, Aes256Gcm};
+use chacha20poly1305::{ChaCha20Poly1305, XChaCha20Poly1305};
+use tink_prefix::aead::{generic_array::typenum::Unsigned, AeadInPlace, Buffer, NewAead, Payload};
+use tink_prefix::{AeadCore, Error, OutputPrefix, TinkPrefix, PREFIX_SIZE};
+
+const PLAINTEXT: &[u8] = b"In the beginning the Universe was created.";
+const AAD: &[u8] = b"associated data";
+
+/// Key bytes `0x00..0x20` of the keysets
+const KEY: [u8; 32] = hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+
+/// Fixed-capacity buffer, for encryption failures
+struct ArrayBuffer {
+    bytes: [u8; 36],
+    len: usize,
+}
+
+impl Default for ArrayBuffer {
+    fn default() -> Self {
+        Self {
+            bytes: [0; 36],
+            len: 0,
+        }
+    }
+}
+
+impl AsRef<[u8]> for ArrayBuffer {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl AsMut<[u8]> for ArrayBuffer {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.bytes[..self.len]
+    }
+}
+
+impl Buffer for ArrayBuffer {
+    fn extend_from_slice(&mut self, other: &[u8]) -> Result<(), Error> {
+        let end = self.len + other.len();
+        if end > self.bytes.len() {
+            return Err(Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(other);
+        self.len = end;
+        Ok(())
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.len = self.len.min(len);
+    }
+}
+
+/// Test vectors
+#[derive(Debug)]
+pub struct TestVector {
+    pub prefix: OutputPrefix,
+    pub ciphertext: &'static [u8],
+}
+
+macro_rules! tests {
+    ($name:ident, $aead:ty, $key_size:expr, $vector:expr) => {
+        mod $name {
+            use super::*;
+
+            const VECTOR: TestVector = $vector;
+
+            fn cipher() -> TinkPrefix<$aead> {
+                let aead = <$aead>::new_from_slice(&KEY[..$key_size]).unwrap();
+                TinkPrefix::new(aead, VECTOR.prefix)
+            }
+
+            /// Encrypting with the nonce Tink picked gives its ciphertext
+            #[test]
+            fn encrypt() {
+                let cipher = cipher();
+                let prefix_len = match VECTOR.prefix {
+                    OutputPrefix::Raw => 0,
+                    _ => PREFIX_SIZE,
+                };
+                let nonce_len = <$aead as AeadCore>::NonceSize::to_usize();
+                let nonce = &VECTOR.ciphertext[prefix_len..prefix_len + nonce_len];
+
+                let payload = Payload {
+                    msg: PLAINTEXT,
+                    aad: AAD,
+                };
+                let ciphertext = cipher.encrypt(nonce.into(), payload).unwrap();
+                assert_eq!(VECTOR.ciphertext, ciphertext.as_slice());
+            }
+
+            #[test]
+            fn decrypt() {
+                let cipher = cipher();
+                let payload = Payload {
+                    msg: VECTOR.ciphertext,
+                    aad: AAD,
+                };
+                let plaintext = cipher.decrypt(payload).unwrap();
+                assert_eq!(PLAINTEXT, plaintext.as_slice());
+            }
+
+            #[test]
+            fn decrypt_in_place() {
+                let cipher = cipher();
+                let mut buffer = VECTOR.ciphertext.to_vec();
+                cipher.decrypt_in_place(AAD, &mut buffer).unwrap();
+                assert_eq!(PLAINTEXT, buffer.as_slice());
+            }
+
+            #[test]
+            fn decrypt_modified() {
+                let cipher = cipher();
+                let mut ciphertext = VECTOR.ciphertext.to_vec();
+
+                // Tweak every byte: of the prefix, nonce, ciphertext and tag
+                for i in 0..ciphertext.len() {
+                    ciphertext[i] ^= 0xaa;
+                    let mut buffer = ciphertext.clone();
+                    assert!(cipher.decrypt_in_place(AAD, &mut buffer).is_err());
+                    ciphertext[i] ^= 0xaa;
+                }
+
+                // Or the associated data
+                let mut buffer = ciphertext;
+                assert!(cipher.decrypt_in_place(b"", &mut buffer).is_err());
+            }
+
+            #[test]
+            fn decrypt_truncated() {
+                let cipher = cipher();
+
+                for len in 0..VECTOR.ciphertext.len() {
+                    let mut buffer = VECTOR.ciphertext[..len].to_vec();
+                    assert!(cipher.decrypt_in_place(AAD, &mut buffer).is_err());
+                }
+            }
+        }
+    };
+}
+
+tests!(
+    chacha20poly1305_ietf,
+    ChaCha20Poly1305,
+    32,
+    TestVector {
+        prefix: OutputPrefix::Tink(0x12345678),
+        ciphertext: &hex!("011234567883b0a8804856247b1054dd1c2f76367591106f45eb4f2b7b5ec7f6fa0f049e6f50f6f8fd95bbaa4903db08cd7718ec3d7d62068bd55506dab4a7b7ba7b12cd27c65c237ed8b0"),
+    }
+);
+
+tests!(
+    aes128gcm,
+    Aes128Gcm,
+    16,
+    TestVector {
+        prefix: OutputPrefix::Legacy(0x9abcdef0),
+        ciphertext: &hex!("009abcdef05818c1ea8c4a4e0910a25aeb6d383c037312d2c34d57f4d064204cd220eaf6e9e5f3fa7b4e2bdfd10d4f6e36e6af0ea62fc772e958191e64e4ac6e9c268687e0afcd68ac354c"),
+    }
+);
+
+tests!(
+    aes256gcm,
+    Aes256Gcm,
+    32,
+    TestVector {
+        prefix: OutputPrefix::Raw,
+        ciphertext: &hex!("3202bb482f71ce024f78309d448551d5843c05b6e299bcfe676ff86f5444b1c8d9b8e9ad8615a85a7ef3833e5e5c1fd02362a9582a60c8e8120998effb954984a4d8000a1657"),
+    }
+);
+
+tests!(
+    xchacha20poly1305,
+    XChaCha20Poly1305,
+    32,
+    TestVector {
+        prefix: OutputPrefix::Tink(42),
+        ciphertext: &hex!("010000002a4dae3698402355d224a2aa16aa97eef8b633378a77054576fc02209346753d1e57bda78dc67c78c0d5354b8190e22a4ff2ea9af639ea810e2c7f35d393dd01b378918c00ac8f29ef59731d4f6378d76c67c2"),
+    }
+);
+
+#[test]
+fn from_ciphertext() {
+    assert_eq!(
+        OutputPrefix::from_ciphertext(&hex!("0112345678ff")),
+        Some(OutputPrefix::Tink(0x12345678))
+    );
+    assert_eq!(
+        OutputPrefix::from_ciphertext(&hex!("009abcdef0")),
+        Some(OutputPrefix::Legacy(0x9abcdef0))
+    );
+    assert_eq!(OutputPrefix::from_ciphertext(&hex!("0212345678")), None);
+    assert_eq!(OutputPrefix::from_ciphertext(&hex!("01123456")), None);
+}
+
+#[test]
+fn key_id() {
+    assert_eq!(OutputPrefix::Tink(1).key_id(), Some(1));
+    assert_eq!(OutputPrefix::Legacy(2).key_id(), Some(2));
+    assert_eq!(OutputPrefix::Raw.key_id(), None);
+}
+
+/// The ciphertext of the inner AEAD follows the prefix and nonce
+#[test]
+fn layout() {
+    let aead = ChaCha20Poly1305::new(&KEY.into());
+    let cipher = TinkPrefix::new(aead.clone(), OutputPrefix::Legacy(7));
+    let nonce = b"random nonce".into();
+
+    let mut buffer = PLAINTEXT.to_vec();
+    cipher.encrypt_in_place(nonce, AAD, &mut buffer).unwrap();
+    assert_eq!(&buffer[..5], &hex!("0000000007"));
+    assert_eq!(&buffer[5..17], b"random nonce");
+
+    let mut inner = buffer[17..].to_vec();
+    aead.decrypt_in_place(nonce, AAD, &mut inner).unwrap();
+    assert_eq!(inner, PLAINTEXT);
+}
+
+/// Ciphertexts of another key are rejected, even with the same key bytes
+#[test]
+fn prefix_mismatch() {
+    let aead = ChaCha20Poly1305::new(&KEY.into());
+    let nonce = b"random nonce".into();
+
+    let ciphertext = TinkPrefix::new(aead.clone(), OutputPrefix::Tink(1))
+        .encrypt(nonce, PLAINTEXT)
+        .unwrap();
+
+    for &prefix in &[
+        OutputPrefix::Tink(2),
+        OutputPrefix::Legacy(1),
+        OutputPrefix::Raw,
+    ] {
+        let cipher = TinkPrefix::new(aead.clone(), prefix);
+        assert!(cipher.decrypt(ciphertext.as_slice()).is_err());
+    }
+}
+
+/// A buffer without room for the prefix, nonce and tag is left unchanged
+#[test]
+fn encrypt_in_place_full() {
+    let cipher = TinkPrefix::new(ChaCha20Poly1305::new(&KEY.into()), OutputPrefix::Tink(1));
+
+    // 5 + 12 + 16 bytes of overhead, so only up to 3 bytes of plaintext
+    let mut buffer = ArrayBuffer::default();
+    buffer.extend_from_slice(b"message").unwrap();
+    assert!(cipher
+        .encrypt_in_place(b"random nonce".into(), b"", &mut buffer)
+        .is_err());
+    assert_eq!(buffer.as_ref(), b"message");
+
+    let mut buffer = ArrayBuffer::default();
+    cipher
+        .encrypt_in_place(b"random nonce".into(), b"", &mut buffer)
+        .unwrap();
+    assert_eq!(buffer.as_ref().len(), 5 + 12 + 16);
+}
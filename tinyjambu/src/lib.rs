@@ -0,0 +1,236 @@
+//! [TinyJAMBU][1]: a lightweight [Authenticated Encryption with Associated
+//! Data (AEAD)][2] cipher built on a keyed nonlinear feedback shift register.
+//!
+//! TinyJAMBU was a finalist of the NIST lightweight cryptography process. Its
+//! state is only 128 bits, and the whole cipher consists of 32-bit shifts,
+//! AND, NOT and XOR, which makes it a good fit for microcontrollers with only
+//! a few kilobytes of flash.
+//!
+//! The following variants are provided, differing only in key size and
+//! number of permutation steps:
+//!
+//! - [`TinyJambu128`]: 128-bit key.
+//! - [`TinyJambu192`]: 192-bit key.
+//! - [`TinyJambu256`]: 256-bit key.
+//!
+//! The implementation never allocates, and keys can be set up in `const`
+//! contexts with e.g. [`TinyJambu128::from_key_bytes`].
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! TinyJAMBU has a 64-bit tag, so each forgery attempt succeeds with
+//! probability 2^-64. Applications which can't limit the number of decryption
+//! attempts should prefer an AEAD with a longer tag.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! ```
+//! use tinyjambu::{TinyJambu128, Key, Nonce};
+//! use tinyjambu::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = TinyJambu128::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce"); // 96-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! Note that if you enable the `heapless` feature of this crate,
+//! you will receive an impl of [`aead::Buffer`] for `heapless::Vec`
+//! (re-exported from the [`aead`] crate as [`aead::heapless::Vec`]),
+//! which can then be passed as the `buffer` parameter to the in-place encrypt
+//! and decrypt methods:
+//!
+//! ```
+//! # #[cfg(feature = "heapless")]
+//! # {
+//! use tinyjambu::{TinyJambu128, Nonce};
+//! use tinyjambu::aead::AeadInPlace;
+//! use tinyjambu::aead::heapless::Vec;
+//!
+//! // Keys can be set up at compile time
+//! static CIPHER: TinyJambu128 = TinyJambu128::from_key_bytes(b"very secret key.");
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce"); // 96-bits; unique per message
+//!
+//! let mut buffer: Vec<u8, 128> = Vec::new(); // Buffer needs 8-bytes overhead for the tag
+//! buffer.extend_from_slice(b"plaintext message");
+//!
+//! // Encrypt `buffer` in-place, replacing the plaintext contents with ciphertext
+//! CIPHER.encrypt_in_place(nonce, b"", &mut buffer).expect("encryption failure!");
+//!
+//! // `buffer` now contains the message ciphertext
+//! assert_ne!(&buffer, b"plaintext message");
+//!
+//! // Decrypt `buffer` in-place, replacing its ciphertext context with the original plaintext
+//! CIPHER.decrypt_in_place(nonce, b"", &mut buffer).expect("decryption failure!");
+//! assert_eq!(&buffer, b"plaintext message");
+//! # }
+//! ```
+//!
+//! [1]: https://csrc.nist.gov/projects/lightweight-cryptography/finalists
+//! [2]: https://en.wikipedia.org/wiki/Authenticated_encryption
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![deny(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use aead::{self, consts, AeadCore, AeadInPlace, Error, NewAead};
+
+mod permutation;
+mod state;
+
+use aead::{
+    consts::{U0, U12, U16, U24, U32, U8},
+    generic_array::GenericArray,
+};
+use state::State;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// TinyJAMBU keys (128-bit by default, or 192-bit and 256-bit)
+pub type Key<KeySize = U16> = GenericArray<u8, KeySize>;
+
+/// TinyJAMBU nonces (96-bit)
+pub type Nonce = GenericArray<u8, U12>;
+
+/// TinyJAMBU tags (64-bit)
+pub type Tag = GenericArray<u8, U8>;
+
+macro_rules! impl_tinyjambu {
+    ($name:ident, $key_size:ty, $key_len:expr, $rounds:expr, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone)]
+        pub struct $name {
+            /// Secret key as little-endian words
+            key: [u32; $key_len / 4],
+        }
+
+        impl $name {
+            /// Set up a key. Unlike [`NewAead::new`], this can be used in
+            /// `const` contexts.
+            pub const fn from_key_bytes(key: &[u8; $key_len]) -> Self {
+                let mut words = [0u32; $key_len / 4];
+                let mut i = 0;
+
+                while i < $key_len / 4 {
+                    words[i] = key[4 * i] as u32
+                        | (key[4 * i + 1] as u32) << 8
+                        | (key[4 * i + 2] as u32) << 16
+                        | (key[4 * i + 3] as u32) << 24;
+                    i += 1;
+                }
+
+                Self { key: words }
+            }
+        }
+
+        impl NewAead for $name {
+            type KeySize = $key_size;
+
+            fn new(key: &Key<$key_size>) -> Self {
+                let mut bytes = [0u8; $key_len];
+                bytes.copy_from_slice(key);
+                let cipher = Self::from_key_bytes(&bytes);
+                bytes.zeroize();
+                cipher
+            }
+        }
+
+        impl AeadCore for $name {
+            type NonceSize = U12;
+            type TagSize = U8;
+            type CiphertextOverhead = U0;
+        }
+
+        impl AeadInPlace for $name {
+            fn encrypt_in_place_detached(
+                &self,
+                nonce: &Nonce,
+                associated_data: &[u8],
+                buffer: &mut [u8],
+            ) -> Result<Tag, Error> {
+                let mut state = State::new(&self.key, $rounds, nonce);
+                state.absorb(associated_data);
+                state.encrypt(buffer);
+                Ok(state.finalize())
+            }
+
+            fn decrypt_in_place_detached(
+                &self,
+                nonce: &Nonce,
+                associated_data: &[u8],
+                buffer: &mut [u8],
+                tag: &Tag,
+            ) -> Result<(), Error> {
+                let mut state = State::new(&self.key, $rounds, nonce);
+                state.absorb(associated_data);
+                state.decrypt(buffer);
+                let expected_tag = state.finalize();
+
+                if expected_tag.ct_eq(tag).unwrap_u8() == 1 {
+                    Ok(())
+                } else {
+                    // Don't release unauthenticated plaintext
+                    buffer.iter_mut().for_each(|b| *b = 0);
+                    Err(Error)
+                }
+            }
+        }
+
+        impl Drop for $name {
+            fn drop(&mut self) {
+                self.key.zeroize();
+            }
+        }
+    };
+}
+
+impl_tinyjambu!(
+    TinyJambu128,
+    U16,
+    16,
+    1024,
+    "TinyJAMBU-128: 128-bit key, 96-bit nonce, 64-bit tag."
+);
+impl_tinyjambu!(
+    TinyJambu192,
+    U24,
+    24,
+    1152,
+    "TinyJAMBU-192: 192-bit key, 96-bit nonce, 64-bit tag."
+);
+impl_tinyjambu!(
+    TinyJambu256,
+    U32,
+    32,
+    1280,
+    "TinyJAMBU-256: 256-bit key, 96-bit nonce, 64-bit tag."
+);
@@ -0,0 +1,49 @@
+//! Keyed permutation of TinyJAMBU
+//!
+//! The permutation is a 128-bit nonlinear feedback shift register clocked
+//! `steps` times. Since no feedback bit depends on bits computed in the same
+//! 32 steps, the register is updated one 32-bit word at a time.
+
+/// Update the state with `steps` steps of the NLFSR, which must be a multiple
+/// of 128. Bit `i` of the key stream is bit `i mod 32` of
+/// `key[(i / 32) % key.len()]`.
+pub(crate) const fn state_update(mut s: [u32; 4], key: &[u32], steps: usize) -> [u32; 4] {
+    let mut k = 0;
+    let mut i = 0;
+
+    while i < steps {
+        s[0] ^= feedback(s[1], s[2], s[3], key[k]);
+        k = next(k, key.len());
+        s[1] ^= feedback(s[2], s[3], s[0], key[k]);
+        k = next(k, key.len());
+        s[2] ^= feedback(s[3], s[0], s[1], key[k]);
+        k = next(k, key.len());
+        s[3] ^= feedback(s[0], s[1], s[2], key[k]);
+        k = next(k, key.len());
+
+        i += 128;
+    }
+
+    s
+}
+
+/// Compute 32 feedback bits `s47 ^ ~(s70 & s85) ^ s91 ^ k` of the words
+/// following the one being updated
+#[inline(always)]
+const fn feedback(s1: u32, s2: u32, s3: u32, k: u32) -> u32 {
+    let t47 = (s1 >> 15) | (s2 << 17);
+    let t70 = (s2 >> 6) | (s3 << 26);
+    let t85 = (s2 >> 21) | (s3 << 11);
+    let t91 = (s2 >> 27) | (s3 << 5);
+    t47 ^ !(t70 & t85) ^ t91 ^ k
+}
+
+/// Index of the next key word
+#[inline(always)]
+const fn next(k: usize, len: usize) -> usize {
+    if k + 1 == len {
+        0
+    } else {
+        k + 1
+    }
+}
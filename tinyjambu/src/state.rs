@@ -0,0 +1,145 @@
+//! TinyJAMBU mode of operation
+
+use crate::{permutation::state_update, Nonce, Tag};
+use core::convert::TryInto;
+use zeroize::Zeroize;
+
+/// Number of steps of the short permutation `P_640`
+const ROUNDS_1: usize = 640;
+
+/// Frame bits of the nonce
+const FRAME_NONCE: u32 = 0x10;
+
+/// Frame bits of the associated data
+const FRAME_AD: u32 = 0x30;
+
+/// Frame bits of the plaintext and ciphertext
+const FRAME_MESSAGE: u32 = 0x50;
+
+/// Frame bits of the tag
+const FRAME_TAG: u32 = 0x70;
+
+/// Keyed TinyJAMBU state
+pub(crate) struct State<'k> {
+    s: [u32; 4],
+
+    /// Key as little-endian words
+    key: &'k [u32],
+
+    /// Number of steps of the long permutation, which depends on the key size
+    rounds_2: usize,
+}
+
+impl<'k> State<'k> {
+    /// Set up the key and absorb the nonce
+    pub(crate) fn new(key: &'k [u32], rounds_2: usize, nonce: &Nonce) -> Self {
+        let mut state = Self {
+            s: state_update([0; 4], key, rounds_2),
+            key,
+            rounds_2,
+        };
+
+        for word in nonce.chunks_exact(4) {
+            state.s[1] ^= FRAME_NONCE;
+            state.permute(ROUNDS_1);
+            state.s[3] ^= load(word);
+        }
+
+        state
+    }
+
+    /// Absorb the associated data
+    pub(crate) fn absorb(&mut self, associated_data: &[u8]) {
+        for chunk in associated_data.chunks(4) {
+            self.s[1] ^= FRAME_AD;
+            self.permute(ROUNDS_1);
+            self.s[3] ^= load_partial(chunk);
+
+            if chunk.len() < 4 {
+                self.s[1] ^= chunk.len() as u32;
+            }
+        }
+    }
+
+    /// Encrypt `buffer` in-place
+    pub(crate) fn encrypt(&mut self, buffer: &mut [u8]) {
+        for chunk in buffer.chunks_mut(4) {
+            self.s[1] ^= FRAME_MESSAGE;
+            self.permute(self.rounds_2);
+
+            let m = load_partial(chunk);
+            self.s[3] ^= m;
+            store_partial(chunk, self.s[2] ^ m);
+
+            if chunk.len() < 4 {
+                self.s[1] ^= chunk.len() as u32;
+            }
+        }
+    }
+
+    /// Decrypt `buffer` in-place
+    pub(crate) fn decrypt(&mut self, buffer: &mut [u8]) {
+        for chunk in buffer.chunks_mut(4) {
+            self.s[1] ^= FRAME_MESSAGE;
+            self.permute(self.rounds_2);
+
+            // Keystream bytes beyond the end of a partial block must not be
+            // absorbed with the plaintext
+            let mask = !0u32 >> (32 - 8 * chunk.len());
+            let m = (self.s[2] ^ load_partial(chunk)) & mask;
+            self.s[3] ^= m;
+            store_partial(chunk, m);
+
+            if chunk.len() < 4 {
+                self.s[1] ^= chunk.len() as u32;
+            }
+        }
+    }
+
+    /// Compute the 64-bit authentication tag
+    pub(crate) fn finalize(mut self) -> Tag {
+        let mut tag = Tag::default();
+
+        self.s[1] ^= FRAME_TAG;
+        self.permute(self.rounds_2);
+        tag[..4].copy_from_slice(&self.s[2].to_le_bytes());
+
+        self.s[1] ^= FRAME_TAG;
+        self.permute(ROUNDS_1);
+        tag[4..].copy_from_slice(&self.s[2].to_le_bytes());
+
+        tag
+    }
+
+    #[inline(always)]
+    fn permute(&mut self, steps: usize) {
+        self.s = state_update(self.s, self.key, steps);
+    }
+}
+
+impl Drop for State<'_> {
+    fn drop(&mut self) {
+        self.s.zeroize();
+    }
+}
+
+/// Load a little-endian 32-bit word
+#[inline(always)]
+fn load(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes(bytes.try_into().unwrap())
+}
+
+/// Load up to four bytes as a little-endian word padded with zeroes
+#[inline(always)]
+fn load_partial(bytes: &[u8]) -> u32 {
+    let mut word = [0u8; 4];
+    word[..bytes.len()].copy_from_slice(bytes);
+    u32::from_le_bytes(word)
+}
+
+/// Store the first `bytes.len()` bytes of a little-endian word
+#[inline(always)]
+fn store_partial(bytes: &mut [u8], word: u32) {
+    let len = bytes.len();
+    bytes.copy_from_slice(&word.to_le_bytes()[..len]);
+}
@@ -0,0 +1,144 @@
+//! TinyJAMBU tests
+
+#[macro_use]
+extern crate hex_literal;
+
+use tinyjambu::aead::{generic_array::GenericArray, Aead, NewAead, Payload};
+use tinyjambu::{TinyJambu128, TinyJambu192, TinyJambu256};
+
+// Known-answer tests in the format of the NIST LWC submissions
+aead::new_test!(tinyjambu128, "tinyjambu128", TinyJambu128);
+aead::new_test!(tinyjambu192, "tinyjambu192", TinyJambu192);
+aead::new_test!(tinyjambu256, "tinyjambu256", TinyJambu256);
+
+/// Test vectors
+#[derive(Debug)]
+pub struct TestVector {
+    pub key: &'static [u8; 16],
+    pub nonce: &'static [u8; 12],
+    pub aad: &'static [u8],
+    pub plaintext: &'static [u8],
+    pub ciphertext: &'static [u8],
+    pub tag: &'static [u8; 8],
+}
+
+/// TinyJAMBU-128 test vectors, selected from the known-answer tests to cover
+/// empty, partial and multi-block inputs
+const TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b"),
+        aad: &hex!(""),
+        plaintext: &hex!(""),
+        ciphertext: &hex!(""),
+        tag: &hex!("ed7b37cc6e9bdc7b"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b"),
+        aad: &hex!(""),
+        plaintext: &hex!("00"),
+        ciphertext: &hex!("47"),
+        tag: &hex!("959eb5dd7ddd745f"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b"),
+        aad: &hex!("00"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f"),
+        ciphertext: &hex!("a133304b3bbe0790c0842e6079c34849"),
+        tag: &hex!("2a34619cbf137bff"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b"),
+        aad: &hex!("000102030405060708090a0b0c0d0e"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f10111213141516"),
+        ciphertext: &hex!("f890838db2cd40097a2fef9800a5f318651185a2b56daf"),
+        tag: &hex!("537f4a91abbf27c5"),
+    },
+    TestVector {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        nonce: &hex!("000102030405060708090a0b"),
+        aad: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        plaintext: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        ciphertext: &hex!("bb28a2ff7eae50bb6388c5f5a82276e093bccd71add0f302b5597b9cef223d06"),
+        tag: &hex!("b8498ba24f4f03cb"),
+    },
+];
+
+#[test]
+fn encrypt() {
+    for vector in TEST_VECTORS {
+        let key = GenericArray::from_slice(vector.key);
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let payload = Payload {
+            msg: vector.plaintext,
+            aad: vector.aad,
+        };
+
+        let cipher = TinyJambu128::new(key);
+        let ciphertext = cipher.encrypt(nonce, payload).unwrap();
+        let (ct, tag) = ciphertext.split_at(vector.plaintext.len());
+        assert_eq!(vector.ciphertext, ct);
+        assert_eq!(vector.tag, tag);
+    }
+}
+
+#[test]
+fn decrypt() {
+    for vector in TEST_VECTORS {
+        let key = GenericArray::from_slice(vector.key);
+        let nonce = GenericArray::from_slice(vector.nonce);
+        let mut ciphertext = Vec::from(vector.ciphertext);
+        ciphertext.extend_from_slice(vector.tag);
+
+        let payload = Payload {
+            msg: &ciphertext,
+            aad: vector.aad,
+        };
+
+        let cipher = TinyJambu128::new(key);
+        let plaintext = cipher.decrypt(nonce, payload).unwrap();
+
+        assert_eq!(vector.plaintext, plaintext.as_slice());
+    }
+}
+
+#[test]
+fn decrypt_modified() {
+    let vector = &TEST_VECTORS[4];
+    let key = GenericArray::from_slice(vector.key);
+    let nonce = GenericArray::from_slice(vector.nonce);
+
+    let mut ciphertext = Vec::from(vector.ciphertext);
+    ciphertext.extend_from_slice(vector.tag);
+
+    // Tweak the first byte
+    ciphertext[0] ^= 0xaa;
+
+    let payload = Payload {
+        msg: &ciphertext,
+        aad: vector.aad,
+    };
+
+    let cipher = TinyJambu128::new(key);
+    assert!(cipher.decrypt(nonce, payload).is_err());
+}
+
+#[test]
+fn const_key_setup() {
+    const CIPHER: TinyJambu128 = TinyJambu128::from_key_bytes(TEST_VECTORS[4].key);
+
+    let vector = &TEST_VECTORS[4];
+    let nonce = GenericArray::from_slice(vector.nonce);
+    let payload = Payload {
+        msg: vector.plaintext,
+        aad: vector.aad,
+    };
+
+    let ciphertext = CIPHER.encrypt(nonce, payload).unwrap();
+    let (ct, tag) = ciphertext.split_at(vector.plaintext.len());
+    assert_eq!(vector.ciphertext, ct);
+    assert_eq!(vector.tag, tag);
+}
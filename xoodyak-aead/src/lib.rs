@@ -0,0 +1,315 @@
+//! [Xoodyak][1]: a lightweight [Authenticated Encryption with Associated
+//! Data (AEAD)][2] cipher built on the Xoodoo permutation, used here in the
+//! AEAD mode of the NIST lightweight cryptography submission.
+//!
+//! Xoodyak runs the Cyclist duplex construction on the 384-bit Xoodoo
+//! permutation, which only needs 32-bit bitwise operations and rotations. This
+//! makes it small and fast on 32-bit microcontrollers.
+//!
+//! ## Security Notes
+//!
+//! No security audits of this crate have ever been performed, and it has not
+//! been thoroughly assessed to ensure its operation is constant-time on common
+//! CPU architectures.
+//!
+//! USE AT YOUR OWN RISK!
+//!
+//! # Usage
+//!
+//! ```
+//! use xoodyak_aead::{Xoodyak, Key, Nonce};
+//! use xoodyak_aead::aead::{Aead, NewAead};
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = Xoodyak::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce 128"); // 128-bits; unique per message
+//!
+//! let ciphertext = cipher.encrypt(nonce, b"plaintext message".as_ref())
+//!     .expect("encryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! let plaintext = cipher.decrypt(nonce, ciphertext.as_ref())
+//!     .expect("decryption failure!"); // NOTE: handle this error to avoid panics!
+//!
+//! assert_eq!(&plaintext, b"plaintext message");
+//! ```
+//!
+//! ## In-place Usage (eliminates `alloc` requirement)
+//!
+//! This crate has an optional `alloc` feature which can be disabled in e.g.
+//! microcontroller environments that don't have a heap.
+//!
+//! The [`AeadInPlace::encrypt_in_place`] and [`AeadInPlace::decrypt_in_place`]
+//! methods accept any type that impls the [`aead::Buffer`] trait which
+//! contains the plaintext for encryption or ciphertext for decryption.
+//!
+//! Note that if you enable the `heapless` feature of this crate,
+//! you will receive an impl of [`aead::Buffer`] for `heapless::Vec`
+//! (re-exported from the [`aead`] crate as [`aead::heapless::Vec`]),
+//! which can then be passed as the `buffer` parameter to the in-place encrypt
+//! and decrypt methods:
+//!
+//! ```
+//! # #[cfg(feature = "heapless")]
+//! # {
+//! use xoodyak_aead::{Xoodyak, Key, Nonce};
+//! use xoodyak_aead::aead::{AeadInPlace, NewAead};
+//! use xoodyak_aead::aead::heapless::Vec;
+//!
+//! let key = Key::from_slice(b"very secret key.");
+//! let cipher = Xoodyak::new(key);
+//!
+//! let nonce = Nonce::from_slice(b"unique nonce 128"); // 128-bits; unique per message
+//!
+//! let mut buffer: Vec<u8, 128> = Vec::new(); // Buffer needs 16-bytes overhead for the tag
+//! buffer.extend_from_slice(b"plaintext message");
+//!
+//! // Encrypt `buffer` in-place, replacing the plaintext contents with ciphertext
+//! cipher.encrypt_in_place(nonce, b"", &mut buffer).expect("encryption failure!");
+//!
+//! // `buffer` now contains the message ciphertext
+//! assert_ne!(&buffer, b"plaintext message");
+//!
+//! // Decrypt `buffer` in-place, replacing its ciphertext context with the original plaintext
+//! cipher.decrypt_in_place(nonce, b"", &mut buffer).expect("decryption failure!");
+//! assert_eq!(&buffer, b"plaintext message");
+//! # }
+//! ```
+//!
+//! [1]: https://keccak.team/xoodyak.html
+//! [2]: https://en.wikipedia.org/wiki/Authenticated_encryption
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![deny(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use aead::{self, consts, AeadCore, AeadInPlace, Error, NewAead};
+
+mod xoodoo;
+
+use aead::{
+    consts::{U0, U16},
+    generic_array::GenericArray,
+};
+use core::cmp::min;
+use subtle::ConstantTimeEq;
+use xoodoo::{Xoodoo, STATE_SIZE};
+use zeroize::Zeroize;
+
+/// Xoodyak keys (128-bit)
+pub type Key = GenericArray<u8, U16>;
+
+/// Xoodyak nonces (128-bit)
+pub type Nonce = GenericArray<u8, U16>;
+
+/// Xoodyak tags (128-bit)
+pub type Tag = GenericArray<u8, U16>;
+
+/// Absorption rate in keyed mode
+const RATE_ABSORB: usize = 44;
+
+/// Squeezing (and encryption) rate in keyed mode
+const RATE_SQUEEZE: usize = 24;
+
+/// Color of the block absorbing the key and nonce
+const COLOR_KEY: u8 = 0x02;
+
+/// Color of the first block of associated data
+const COLOR_ABSORB: u8 = 0x03;
+
+/// Color of the first block of the message
+const COLOR_CRYPT: u8 = 0x80;
+
+/// Color of the squeezed tag
+const COLOR_SQUEEZE: u8 = 0x40;
+
+/// Xoodyak: 128-bit key, 128-bit nonce, 128-bit tag.
+///
+/// Messages are processed as `Cyclist(K, N, ε)`, `Absorb(A)`, `Encrypt(P)`
+/// and `Squeeze(16)`, i.e. the nonce is absorbed together with the key as its
+/// identifier, as in the final round of the NIST lightweight cryptography
+/// process.
+#[derive(Clone)]
+pub struct Xoodyak {
+    /// Secret key
+    key: Key,
+}
+
+impl NewAead for Xoodyak {
+    type KeySize = U16;
+
+    fn new(key: &Key) -> Self {
+        Self { key: *key }
+    }
+}
+
+impl AeadCore for Xoodyak {
+    type NonceSize = U16;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl AeadInPlace for Xoodyak {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        let mut cyclist = Cyclist::new(&self.key, nonce);
+        cyclist.absorb(associated_data, RATE_ABSORB, COLOR_ABSORB);
+        cyclist.encrypt(buffer);
+        Ok(cyclist.squeeze())
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        let mut cyclist = Cyclist::new(&self.key, nonce);
+        cyclist.absorb(associated_data, RATE_ABSORB, COLOR_ABSORB);
+        cyclist.decrypt(buffer);
+        let expected_tag = cyclist.squeeze();
+
+        if expected_tag.ct_eq(tag).unwrap_u8() == 1 {
+            Ok(())
+        } else {
+            // Don't release unauthenticated plaintext
+            buffer.iter_mut().for_each(|b| *b = 0);
+            Err(Error)
+        }
+    }
+}
+
+impl Drop for Xoodyak {
+    fn drop(&mut self) {
+        self.key.as_mut_slice().zeroize();
+    }
+}
+
+/// Cyclist duplex object in keyed mode
+struct Cyclist {
+    state: Xoodoo,
+
+    /// Whether the last call was `Up`
+    up: bool,
+}
+
+impl Cyclist {
+    /// Initialize the duplex with the key, using the nonce as key identifier
+    fn new(key: &Key, nonce: &Nonce) -> Self {
+        let mut cyclist = Self {
+            state: Xoodoo::new(),
+            up: true,
+        };
+
+        let mut block = [0u8; 33];
+        block[..16].copy_from_slice(key);
+        block[16..32].copy_from_slice(nonce);
+        block[32] = 16;
+        cyclist.absorb(&block, RATE_ABSORB, COLOR_KEY);
+        block.zeroize();
+
+        cyclist
+    }
+
+    /// Apply the permutation after adding the color `cu`
+    #[inline(always)]
+    fn up(&mut self, cu: u8) {
+        self.state.bytes[STATE_SIZE - 1] ^= cu;
+        self.state.permute();
+        self.up = true;
+    }
+
+    /// Pad the `len` bytes just added to the state and add the color `cd`
+    #[inline(always)]
+    fn down(&mut self, len: usize, cd: u8) {
+        self.state.bytes[len] ^= 0x01;
+        self.state.bytes[STATE_SIZE - 1] ^= cd;
+        self.up = false;
+    }
+
+    /// Absorb `data` in blocks of `rate` bytes, coloring the first one with
+    /// `cd`. Empty data is absorbed as a single empty block.
+    fn absorb(&mut self, data: &[u8], rate: usize, cd: u8) {
+        let mut cd = cd;
+        let mut rest = data;
+
+        loop {
+            let (block, tail) = rest.split_at(min(rate, rest.len()));
+
+            if !self.up {
+                self.up(0x00);
+            }
+
+            for (s, b) in self.state.bytes.iter_mut().zip(block) {
+                *s ^= b;
+            }
+            self.down(block.len(), cd);
+
+            cd = 0x00;
+            rest = tail;
+            if rest.is_empty() {
+                break;
+            }
+        }
+    }
+
+    /// Encrypt `buffer` in-place
+    fn encrypt(&mut self, buffer: &mut [u8]) {
+        self.crypt(buffer, |s, b| {
+            *s ^= *b;
+            *b = *s;
+        });
+    }
+
+    /// Decrypt `buffer` in-place
+    fn decrypt(&mut self, buffer: &mut [u8]) {
+        self.crypt(buffer, |s, b| {
+            let c = *b;
+            *b ^= *s;
+            *s = c;
+        });
+    }
+
+    /// Process `buffer` in blocks of the squeezing rate, an empty buffer being
+    /// processed as a single empty block. After `Up`, `f` is
+    /// called on each state and buffer byte and must leave the ciphertext in
+    /// the state, which is what absorbing the plaintext with `Down` does.
+    #[inline(always)]
+    fn crypt(&mut self, buffer: &mut [u8], f: impl Fn(&mut u8, &mut u8)) {
+        let mut cu = COLOR_CRYPT;
+        let mut rest = buffer;
+
+        loop {
+            let n = min(RATE_SQUEEZE, rest.len());
+            let (block, tail) = rest.split_at_mut(n);
+
+            self.up(cu);
+            for (s, b) in self.state.bytes.iter_mut().zip(block.iter_mut()) {
+                f(s, b);
+            }
+            self.down(n, 0x00);
+
+            cu = 0x00;
+            rest = tail;
+            if rest.is_empty() {
+                break;
+            }
+        }
+    }
+
+    /// Squeeze the authentication tag
+    fn squeeze(mut self) -> Tag {
+        self.up(COLOR_SQUEEZE);
+        Tag::clone_from_slice(&self.state.bytes[..16])
+    }
+}
@@ -0,0 +1,94 @@
+//! Xoodoo permutation
+
+use core::convert::TryInto;
+use zeroize::Zeroize;
+
+/// Size of the state in bytes
+pub(crate) const STATE_SIZE: usize = 48;
+
+/// Round constants of the 12 rounds
+const ROUND_CONSTANTS: [u32; 12] = [
+    0x058, 0x038, 0x3c0, 0x0d0, 0x120, 0x014, 0x060, 0x02c, 0x380, 0x0f0, 0x1a0, 0x012,
+];
+
+/// Xoodoo state: three planes of four 32-bit lanes, serialized in
+/// little-endian order
+pub(crate) struct Xoodoo {
+    pub(crate) bytes: [u8; STATE_SIZE],
+}
+
+impl Xoodoo {
+    /// Create an all-zero state
+    pub(crate) fn new() -> Self {
+        Self {
+            bytes: [0u8; STATE_SIZE],
+        }
+    }
+
+    /// Apply the 12-round permutation
+    pub(crate) fn permute(&mut self) {
+        let mut a = [0u32; 12];
+        for (lane, chunk) in a.iter_mut().zip(self.bytes.chunks_exact(4)) {
+            *lane = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+
+        for &rc in ROUND_CONSTANTS.iter() {
+            round(&mut a, rc);
+        }
+
+        for (lane, chunk) in a.iter().zip(self.bytes.chunks_exact_mut(4)) {
+            chunk.copy_from_slice(&lane.to_le_bytes());
+        }
+        a.zeroize();
+    }
+}
+
+impl Drop for Xoodoo {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+/// A single round on the lanes `a[x + 4 * y]`
+#[inline(always)]
+fn round(a: &mut [u32; 12], rc: u32) {
+    // θ: column parity mixing
+    let mut p = [0u32; 4];
+    for x in 0..4 {
+        p[x] = a[x] ^ a[x + 4] ^ a[x + 8];
+    }
+    for x in 0..4 {
+        let t = p[(x + 3) % 4];
+        let e = t.rotate_left(5) ^ t.rotate_left(14);
+        a[x] ^= e;
+        a[x + 4] ^= e;
+        a[x + 8] ^= e;
+    }
+
+    // ρ-west: shift plane 1 by one lane, rotate plane 2 by 11 bits
+    a[4..8].rotate_right(1);
+    for lane in a[8..].iter_mut() {
+        *lane = lane.rotate_left(11);
+    }
+
+    // ι
+    a[0] ^= rc;
+
+    // χ
+    for x in 0..4 {
+        let (a0, a1, a2) = (a[x], a[x + 4], a[x + 8]);
+        a[x] = a0 ^ (!a1 & a2);
+        a[x + 4] = a1 ^ (!a2 & a0);
+        a[x + 8] = a2 ^ (!a0 & a1);
+    }
+
+    // ρ-east: rotate plane 1 by one bit, shift plane 2 by two lanes and
+    // rotate it by 8 bits
+    for lane in a[4..8].iter_mut() {
+        *lane = lane.rotate_left(1);
+    }
+    a[8..].rotate_right(2);
+    for lane in a[8..].iter_mut() {
+        *lane = lane.rotate_left(8);
+    }
+}
@@ -82,6 +82,12 @@
 //! # }
 //! ```
 //!
+//! ## Salsa20Poly1305
+//!
+//! [`Salsa20Poly1305`] is the same construction on top of the plain Salsa20
+//! stream cipher with a 64-bit nonce, for interoperability with legacy
+//! NaCl-based wire formats which used `crypto_stream_salsa20` directly.
+//!
 //! [1]: https://nacl.cr.yp.to/secretbox.html
 //! [2]: https://en.wikipedia.org/wiki/Authenticated_encryption
 //! [3]: https://docs.rs/salsa20
@@ -99,17 +105,17 @@
 #![warn(missing_docs, rust_2018_idioms)]
 
 pub use aead;
-pub use salsa20::{Key, XNonce as Nonce};
+pub use salsa20::{Key, Nonce as Salsa20Nonce, XNonce as Nonce};
 
 use aead::{
-    consts::{U0, U16, U24, U32},
+    consts::{U0, U16, U24, U32, U8},
     generic_array::GenericArray,
     AeadCore, AeadInPlace, Buffer, Error, NewAead,
 };
 use poly1305::{universal_hash::NewUniversalHash, Poly1305};
 use salsa20::{
     cipher::{NewCipher, StreamCipher, StreamCipherSeek},
-    XSalsa20,
+    Salsa20, XSalsa20,
 };
 use zeroize::Zeroize;
 
@@ -122,6 +128,9 @@ pub const KEY_SIZE: usize = 32;
 /// Size of an XSalsa20Poly1305 nonce in bytes
 pub const NONCE_SIZE: usize = 24;
 
+/// Size of a Salsa20Poly1305 nonce in bytes
+pub const SALSA20_NONCE_SIZE: usize = 8;
+
 /// Size of a Poly1305 tag in bytes
 pub const TAG_SIZE: usize = 16;
 
@@ -150,100 +159,123 @@ pub struct XSalsa20Poly1305 {
     key: Key,
 }
 
-impl NewAead for XSalsa20Poly1305 {
-    type KeySize = U32;
-
-    fn new(key: &Key) -> Self {
-        XSalsa20Poly1305 { key: *key }
-    }
-}
-
-impl AeadCore for XSalsa20Poly1305 {
-    type NonceSize = U24;
-    type TagSize = U16;
-    type CiphertextOverhead = U0;
+/// **Salsa20Poly1305**: the `crypto_secretbox` construction on top of the
+/// plain Salsa20 stream cipher, with a 64-bit nonce.
+///
+/// This is what legacy NaCl-based wire formats which combined
+/// `crypto_stream_salsa20` and `crypto_onetimeauth_poly1305` directly
+/// produce: the tag is prepended to the ciphertext, just like with
+/// [`XSalsa20Poly1305`]. It is only provided for interoperability with them.
+///
+/// A 64-bit nonce is too short to be picked at random: use a counter, or
+/// [`XSalsa20Poly1305`] for new protocols.
+#[derive(Clone)]
+pub struct Salsa20Poly1305 {
+    /// Secret key
+    key: Key,
 }
 
-impl AeadInPlace for XSalsa20Poly1305 {
-    fn encrypt_in_place(
-        &self,
-        nonce: &Nonce,
-        associated_data: &[u8],
-        buffer: &mut dyn Buffer,
-    ) -> Result<(), Error> {
-        let pt_len = buffer.len();
-
-        // Make room in the buffer for the tag. It needs to be prepended.
-        buffer.extend_from_slice(Tag::default().as_slice())?;
-
-        // TODO(tarcieri): add offset param to `encrypt_in_place_detached`
-        buffer.as_mut().copy_within(..pt_len, TAG_SIZE);
-
-        let tag = self.encrypt_in_place_detached(
-            nonce,
-            associated_data,
-            &mut buffer.as_mut()[TAG_SIZE..],
-        )?;
-        buffer.as_mut()[..TAG_SIZE].copy_from_slice(tag.as_slice());
-        Ok(())
-    }
-
-    fn encrypt_in_place_detached(
-        &self,
-        nonce: &Nonce,
-        associated_data: &[u8],
-        buffer: &mut [u8],
-    ) -> Result<Tag, Error> {
-        Cipher::new(XSalsa20::new(&self.key, nonce))
-            .encrypt_in_place_detached(associated_data, buffer)
-    }
+macro_rules! impl_salsa20_poly1305 {
+    ($name:ident, $cipher:ident, $nonce_size:ident, $nonce:ident) => {
+        impl NewAead for $name {
+            type KeySize = U32;
 
-    fn decrypt_in_place(
-        &self,
-        nonce: &Nonce,
-        associated_data: &[u8],
-        buffer: &mut dyn Buffer,
-    ) -> Result<(), Error> {
-        if buffer.len() < TAG_SIZE {
-            return Err(Error);
+            fn new(key: &Key) -> Self {
+                $name { key: *key }
+            }
         }
 
-        let tag = Tag::clone_from_slice(&buffer.as_ref()[..TAG_SIZE]);
-        self.decrypt_in_place_detached(
-            nonce,
-            associated_data,
-            &mut buffer.as_mut()[TAG_SIZE..],
-            &tag,
-        )?;
-
-        let pt_len = buffer.len() - TAG_SIZE;
+        impl AeadCore for $name {
+            type NonceSize = $nonce_size;
+            type TagSize = U16;
+            type CiphertextOverhead = U0;
+        }
 
-        // TODO(tarcieri): add offset param to `encrypt_in_place_detached`
-        buffer.as_mut().copy_within(TAG_SIZE.., 0);
-        buffer.truncate(pt_len);
-        Ok(())
-    }
+        impl AeadInPlace for $name {
+            fn encrypt_in_place(
+                &self,
+                nonce: &$nonce,
+                associated_data: &[u8],
+                buffer: &mut dyn Buffer,
+            ) -> Result<(), Error> {
+                let pt_len = buffer.len();
+
+                // Make room in the buffer for the tag. It needs to be prepended.
+                buffer.extend_from_slice(Tag::default().as_slice())?;
+
+                // TODO(tarcieri): add offset param to `encrypt_in_place_detached`
+                buffer.as_mut().copy_within(..pt_len, TAG_SIZE);
+
+                let tag = self.encrypt_in_place_detached(
+                    nonce,
+                    associated_data,
+                    &mut buffer.as_mut()[TAG_SIZE..],
+                )?;
+                buffer.as_mut()[..TAG_SIZE].copy_from_slice(tag.as_slice());
+                Ok(())
+            }
+
+            fn encrypt_in_place_detached(
+                &self,
+                nonce: &$nonce,
+                associated_data: &[u8],
+                buffer: &mut [u8],
+            ) -> Result<Tag, Error> {
+                Cipher::new($cipher::new(&self.key, nonce))
+                    .encrypt_in_place_detached(associated_data, buffer)
+            }
+
+            fn decrypt_in_place(
+                &self,
+                nonce: &$nonce,
+                associated_data: &[u8],
+                buffer: &mut dyn Buffer,
+            ) -> Result<(), Error> {
+                if buffer.len() < TAG_SIZE {
+                    return Err(Error);
+                }
+
+                let tag = Tag::clone_from_slice(&buffer.as_ref()[..TAG_SIZE]);
+                self.decrypt_in_place_detached(
+                    nonce,
+                    associated_data,
+                    &mut buffer.as_mut()[TAG_SIZE..],
+                    &tag,
+                )?;
+
+                let pt_len = buffer.len() - TAG_SIZE;
+
+                // TODO(tarcieri): add offset param to `encrypt_in_place_detached`
+                buffer.as_mut().copy_within(TAG_SIZE.., 0);
+                buffer.truncate(pt_len);
+                Ok(())
+            }
+
+            fn decrypt_in_place_detached(
+                &self,
+                nonce: &$nonce,
+                associated_data: &[u8],
+                buffer: &mut [u8],
+                tag: &Tag,
+            ) -> Result<(), Error> {
+                Cipher::new($cipher::new(&self.key, nonce)).decrypt_in_place_detached(
+                    associated_data,
+                    buffer,
+                    tag,
+                )
+            }
+        }
 
-    fn decrypt_in_place_detached(
-        &self,
-        nonce: &Nonce,
-        associated_data: &[u8],
-        buffer: &mut [u8],
-        tag: &Tag,
-    ) -> Result<(), Error> {
-        Cipher::new(XSalsa20::new(&self.key, nonce)).decrypt_in_place_detached(
-            associated_data,
-            buffer,
-            tag,
-        )
-    }
+        impl Drop for $name {
+            fn drop(&mut self) {
+                self.key.as_mut_slice().zeroize();
+            }
+        }
+    };
 }
 
-impl Drop for XSalsa20Poly1305 {
-    fn drop(&mut self) {
-        self.key.as_mut_slice().zeroize();
-    }
-}
+impl_salsa20_poly1305!(XSalsa20Poly1305, XSalsa20, U24, Nonce);
+impl_salsa20_poly1305!(Salsa20Poly1305, Salsa20, U8, Salsa20Nonce);
 
 /// Salsa20Poly1305 instantiated with a particular nonce
 pub(crate) struct Cipher<C>
@@ -262,8 +294,8 @@ where
     pub(crate) fn new(mut cipher: C) -> Self {
         // Derive Poly1305 key from the first 32-bytes of the Salsa20 keystream
         let mut mac_key = poly1305::Key::default();
-        cipher.apply_keystream(&mut *mac_key);
-        let mac = Poly1305::new(GenericArray::from_slice(&*mac_key));
+        cipher.apply_keystream(&mut mac_key);
+        let mac = Poly1305::new(GenericArray::from_slice(&mac_key));
         mac_key.zeroize();
 
         Self { cipher, mac }
@@ -301,7 +333,7 @@ where
         let expected_tag = self.mac.compute_unpadded(buffer).into_bytes();
 
         // This performs a constant-time comparison using the `subtle` crate
-        if expected_tag.ct_eq(&tag).unwrap_u8() == 1 {
+        if expected_tag.ct_eq(tag).unwrap_u8() == 1 {
             self.cipher.apply_keystream(buffer);
             Ok(())
         } else {
@@ -0,0 +1,90 @@
+//! Salsa20Poly1305 test vectors.
+//!
+//! Generated with libsodium, as `crypto_secretbox_xsalsa20poly1305` with
+//! `crypto_stream_salsa20_xor` in place of `crypto_stream_xsalsa20_xor`.
+
+use xsalsa20poly1305::aead::generic_array::GenericArray;
+use xsalsa20poly1305::aead::{Aead, AeadInPlace, NewAead};
+use xsalsa20poly1305::{Salsa20Poly1305, XSalsa20Poly1305};
+
+const KEY: [u8; 32] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+];
+
+const NONCE: [u8; 8] = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+
+const PLAINTEXT: &[u8] = b"Legacy NaCl wire formats used crypto_stream_salsa20 directly.";
+
+const CIPHERTEXT: &[u8] = &[
+    0x65, 0xbd, 0x86, 0x97, 0xff, 0xde, 0xe3, 0x64, 0x2d, 0x69, 0xbb, 0x83, 0xe8, 0x15, 0x3b, 0xd1,
+    0x3b, 0xda, 0x62, 0x1e, 0x35, 0x20, 0xf7, 0x3b, 0x59, 0x0b, 0x94, 0xf3, 0x89, 0x1f, 0xee, 0xc0,
+    0xdd, 0xbe, 0x6a, 0x0f, 0x2b, 0x53, 0x1d, 0xe3, 0xc5, 0x84, 0x45, 0x87, 0x98, 0x97, 0xd8, 0x0e,
+    0xd8, 0x4a, 0x5f, 0x36, 0x86, 0x77, 0x0f, 0xff, 0xdb, 0xd8, 0x53, 0x9b, 0xc4, 0xed, 0x8d, 0xd6,
+    0xfa, 0xf0, 0x20, 0x44, 0x77, 0x71, 0xbe, 0xab, 0x0a, 0x39, 0x5c, 0x86, 0xaf,
+];
+
+const EMPTY_CIPHERTEXT: &[u8] = &[
+    0x2f, 0xdb, 0x44, 0xa8, 0x7b, 0x9c, 0xd8, 0xd2, 0x19, 0xe4, 0xec, 0x14, 0xae, 0xf9, 0xc6, 0xbc,
+];
+
+#[test]
+fn encrypt() {
+    let cipher = Salsa20Poly1305::new(&KEY.into());
+    let nonce = GenericArray::from_slice(&NONCE);
+
+    assert_eq!(
+        CIPHERTEXT,
+        cipher.encrypt(nonce, PLAINTEXT).unwrap().as_slice()
+    );
+    assert_eq!(
+        EMPTY_CIPHERTEXT,
+        cipher.encrypt(nonce, &b""[..]).unwrap().as_slice()
+    );
+}
+
+#[test]
+fn decrypt() {
+    let cipher = Salsa20Poly1305::new(&KEY.into());
+    let nonce = GenericArray::from_slice(&NONCE);
+
+    assert_eq!(
+        PLAINTEXT,
+        cipher.decrypt(nonce, CIPHERTEXT).unwrap().as_slice()
+    );
+    assert!(cipher.decrypt(nonce, EMPTY_CIPHERTEXT).unwrap().is_empty());
+}
+
+#[test]
+fn decrypt_modified() {
+    let cipher = Salsa20Poly1305::new(&KEY.into());
+    let nonce = GenericArray::from_slice(&NONCE);
+
+    let mut ciphertext = Vec::from(CIPHERTEXT);
+
+    // Tweak the first byte
+    ciphertext[0] ^= 0xaa;
+
+    assert!(cipher.decrypt(nonce, ciphertext.as_slice()).is_err());
+}
+
+/// XSalsa20Poly1305 is Salsa20Poly1305 keyed with `HSalsa20(key, nonce[..16])`,
+/// with `nonce[16..]` as its nonce.
+#[test]
+fn xsalsa20_equivalence() {
+    let nonce = *b"extra long unique nonce!";
+    let subkey = salsa20::hsalsa20(&KEY.into(), GenericArray::from_slice(&nonce[..16]));
+
+    let mut expected = PLAINTEXT.to_vec();
+    let expected_tag = XSalsa20Poly1305::new(&KEY.into())
+        .encrypt_in_place_detached(GenericArray::from_slice(&nonce), b"", &mut expected)
+        .unwrap();
+
+    let mut buffer = PLAINTEXT.to_vec();
+    let tag = Salsa20Poly1305::new(&subkey)
+        .encrypt_in_place_detached(GenericArray::from_slice(&nonce[16..]), b"", &mut buffer)
+        .unwrap();
+
+    assert_eq!(expected, buffer);
+    assert_eq!(expected_tag, tag);
+}